@@ -3,20 +3,57 @@ use criterion::{Criterion, criterion_group, criterion_main};
 use rust_decimal_macros::dec;
 
 use eth_btc_strategy::backtest::{EquityPoint, Trade, TradeExitReason, compute_metrics};
+use eth_btc_strategy::core::TradeDirection;
 
 fn bench_compute_metrics(c: &mut Criterion) {
     let trades = vec![
         Trade {
+            pair: None,
+            direction: TradeDirection::LongBaseShortQuote,
             entry_time: Utc.timestamp_opt(0, 0).unwrap(),
             exit_time: Utc.timestamp_opt(3600, 0).unwrap(),
+            base_qty: dec!(0),
+            quote_qty: dec!(0),
+            entry_base_price: dec!(0),
+            entry_quote_price: dec!(0),
+            base_price: dec!(0),
+            quote_price: dec!(0),
+            entry_zscore: None,
+            exit_zscore: None,
             pnl: dec!(10),
+            gross_pnl: dec!(0),
             exit_reason: TradeExitReason::TakeProfit,
+            notional: dec!(0),
+            fee: dec!(0),
+            slippage: dec!(0),
+            base_slippage_bps: None,
+            quote_slippage_bps: None,
+            funding: dec!(0),
+            holding_hours: dec!(0),
         },
         Trade {
+            pair: None,
+            direction: TradeDirection::LongBaseShortQuote,
             entry_time: Utc.timestamp_opt(7200, 0).unwrap(),
             exit_time: Utc.timestamp_opt(10800, 0).unwrap(),
+            base_qty: dec!(0),
+            quote_qty: dec!(0),
+            entry_base_price: dec!(0),
+            entry_quote_price: dec!(0),
+            base_price: dec!(0),
+            quote_price: dec!(0),
+            entry_zscore: None,
+            exit_zscore: None,
             pnl: dec!(-5),
+            gross_pnl: dec!(0),
             exit_reason: TradeExitReason::StopLoss,
+            notional: dec!(0),
+            fee: dec!(0),
+            slippage: dec!(0),
+            base_slippage_bps: None,
+            quote_slippage_bps: None,
+            funding: dec!(0),
+            holding_hours: dec!(0),
         },
     ];
     let equity = vec![
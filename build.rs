@@ -0,0 +1,20 @@
+use std::path::Path;
+use std::process::Command;
+
+/// Stamps the git revision into `ETH_BTC_GIT_REVISION` for backtest run manifests.
+fn main() {
+    for path in [".git/HEAD", ".git/refs/heads", ".git/packed-refs"] {
+        if Path::new(path).exists() {
+            println!("cargo:rerun-if-changed={path}");
+        }
+    }
+    let revision = Command::new("git")
+        .args(["rev-parse", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok());
+    if let Some(revision) = revision {
+        println!("cargo:rustc-env=ETH_BTC_GIT_REVISION={}", revision.trim());
+    }
+}
@@ -9,7 +9,7 @@ use rust_decimal::Decimal;
 use serde_json::Value;
 use thiserror::Error;
 
-use crate::config::Symbol;
+use crate::config::{PairConfig, Symbol};
 use crate::util::http::{HyperliquidHttpTimeouts, hyperliquid_reqwest_client};
 use crate::util::rate_limiter::{FixedRateLimiter, RateLimiter};
 
@@ -40,8 +40,8 @@ pub struct ExchangePosition {
 
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct PairExposure {
-    pub eth: Option<ExchangePosition>,
-    pub btc: Option<ExchangePosition>,
+    pub base: Option<ExchangePosition>,
+    pub quote: Option<ExchangePosition>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -57,28 +57,28 @@ pub struct ExchangeFill {
 }
 
 impl PairExposure {
-    pub fn eth_qty(&self) -> Decimal {
-        self.eth
+    pub fn base_qty(&self) -> Decimal {
+        self.base
             .as_ref()
             .map(|position| position.qty)
             .unwrap_or(Decimal::ZERO)
     }
 
-    pub fn btc_qty(&self) -> Decimal {
-        self.btc
+    pub fn quote_qty(&self) -> Decimal {
+        self.quote
             .as_ref()
             .map(|position| position.qty)
             .unwrap_or(Decimal::ZERO)
     }
 
     pub fn is_flat(&self) -> bool {
-        self.eth_qty() == Decimal::ZERO && self.btc_qty() == Decimal::ZERO
+        self.base_qty() == Decimal::ZERO && self.quote_qty() == Decimal::ZERO
     }
 
     pub fn has_residual(&self) -> bool {
-        let eth_zero = self.eth_qty() == Decimal::ZERO;
-        let btc_zero = self.btc_qty() == Decimal::ZERO;
-        (eth_zero && !btc_zero) || (!eth_zero && btc_zero)
+        let base_zero = self.base_qty() == Decimal::ZERO;
+        let quote_zero = self.quote_qty() == Decimal::ZERO;
+        (base_zero && !quote_zero) || (!base_zero && quote_zero)
     }
 }
 
@@ -160,6 +160,7 @@ pub struct HyperliquidAccountSource {
     user: String,
     http: Arc<dyn AccountHttpClient>,
     rate_limiter: Arc<dyn RateLimiter>,
+    pair: PairConfig,
 }
 
 impl HyperliquidAccountSource {
@@ -183,9 +184,15 @@ impl HyperliquidAccountSource {
             user: user.into(),
             http,
             rate_limiter,
+            pair: PairConfig::default(),
         }
     }
 
+    pub fn with_pair(mut self, pair: PairConfig) -> Self {
+        self.pair = pair;
+        self
+    }
+
     fn endpoint_url(&self) -> String {
         format!("{}/info", self.base_url.trim_end_matches('/'))
     }
@@ -279,23 +286,19 @@ impl HyperliquidAccountSource {
             let Some((coin, position)) = Self::parse_position(asset_position)? else {
                 continue;
             };
-            match coin {
-                "ETH" => exposure.eth = Some(position),
-                "BTC" => exposure.btc = Some(position),
-                _ => {}
+            match self.pair.symbol_for_coin(coin) {
+                Some(Symbol::Base) => exposure.base = Some(position),
+                Some(Symbol::Quote) => exposure.quote = Some(position),
+                None => {}
             }
         }
         Ok(exposure)
     }
 
-    fn parse_coin(value: &str) -> Result<Symbol, AccountError> {
-        match value {
-            "ETH" => Ok(Symbol::EthPerp),
-            "BTC" => Ok(Symbol::BtcPerp),
-            _ => Err(AccountError::InvalidResponse(format!(
-                "unsupported fill coin: {value}"
-            ))),
-        }
+    fn parse_coin(&self, value: &str) -> Result<Symbol, AccountError> {
+        self.pair
+            .symbol_for_coin(value)
+            .ok_or_else(|| AccountError::InvalidResponse(format!("unsupported fill coin: {value}")))
     }
 
     fn parse_optional_u64(value: Option<&Value>) -> Result<Option<u64>, AccountError> {
@@ -314,12 +317,12 @@ impl HyperliquidAccountSource {
         }
     }
 
-    fn parse_exchange_fill(value: &Value) -> Result<Option<ExchangeFill>, AccountError> {
+    fn parse_exchange_fill(&self, value: &Value) -> Result<Option<ExchangeFill>, AccountError> {
         let coin_raw = value
             .get("coin")
             .and_then(Value::as_str)
             .ok_or_else(|| AccountError::MissingData("fill.coin missing".to_string()))?;
-        let coin = match Self::parse_coin(coin_raw) {
+        let coin = match self.parse_coin(coin_raw) {
             Ok(coin) => coin,
             Err(AccountError::InvalidResponse(_)) => return Ok(None),
             Err(err) => return Err(err),
@@ -370,7 +373,7 @@ impl HyperliquidAccountSource {
         })?;
         let mut parsed = Vec::new();
         for fill in fills {
-            if let Some(fill) = Self::parse_exchange_fill(fill)? {
+            if let Some(fill) = self.parse_exchange_fill(fill)? {
                 parsed.push(fill);
             }
         }
@@ -8,7 +8,7 @@ use serde::Serialize;
 use serde_json::Value;
 use thiserror::Error;
 
-use crate::config::Symbol;
+use crate::config::{BarInterval, Symbol};
 
 #[derive(Debug, Error)]
 pub enum AnalysisError {
@@ -51,8 +51,8 @@ pub enum CycleKind {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum TradeDirection {
-    LongEthShortBtc,
-    ShortEthLongBtc,
+    LongBaseShortQuote,
+    ShortBaseLongQuote,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -154,6 +154,7 @@ pub struct RegimeStudyConfig {
     pub entry_z: Decimal,
     pub tp_z: Decimal,
     pub sl_z: Decimal,
+    pub bar_interval: BarInterval,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -179,6 +180,7 @@ pub struct RegimeSweepConfig {
     pub sl_z: Decimal,
     pub min_trades: usize,
     pub top_n: usize,
+    pub bar_interval: BarInterval,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -215,6 +217,7 @@ pub struct FundingCarryReplayConfig {
     pub min_net_edge_bps: Decimal,
     pub max_hold_hours: u32,
     pub funding_interval_hours: u32,
+    pub bar_interval: BarInterval,
 }
 
 #[derive(Debug, Clone)]
@@ -238,26 +241,26 @@ enum ReplayState {
 #[derive(Debug, Clone)]
 struct StatsReplayRow {
     timestamp: DateTime<Utc>,
-    eth_price: Decimal,
-    btc_price: Decimal,
+    base_price: Decimal,
+    quote_price: Decimal,
     zscore: Decimal,
-    w_eth: Decimal,
-    w_btc: Decimal,
-    funding_eth: Option<Decimal>,
-    funding_btc: Option<Decimal>,
+    w_base: Decimal,
+    w_quote: Decimal,
+    funding_base: Option<Decimal>,
+    funding_quote: Option<Decimal>,
     state: ReplayState,
 }
 
 #[derive(Debug, Clone)]
 struct RegimeStatsRow {
-    eth_price: Decimal,
-    btc_price: Decimal,
-    log_eth: f64,
-    log_btc: f64,
+    base_price: Decimal,
+    quote_price: Decimal,
+    log_base: f64,
+    log_quote: f64,
     fixed_spread: f64,
     zscore: Decimal,
-    w_eth: Decimal,
-    w_btc: Decimal,
+    w_base: Decimal,
+    w_quote: Decimal,
 }
 
 #[derive(Debug, Clone)]
@@ -267,19 +270,19 @@ struct RegimeEvaluatedRow {
     fixed_half_life: Option<f64>,
     residual_zscore: Option<Decimal>,
     residual_half_life: Option<f64>,
-    residual_w_eth: Option<Decimal>,
-    residual_w_btc: Option<Decimal>,
+    residual_w_base: Option<Decimal>,
+    residual_w_quote: Option<Decimal>,
     fixed_regime: String,
     residual_regime: String,
 }
 
 #[derive(Debug, Clone)]
 struct FilteredReplayRow {
-    eth_price: Decimal,
-    btc_price: Decimal,
+    base_price: Decimal,
+    quote_price: Decimal,
     zscore: Decimal,
-    w_eth: Decimal,
-    w_btc: Decimal,
+    w_base: Decimal,
+    w_quote: Decimal,
     entry_allowed: bool,
 }
 
@@ -432,11 +435,12 @@ pub fn replay_stats_log(
     content: &str,
     since: Option<DateTime<Utc>>,
     configs: &[ReplayStrategyConfig],
+    bar_interval: BarInterval,
 ) -> Result<StatsReplayReport, AnalysisError> {
     let rows = parse_stats_replay_rows(content, since)?;
     let strategies = configs
         .iter()
-        .map(|config| replay_strategy(&rows, config))
+        .map(|config| replay_strategy(&rows, config, bar_interval))
         .collect();
     Ok(StatsReplayReport {
         rows: rows.len(),
@@ -484,7 +488,7 @@ pub fn replay_funding_carry_stats_log(
         cooldown_recovery_bars: 0,
     };
     let strategies = vec![
-        replay_strategy(&rows, &price_only_config),
+        replay_strategy(&rows, &price_only_config, config.bar_interval),
         replay_funding_carry_strategy("funding_signed_carry", &rows, config, false),
         replay_funding_carry_strategy("funding_carry_gate", &rows, config, true),
     ];
@@ -539,6 +543,7 @@ pub fn study_residual_regimes(
             config.entry_z,
             config.tp_z,
             config.sl_z,
+            config.bar_interval,
         ),
         replay_filtered_strategy(
             "fixed_spread_half_life".to_string(),
@@ -546,6 +551,7 @@ pub fn study_residual_regimes(
             config.entry_z,
             config.tp_z,
             config.sl_z,
+            config.bar_interval,
         ),
         replay_filtered_strategy(
             "rolling_beta_residual".to_string(),
@@ -553,6 +559,7 @@ pub fn study_residual_regimes(
             config.entry_z,
             config.tp_z,
             config.sl_z,
+            config.bar_interval,
         ),
         replay_filtered_strategy(
             "rolling_beta_residual_half_life".to_string(),
@@ -560,6 +567,7 @@ pub fn study_residual_regimes(
             config.entry_z,
             config.tp_z,
             config.sl_z,
+            config.bar_interval,
         ),
     ];
 
@@ -642,6 +650,7 @@ pub fn sweep_residual_regime_parameters(
                 entry_z,
                 tp_z: config.tp_z,
                 sl_z: config.sl_z,
+                bar_interval: config.bar_interval,
             };
             let fixed_rows = build_fixed_regime_replay_rows(&evaluated, false, &base_config);
             let residual_rows = build_residual_regime_replay_rows(&evaluated, false, &base_config);
@@ -654,6 +663,7 @@ pub fn sweep_residual_regime_parameters(
                     entry_z,
                     config.tp_z,
                     config.sl_z,
+                    config.bar_interval,
                 ),
                 lookback_bars,
                 entry_z,
@@ -668,6 +678,7 @@ pub fn sweep_residual_regime_parameters(
                     entry_z,
                     config.tp_z,
                     config.sl_z,
+                    config.bar_interval,
                 ),
                 lookback_bars,
                 entry_z,
@@ -682,6 +693,7 @@ pub fn sweep_residual_regime_parameters(
                     entry_z,
                     tp_z: config.tp_z,
                     sl_z: config.sl_z,
+                    bar_interval: config.bar_interval,
                 };
                 let fixed_half_life_rows =
                     build_fixed_regime_replay_rows(&evaluated, true, &half_life_config);
@@ -696,6 +708,7 @@ pub fn sweep_residual_regime_parameters(
                         entry_z,
                         config.tp_z,
                         config.sl_z,
+                        config.bar_interval,
                     ),
                     lookback_bars,
                     entry_z,
@@ -710,6 +723,7 @@ pub fn sweep_residual_regime_parameters(
                         entry_z,
                         config.tp_z,
                         config.sl_z,
+                        config.bar_interval,
                     ),
                     lookback_bars,
                     entry_z,
@@ -862,8 +876,8 @@ fn parse_timestamp(row: usize, value: &str) -> Result<DateTime<Utc>, AnalysisErr
 
 fn parse_symbol(row: usize, value: &str) -> Result<Symbol, AnalysisError> {
     match value.trim().to_uppercase().as_str() {
-        "ETH" | "ETH-PERP" | "ETH_PERP" => Ok(Symbol::EthPerp),
-        "BTC" | "BTC-PERP" | "BTC_PERP" => Ok(Symbol::BtcPerp),
+        "BASE" | "ETH" | "ETH-PERP" | "ETH_PERP" => Ok(Symbol::Base),
+        "QUOTE" | "BTC" | "BTC-PERP" | "BTC_PERP" => Ok(Symbol::Quote),
         _ => Err(AnalysisError::InvalidSymbol {
             row,
             value: value.to_string(),
@@ -895,17 +909,17 @@ fn parse_decimal(row: usize, field: &'static str, value: &str) -> Result<Decimal
 fn reconstruct_cycles(rows: impl IntoIterator<Item = TradeHistoryRow>) -> Vec<TradeCycle> {
     let mut cycles = Vec::new();
     let mut current = Vec::new();
-    let mut eth_qty = Decimal::ZERO;
-    let mut btc_qty = Decimal::ZERO;
+    let mut base_qty = Decimal::ZERO;
+    let mut quote_qty = Decimal::ZERO;
 
     for row in rows {
         let delta = row.action.signed_delta(row.size);
         match row.coin {
-            Symbol::EthPerp => eth_qty += delta,
-            Symbol::BtcPerp => btc_qty += delta,
+            Symbol::Base => base_qty += delta,
+            Symbol::Quote => quote_qty += delta,
         }
         current.push(row);
-        if eth_qty == Decimal::ZERO && btc_qty == Decimal::ZERO && !current.is_empty() {
+        if base_qty == Decimal::ZERO && quote_qty == Decimal::ZERO && !current.is_empty() {
             cycles.push(build_cycle(&current, true));
             current.clear();
         }
@@ -924,18 +938,18 @@ fn build_cycle(rows: &[TradeHistoryRow], closed: bool) -> TradeCycle {
         .map(|row| row.timestamp)
         .unwrap_or_else(Utc::now);
     let end_time = rows.last().map(|row| row.timestamp).unwrap_or(start_time);
-    let mut eth_qty = Decimal::ZERO;
-    let mut btc_qty = Decimal::ZERO;
-    let mut saw_eth = false;
-    let mut saw_btc = false;
+    let mut base_qty = Decimal::ZERO;
+    let mut quote_qty = Decimal::ZERO;
+    let mut saw_base = false;
+    let mut saw_quote = false;
     let mut direction = None;
     let mut net_pnl = Decimal::ZERO;
     let mut fees = Decimal::ZERO;
     let mut open_notional = Decimal::ZERO;
 
     for row in rows {
-        saw_eth |= row.coin == Symbol::EthPerp;
-        saw_btc |= row.coin == Symbol::BtcPerp;
+        saw_base |= row.coin == Symbol::Base;
+        saw_quote |= row.coin == Symbol::Quote;
         net_pnl += row.closed_pnl;
         fees += row.fee;
         if row.action.is_open() {
@@ -943,17 +957,17 @@ fn build_cycle(rows: &[TradeHistoryRow], closed: bool) -> TradeCycle {
         }
         let delta = row.action.signed_delta(row.size);
         match row.coin {
-            Symbol::EthPerp => eth_qty += delta,
-            Symbol::BtcPerp => btc_qty += delta,
+            Symbol::Base => base_qty += delta,
+            Symbol::Quote => quote_qty += delta,
         }
         if direction.is_none() {
-            direction = detect_direction(eth_qty, btc_qty);
+            direction = detect_direction(base_qty, quote_qty);
         }
     }
 
     let kind = if direction.is_some() {
         CycleKind::Paired
-    } else if saw_eth ^ saw_btc {
+    } else if saw_base ^ saw_quote {
         CycleKind::SingleLeg
     } else {
         CycleKind::Unclassified
@@ -973,11 +987,11 @@ fn build_cycle(rows: &[TradeHistoryRow], closed: bool) -> TradeCycle {
     }
 }
 
-fn detect_direction(eth_qty: Decimal, btc_qty: Decimal) -> Option<TradeDirection> {
-    if eth_qty > Decimal::ZERO && btc_qty < Decimal::ZERO {
-        Some(TradeDirection::LongEthShortBtc)
-    } else if eth_qty < Decimal::ZERO && btc_qty > Decimal::ZERO {
-        Some(TradeDirection::ShortEthLongBtc)
+fn detect_direction(base_qty: Decimal, quote_qty: Decimal) -> Option<TradeDirection> {
+    if base_qty > Decimal::ZERO && quote_qty < Decimal::ZERO {
+        Some(TradeDirection::LongBaseShortQuote)
+    } else if base_qty < Decimal::ZERO && quote_qty > Decimal::ZERO {
+        Some(TradeDirection::ShortBaseLongQuote)
     } else {
         None
     }
@@ -1183,7 +1197,7 @@ fn median(mut values: Vec<f64>) -> Option<f64> {
     }
     values.sort_by(f64::total_cmp);
     let middle = values.len() / 2;
-    if values.len() % 2 == 0 {
+    if values.len().is_multiple_of(2) {
         Some((values[middle - 1] + values[middle]) / 2.0)
     } else {
         Some(values[middle])
@@ -1204,8 +1218,12 @@ fn half_life_allowed(half_life: Option<f64>, max_half_life_bars: f64) -> bool {
     half_life.is_some_and(|value| value <= max_half_life_bars)
 }
 
-const REPLAY_MAX_HOLD_BARS: usize = 48 * 4;
-const REPLAY_COOLDOWN_BARS: usize = 24 * 4;
+const REPLAY_MAX_HOLD_HOURS: i64 = 48;
+const REPLAY_COOLDOWN_HOURS: i64 = 24;
+
+fn replay_bars(hours: i64, bar_interval: BarInterval) -> usize {
+    (hours * 3600 / bar_interval.seconds()) as usize
+}
 
 fn replay_cost_bps() -> Decimal {
     Decimal::new(898, 2)
@@ -1248,11 +1266,11 @@ fn parse_stats_replay_row(
             message: "timestamp missing".to_string(),
         })
         .and_then(|value| parse_rfc3339_stats_timestamp(line, value))?;
-    let eth_price = match optional_decimal_field(payload, "eth_price", line)? {
+    let base_price = match optional_leg_field(payload, "base_price", "eth_price", line)? {
         Some(value) => value,
         None => return Ok(None),
     };
-    let btc_price = match optional_decimal_field(payload, "btc_price", line)? {
+    let quote_price = match optional_leg_field(payload, "quote_price", "btc_price", line)? {
         Some(value) => value,
         None => return Ok(None),
     };
@@ -1260,23 +1278,23 @@ fn parse_stats_replay_row(
         Some(value) => value,
         None => return Ok(None),
     };
-    let w_eth =
-        optional_decimal_field(payload, "w_eth", line)?.unwrap_or_else(|| Decimal::new(5, 1));
-    let w_btc =
-        optional_decimal_field(payload, "w_btc", line)?.unwrap_or_else(|| Decimal::new(5, 1));
-    let funding_eth = optional_decimal_field(payload, "funding_eth", line)?;
-    let funding_btc = optional_decimal_field(payload, "funding_btc", line)?;
+    let w_base =
+        optional_leg_field(payload, "w_base", "w_eth", line)?.unwrap_or_else(|| Decimal::new(5, 1));
+    let w_quote = optional_leg_field(payload, "w_quote", "w_btc", line)?
+        .unwrap_or_else(|| Decimal::new(5, 1));
+    let funding_base = optional_leg_field(payload, "funding_base", "funding_eth", line)?;
+    let funding_quote = optional_leg_field(payload, "funding_quote", "funding_btc", line)?;
     let state = parse_replay_state(payload.get("state"));
 
     Ok(Some(StatsReplayRow {
         timestamp,
-        eth_price,
-        btc_price,
+        base_price,
+        quote_price,
         zscore,
-        w_eth,
-        w_btc,
-        funding_eth,
-        funding_btc,
+        w_base,
+        w_quote,
+        funding_base,
+        funding_quote,
         state,
     }))
 }
@@ -1302,21 +1320,21 @@ fn parse_stats_regime_rows(
         if since.map(|since| row.timestamp < since).unwrap_or(false) {
             continue;
         }
-        let eth_price = decimal_to_positive_f64(row.eth_price, "eth_price", line_number)?;
-        let btc_price = decimal_to_positive_f64(row.btc_price, "btc_price", line_number)?;
-        let log_eth = eth_price.ln();
-        let log_btc = btc_price.ln();
+        let base_price = decimal_to_positive_f64(row.base_price, "base_price", line_number)?;
+        let quote_price = decimal_to_positive_f64(row.quote_price, "quote_price", line_number)?;
+        let log_base = base_price.ln();
+        let log_quote = quote_price.ln();
         rows_by_timestamp.insert(
             row.timestamp,
             RegimeStatsRow {
-                eth_price: row.eth_price,
-                btc_price: row.btc_price,
-                log_eth,
-                log_btc,
-                fixed_spread: log_eth - log_btc,
+                base_price: row.base_price,
+                quote_price: row.quote_price,
+                log_base,
+                log_quote,
+                fixed_spread: log_base - log_quote,
                 zscore: row.zscore,
-                w_eth: row.w_eth,
-                w_btc: row.w_btc,
+                w_base: row.w_base,
+                w_quote: row.w_quote,
             },
         );
     }
@@ -1332,6 +1350,20 @@ fn parse_rfc3339_stats_timestamp(line: usize, value: &str) -> Result<DateTime<Ut
         })
 }
 
+/// Reads a per-leg field, falling back to the `eth_*`/`btc_*` name bar logs used before
+/// the legs were renamed to base/quote.
+fn optional_leg_field(
+    payload: &Value,
+    field: &'static str,
+    legacy: &'static str,
+    line: usize,
+) -> Result<Option<Decimal>, AnalysisError> {
+    match optional_decimal_field(payload, field, line)? {
+        Some(value) => Ok(Some(value)),
+        None => optional_decimal_field(payload, legacy, line),
+    }
+}
+
 fn optional_decimal_field(
     payload: &Value,
     field: &'static str,
@@ -1383,21 +1415,21 @@ fn evaluate_regime_rows(rows: &[RegimeStatsRow], lookback_bars: usize) -> Vec<Re
             .collect::<Vec<_>>();
         let fixed_half_life = estimate_half_life_bars(&fixed_series);
         let regression = ols_alpha_beta(
-            &window.iter().map(|row| row.log_btc).collect::<Vec<_>>(),
-            &window.iter().map(|row| row.log_eth).collect::<Vec<_>>(),
+            &window.iter().map(|row| row.log_quote).collect::<Vec<_>>(),
+            &window.iter().map(|row| row.log_base).collect::<Vec<_>>(),
         );
-        let (beta, residual_zscore, residual_half_life, residual_w_eth, residual_w_btc) =
+        let (beta, residual_zscore, residual_half_life, residual_w_base, residual_w_quote) =
             if let Some((alpha, beta)) = regression {
                 let residuals = window
                     .iter()
-                    .map(|row| row.log_eth - (alpha + beta * row.log_btc))
+                    .map(|row| row.log_base - (alpha + beta * row.log_quote))
                     .collect::<Vec<_>>();
-                let current_residual = source.log_eth - (alpha + beta * source.log_btc);
+                let current_residual = source.log_base - (alpha + beta * source.log_quote);
                 let residual_zscore = standard_score(current_residual, &residuals)
                     .and_then(decimal_from_f64_for_analysis);
                 let residual_half_life = estimate_half_life_bars(&residuals);
                 let hedge_weight = beta.abs();
-                let (residual_w_eth, residual_w_btc) =
+                let (residual_w_base, residual_w_quote) =
                     if hedge_weight.is_finite() && hedge_weight > 0.0 {
                         (
                             decimal_from_f64_for_analysis(1.0 / (1.0 + hedge_weight)),
@@ -1410,8 +1442,8 @@ fn evaluate_regime_rows(rows: &[RegimeStatsRow], lookback_bars: usize) -> Vec<Re
                     Some(beta),
                     residual_zscore,
                     residual_half_life,
-                    residual_w_eth,
-                    residual_w_btc,
+                    residual_w_base,
+                    residual_w_quote,
                 )
             } else {
                 (None, None, None, None, None)
@@ -1424,8 +1456,8 @@ fn evaluate_regime_rows(rows: &[RegimeStatsRow], lookback_bars: usize) -> Vec<Re
             fixed_half_life,
             residual_zscore,
             residual_half_life,
-            residual_w_eth,
-            residual_w_btc,
+            residual_w_base,
+            residual_w_quote,
             fixed_regime,
             residual_regime,
         });
@@ -1440,11 +1472,11 @@ fn build_fixed_regime_replay_rows(
 ) -> Vec<FilteredReplayRow> {
     rows.iter()
         .map(|row| FilteredReplayRow {
-            eth_price: row.source.eth_price,
-            btc_price: row.source.btc_price,
+            base_price: row.source.base_price,
+            quote_price: row.source.quote_price,
             zscore: row.source.zscore,
-            w_eth: row.source.w_eth,
-            w_btc: row.source.w_btc,
+            w_base: row.source.w_base,
+            w_quote: row.source.w_quote,
             entry_allowed: !use_half_life_filter
                 || half_life_allowed(row.fixed_half_life, config.max_half_life_bars),
         })
@@ -1459,14 +1491,14 @@ fn build_residual_regime_replay_rows(
     rows.iter()
         .filter_map(|row| {
             let zscore = row.residual_zscore?;
-            let w_eth = row.residual_w_eth?;
-            let w_btc = row.residual_w_btc?;
+            let w_base = row.residual_w_base?;
+            let w_quote = row.residual_w_quote?;
             Some(FilteredReplayRow {
-                eth_price: row.source.eth_price,
-                btc_price: row.source.btc_price,
+                base_price: row.source.base_price,
+                quote_price: row.source.quote_price,
                 zscore,
-                w_eth,
-                w_btc,
+                w_base,
+                w_quote,
                 entry_allowed: !use_half_life_filter
                     || half_life_allowed(row.residual_half_life, config.max_half_life_bars),
             })
@@ -1480,6 +1512,7 @@ fn replay_filtered_strategy(
     entry_z: Decimal,
     tp_z: Decimal,
     sl_z: Decimal,
+    bar_interval: BarInterval,
 ) -> StatsReplaySummary {
     let mut position: Option<FilteredOpenPosition> = None;
     let mut trades = Vec::new();
@@ -1489,7 +1522,8 @@ fn replay_filtered_strategy(
     for (idx, row) in rows.iter().enumerate() {
         let mut just_exited_stop = false;
         if let Some(open) = position.as_ref()
-            && let Some(reason) = filtered_replay_exit_reason(open, row, idx, tp_z, sl_z)
+            && let Some(reason) =
+                filtered_replay_exit_reason(open, row, idx, tp_z, sl_z, bar_interval)
         {
             trades.push(ReplayTrade {
                 direction: open.direction,
@@ -1499,7 +1533,7 @@ fn replay_filtered_strategy(
             });
             position = None;
             if reason == "SL" {
-                cooldown_until = Some(idx + REPLAY_COOLDOWN_BARS);
+                cooldown_until = Some(idx + replay_bars(REPLAY_COOLDOWN_HOURS, bar_interval));
                 just_exited_stop = true;
             }
         }
@@ -1527,9 +1561,9 @@ fn replay_filtered_strategy(
                 entry_index: idx,
                 entry: row.clone(),
                 direction: if row.zscore >= Decimal::ZERO {
-                    TradeDirection::ShortEthLongBtc
+                    TradeDirection::ShortBaseLongQuote
                 } else {
-                    TradeDirection::LongEthShortBtc
+                    TradeDirection::LongBaseShortQuote
                 },
                 source: "cross",
             });
@@ -1547,13 +1581,16 @@ fn filtered_replay_exit_reason(
     idx: usize,
     tp_z: Decimal,
     sl_z: Decimal,
+    bar_interval: BarInterval,
 ) -> Option<&'static str> {
     let abs_z = row.zscore.abs();
     if abs_z <= tp_z {
         Some("TP")
     } else if abs_z >= sl_z {
         Some("SL")
-    } else if idx.saturating_sub(open.entry_index) >= REPLAY_MAX_HOLD_BARS {
+    } else if idx.saturating_sub(open.entry_index)
+        >= replay_bars(REPLAY_MAX_HOLD_HOURS, bar_interval)
+    {
         Some("TIME")
     } else {
         None
@@ -1561,20 +1598,24 @@ fn filtered_replay_exit_reason(
 }
 
 fn filtered_replay_trade_net_bps(open: &FilteredOpenPosition, exit: &FilteredReplayRow) -> Decimal {
-    let eth_return = exit.eth_price / open.entry.eth_price - Decimal::ONE;
-    let btc_return = exit.btc_price / open.entry.btc_price - Decimal::ONE;
+    let base_return = exit.base_price / open.entry.base_price - Decimal::ONE;
+    let quote_return = exit.quote_price / open.entry.quote_price - Decimal::ONE;
     let gross = match open.direction {
-        TradeDirection::LongEthShortBtc => {
-            open.entry.w_eth * eth_return - open.entry.w_btc * btc_return
+        TradeDirection::LongBaseShortQuote => {
+            open.entry.w_base * base_return - open.entry.w_quote * quote_return
         }
-        TradeDirection::ShortEthLongBtc => {
-            -open.entry.w_eth * eth_return + open.entry.w_btc * btc_return
+        TradeDirection::ShortBaseLongQuote => {
+            -open.entry.w_base * base_return + open.entry.w_quote * quote_return
         }
     };
     gross * Decimal::from(10_000u32) - replay_cost_bps()
 }
 
-fn replay_strategy(rows: &[StatsReplayRow], config: &ReplayStrategyConfig) -> StatsReplaySummary {
+fn replay_strategy(
+    rows: &[StatsReplayRow],
+    config: &ReplayStrategyConfig,
+    bar_interval: BarInterval,
+) -> StatsReplaySummary {
     let mut position: Option<ReplayOpenPosition> = None;
     let mut trades = Vec::new();
     let mut prev_z = None;
@@ -1584,19 +1625,19 @@ fn replay_strategy(rows: &[StatsReplayRow], config: &ReplayStrategyConfig) -> St
 
     for (idx, row) in rows.iter().enumerate() {
         let mut just_exited_stop = false;
-        if let Some(open) = position.as_ref() {
-            if let Some(reason) = replay_exit_reason(open, row, idx, config) {
-                trades.push(ReplayTrade {
-                    direction: open.direction,
-                    source: open.source,
-                    exit_reason: reason,
-                    net_bps: replay_trade_net_bps(open, row),
-                });
-                position = None;
-                if reason == "SL" {
-                    cooldown_until = Some(idx + REPLAY_COOLDOWN_BARS);
-                    just_exited_stop = true;
-                }
+        if let Some(open) = position.as_ref()
+            && let Some(reason) = replay_exit_reason(open, row, idx, config, bar_interval)
+        {
+            trades.push(ReplayTrade {
+                direction: open.direction,
+                source: open.source,
+                exit_reason: reason,
+                net_bps: replay_trade_net_bps(open, row),
+            });
+            position = None;
+            if reason == "SL" {
+                cooldown_until = Some(idx + replay_bars(REPLAY_COOLDOWN_HOURS, bar_interval));
+                just_exited_stop = true;
             }
         }
 
@@ -1648,9 +1689,9 @@ fn replay_strategy(rows: &[StatsReplayRow], config: &ReplayStrategyConfig) -> St
                     entry_index: idx,
                     entry: row.clone(),
                     direction: if row.zscore >= Decimal::ZERO {
-                        TradeDirection::ShortEthLongBtc
+                        TradeDirection::ShortBaseLongQuote
                     } else {
-                        TradeDirection::LongEthShortBtc
+                        TradeDirection::LongBaseShortQuote
                     },
                     source,
                 });
@@ -1671,6 +1712,7 @@ fn replay_funding_carry_strategy(
     config: &FundingCarryReplayConfig,
     enforce_gate: bool,
 ) -> StatsReplaySummary {
+    let bar_interval = config.bar_interval;
     let mut position: Option<ReplayOpenPosition> = None;
     let mut trades = Vec::new();
     let mut prev_z = None;
@@ -1691,6 +1733,7 @@ fn replay_funding_carry_strategy(
                     cooldown_recovery: false,
                     cooldown_recovery_bars: 0,
                 },
+                bar_interval,
             )
         {
             trades.push(ReplayTrade {
@@ -1703,11 +1746,12 @@ fn replay_funding_carry_strategy(
                         open.direction,
                         idx.saturating_sub(open.entry_index),
                         config.funding_interval_hours,
+                        bar_interval,
                     ),
             });
             position = None;
             if reason == "SL" {
-                cooldown_until = Some(idx + REPLAY_COOLDOWN_BARS);
+                cooldown_until = Some(idx + replay_bars(REPLAY_COOLDOWN_HOURS, bar_interval));
                 just_exited_stop = true;
             }
         }
@@ -1728,9 +1772,9 @@ fn replay_funding_carry_strategy(
         });
         if position.is_none() && !cooldown_active && !just_exited_stop && crossed {
             let direction = if row.zscore >= Decimal::ZERO {
-                TradeDirection::ShortEthLongBtc
+                TradeDirection::ShortBaseLongQuote
             } else {
-                TradeDirection::LongEthShortBtc
+                TradeDirection::LongBaseShortQuote
             };
             let carry_gate_pass = !enforce_gate
                 || funding_carry_bps_for_hours(
@@ -1764,13 +1808,16 @@ fn replay_exit_reason(
     row: &StatsReplayRow,
     idx: usize,
     config: &ReplayStrategyConfig,
+    bar_interval: BarInterval,
 ) -> Option<&'static str> {
     let abs_z = row.zscore.abs();
     if abs_z <= config.tp_z {
         Some("TP")
     } else if abs_z >= config.sl_z {
         Some("SL")
-    } else if idx.saturating_sub(open.entry_index) >= REPLAY_MAX_HOLD_BARS {
+    } else if idx.saturating_sub(open.entry_index)
+        >= replay_bars(REPLAY_MAX_HOLD_HOURS, bar_interval)
+    {
         Some("TIME")
     } else {
         None
@@ -1782,12 +1829,14 @@ fn funding_carry_bps(
     direction: TradeDirection,
     holding_bars: usize,
     funding_interval_hours: u32,
+    bar_interval: BarInterval,
 ) -> Decimal {
     if funding_interval_hours == 0 {
         return Decimal::ZERO;
     }
-    let holding_minutes = Decimal::from(holding_bars as u64) * Decimal::from(15u32);
-    let holding_hours = holding_minutes / Decimal::from(60u32);
+    let holding_seconds =
+        Decimal::from(holding_bars as u64) * Decimal::from(bar_interval.seconds());
+    let holding_hours = holding_seconds / Decimal::from(3600u32);
     funding_carry_bps_for_decimal_hours(entry, direction, holding_hours, funding_interval_hours)
 }
 
@@ -1814,29 +1863,33 @@ fn funding_carry_bps_for_decimal_hours(
     if funding_interval_hours == 0 {
         return Decimal::ZERO;
     }
-    let Some(funding_eth) = entry.funding_eth else {
+    let Some(funding_base) = entry.funding_base else {
         return Decimal::ZERO;
     };
-    let Some(funding_btc) = entry.funding_btc else {
+    let Some(funding_quote) = entry.funding_quote else {
         return Decimal::ZERO;
     };
     let per_interval_cost = match direction {
-        TradeDirection::LongEthShortBtc => funding_eth * entry.w_eth - funding_btc * entry.w_btc,
-        TradeDirection::ShortEthLongBtc => -funding_eth * entry.w_eth + funding_btc * entry.w_btc,
+        TradeDirection::LongBaseShortQuote => {
+            funding_base * entry.w_base - funding_quote * entry.w_quote
+        }
+        TradeDirection::ShortBaseLongQuote => {
+            -funding_base * entry.w_base + funding_quote * entry.w_quote
+        }
     };
     let intervals = holding_hours / Decimal::from(funding_interval_hours);
     -per_interval_cost * intervals * Decimal::from(10_000u32)
 }
 
 fn replay_trade_net_bps(open: &ReplayOpenPosition, exit: &StatsReplayRow) -> Decimal {
-    let eth_return = exit.eth_price / open.entry.eth_price - Decimal::ONE;
-    let btc_return = exit.btc_price / open.entry.btc_price - Decimal::ONE;
+    let base_return = exit.base_price / open.entry.base_price - Decimal::ONE;
+    let quote_return = exit.quote_price / open.entry.quote_price - Decimal::ONE;
     let gross = match open.direction {
-        TradeDirection::LongEthShortBtc => {
-            open.entry.w_eth * eth_return - open.entry.w_btc * btc_return
+        TradeDirection::LongBaseShortQuote => {
+            open.entry.w_base * base_return - open.entry.w_quote * quote_return
         }
-        TradeDirection::ShortEthLongBtc => {
-            -open.entry.w_eth * eth_return + open.entry.w_btc * btc_return
+        TradeDirection::ShortBaseLongQuote => {
+            -open.entry.w_base * base_return + open.entry.w_quote * quote_return
         }
     };
     gross * Decimal::from(10_000u32) - replay_cost_bps()
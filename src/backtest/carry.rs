@@ -0,0 +1,42 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+
+use crate::backtest::BacktestBar;
+use crate::state::PositionSnapshot;
+
+/// Funding settlements falling in `(previous, current]` on an `interval_hours` UTC grid.
+pub fn settlements_between(
+    previous: DateTime<Utc>,
+    current: DateTime<Utc>,
+    interval_hours: u32,
+) -> u32 {
+    let interval_secs = i64::from(interval_hours.max(1)) * 3600;
+    let settled = current.timestamp().div_euclid(interval_secs)
+        - previous.timestamp().div_euclid(interval_secs);
+    settled.max(0) as u32
+}
+
+/// Funding paid by `position` at the settlements since `previous`, using `bar`'s rates and
+/// each leg's signed size marked at `bar`'s close; positive is paid.
+///
+/// A positive rate charges longs and credits shorts. Legs without a rate accrue nothing.
+pub fn accrued_funding(
+    position: &PositionSnapshot,
+    previous: DateTime<Utc>,
+    bar: &BacktestBar,
+    interval_hours: u32,
+) -> Decimal {
+    let settlements = settlements_between(previous, bar.timestamp, interval_hours);
+    if settlements == 0 {
+        return Decimal::ZERO;
+    }
+    let base = bar
+        .funding_base
+        .map(|rate| position.base.qty * bar.base_price * rate)
+        .unwrap_or(Decimal::ZERO);
+    let quote = bar
+        .funding_quote
+        .map(|rate| position.quote.qty * bar.quote_price * rate)
+        .unwrap_or(Decimal::ZERO);
+    (base + quote) * Decimal::from(settlements)
+}
@@ -0,0 +1,352 @@
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::backtest::parity::{ParityDecision, decision};
+use crate::backtest::{Trade, TradeExitReason};
+use crate::core::TradeDirection;
+use crate::logging::{BarLog, EntryBlockReason};
+use crate::state::StrategyStatus;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TradeChange {
+    /// Only the candidate run has the trade.
+    Added,
+    /// Only the baseline run has the trade.
+    Removed,
+    /// Both runs enter at the same bar and direction but exit differently.
+    Modified,
+}
+
+/// Why one run acted on a bar where the other did not.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DiffDriver {
+    /// The run without the entry was flat but blocked for this reason.
+    EntryBlocked(EntryBlockReason),
+    /// The run without the entry was still holding an earlier position.
+    InPosition,
+    Cooldown,
+    Exit {
+        baseline: Option<TradeExitReason>,
+        candidate: Option<TradeExitReason>,
+    },
+    /// No bar log covers the bar, e.g. a saved run without `bar_logs.jsonl`.
+    Unknown,
+}
+
+impl DiffDriver {
+    pub fn label(&self) -> String {
+        match self {
+            DiffDriver::EntryBlocked(reason) => serde_json::to_value(reason)
+                .ok()
+                .and_then(|value| value.as_str().map(str::to_string))
+                .unwrap_or_else(|| format!("{reason:?}")),
+            DiffDriver::InPosition => "IN_POSITION".to_string(),
+            DiffDriver::Cooldown => "COOLDOWN".to_string(),
+            DiffDriver::Exit {
+                baseline,
+                candidate,
+            } => format!(
+                "EXIT:{}->{}",
+                exit_label(baseline.as_ref()),
+                exit_label(candidate.as_ref())
+            ),
+            DiffDriver::Unknown => "UNKNOWN".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TradeDiff {
+    pub change: TradeChange,
+    pub entry_time: DateTime<Utc>,
+    pub direction: TradeDirection,
+    pub baseline: Option<Trade>,
+    pub candidate: Option<Trade>,
+    /// Candidate minus baseline net PnL; a missing trade counts as zero.
+    pub pnl_delta: Decimal,
+    pub driver: DiffDriver,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BarDiff {
+    pub timestamp: DateTime<Utc>,
+    pub baseline: ParityDecision,
+    pub candidate: ParityDecision,
+    pub baseline_block_reason: Option<EntryBlockReason>,
+    pub candidate_block_reason: Option<EntryBlockReason>,
+    pub driver: DiffDriver,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DiffSummary {
+    pub baseline_trades: usize,
+    pub candidate_trades: usize,
+    pub added: usize,
+    pub removed: usize,
+    pub modified: usize,
+    pub unchanged: usize,
+    pub baseline_pnl: Decimal,
+    pub candidate_pnl: Decimal,
+    /// Bars present in both bar logs.
+    pub compared_bars: usize,
+    /// Trade differences per driver label.
+    pub trade_drivers: BTreeMap<String, usize>,
+    /// Bar decision differences per driver label.
+    pub bar_drivers: BTreeMap<String, usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BacktestDiff {
+    pub summary: DiffSummary,
+    pub trades: Vec<TradeDiff>,
+    pub bars: Vec<BarDiff>,
+}
+
+/// Diffs two backtest runs over the same bars, trade by trade and bar by bar.
+///
+/// Trades are aligned by entry time and direction; an aligned pair counts as modified when
+/// its exit time, exit reason or net PnL differs. Bar logs (either may be empty) give the
+/// per-bar decisions and the driver of each difference: the entry block reason or state of
+/// the run that did not enter, or both exit reasons.
+pub fn diff_backtests(
+    baseline_trades: &[Trade],
+    baseline_logs: &[BarLog],
+    candidate_trades: &[Trade],
+    candidate_logs: &[BarLog],
+) -> BacktestDiff {
+    let baseline_bars = bars_by_time(baseline_logs);
+    let candidate_bars = bars_by_time(candidate_logs);
+
+    let mut aligned = AlignedTrades::new();
+    for trade in baseline_trades {
+        aligned.entry(trade_key(trade)).or_default().0 = Some(trade);
+    }
+    for trade in candidate_trades {
+        aligned.entry(trade_key(trade)).or_default().1 = Some(trade);
+    }
+
+    let mut trades = Vec::new();
+    let mut unchanged = 0;
+    for ((entry_time, _), pair) in aligned {
+        let (change, driver, direction) = match pair {
+            (Some(baseline), Some(candidate)) => {
+                if baseline.exit_time == candidate.exit_time
+                    && baseline.exit_reason == candidate.exit_reason
+                    && baseline.pnl == candidate.pnl
+                {
+                    unchanged += 1;
+                    continue;
+                }
+                (
+                    TradeChange::Modified,
+                    DiffDriver::Exit {
+                        baseline: Some(baseline.exit_reason.clone()),
+                        candidate: Some(candidate.exit_reason.clone()),
+                    },
+                    baseline.direction,
+                )
+            }
+            (Some(baseline), None) => (
+                TradeChange::Removed,
+                entry_driver(candidate_bars.get(&entry_time).map(|(log, _)| *log)),
+                baseline.direction,
+            ),
+            (None, Some(candidate)) => (
+                TradeChange::Added,
+                entry_driver(baseline_bars.get(&entry_time).map(|(log, _)| *log)),
+                candidate.direction,
+            ),
+            (None, None) => continue,
+        };
+        let pnl = |trade: Option<&Trade>| trade.map(|trade| trade.pnl).unwrap_or(Decimal::ZERO);
+        trades.push(TradeDiff {
+            change,
+            entry_time,
+            direction,
+            baseline: pair.0.cloned(),
+            candidate: pair.1.cloned(),
+            pnl_delta: pnl(pair.1) - pnl(pair.0),
+            driver,
+        });
+    }
+
+    let mut bars = Vec::new();
+    let mut compared_bars = 0;
+    for (timestamp, (baseline_log, baseline_decision)) in &baseline_bars {
+        let Some((candidate_log, candidate_decision)) = candidate_bars.get(timestamp) else {
+            continue;
+        };
+        compared_bars += 1;
+        let (Some(baseline), Some(candidate)) = (baseline_decision, candidate_decision) else {
+            continue;
+        };
+        if baseline == candidate {
+            continue;
+        }
+        let driver = if *baseline == ParityDecision::Enter {
+            entry_driver(Some(candidate_log))
+        } else if *candidate == ParityDecision::Enter {
+            entry_driver(Some(baseline_log))
+        } else {
+            DiffDriver::Exit {
+                baseline: exit_reason_at(baseline_trades, *timestamp),
+                candidate: exit_reason_at(candidate_trades, *timestamp),
+            }
+        };
+        bars.push(BarDiff {
+            timestamp: *timestamp,
+            baseline: *baseline,
+            candidate: *candidate,
+            baseline_block_reason: baseline_log.entry_block_reason.clone(),
+            candidate_block_reason: candidate_log.entry_block_reason.clone(),
+            driver,
+        });
+    }
+
+    let count = |labels: Vec<String>| {
+        let mut counts = BTreeMap::new();
+        for label in labels {
+            *counts.entry(label).or_insert(0) += 1;
+        }
+        counts
+    };
+    let changes = |change| trades.iter().filter(|diff| diff.change == change).count();
+    let summary = DiffSummary {
+        baseline_trades: baseline_trades.len(),
+        candidate_trades: candidate_trades.len(),
+        added: changes(TradeChange::Added),
+        removed: changes(TradeChange::Removed),
+        modified: changes(TradeChange::Modified),
+        unchanged,
+        baseline_pnl: baseline_trades.iter().map(|trade| trade.pnl).sum(),
+        candidate_pnl: candidate_trades.iter().map(|trade| trade.pnl).sum(),
+        compared_bars,
+        trade_drivers: count(trades.iter().map(|diff| diff.driver.label()).collect()),
+        bar_drivers: count(bars.iter().map(|diff| diff.driver.label()).collect()),
+    };
+
+    BacktestDiff {
+        summary,
+        trades,
+        bars,
+    }
+}
+
+pub fn format_backtest_diff_text(diff: &BacktestDiff) -> String {
+    let summary = &diff.summary;
+    let mut output = String::new();
+    let _ = writeln!(
+        output,
+        "trades baseline={} candidate={} added={} removed={} modified={} unchanged={}",
+        summary.baseline_trades,
+        summary.candidate_trades,
+        summary.added,
+        summary.removed,
+        summary.modified,
+        summary.unchanged
+    );
+    let _ = writeln!(
+        output,
+        "pnl baseline={} candidate={} delta={}",
+        summary.baseline_pnl,
+        summary.candidate_pnl,
+        summary.candidate_pnl - summary.baseline_pnl
+    );
+    let _ = writeln!(
+        output,
+        "bars compared={} decision_diffs={}",
+        summary.compared_bars,
+        diff.bars.len()
+    );
+    for (title, drivers) in [
+        ("trade drivers", &summary.trade_drivers),
+        ("bar drivers", &summary.bar_drivers),
+    ] {
+        if drivers.is_empty() {
+            continue;
+        }
+        let _ = writeln!(output, "{title}:");
+        for (label, count) in drivers {
+            let _ = writeln!(output, "  {label} {count}");
+        }
+    }
+    for trade in &diff.trades {
+        let _ = writeln!(
+            output,
+            "{:?} {} {:?} pnl_delta={} driver={}",
+            trade.change,
+            trade.entry_time.to_rfc3339(),
+            trade.direction,
+            trade.pnl_delta,
+            trade.driver.label()
+        );
+    }
+    for bar in &diff.bars {
+        let _ = writeln!(
+            output,
+            "bar {} baseline={:?} candidate={:?} driver={}",
+            bar.timestamp.to_rfc3339(),
+            bar.baseline,
+            bar.candidate,
+            bar.driver.label()
+        );
+    }
+    output
+}
+
+/// Baseline and candidate trades keyed by entry time and whether ETH is long.
+type AlignedTrades<'a> = BTreeMap<(DateTime<Utc>, bool), (Option<&'a Trade>, Option<&'a Trade>)>;
+
+type BarsByTime<'a> = BTreeMap<DateTime<Utc>, (&'a BarLog, Option<ParityDecision>)>;
+
+fn bars_by_time(logs: &[BarLog]) -> BarsByTime<'_> {
+    let mut bars = BTreeMap::new();
+    let mut previous = None;
+    for log in logs {
+        bars.insert(
+            log.timestamp,
+            (log, previous.map(|state| decision(state, log.state))),
+        );
+        previous = Some(log.state);
+    }
+    bars
+}
+
+fn trade_key(trade: &Trade) -> (DateTime<Utc>, bool) {
+    (trade.entry_time, trade.direction.is_base_long())
+}
+
+fn entry_driver(log: Option<&BarLog>) -> DiffDriver {
+    let Some(log) = log else {
+        return DiffDriver::Unknown;
+    };
+    match (log.state, &log.entry_block_reason) {
+        (StrategyStatus::InPosition | StrategyStatus::PendingEntry, _) => DiffDriver::InPosition,
+        (StrategyStatus::Cooldown, _) => DiffDriver::Cooldown,
+        (_, Some(reason)) => DiffDriver::EntryBlocked(reason.clone()),
+        (_, None) => DiffDriver::Unknown,
+    }
+}
+
+fn exit_reason_at(trades: &[Trade], timestamp: DateTime<Utc>) -> Option<TradeExitReason> {
+    trades
+        .iter()
+        .find(|trade| trade.exit_time == timestamp)
+        .map(|trade| trade.exit_reason.clone())
+}
+
+fn exit_label(reason: Option<&TradeExitReason>) -> &'static str {
+    match reason {
+        Some(TradeExitReason::TakeProfit) => "TAKE_PROFIT",
+        Some(TradeExitReason::StopLoss) => "STOP_LOSS",
+        Some(TradeExitReason::TimeStop) => "TIME_STOP",
+        Some(TradeExitReason::ResidualRepair) => "RESIDUAL_REPAIR",
+        None => "NONE",
+    }
+}
@@ -1,21 +1,24 @@
 use std::collections::BTreeMap;
 use std::sync::Arc;
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use rust_decimal::Decimal;
 use thiserror::Error;
 
 use crate::backtest::BacktestBar;
-use crate::config::Symbol;
+use crate::config::{BarInterval, PairConfig, Symbol};
 use crate::data::{
     DataError, HttpClient, HyperliquidPriceSource, PriceBar, PriceSource, align_to_bar_close,
 };
+use crate::funding::{FundingError, FundingRate, FundingSource, HyperliquidFundingSource};
 use crate::storage::{PriceBarRecord, PriceStore};
 
 #[derive(Debug, Error)]
 pub enum DownloadError {
     #[error("data error: {0}")]
     Data(#[from] DataError),
+    #[error("funding error: {0}")]
+    Funding(#[from] FundingError),
     #[error("missing price for {0}")]
     MissingPrice(String),
     #[error("history coverage incomplete: start {start} end {end} first {first:?} last {last:?}")]
@@ -30,45 +33,80 @@ pub enum DownloadError {
 #[derive(Clone)]
 pub struct HyperliquidDownloader {
     source: HyperliquidPriceSource,
+    funding: Option<Arc<dyn FundingSource>>,
+    interval: BarInterval,
 }
 
 impl HyperliquidDownloader {
     pub fn new(base_url: impl Into<String>) -> Self {
         Self {
             source: HyperliquidPriceSource::new(base_url),
+            funding: None,
+            interval: BarInterval::default(),
         }
     }
 
     pub fn with_client(base_url: impl Into<String>, http: Arc<dyn HttpClient>) -> Self {
         Self {
             source: HyperliquidPriceSource::with_client(base_url, http),
+            funding: None,
+            interval: BarInterval::default(),
         }
     }
 
+    pub fn with_pair(mut self, pair: PairConfig) -> Self {
+        self.source = self.source.with_pair(pair);
+        self
+    }
+
+    pub fn with_interval(mut self, interval: BarInterval) -> Self {
+        self.source = self.source.with_interval(interval);
+        self.interval = interval;
+        self
+    }
+
+    /// Fills each bar's funding columns from this source's history.
+    pub fn with_funding_source(mut self, source: Arc<dyn FundingSource>) -> Self {
+        self.funding = Some(source);
+        self
+    }
+
+    pub fn with_hyperliquid_funding(self, base_url: impl Into<String>, pair: PairConfig) -> Self {
+        self.with_funding_source(Arc::new(
+            HyperliquidFundingSource::new(base_url).with_pair(pair),
+        ))
+    }
+
     pub async fn fetch_backtest_bars(
         &self,
         start: DateTime<Utc>,
         end: DateTime<Utc>,
     ) -> Result<Vec<BacktestBar>, DownloadError> {
-        let start = align_to_bar_close(start)?;
-        let end = align_to_bar_close(end)?;
-        let (eth_bars, btc_bars) = tokio::try_join!(
-            self.source.fetch_history(Symbol::EthPerp, start, end),
-            self.source.fetch_history(Symbol::BtcPerp, start, end),
+        let start = align_to_bar_close(start, self.interval)?;
+        let end = align_to_bar_close(end, self.interval)?;
+        let (base_bars, quote_bars) = tokio::try_join!(
+            self.source.fetch_history(Symbol::Base, start, end),
+            self.source.fetch_history(Symbol::Quote, start, end),
         )?;
 
-        let eth_map = Self::map_prices(eth_bars)?;
-        let btc_map = Self::map_prices(btc_bars)?;
+        let base_map = Self::map_prices(base_bars)?;
+        let quote_map = Self::map_prices(quote_bars)?;
 
         let mut merged = Vec::new();
-        for (timestamp, eth_price) in eth_map {
-            if let Some(btc_price) = btc_map.get(&timestamp) {
+        for (timestamp, (base_price, base_bar)) in base_map {
+            if let Some((quote_price, quote_bar)) = quote_map.get(&timestamp) {
                 merged.push(BacktestBar {
                     timestamp,
-                    eth_price,
-                    btc_price: *btc_price,
-                    funding_eth: None,
-                    funding_btc: None,
+                    base_price,
+                    quote_price: *quote_price,
+                    funding_base: None,
+                    funding_quote: None,
+                    base_open: base_bar.open,
+                    base_high: base_bar.high,
+                    base_low: base_bar.low,
+                    quote_open: quote_bar.open,
+                    quote_high: quote_bar.high,
+                    quote_low: quote_bar.low,
                 });
             }
         }
@@ -82,10 +120,23 @@ impl HyperliquidDownloader {
                 last,
             });
         }
+        if let Some(funding) = &self.funding {
+            // Reach back one interval so the first bars see the rate already in effect.
+            let funding_start = start - Duration::hours(1);
+            let (mut base_rates, mut quote_rates) = tokio::try_join!(
+                funding.fetch_history(Symbol::Base, funding_start, end),
+                funding.fetch_history(Symbol::Quote, funding_start, end),
+            )?;
+            base_rates.sort_by_key(|rate| rate.timestamp);
+            quote_rates.sort_by_key(|rate| rate.timestamp);
+            apply_funding_history(&mut merged, &base_rates, &quote_rates);
+        }
         Ok(merged)
     }
 
-    fn map_prices(bars: Vec<PriceBar>) -> Result<BTreeMap<DateTime<Utc>, Decimal>, DownloadError> {
+    fn map_prices(
+        bars: Vec<PriceBar>,
+    ) -> Result<BTreeMap<DateTime<Utc>, (Decimal, PriceBar)>, DownloadError> {
         let mut map = BTreeMap::new();
         for bar in bars {
             let price = bar.close.or(bar.mid).or(bar.mark).ok_or_else(|| {
@@ -95,31 +146,61 @@ impl HyperliquidDownloader {
                     bar.timestamp.to_rfc3339()
                 ))
             })?;
-            map.insert(bar.timestamp, price);
+            map.insert(bar.timestamp, (price, bar));
         }
         Ok(map)
     }
 }
 
+/// Stamps every bar with the latest settled funding rate per leg, i.e. the rate at the most
+/// recent funding timestamp at or before the bar close and no older than its interval. Bars
+/// with no such rate keep `None`. Rates must be sorted by timestamp.
+pub fn apply_funding_history(
+    bars: &mut [BacktestBar],
+    base_rates: &[FundingRate],
+    quote_rates: &[FundingRate],
+) {
+    let rate_at = |rates: &[FundingRate], timestamp: DateTime<Utc>| {
+        let index = rates.partition_point(|rate| rate.timestamp <= timestamp);
+        let rate = rates.get(index.checked_sub(1)?)?;
+        (timestamp - rate.timestamp < Duration::hours(rate.interval_hours as i64))
+            .then_some(rate.rate)
+    };
+    for bar in bars {
+        bar.funding_base = rate_at(base_rates, bar.timestamp);
+        bar.funding_quote = rate_at(quote_rates, bar.timestamp);
+    }
+}
+
 pub fn write_bars_to_output(
     bars: &[BacktestBar],
     path: &std::path::Path,
+    pair: &PairConfig,
+    interval: BarInterval,
 ) -> Result<(), DownloadError> {
     if path.extension().and_then(|ext| ext.to_str()) == Some("sqlite") {
-        let store = PriceStore::new(path.to_string_lossy().as_ref())
+        let store = PriceStore::new_for_series(path.to_string_lossy().as_ref(), pair, interval)
             .map_err(|err| DownloadError::Data(DataError::Http(err.to_string())))?;
         for bar in bars {
             let record = PriceBarRecord {
                 timestamp: bar.timestamp,
-                eth_mid: Some(bar.eth_price),
-                eth_mark: None,
-                eth_close: None,
-                btc_mid: Some(bar.btc_price),
-                btc_mark: None,
-                btc_close: None,
-                funding_eth: bar.funding_eth,
-                funding_btc: bar.funding_btc,
-                funding_interval_hours: None,
+                base_mid: Some(bar.base_price),
+                base_mark: None,
+                base_close: None,
+                quote_mid: Some(bar.quote_price),
+                quote_mark: None,
+                quote_close: None,
+                funding_base: bar.funding_base,
+                funding_quote: bar.funding_quote,
+                // Hyperliquid settles funding hourly.
+                funding_interval_hours: (bar.funding_base.is_some() || bar.funding_quote.is_some())
+                    .then_some(1),
+                base_open: bar.base_open,
+                base_high: bar.base_high,
+                base_low: bar.base_low,
+                quote_open: bar.quote_open,
+                quote_high: bar.quote_high,
+                quote_low: bar.quote_low,
             };
             store
                 .save(&record)
@@ -0,0 +1,392 @@
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::backtest::{
+    BacktestBar, BacktestEngine, BacktestError, Metrics, Trade, precompute_indicators,
+};
+use crate::config::{Config, SigmaFloorMode};
+use crate::core::pipeline::IndicatorSnapshot;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SearchObjective {
+    #[default]
+    Sharpe,
+    NetPnl,
+    ProfitFactor,
+}
+
+impl FromStr for SearchObjective {
+    type Err = BacktestError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_uppercase().replace('-', "_").as_str() {
+            "SHARPE" => Ok(SearchObjective::Sharpe),
+            "NET_PNL" => Ok(SearchObjective::NetPnl),
+            "PROFIT_FACTOR" => Ok(SearchObjective::ProfitFactor),
+            _ => Err(BacktestError::InvalidConfig(format!(
+                "unsupported search objective: {value}"
+            ))),
+        }
+    }
+}
+
+impl SearchObjective {
+    pub fn score(&self, trades: &[Trade], metrics: &Metrics) -> Decimal {
+        match self {
+            SearchObjective::Sharpe => metrics.sharpe_ratio,
+            SearchObjective::NetPnl => trades.iter().map(|trade| trade.pnl).sum(),
+            SearchObjective::ProfitFactor => metrics.profit_factor,
+        }
+    }
+}
+
+/// One point of the parameter search space.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParameterSet {
+    pub entry_z: Decimal,
+    pub tp_z: Decimal,
+    pub sl_z: Decimal,
+    pub n_z: usize,
+    pub cooldown_hours: u32,
+    pub sigma_floor_mode: SigmaFloorMode,
+}
+
+impl ParameterSet {
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            entry_z: config.strategy.entry_z,
+            tp_z: config.strategy.tp_z,
+            sl_z: config.strategy.sl_z,
+            n_z: config.strategy.n_z,
+            cooldown_hours: config.risk.cooldown_hours,
+            sigma_floor_mode: config.sigma_floor.mode,
+        }
+    }
+
+    pub fn apply(&self, config: &Config) -> Config {
+        let mut config = config.clone();
+        config.strategy.entry_z = self.entry_z;
+        config.strategy.tp_z = self.tp_z;
+        config.strategy.sl_z = self.sl_z;
+        config.strategy.n_z = self.n_z;
+        config.risk.cooldown_hours = self.cooldown_hours;
+        config.sigma_floor.mode = self.sigma_floor_mode;
+        config
+    }
+
+    /// Parameters that change the indicator series; sets sharing a key share indicators.
+    fn indicator_key(&self) -> (usize, SigmaFloorMode) {
+        (self.n_z, self.sigma_floor_mode)
+    }
+}
+
+impl fmt::Display for ParameterSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "entry_z={} tp_z={} sl_z={} n_z={} cooldown_hours={} sigma_floor={:?}",
+            self.entry_z,
+            self.tp_z,
+            self.sl_z,
+            self.n_z,
+            self.cooldown_hours,
+            self.sigma_floor_mode
+        )
+    }
+}
+
+/// Candidate values per parameter; an empty list keeps the base config value.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ParameterGrid {
+    pub entry_z: Vec<Decimal>,
+    pub tp_z: Vec<Decimal>,
+    pub sl_z: Vec<Decimal>,
+    pub n_z: Vec<usize>,
+    pub cooldown_hours: Vec<u32>,
+    pub sigma_floor_mode: Vec<SigmaFloorMode>,
+}
+
+impl ParameterGrid {
+    /// Cartesian product of the grid, dropping combinations the base config rejects.
+    pub fn candidates(&self, base: &Config) -> Vec<ParameterSet> {
+        fn or_current<T: Clone>(values: &[T], current: T) -> Vec<T> {
+            if values.is_empty() {
+                vec![current]
+            } else {
+                values.to_vec()
+            }
+        }
+        let current = ParameterSet::from_config(base);
+        let entry_zs = or_current(&self.entry_z, current.entry_z);
+        let tp_zs = or_current(&self.tp_z, current.tp_z);
+        let sl_zs = or_current(&self.sl_z, current.sl_z);
+        let n_zs = or_current(&self.n_z, current.n_z);
+        let cooldowns = or_current(&self.cooldown_hours, current.cooldown_hours);
+        let modes = or_current(&self.sigma_floor_mode, current.sigma_floor_mode);
+
+        let mut candidates = Vec::new();
+        for &entry_z in &entry_zs {
+            for &tp_z in &tp_zs {
+                for &sl_z in &sl_zs {
+                    for &n_z in &n_zs {
+                        for &cooldown_hours in &cooldowns {
+                            for &sigma_floor_mode in &modes {
+                                let candidate = ParameterSet {
+                                    entry_z,
+                                    tp_z,
+                                    sl_z,
+                                    n_z,
+                                    cooldown_hours,
+                                    sigma_floor_mode,
+                                };
+                                if candidate.apply(base).validate().is_ok() {
+                                    candidates.push(candidate);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        candidates
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GridSearchConfig {
+    pub grid: ParameterGrid,
+    pub objective: SearchObjective,
+    /// Trades a combination needs to rank ahead of combinations below the minimum.
+    pub min_trades: usize,
+    /// Worker threads; defaults to the available parallelism.
+    pub threads: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GridSearchResult {
+    pub rank: usize,
+    pub parameters: ParameterSet,
+    pub score: Decimal,
+    pub meets_min_trades: bool,
+    pub net_pnl: Decimal,
+    pub metrics: Metrics,
+}
+
+/// Backtests every valid grid combination across worker threads and ranks them.
+///
+/// Indicators are computed once per distinct `(n_z, sigma_floor_mode)` and shared by all
+/// combinations that only differ in thresholds or cooldown. Results are ordered by
+/// `meets_min_trades`, then score, then grid order, so ties are deterministic.
+pub fn run_grid_search(
+    base: &Config,
+    bars: &[BacktestBar],
+    config: &GridSearchConfig,
+) -> Result<Vec<GridSearchResult>, BacktestError> {
+    let candidates = config.grid.candidates(base);
+    if candidates.is_empty() {
+        return Err(BacktestError::InvalidConfig(
+            "parameter grid has no valid combinations".to_string(),
+        ));
+    }
+    let threads = match config.threads {
+        Some(0) => {
+            return Err(BacktestError::InvalidConfig(
+                "threads must be > 0".to_string(),
+            ));
+        }
+        Some(value) => value,
+        None => thread::available_parallelism()
+            .map(|value| value.get())
+            .unwrap_or(1),
+    };
+
+    let mut keys: Vec<(usize, SigmaFloorMode)> = Vec::new();
+    for candidate in &candidates {
+        let key = candidate.indicator_key();
+        if !keys.contains(&key) {
+            keys.push(key);
+        }
+    }
+    let series: Vec<Vec<IndicatorSnapshot>> = parallel_map(&keys, threads, |key| {
+        let representative = candidates
+            .iter()
+            .find(|candidate| candidate.indicator_key() == *key)
+            .expect("key taken from candidates");
+        precompute_indicators(&representative.apply(base), bars)
+    })?;
+
+    let evaluated = parallel_map(&candidates, threads, |candidate| {
+        let key = candidate.indicator_key();
+        let index = keys
+            .iter()
+            .position(|value| *value == key)
+            .expect("key taken from candidates");
+        let result =
+            BacktestEngine::new(candidate.apply(base)).run_with_indicators(bars, &series[index])?;
+        Ok((
+            config.objective.score(&result.trades, &result.metrics),
+            result.trades.iter().map(|trade| trade.pnl).sum::<Decimal>(),
+            result.metrics,
+        ))
+    })?;
+
+    let mut results: Vec<(usize, GridSearchResult)> = candidates
+        .into_iter()
+        .zip(evaluated)
+        .enumerate()
+        .map(|(index, (parameters, (score, net_pnl, metrics)))| {
+            (
+                index,
+                GridSearchResult {
+                    rank: 0,
+                    parameters,
+                    score,
+                    meets_min_trades: metrics.trade_count >= config.min_trades,
+                    net_pnl,
+                    metrics,
+                },
+            )
+        })
+        .collect();
+    results.sort_by(|(left_index, left), (right_index, right)| {
+        right
+            .meets_min_trades
+            .cmp(&left.meets_min_trades)
+            .then_with(|| right.score.cmp(&left.score))
+            .then_with(|| left_index.cmp(right_index))
+    });
+    Ok(results
+        .into_iter()
+        .enumerate()
+        .map(|(rank, (_, mut result))| {
+            result.rank = rank + 1;
+            result
+        })
+        .collect())
+}
+
+/// Maps `items` on up to `threads` scoped workers, preserving input order.
+fn parallel_map<T, R, F>(items: &[T], threads: usize, f: F) -> Result<Vec<R>, BacktestError>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> Result<R, BacktestError> + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let slots: Mutex<Vec<Option<Result<R, BacktestError>>>> =
+        Mutex::new((0..items.len()).map(|_| None).collect());
+    thread::scope(|scope| {
+        for _ in 0..threads.min(items.len()) {
+            scope.spawn(|| {
+                loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(item) = items.get(index) else {
+                        break;
+                    };
+                    let result = f(item);
+                    slots.lock().expect("grid search lock poisoned")[index] = Some(result);
+                }
+            });
+        }
+    });
+    slots
+        .into_inner()
+        .expect("grid search lock poisoned")
+        .into_iter()
+        .map(|slot| slot.expect("every grid item evaluated"))
+        .collect()
+}
+
+pub fn export_grid_results_csv(
+    path: &Path,
+    results: &[GridSearchResult],
+) -> Result<(), BacktestError> {
+    let mut contents = String::from(
+        "rank,entry_z,tp_z,sl_z,n_z,cooldown_hours,sigma_floor_mode,score,meets_min_trades,net_pnl,annualized_return,sharpe_ratio,max_drawdown,win_rate,profit_factor,stop_loss_rate,trade_count,sortino_ratio,calmar_ratio,max_drawdown_duration_hours,time_in_market,avg_holding_hours,median_holding_hours,traded_notional,turnover,total_fees,total_funding,pnl_by_exit_reason,liquidation_bars,max_margin_usage\n",
+    );
+    for result in results {
+        let params = &result.parameters;
+        let metrics = &result.metrics;
+        contents.push_str(&format!(
+            "{},{},{},{},{},{},{:?},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}\n",
+            result.rank,
+            params.entry_z,
+            params.tp_z,
+            params.sl_z,
+            params.n_z,
+            params.cooldown_hours,
+            params.sigma_floor_mode,
+            result.score,
+            result.meets_min_trades,
+            result.net_pnl,
+            metrics.annualized_return,
+            metrics.sharpe_ratio,
+            metrics.max_drawdown,
+            metrics.win_rate,
+            metrics.profit_factor,
+            metrics.stop_loss_rate,
+            metrics.trade_count,
+            metrics.sortino_ratio,
+            metrics.calmar_ratio,
+            metrics.max_drawdown_duration_hours,
+            metrics.time_in_market,
+            metrics.avg_holding_hours,
+            metrics.median_holding_hours,
+            metrics.traded_notional,
+            metrics.turnover,
+            metrics.total_fees,
+            metrics.total_funding,
+            metrics
+                .pnl_by_exit_reason
+                .iter()
+                .map(|(reason, pnl)| format!("{reason}={pnl}"))
+                .collect::<Vec<_>>()
+                .join(";"),
+            metrics.liquidation_bars,
+            metrics.max_margin_usage,
+        ));
+    }
+    fs::write(path, contents).map_err(|err| BacktestError::Io(err.to_string()))
+}
+
+pub fn export_grid_results_json(
+    path: &Path,
+    results: &[GridSearchResult],
+) -> Result<(), BacktestError> {
+    let payload = serde_json::to_string_pretty(results)
+        .map_err(|err| BacktestError::Serialization(err.to_string()))?;
+    fs::write(path, payload).map_err(|err| BacktestError::Io(err.to_string()))
+}
+
+pub fn format_grid_results_text(results: &[GridSearchResult], top: usize) -> String {
+    let mut output = String::new();
+    output.push_str(&format!(
+        "grid search combinations={} top={}\n",
+        results.len(),
+        top.min(results.len())
+    ));
+    output.push_str("rank score net_pnl sharpe max_drawdown win_rate trades parameters\n");
+    for result in results.iter().take(top) {
+        output.push_str(&format!(
+            "{} {} {} {} {} {} {} {}\n",
+            result.rank,
+            result.score.round_dp(4),
+            result.net_pnl.round_dp(4),
+            result.metrics.sharpe_ratio.round_dp(4),
+            result.metrics.max_drawdown.round_dp(4),
+            result.metrics.win_rate.round_dp(4),
+            result.metrics.trade_count,
+            result.parameters,
+        ));
+    }
+    output
+}
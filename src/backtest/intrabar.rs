@@ -0,0 +1,140 @@
+use rust_decimal::{Decimal, MathematicalOps};
+
+use crate::backtest::BacktestBar;
+use crate::core::{ExitReason, ExitSignal, TradeDirection};
+use crate::indicators::ZScoreSnapshot;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntrabarExit {
+    pub signal: ExitSignal,
+    pub base_price: Decimal,
+    pub quote_price: Decimal,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct PathPoint {
+    base_price: Decimal,
+    quote_price: Decimal,
+    base_ln: Decimal,
+    quote_ln: Decimal,
+    zscore: Decimal,
+}
+
+/// Checks an open position's SL/TP bounds along the worst-case path through `bar`: open,
+/// the spread extreme against the position, the extreme in its favour, then close.
+///
+/// The spread is linear in log prices, so z is interpolated along each leg of the path using
+/// the bar's mean, effective sigma and hedge ratio. The first bound crossed exits at the
+/// prices where it is crossed, with the stop-loss winning ties. Returns `None` when either
+/// leg lacks OHLC or the z-score is not available yet.
+pub fn worst_case_intrabar_exit(
+    bar: &BacktestBar,
+    direction: TradeDirection,
+    beta: Decimal,
+    z_snapshot: &ZScoreSnapshot,
+    tp_z: Decimal,
+    sl_z: Decimal,
+    check_take_profit: bool,
+) -> Option<IntrabarExit> {
+    z_snapshot.zscore?;
+    let mean = z_snapshot.mean?;
+    let sigma = z_snapshot
+        .sigma_eff
+        .filter(|value| *value > Decimal::ZERO)?;
+    let base_close_ln = bar.base_price.checked_ln()?;
+    let quote_close_ln = bar.quote_price.checked_ln()?;
+    let point = |base_price: Decimal, quote_price: Decimal| -> Option<PathPoint> {
+        let base_ln = base_price.checked_ln()?;
+        let quote_ln = quote_price.checked_ln()?;
+        let r = z_snapshot.r + (base_ln - base_close_ln) - beta * (quote_ln - quote_close_ln);
+        Some(PathPoint {
+            base_price,
+            quote_price,
+            base_ln,
+            quote_ln,
+            zscore: (r - mean) / sigma,
+        })
+    };
+
+    let (quote_for_low, quote_for_high) = if beta >= Decimal::ZERO {
+        (bar.quote_high?, bar.quote_low?)
+    } else {
+        (bar.quote_low?, bar.quote_high?)
+    };
+    let spread_low = point(bar.base_low?, quote_for_low)?;
+    let spread_high = point(bar.base_high?, quote_for_high)?;
+    let (adverse, favourable) = match direction {
+        TradeDirection::LongBaseShortQuote => (spread_low, spread_high),
+        TradeDirection::ShortBaseLongQuote => (spread_high, spread_low),
+    };
+    let path = [
+        point(bar.base_open?, bar.quote_open?)?,
+        adverse,
+        favourable,
+        point(bar.base_price, bar.quote_price)?,
+    ];
+
+    let open = path[0];
+    let at_open = |reason| exit_at(reason, open.zscore, open, open, Decimal::ZERO);
+    if open.zscore.abs() >= sl_z {
+        return Some(at_open(ExitReason::StopLoss));
+    }
+    if check_take_profit && open.zscore.abs() <= tp_z {
+        return Some(at_open(ExitReason::TakeProfit));
+    }
+    for segment in path.windows(2) {
+        let (from, to) = (segment[0], segment[1]);
+        let dz = to.zscore - from.zscore;
+        if dz == Decimal::ZERO {
+            continue;
+        }
+        let crossing = |level: Decimal| {
+            let t = (level - from.zscore) / dz;
+            (t >= Decimal::ZERO && t <= Decimal::ONE).then_some(t)
+        };
+        let (sl_level, tp_level) = if dz > Decimal::ZERO {
+            (sl_z, -tp_z)
+        } else {
+            (-sl_z, tp_z)
+        };
+        let stop = crossing(sl_level);
+        let take = if check_take_profit {
+            crossing(tp_level)
+        } else {
+            None
+        };
+        match (stop, take) {
+            (Some(t_stop), Some(t_take)) if t_take < t_stop => {
+                return Some(exit_at(ExitReason::TakeProfit, tp_level, from, to, t_take));
+            }
+            (Some(t_stop), _) => {
+                return Some(exit_at(ExitReason::StopLoss, sl_level, from, to, t_stop));
+            }
+            (None, Some(t_take)) => {
+                return Some(exit_at(ExitReason::TakeProfit, tp_level, from, to, t_take));
+            }
+            (None, None) => {}
+        }
+    }
+    None
+}
+
+fn exit_at(
+    reason: ExitReason,
+    zscore: Decimal,
+    from: PathPoint,
+    to: PathPoint,
+    t: Decimal,
+) -> IntrabarExit {
+    let interpolate = |price: Decimal, from_ln: Decimal, to_ln: Decimal| {
+        ((to_ln - from_ln) * t)
+            .checked_exp()
+            .map(|factor| price * factor)
+            .unwrap_or(price)
+    };
+    IntrabarExit {
+        signal: ExitSignal { reason, zscore },
+        base_price: interpolate(from.base_price, from.base_ln, to.base_ln),
+        quote_price: interpolate(from.quote_price, from.quote_ln, to.quote_ln),
+    }
+}
@@ -0,0 +1,74 @@
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::Decimal;
+
+use crate::backtest::BacktestBar;
+use crate::config::ExecutionDelay;
+
+/// Base and quote prices at which an order decided at the close of `bars[index]` fills.
+///
+/// `NEXT_BAR` fills at the following bar's open, per leg, and at its close when the bar
+/// has no open. `NEXT_MINUTE` reads the first bar of `minute_bars` closing at least a minute after the
+/// signal and falls back to interpolating 60s ahead when none is available. Delays that run
+/// past the last bar fill at the last close.
+pub fn delayed_fill_prices(
+    delay: ExecutionDelay,
+    offset_secs: u32,
+    bars: &[BacktestBar],
+    index: usize,
+    minute_bars: &[BacktestBar],
+) -> (Decimal, Decimal) {
+    let bar = &bars[index];
+    match delay {
+        ExecutionDelay::None => (bar.base_price, bar.quote_price),
+        ExecutionDelay::NextBar => bars
+            .get(index + 1)
+            .map(|next| {
+                (
+                    next.base_open.unwrap_or(next.base_price),
+                    next.quote_open.unwrap_or(next.quote_price),
+                )
+            })
+            .unwrap_or((bar.base_price, bar.quote_price)),
+        ExecutionDelay::NextMinute => {
+            let target = bar.timestamp + Duration::minutes(1);
+            let position = minute_bars.partition_point(|minute| minute.timestamp < target);
+            match minute_bars.get(position) {
+                Some(minute)
+                    if bars
+                        .get(index + 1)
+                        .is_none_or(|next| minute.timestamp <= next.timestamp) =>
+                {
+                    (minute.base_price, minute.quote_price)
+                }
+                _ => interpolate(bars, index, target),
+            }
+        }
+        ExecutionDelay::FixedOffset => interpolate(
+            bars,
+            index,
+            bar.timestamp + Duration::seconds(offset_secs as i64),
+        ),
+    }
+}
+
+/// Linear interpolation between the bar closes around `target`.
+fn interpolate(bars: &[BacktestBar], index: usize, target: DateTime<Utc>) -> (Decimal, Decimal) {
+    let mut current = &bars[index];
+    for next in &bars[index + 1..] {
+        if next.timestamp >= target {
+            let span = (next.timestamp - current.timestamp).num_milliseconds();
+            if span <= 0 {
+                return (next.base_price, next.quote_price);
+            }
+            let fraction = Decimal::from((target - current.timestamp).num_milliseconds())
+                / Decimal::from(span);
+            let lerp = |from: Decimal, to: Decimal| from + (to - from) * fraction;
+            return (
+                lerp(current.base_price, next.base_price),
+                lerp(current.quote_price, next.quote_price),
+            );
+        }
+        current = next;
+    }
+    (current.base_price, current.quote_price)
+}
@@ -0,0 +1,278 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use alloy_primitives::keccak256;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::backtest::{BacktestBar, BacktestError, BacktestResult};
+use crate::config::{AuthConfig, Config};
+
+pub const MANIFEST_FILE: &str = "manifest.json";
+/// The archived effective config, read back by `backtest verify`.
+pub const CONFIG_FILE: &str = "config.json";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BarSource {
+    File {
+        path: PathBuf,
+    },
+    Db {
+        path: PathBuf,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    },
+}
+
+/// A file read by the run, with the keccak256 of its bytes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InputFile {
+    pub path: PathBuf,
+    pub checksum: String,
+}
+
+impl InputFile {
+    pub fn read(path: &Path) -> Result<Self, BacktestError> {
+        let bytes = fs::read(path).map_err(|err| BacktestError::Io(err.to_string()))?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            checksum: hex::encode(keccak256(&bytes)),
+        })
+    }
+}
+
+/// Everything besides the config needed to repeat a run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunInputs {
+    pub bars: BarSource,
+    #[serde(default)]
+    pub minute_bars: Option<InputFile>,
+    #[serde(default)]
+    pub book_logs: Option<InputFile>,
+    #[serde(default)]
+    pub simulated_exchange: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BarSetFingerprint {
+    pub start: Option<DateTime<Utc>>,
+    pub end: Option<DateTime<Utc>>,
+    pub count: usize,
+    pub checksum: String,
+}
+
+impl BarSetFingerprint {
+    pub fn of(bars: &[BacktestBar]) -> Result<Self, BacktestError> {
+        Ok(Self {
+            start: bars.first().map(|bar| bar.timestamp),
+            end: bars.last().map(|bar| bar.timestamp),
+            count: bars.len(),
+            checksum: hash_json(bars)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunManifest {
+    pub crate_version: String,
+    /// `git rev-parse HEAD` of the build, when built from a checkout.
+    pub git_revision: Option<String>,
+    /// Hash of the archived effective config (see `archived_config`).
+    pub config_hash: String,
+    pub bars: BarSetFingerprint,
+    pub inputs: RunInputs,
+    pub trade_count: usize,
+    pub trades_hash: String,
+    pub equity_hash: String,
+}
+
+impl RunManifest {
+    pub fn new(
+        config: &Config,
+        bars: &[BacktestBar],
+        inputs: RunInputs,
+        result: &BacktestResult,
+    ) -> Result<Self, BacktestError> {
+        Ok(Self {
+            crate_version: env!("CARGO_PKG_VERSION").to_string(),
+            git_revision: option_env!("ETH_BTC_GIT_REVISION").map(str::to_string),
+            config_hash: hash_json(&archived_config(config))?,
+            bars: BarSetFingerprint::of(bars)?,
+            inputs,
+            trade_count: result.trades.len(),
+            trades_hash: hash_json(&result.trades)?,
+            equity_hash: hash_json(&result.equity_curve)?,
+        })
+    }
+
+    /// Field-by-field comparison against a re-run. Code identity is reported but not
+    /// required: a different revision that reproduces the outputs still verifies.
+    pub fn compare(&self, rerun: &RunManifest) -> ManifestVerification {
+        let check = |field: &str, expected: String, actual: String, required: bool| ManifestCheck {
+            field: field.to_string(),
+            matches: expected == actual,
+            expected,
+            actual,
+            required,
+        };
+        let optional = |value: Option<&String>| value.cloned().unwrap_or_else(|| "NONE".into());
+        let checksum = |file: Option<&InputFile>| optional(file.map(|file| &file.checksum));
+        let checks = vec![
+            check(
+                "crate_version",
+                self.crate_version.clone(),
+                rerun.crate_version.clone(),
+                false,
+            ),
+            check(
+                "git_revision",
+                optional(self.git_revision.as_ref()),
+                optional(rerun.git_revision.as_ref()),
+                false,
+            ),
+            check(
+                "config_hash",
+                self.config_hash.clone(),
+                rerun.config_hash.clone(),
+                true,
+            ),
+            check(
+                "bars.count",
+                self.bars.count.to_string(),
+                rerun.bars.count.to_string(),
+                true,
+            ),
+            check(
+                "bars.checksum",
+                self.bars.checksum.clone(),
+                rerun.bars.checksum.clone(),
+                true,
+            ),
+            check(
+                "minute_bars",
+                checksum(self.inputs.minute_bars.as_ref()),
+                checksum(rerun.inputs.minute_bars.as_ref()),
+                true,
+            ),
+            check(
+                "book_logs",
+                checksum(self.inputs.book_logs.as_ref()),
+                checksum(rerun.inputs.book_logs.as_ref()),
+                true,
+            ),
+            check(
+                "trade_count",
+                self.trade_count.to_string(),
+                rerun.trade_count.to_string(),
+                true,
+            ),
+            check(
+                "trades_hash",
+                self.trades_hash.clone(),
+                rerun.trades_hash.clone(),
+                true,
+            ),
+            check(
+                "equity_hash",
+                self.equity_hash.clone(),
+                rerun.equity_hash.clone(),
+                true,
+            ),
+        ];
+        ManifestVerification {
+            verified: checks.iter().all(|check| check.matches || !check.required),
+            checks,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManifestCheck {
+    pub field: String,
+    pub expected: String,
+    pub actual: String,
+    pub matches: bool,
+    pub required: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManifestVerification {
+    pub verified: bool,
+    pub checks: Vec<ManifestCheck>,
+}
+
+pub fn format_verification_text(verification: &ManifestVerification) -> String {
+    let mut output = String::new();
+    for check in &verification.checks {
+        let status = match (check.matches, check.required) {
+            (true, _) => "OK",
+            (false, true) => "MISMATCH",
+            (false, false) => "CHANGED",
+        };
+        if check.matches {
+            output.push_str(&format!("{status} {} {}\n", check.field, check.actual));
+        } else {
+            output.push_str(&format!(
+                "{status} {} expected={} actual={}\n",
+                check.field, check.expected, check.actual
+            ));
+        }
+    }
+    output.push_str(if verification.verified {
+        "verified\n"
+    } else {
+        "NOT verified\n"
+    });
+    output
+}
+
+/// The effective config as archived with a run: credentials and the alert webhook are
+/// cleared, since they never affect a backtest and must not land in a research archive.
+pub fn archived_config(config: &Config) -> Config {
+    let mut config = config.clone();
+    config.auth = AuthConfig::default();
+    config.alerts.webhook_url = String::new();
+    config
+}
+
+/// keccak256 of the canonical JSON of `value`, hex encoded.
+///
+/// Values go through `serde_json::Value` first so map keys are sorted, which keeps
+/// `HashMap` fields stable across runs.
+pub fn hash_json<T: Serialize + ?Sized>(value: &T) -> Result<String, BacktestError> {
+    let value =
+        serde_json::to_value(value).map_err(|err| BacktestError::Serialization(err.to_string()))?;
+    let bytes =
+        serde_json::to_vec(&value).map_err(|err| BacktestError::Serialization(err.to_string()))?;
+    Ok(hex::encode(keccak256(&bytes)))
+}
+
+/// Writes `manifest.json` and the archived `config.json` into `dir`.
+pub fn export_manifest(
+    dir: &Path,
+    manifest: &RunManifest,
+    config: &Config,
+) -> Result<(), BacktestError> {
+    let write = |name: &str, payload: Result<String, serde_json::Error>| {
+        let payload = payload.map_err(|err| BacktestError::Serialization(err.to_string()))?;
+        fs::write(dir.join(name), payload).map_err(|err| BacktestError::Io(err.to_string()))
+    };
+    write(MANIFEST_FILE, serde_json::to_string_pretty(manifest))?;
+    write(
+        CONFIG_FILE,
+        serde_json::to_string_pretty(&archived_config(config)),
+    )
+}
+
+/// Reads `manifest.json` and `config.json` back from a run directory.
+pub fn load_manifest(dir: &Path) -> Result<(RunManifest, Config), BacktestError> {
+    let read = |name: &str| {
+        fs::read_to_string(dir.join(name)).map_err(|err| BacktestError::Io(err.to_string()))
+    };
+    let manifest = serde_json::from_str(&read(MANIFEST_FILE)?)
+        .map_err(|err| BacktestError::Serialization(err.to_string()))?;
+    let config = serde_json::from_str(&read(CONFIG_FILE)?)
+        .map_err(|err| BacktestError::Serialization(err.to_string()))?;
+    Ok((manifest, config))
+}
@@ -0,0 +1,138 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::backtest::BacktestBar;
+use crate::config::{Config, MarginMode, MarginPolicy, Symbol};
+use crate::state::{PositionLeg, PositionSnapshot};
+
+/// Account state at a bar close under `execution.leverage` and `execution.margin_mode`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MarginPoint {
+    pub timestamp: DateTime<Utc>,
+    /// Realized equity plus unrealized PnL at the close.
+    pub account_equity: Decimal,
+    /// Initial margin held by the open position; zero when flat or without a leverage.
+    pub used_margin: Decimal,
+    pub available_margin: Decimal,
+    /// Legs past maintenance margin at their worst price in the bar. Under cross margin the
+    /// whole account is liquidated, so both legs are listed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub liquidated: Vec<Symbol>,
+}
+
+/// How an entry fits the margin available to it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EntryMargin {
+    Fits,
+    /// Scale the entry capital by this factor.
+    Shrink(Decimal),
+    Reject,
+}
+
+/// Checks an entry of `notional` (both legs) against `equity` under `execution.leverage`;
+/// without a leverage every entry fits.
+///
+/// Entries only happen flat, so the whole realized equity is available.
+pub fn entry_margin(config: &Config, equity: Decimal, notional: Decimal) -> EntryMargin {
+    let Some(leverage) = config.execution.leverage.map(Decimal::from) else {
+        return EntryMargin::Fits;
+    };
+    let required = notional / leverage;
+    let available = equity.max(Decimal::ZERO);
+    if required <= available {
+        return EntryMargin::Fits;
+    }
+    match config.backtest.margin_policy {
+        MarginPolicy::Reject => EntryMargin::Reject,
+        MarginPolicy::Shrink if available > Decimal::ZERO => {
+            EntryMargin::Shrink(available / required)
+        }
+        MarginPolicy::Shrink => EntryMargin::Reject,
+    }
+}
+
+/// Marks the account at `bar`'s close with `equity` realized.
+///
+/// Isolated legs hold their entry margin and are liquidated when that margin plus the leg's
+/// PnL falls below `backtest.maintenance_margin_rate` of the leg's notional. Cross margin
+/// pools both legs against the account equity. Liquidation uses each leg's adverse
+/// intrabar extreme when the bar has one and is only flagged; the position is not closed.
+pub fn mark_margin(
+    config: &Config,
+    equity: Decimal,
+    position: Option<&PositionSnapshot>,
+    bar: &BacktestBar,
+) -> MarginPoint {
+    let leverage = config.execution.leverage.map(Decimal::from);
+    let maintenance = config.backtest.maintenance_margin_rate;
+    let mut point = MarginPoint {
+        timestamp: bar.timestamp,
+        account_equity: equity,
+        used_margin: Decimal::ZERO,
+        available_margin: equity,
+        liquidated: Vec::new(),
+    };
+    let Some(position) = position else {
+        return point;
+    };
+    let base = LegMark::new(&position.base, bar.base_price, bar.base_low, bar.base_high);
+    let quote = LegMark::new(
+        &position.quote,
+        bar.quote_price,
+        bar.quote_low,
+        bar.quote_high,
+    );
+    point.account_equity = equity + base.pnl + quote.pnl;
+    point.available_margin = point.account_equity;
+    let Some(leverage) = leverage else {
+        return point;
+    };
+    match config.execution.margin_mode {
+        MarginMode::Isolated => {
+            point.used_margin = (position.base.notional + position.quote.notional) / leverage;
+            point.available_margin = equity - point.used_margin;
+            for (symbol, leg, mark) in [
+                (Symbol::Base, &position.base, &base),
+                (Symbol::Quote, &position.quote, &quote),
+            ] {
+                if leg.notional / leverage + mark.worst_pnl < maintenance * mark.worst_notional {
+                    point.liquidated.push(symbol);
+                }
+            }
+        }
+        MarginMode::Cross => {
+            point.used_margin = (base.notional + quote.notional) / leverage;
+            point.available_margin = point.account_equity - point.used_margin;
+            let worst_equity = equity + base.worst_pnl + quote.worst_pnl;
+            if worst_equity < maintenance * (base.worst_notional + quote.worst_notional) {
+                point.liquidated = vec![Symbol::Base, Symbol::Quote];
+            }
+        }
+    }
+    point
+}
+
+/// A leg marked at the close and at its adverse extreme.
+struct LegMark {
+    pnl: Decimal,
+    notional: Decimal,
+    worst_pnl: Decimal,
+    worst_notional: Decimal,
+}
+
+impl LegMark {
+    fn new(leg: &PositionLeg, close: Decimal, low: Option<Decimal>, high: Option<Decimal>) -> Self {
+        let worst = if leg.qty > Decimal::ZERO {
+            low.unwrap_or(close).min(close)
+        } else {
+            high.unwrap_or(close).max(close)
+        };
+        Self {
+            pnl: leg.qty * (close - leg.avg_price),
+            notional: leg.qty.abs() * close,
+            worst_pnl: leg.qty * (worst - leg.avg_price),
+            worst_notional: leg.qty.abs() * worst,
+        }
+    }
+}
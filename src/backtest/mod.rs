@@ -1,8 +1,25 @@
+pub mod carry;
+pub mod diff;
 pub mod download;
+pub mod grid;
+pub mod intrabar;
+pub mod latency;
+pub mod manifest;
+pub mod margin;
+pub mod monte_carlo;
+pub mod parity;
+pub mod report;
+mod rng;
+pub mod scenario;
+pub mod simulated;
+pub mod slippage;
+pub mod stress;
+pub mod walk_forward;
 
 use std::collections::BTreeMap;
 use std::fs;
 use std::path::Path;
+use std::sync::Arc;
 
 use chrono::{DateTime, Datelike, Utc};
 use rust_decimal::Decimal;
@@ -10,14 +27,30 @@ use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use crate::config::{Config, PriceField, Symbol};
-use crate::core::pipeline::SignalPipeline;
+use crate::account::AccountPositionSource;
+use crate::backtest::carry::accrued_funding;
+use crate::backtest::intrabar::worst_case_intrabar_exit;
+use crate::backtest::latency::delayed_fill_prices;
+use crate::backtest::manifest::hash_json;
+use crate::backtest::margin::{EntryMargin, MarginPoint, entry_margin, mark_margin};
+use crate::backtest::simulated::SimulatedExchange;
+use crate::backtest::slippage::BookSlippageModel;
+use crate::config::{
+    BacktestConfig, BarInterval, Config, FundingMode, PairConfig, PriceField, SlippageModel, Symbol,
+};
+use crate::core::pipeline::{IndicatorSnapshot, SignalPipeline};
+use crate::core::strategy::{
+    SpreadHalfLifeTracker, StrategyBar, StrategyEngine, cost_gate_decision,
+    directional_size_multiplier,
+};
 use crate::core::{ExitReason, TradeDirection};
 use crate::data::align_to_bar_close;
-use crate::funding::{FundingRate, estimate_funding_cost};
-use crate::logging::BarLog;
-use crate::position::{PositionError, SizeConverter, compute_capital, risk_parity_weights};
-use crate::state::{PositionLeg, PositionSnapshot, StateMachine};
+use crate::execution::{ExecutionEngine, RetryConfig};
+use crate::funding::{FundingRate, apply_funding_controls, estimate_funding_cost};
+use crate::indicators::relative_price;
+use crate::logging::{BarLog, EntryBlockReason, LogFormatter, TradeEvent, TradeLog};
+use crate::position::{PositionError, SizeConverter, compute_capital, spread_weights};
+use crate::state::{PositionLeg, PositionSnapshot, StateMachine, StrategyStatus};
 use crate::storage::PriceStore;
 
 #[derive(Debug, Error)]
@@ -34,15 +67,36 @@ pub enum BacktestError {
     Io(String),
     #[error("serialization error: {0}")]
     Serialization(String),
+    #[error("strategy error: {0}")]
+    Strategy(String),
+    #[error("invalid configuration: {0}")]
+    InvalidConfig(String),
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BacktestBar {
     pub timestamp: DateTime<Utc>,
-    pub eth_price: Decimal,
-    pub btc_price: Decimal,
-    pub funding_eth: Option<Decimal>,
-    pub funding_btc: Option<Decimal>,
+    #[serde(alias = "eth_price")]
+    pub base_price: Decimal,
+    #[serde(alias = "btc_price")]
+    pub quote_price: Decimal,
+    #[serde(alias = "funding_eth")]
+    pub funding_base: Option<Decimal>,
+    #[serde(alias = "funding_btc")]
+    pub funding_quote: Option<Decimal>,
+    /// Intrabar open/high/low per leg; `base_price`/`quote_price` are the closes.
+    #[serde(default)]
+    pub base_open: Option<Decimal>,
+    #[serde(default)]
+    pub base_high: Option<Decimal>,
+    #[serde(default)]
+    pub base_low: Option<Decimal>,
+    #[serde(default)]
+    pub quote_open: Option<Decimal>,
+    #[serde(default)]
+    pub quote_high: Option<Decimal>,
+    #[serde(default)]
+    pub quote_low: Option<Decimal>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -50,6 +104,8 @@ pub enum TradeExitReason {
     TakeProfit,
     StopLoss,
     TimeStop,
+    /// A one-legged position flattened by residual repair (simulated exchange only).
+    ResidualRepair,
 }
 
 impl From<ExitReason> for TradeExitReason {
@@ -62,12 +118,57 @@ impl From<ExitReason> for TradeExitReason {
     }
 }
 
+/// A closed backtest trade, named after the live `TradeLog` fields where they overlap.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Trade {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pair: Option<String>,
+    pub direction: TradeDirection,
     pub entry_time: DateTime<Utc>,
     pub exit_time: DateTime<Utc>,
+    /// Signed leg sizes held; positive is long.
+    #[serde(default)]
+    pub base_qty: Decimal,
+    #[serde(default)]
+    pub quote_qty: Decimal,
+    #[serde(default)]
+    pub entry_base_price: Decimal,
+    #[serde(default)]
+    pub entry_quote_price: Decimal,
+    /// Exit fill prices.
+    #[serde(default)]
+    pub base_price: Decimal,
+    #[serde(default)]
+    pub quote_price: Decimal,
+    #[serde(default)]
+    pub entry_zscore: Option<Decimal>,
+    #[serde(default)]
+    pub exit_zscore: Option<Decimal>,
+    /// Net PnL: `gross_pnl` less fee, slippage and funding.
     pub pnl: Decimal,
+    /// Price PnL before costs.
+    #[serde(default)]
+    pub gross_pnl: Decimal,
     pub exit_reason: TradeExitReason,
+    /// Gross entry notional across both legs.
+    #[serde(default)]
+    pub notional: Decimal,
+    /// Entry plus exit fees.
+    #[serde(default, alias = "fees")]
+    pub fee: Decimal,
+    /// Entry plus exit slippage in quote currency.
+    #[serde(default)]
+    pub slippage: Decimal,
+    /// Per-leg slippage over both fills, in bps of the leg notional.
+    #[serde(default)]
+    pub base_slippage_bps: Option<Decimal>,
+    #[serde(default)]
+    pub quote_slippage_bps: Option<Decimal>,
+    /// Funding accrued at each settlement while open; positive is paid.
+    #[serde(default)]
+    pub funding: Decimal,
+    #[serde(default)]
+    pub holding_hours: Decimal,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -85,6 +186,39 @@ pub struct Metrics {
     pub profit_factor: Decimal,
     pub stop_loss_rate: Decimal,
     pub trade_count: usize,
+    #[serde(default)]
+    pub sortino_ratio: Decimal,
+    /// Annualized return over max drawdown.
+    #[serde(default)]
+    pub calmar_ratio: Decimal,
+    /// Longest time spent below a previous equity peak.
+    #[serde(default)]
+    pub max_drawdown_duration_hours: Decimal,
+    /// Share of the equity curve span with an open position.
+    #[serde(default)]
+    pub time_in_market: Decimal,
+    #[serde(default)]
+    pub avg_holding_hours: Decimal,
+    #[serde(default)]
+    pub median_holding_hours: Decimal,
+    /// Entry plus exit notional across all trades.
+    #[serde(default)]
+    pub traded_notional: Decimal,
+    /// `traded_notional` over starting equity.
+    #[serde(default)]
+    pub turnover: Decimal,
+    #[serde(default)]
+    pub total_fees: Decimal,
+    #[serde(default)]
+    pub total_funding: Decimal,
+    #[serde(default)]
+    pub pnl_by_exit_reason: BTreeMap<String, Decimal>,
+    /// Bars where a leg would have been liquidated (see `MarginPoint::liquidated`).
+    #[serde(default)]
+    pub liquidation_bars: usize,
+    /// Peak used margin over account equity.
+    #[serde(default)]
+    pub max_margin_usage: Decimal,
 }
 
 impl Default for Metrics {
@@ -97,6 +231,19 @@ impl Default for Metrics {
             profit_factor: Decimal::ZERO,
             stop_loss_rate: Decimal::ZERO,
             trade_count: 0,
+            sortino_ratio: Decimal::ZERO,
+            calmar_ratio: Decimal::ZERO,
+            max_drawdown_duration_hours: Decimal::ZERO,
+            time_in_market: Decimal::ZERO,
+            avg_holding_hours: Decimal::ZERO,
+            median_holding_hours: Decimal::ZERO,
+            traded_notional: Decimal::ZERO,
+            turnover: Decimal::ZERO,
+            total_fees: Decimal::ZERO,
+            total_funding: Decimal::ZERO,
+            pnl_by_exit_reason: BTreeMap::new(),
+            liquidation_bars: 0,
+            max_margin_usage: Decimal::ZERO,
         }
     }
 }
@@ -106,161 +253,458 @@ pub struct BacktestResult {
     pub trades: Vec<Trade>,
     pub equity_curve: Vec<EquityPoint>,
     pub bar_logs: Vec<BarLog>,
+    /// Account equity and margin per bar.
+    pub margin: Vec<MarginPoint>,
     pub metrics: Metrics,
 }
 
 #[derive(Debug, Clone)]
 pub struct BacktestEngine {
     config: Config,
+    minute_bars: Vec<BacktestBar>,
+    book_slippage: BookSlippageModel,
+    trading_start: Option<DateTime<Utc>>,
+    starting_equity: Option<Decimal>,
 }
 
 impl BacktestEngine {
     pub fn new(config: Config) -> Self {
-        Self { config }
+        Self {
+            config,
+            minute_bars: Vec::new(),
+            book_slippage: BookSlippageModel::default(),
+            trading_start: None,
+            starting_equity: None,
+        }
     }
 
-    pub fn run(&self, bars: &[BacktestBar]) -> Result<BacktestResult, BacktestError> {
-        let mut pipeline = SignalPipeline::new(&self.config)
-            .map_err(|err| BacktestError::Indicator(err.to_string()))?;
-        let mut state_machine = StateMachine::new(self.config.risk.clone());
+    /// Book data for `slippage_model = "BOOK_DEPTH"`.
+    pub fn with_book_slippage(mut self, model: BookSlippageModel) -> Self {
+        self.book_slippage = model;
+        self
+    }
 
-        let mut trades = Vec::new();
-        let mut equity_curve = Vec::new();
-        let mut bar_logs = Vec::new();
-        let mut equity = match self.config.position.c_mode {
-            crate::config::CapitalMode::FixedNotional => self
+    /// 1m bars used for `NEXT_MINUTE` fills, sorted by timestamp.
+    pub fn with_minute_bars(mut self, bars: Vec<BacktestBar>) -> Self {
+        self.minute_bars = bars;
+        self
+    }
+
+    /// Native runs only warm their indicators on bars before `start` and open no positions
+    /// there.
+    pub fn with_trading_start(mut self, start: DateTime<Utc>) -> Self {
+        self.trading_start = Some(start);
+        self
+    }
+
+    /// Overrides the equity the backtest starts from and sizes its first entry with.
+    pub fn with_starting_equity(mut self, equity: Decimal) -> Self {
+        self.starting_equity = Some(equity);
+        self
+    }
+
+    /// Equity the backtest starts from: `c_value` for fixed notional, else `equity_value`,
+    /// unless set with `with_starting_equity`.
+    pub fn starting_equity(&self) -> Result<Decimal, BacktestError> {
+        if let Some(equity) = self.starting_equity {
+            return Ok(equity);
+        }
+        match self.config.position.c_mode {
+            crate::config::CapitalMode::FixedNotional => Ok(self
                 .config
                 .position
                 .c_value
-                .unwrap_or(Decimal::new(100000, 0)),
+                .unwrap_or(Decimal::new(100000, 0))),
             crate::config::CapitalMode::EquityRatio => {
                 self.config.position.equity_value.ok_or_else(|| {
                     BacktestError::Position(
                         "equity_value required for equity ratio mode".to_string(),
                     )
-                })?
+                })
             }
-        };
+        }
+    }
 
-        let mut open_trade: Option<(PositionSnapshot, Decimal, Decimal)> = None;
+    pub fn run(&self, bars: &[BacktestBar]) -> Result<BacktestResult, BacktestError> {
+        self.run_inner(bars, None)
+    }
 
-        for bar in bars {
-            let output = pipeline
-                .update(
-                    bar.timestamp,
-                    bar.eth_price,
-                    bar.btc_price,
-                    state_machine.state().status,
-                    state_machine.state().position.as_ref(),
-                )
-                .map_err(|err| BacktestError::Indicator(err.to_string()))?;
+    /// Runs over indicator values precomputed by `precompute_indicators` for these bars
+    /// with the same indicator settings as this engine's config.
+    pub fn run_with_indicators(
+        &self,
+        bars: &[BacktestBar],
+        indicators: &[IndicatorSnapshot],
+    ) -> Result<BacktestResult, BacktestError> {
+        if indicators.len() != bars.len() {
+            return Err(BacktestError::InvalidConfig(format!(
+                "indicator series has {} bars, expected {}",
+                indicators.len(),
+                bars.len()
+            )));
+        }
+        self.run_inner(bars, Some(indicators))
+    }
+
+    fn run_inner(
+        &self,
+        bars: &[BacktestBar],
+        indicators: Option<&[IndicatorSnapshot]>,
+    ) -> Result<BacktestResult, BacktestError> {
+        let mut pipeline = SignalPipeline::new(&self.config)
+            .map_err(|err| BacktestError::Indicator(err.to_string()))?;
+        let mut state_machine = StateMachine::new(self.config.risk.clone());
+        let mut regime_tracker = SpreadHalfLifeTracker::new(self.config.regime_gate.lookback_bars);
+
+        let mut trades = Vec::new();
+        let mut equity_curve = Vec::new();
+        let mut bar_logs = Vec::new();
+        let mut margin = Vec::new();
+        let mut equity = self.starting_equity()?;
+
+        let mut open_trade: Option<OpenTrade> = None;
+
+        for (index, bar) in bars.iter().enumerate() {
+            // Expire a stop-loss cooldown the same way the live loop does each bar.
+            state_machine.update(bar.timestamp);
+            let snapshot = match indicators {
+                Some(series) => series[index].clone(),
+                None => pipeline
+                    .update_indicators(bar.timestamp, bar.base_price, bar.quote_price)
+                    .map_err(|err| BacktestError::Indicator(err.to_string()))?,
+            };
+            let output = pipeline.evaluate(
+                bar.timestamp,
+                snapshot,
+                state_machine.state().status,
+                state_machine.state().position.as_ref(),
+                &[],
+            );
             let r = output.r;
             let z_snapshot = output.z_snapshot;
             let vol_snapshot = output.vol_snapshot;
-            let entry_signal = output.entry_signal;
-            let exit_signal = output.exit_signal;
-            if let Some(signal) = entry_signal
-                && vol_snapshot.vol_eth.is_some()
-                && vol_snapshot.vol_btc.is_some()
+            let entry_signal = output.entry_signal.filter(|_| {
+                self.trading_start
+                    .is_none_or(|start| bar.timestamp >= start)
+            });
+            // Positions carried into this bar may hit a bound before the close.
+            let intrabar_exit = match open_trade.as_ref() {
+                Some(open) if self.config.backtest.intrabar_exits => worst_case_intrabar_exit(
+                    bar,
+                    open.position.direction,
+                    output.beta.unwrap_or(Decimal::ONE),
+                    &z_snapshot,
+                    self.config.strategy.tp_z,
+                    self.config.strategy.sl_z,
+                    self.config.risk.confirm_bars_tp == 0,
+                ),
+                _ => None,
+            };
+            // Orders decided at this close fill under the configured execution delay;
+            // intrabar stops fill where their bound is crossed.
+            let fill = || {
+                delayed_fill_prices(
+                    self.config.backtest.execution_delay,
+                    self.config.backtest.execution_delay_secs,
+                    bars,
+                    index,
+                    &self.minute_bars,
+                )
+            };
+            // Funding settling at this close is owed unless an intrabar stop closed the
+            // position first.
+            if let Some(open) = open_trade.as_mut()
+                && intrabar_exit.is_none()
             {
-                let capital = compute_capital(&self.config.position, equity)
+                if self.config.backtest.include_funding {
+                    open.funding += accrued_funding(
+                        &open.position,
+                        open.marked_at,
+                        bar,
+                        self.config.backtest.funding_interval_hours,
+                    );
+                }
+                open.marked_at = bar.timestamp;
+            }
+            let exit = match intrabar_exit {
+                Some(exit) => Some((exit.signal, exit.base_price, exit.quote_price)),
+                None => output.exit_signal.map(|signal| {
+                    let (base, quote) = fill();
+                    (signal, base, quote)
+                }),
+            };
+            let regime_snapshot = regime_tracker.push(
+                r,
+                self.config.regime_gate.max_half_life_bars,
+                self.config.regime_gate.enabled,
+            );
+
+            let mut w_base = None;
+            let mut w_quote = None;
+            let mut notional_base = None;
+            let mut notional_quote = None;
+            let mut funding_cost_est = None;
+            let mut funding_skip = None;
+            let mut cost_gate = None;
+            let mut entry_block_reason = None;
+
+            if let Some(vol_base) = vol_snapshot.vol_base
+                && let Some(vol_quote) = vol_snapshot.vol_quote
+            {
+                let weights = spread_weights(&self.config.spread, vol_base, vol_quote, output.beta)
                     .map_err(|err| BacktestError::Position(err.to_string()))?;
-                if let Some(max_notional) = self.config.position.max_notional
-                    && capital > max_notional
-                {
-                    return Err(BacktestError::Position(format!(
-                        "capital {capital} exceeds max_notional {max_notional}"
-                    )));
+                w_base = Some(weights.w_base);
+                w_quote = Some(weights.w_quote);
+            }
+
+            if state_machine.state().status == StrategyStatus::Flat {
+                if z_snapshot.zscore.is_none() {
+                    entry_block_reason = Some(EntryBlockReason::ZscoreUnavailable);
+                } else if entry_signal.is_none() {
+                    entry_block_reason = Some(EntryBlockReason::NoCross);
+                } else if vol_snapshot.vol_base.is_none() || vol_snapshot.vol_quote.is_none() {
+                    entry_block_reason = Some(EntryBlockReason::VolatilityUnavailable);
                 }
-                let weights = risk_parity_weights(
-                    vol_snapshot.vol_eth.unwrap(),
-                    vol_snapshot.vol_btc.unwrap(),
-                )
-                .map_err(|err| BacktestError::Position(err.to_string()))?;
-                let notional_eth = capital * weights.w_eth;
-                let notional_btc = capital * weights.w_btc;
-
-                let eth_converter = SizeConverter::new(
-                    self.config
-                        .instrument_constraints
-                        .get(&Symbol::EthPerp)
-                        .cloned()
-                        .unwrap_or_default(),
-                    self.config.position.min_size_policy,
-                );
-                let btc_converter = SizeConverter::new(
-                    self.config
-                        .instrument_constraints
-                        .get(&Symbol::BtcPerp)
-                        .cloned()
-                        .unwrap_or_default(),
-                    self.config.position.min_size_policy,
-                );
-                let eth_order = match eth_converter.convert_notional(notional_eth, bar.eth_price) {
-                    Ok(order) => Some(order),
-                    Err(PositionError::BelowMinimum(_)) => None,
-                    Err(err) => return Err(BacktestError::Position(err.to_string())),
-                };
-                let btc_order = match btc_converter.convert_notional(notional_btc, bar.btc_price) {
-                    Ok(order) => Some(order),
-                    Err(PositionError::BelowMinimum(_)) => None,
-                    Err(err) => return Err(BacktestError::Position(err.to_string())),
-                };
+            }
+
+            if let Some(signal) = entry_signal
+                && let (Some(weight_base), Some(weight_quote)) = (w_base, w_quote)
+            {
+                'entry: {
+                    // Entries only happen flat, so the realized equity is the account equity
+                    // live sizing reads from `marginSummary`.
+                    let base_capital = compute_capital(&self.config.position, equity)
+                        .map_err(|err| BacktestError::Position(err.to_string()))?;
+                    let mut capital =
+                        base_capital * directional_size_multiplier(&self.config, signal.direction);
+                    if let Some(max_notional) = self.config.position.max_notional
+                        && capital > max_notional
+                    {
+                        return Err(BacktestError::Position(format!(
+                            "capital {capital} exceeds max_notional {max_notional}"
+                        )));
+                    }
+                    match entry_margin(&self.config, equity, capital * (weight_base + weight_quote))
+                    {
+                        EntryMargin::Fits => {}
+                        EntryMargin::Shrink(scale) => capital *= scale,
+                        EntryMargin::Reject => {
+                            entry_block_reason = Some(EntryBlockReason::InsufficientMargin);
+                            break 'entry;
+                        }
+                    }
+                    let notional_base_value = capital * weight_base;
+                    let notional_quote_value = capital * weight_quote;
+                    notional_base = Some(notional_base_value);
+                    notional_quote = Some(notional_quote_value);
 
-                if let (Some(eth_order), Some(btc_order)) = (eth_order, btc_order) {
+                    if let (Some(funding_base), Some(funding_quote)) =
+                        (bar.funding_base, bar.funding_quote)
+                    {
+                        let base_rate = FundingRate {
+                            symbol: Symbol::Base,
+                            rate: funding_base,
+                            timestamp: bar.timestamp,
+                            interval_hours: self.config.backtest.funding_interval_hours,
+                        };
+                        let quote_rate = FundingRate {
+                            symbol: Symbol::Quote,
+                            rate: funding_quote,
+                            timestamp: bar.timestamp,
+                            interval_hours: self.config.backtest.funding_interval_hours,
+                        };
+                        let estimate = estimate_funding_cost(
+                            signal.direction,
+                            notional_base_value,
+                            notional_quote_value,
+                            &base_rate,
+                            &quote_rate,
+                            self.config.risk.max_hold_hours,
+                        )
+                        .map_err(|err| BacktestError::Funding(err.to_string()))?;
+                        funding_cost_est = Some(estimate.cost_est);
+                        let decision = apply_funding_controls(
+                            &self.config.funding,
+                            self.config.strategy.entry_z,
+                            capital,
+                            &estimate,
+                        )
+                        .map_err(|err| BacktestError::Funding(err.to_string()))?;
+                        funding_skip = Some(decision.should_skip);
+                        if decision.should_skip {
+                            entry_block_reason = Some(EntryBlockReason::FundingFilter);
+                            break 'entry;
+                        }
+                        if self.config.funding.modes.contains(&FundingMode::Threshold)
+                            && signal.zscore.abs() < decision.adjusted_entry_z
+                        {
+                            entry_block_reason = Some(EntryBlockReason::FundingThreshold);
+                            break 'entry;
+                        }
+                    }
+
+                    if self.config.regime_gate.enabled && regime_snapshot.pass != Some(true) {
+                        entry_block_reason = Some(EntryBlockReason::RegimeGate);
+                        break 'entry;
+                    }
+
+                    cost_gate = cost_gate_decision(
+                        &self.config,
+                        signal.direction,
+                        signal.zscore,
+                        z_snapshot.sigma_eff,
+                        capital,
+                        funding_cost_est,
+                    );
+                    if let Some(decision) = cost_gate
+                        && self.config.cost_gate.enforce
+                        && !decision.pass
+                    {
+                        entry_block_reason = Some(EntryBlockReason::CostGate);
+                        break 'entry;
+                    }
+
+                    let base_converter = SizeConverter::new(
+                        self.config
+                            .instrument_constraints
+                            .get(&Symbol::Base)
+                            .cloned()
+                            .unwrap_or_default(),
+                        self.config.position.min_size_policy,
+                    );
+                    let quote_converter = SizeConverter::new(
+                        self.config
+                            .instrument_constraints
+                            .get(&Symbol::Quote)
+                            .cloned()
+                            .unwrap_or_default(),
+                        self.config.position.min_size_policy,
+                    );
+                    let base_order = match base_converter
+                        .convert_notional(notional_base_value, bar.base_price)
+                    {
+                        Ok(order) => order,
+                        Err(PositionError::BelowMinimum(_)) => {
+                            entry_block_reason = Some(EntryBlockReason::BelowMinSizeBase);
+                            break 'entry;
+                        }
+                        Err(err) => return Err(BacktestError::Position(err.to_string())),
+                    };
+                    let quote_order = match quote_converter
+                        .convert_notional(notional_quote_value, bar.quote_price)
+                    {
+                        Ok(order) => order,
+                        Err(PositionError::BelowMinimum(_)) => {
+                            entry_block_reason = Some(EntryBlockReason::BelowMinSizeQuote);
+                            break 'entry;
+                        }
+                        Err(err) => return Err(BacktestError::Position(err.to_string())),
+                    };
+
+                    let (fill_base, fill_quote) = fill();
                     let position = PositionSnapshot {
                         direction: signal.direction,
                         entry_time: bar.timestamp,
-                        eth: PositionLeg {
-                            qty: if signal.direction == TradeDirection::LongEthShortBtc {
-                                eth_order.qty
+                        base: PositionLeg {
+                            qty: if signal.direction == TradeDirection::LongBaseShortQuote {
+                                base_order.qty
                             } else {
-                                -eth_order.qty
+                                -base_order.qty
                             },
-                            avg_price: bar.eth_price,
-                            notional: notional_eth,
+                            avg_price: fill_base,
+                            notional: notional_base_value,
                         },
-                        btc: PositionLeg {
-                            qty: if signal.direction == TradeDirection::LongEthShortBtc {
-                                -btc_order.qty
+                        quote: PositionLeg {
+                            qty: if signal.direction == TradeDirection::LongBaseShortQuote {
+                                -quote_order.qty
                             } else {
-                                btc_order.qty
+                                quote_order.qty
                             },
-                            avg_price: bar.btc_price,
-                            notional: notional_btc,
+                            avg_price: fill_quote,
+                            notional: notional_quote_value,
                         },
                     };
                     state_machine
                         .enter(position.clone(), bar.timestamp)
                         .map_err(|err| BacktestError::Position(err.to_string()))?;
-                    open_trade = Some((position, bar.eth_price, bar.btc_price));
+                    let entry_slippage = self.book_fill_slippage(
+                        bar,
+                        position.direction,
+                        true,
+                        position.base.notional,
+                        position.quote.notional,
+                    );
+                    open_trade = Some(OpenTrade {
+                        position,
+                        entry_base: fill_base,
+                        entry_quote: fill_quote,
+                        entry_slippage,
+                        entry_zscore: signal.zscore,
+                        funding: Decimal::ZERO,
+                        marked_at: bar.timestamp,
+                    });
                 }
             }
 
-            if let Some(exit_signal) = exit_signal
-                && let Some((position, entry_eth, entry_btc)) = open_trade.take()
+            if let Some((exit_signal, exit_base, exit_quote)) = exit
+                && let Some(open) = open_trade.take()
             {
-                let pnl = compute_trade_pnl(
+                let position = open.position;
+                let exit_fill = self.book_fill_slippage(
+                    bar,
+                    position.direction,
+                    false,
+                    exit_notional(position.base.notional, open.entry_base, exit_base),
+                    exit_notional(position.quote.notional, open.entry_quote, exit_quote),
+                );
+                let slippage = open.entry_slippage.zip(exit_fill).map(
+                    |((entry_base, entry_quote), (exit_base, exit_quote))| {
+                        (entry_base + exit_base, entry_quote + exit_quote)
+                    },
+                );
+                let trade_pnl = compute_trade_pnl(
                     TradeInput {
                         direction: position.direction,
-                        entry_eth,
-                        entry_btc,
-                        exit_eth: bar.eth_price,
-                        exit_btc: bar.btc_price,
-                        notional_eth: position.eth.notional,
-                        notional_btc: position.btc.notional,
-                        bar,
-                        holding_hours: (bar.timestamp - position.entry_time).num_hours().max(0)
-                            as u32,
+                        entry_base: open.entry_base,
+                        entry_quote: open.entry_quote,
+                        exit_base,
+                        exit_quote,
+                        notional_base: position.base.notional,
+                        notional_quote: position.quote.notional,
+                        funding: open.funding,
+                        slippage,
                     },
                     &self.config,
-                )?;
-                equity += pnl;
+                );
+                equity += trade_pnl.pnl;
+                let leg_bps = |cost: Decimal, notional: Decimal| {
+                    (notional > Decimal::ZERO).then(|| cost / notional * Decimal::new(10000, 0))
+                };
                 trades.push(Trade {
+                    pair: Some(self.config.pair.label()),
+                    direction: position.direction,
                     entry_time: position.entry_time,
                     exit_time: bar.timestamp,
-                    pnl,
+                    base_qty: position.base.qty,
+                    quote_qty: position.quote.qty,
+                    entry_base_price: open.entry_base,
+                    entry_quote_price: open.entry_quote,
+                    base_price: exit_base,
+                    quote_price: exit_quote,
+                    entry_zscore: Some(open.entry_zscore),
+                    exit_zscore: Some(exit_signal.zscore),
+                    pnl: trade_pnl.pnl,
+                    gross_pnl: trade_pnl.gross_pnl,
                     exit_reason: exit_signal.reason.into(),
+                    notional: position.base.notional + position.quote.notional,
+                    fee: trade_pnl.fee,
+                    slippage: trade_pnl.base_slippage + trade_pnl.quote_slippage,
+                    base_slippage_bps: leg_bps(trade_pnl.base_slippage, position.base.notional),
+                    quote_slippage_bps: leg_bps(trade_pnl.quote_slippage, position.quote.notional),
+                    funding: trade_pnl.funding,
+                    holding_hours: seconds_to_hours(
+                        (bar.timestamp - position.entry_time).num_seconds(),
+                    ),
                 });
                 state_machine
                     .exit(exit_signal.reason, bar.timestamp)
@@ -271,55 +715,66 @@ impl BacktestEngine {
                 timestamp: bar.timestamp,
                 equity,
             });
+            margin.push(mark_margin(
+                &self.config,
+                equity,
+                state_machine.state().position.as_ref(),
+                bar,
+            ));
 
             let unrealized_pnl = state_machine
                 .state()
                 .position
                 .as_ref()
                 .map(|position| {
-                    let eth_pnl = position.eth.qty * (bar.eth_price - position.eth.avg_price);
-                    let btc_pnl = position.btc.qty * (bar.btc_price - position.btc.avg_price);
-                    eth_pnl + btc_pnl
+                    let base_pnl = position.base.qty * (bar.base_price - position.base.avg_price);
+                    let quote_pnl =
+                        position.quote.qty * (bar.quote_price - position.quote.avg_price);
+                    base_pnl + quote_pnl
                 })
                 .unwrap_or(Decimal::ZERO);
 
             bar_logs.push(BarLog {
                 timestamp: bar.timestamp,
-                eth_price: Some(bar.eth_price),
-                btc_price: Some(bar.btc_price),
-                r: Some(r),
+                pair: Some(self.config.pair.label()),
+                base_price: Some(bar.base_price),
+                quote_price: Some(bar.quote_price),
+                r: relative_price(bar.base_price, bar.quote_price).ok(),
+                spread: output.beta.map(|_| r),
+                beta: output.beta,
                 mu: z_snapshot.mean,
                 sigma: z_snapshot.sigma,
                 sigma_eff: z_snapshot.sigma_eff,
                 zscore: z_snapshot.zscore,
-                vol_eth: vol_snapshot.vol_eth,
-                vol_btc: vol_snapshot.vol_btc,
-                w_eth: None,
-                w_btc: None,
-                notional_eth: None,
-                notional_btc: None,
-                funding_eth: bar.funding_eth,
-                funding_btc: bar.funding_btc,
-                funding_cost_est: None,
-                funding_skip: None,
-                regime_half_life_bars: None,
-                regime_gate_pass: None,
-                expected_edge_bps: None,
-                estimated_cost_bps: None,
-                estimated_net_edge_bps: None,
-                cost_gate_required_net_edge_bps: None,
-                cost_gate_pass: None,
-                eth_best_bid: None,
-                eth_best_ask: None,
-                eth_bid_size: None,
-                eth_ask_size: None,
-                eth_spread_bps: None,
-                btc_best_bid: None,
-                btc_best_ask: None,
-                btc_bid_size: None,
-                btc_ask_size: None,
-                btc_spread_bps: None,
-                entry_block_reason: None,
+                vol_base: vol_snapshot.vol_base,
+                vol_quote: vol_snapshot.vol_quote,
+                w_base,
+                w_quote,
+                notional_base,
+                notional_quote,
+                funding_base: bar.funding_base,
+                funding_quote: bar.funding_quote,
+                funding_cost_est,
+                funding_skip,
+                regime_half_life_bars: regime_snapshot.half_life_bars,
+                regime_gate_pass: regime_snapshot.pass,
+                expected_edge_bps: cost_gate.map(|decision| decision.expected_edge_bps),
+                estimated_cost_bps: cost_gate.map(|decision| decision.estimated_cost_bps),
+                estimated_net_edge_bps: cost_gate.map(|decision| decision.estimated_net_edge_bps),
+                cost_gate_required_net_edge_bps: cost_gate
+                    .map(|decision| decision.required_net_edge_bps),
+                cost_gate_pass: cost_gate.map(|decision| decision.pass),
+                base_best_bid: None,
+                base_best_ask: None,
+                base_bid_size: None,
+                base_ask_size: None,
+                base_spread_bps: None,
+                quote_best_bid: None,
+                quote_best_ask: None,
+                quote_bid_size: None,
+                quote_ask_size: None,
+                quote_spread_bps: None,
+                entry_block_reason,
                 run_error: None,
                 unrealized_pnl,
                 state: state_machine.state().status,
@@ -328,148 +783,437 @@ impl BacktestEngine {
             });
         }
 
-        let metrics = compute_metrics(&trades, &equity_curve, Decimal::ZERO)?;
+        let mut metrics = compute_metrics(&trades, &equity_curve, Decimal::ZERO)?;
+        apply_margin_metrics(&mut metrics, &margin);
 
         Ok(BacktestResult {
             trades,
             equity_curve,
             bar_logs,
+            margin,
             metrics,
         })
     }
+
+    /// Book-depth ETH and BTC slippage for opening (`entering`) or closing `direction` with the
+    /// given leg notionals on `bar`; `None` keeps the flat per-fill charge.
+    fn book_fill_slippage(
+        &self,
+        bar: &BacktestBar,
+        direction: TradeDirection,
+        entering: bool,
+        notional_base: Decimal,
+        notional_quote: Decimal,
+    ) -> Option<(Decimal, Decimal)> {
+        let backtest = &self.config.backtest;
+        if !backtest.include_slippage || backtest.slippage_model != SlippageModel::BookDepth {
+            return None;
+        }
+        let buy_base = (direction == TradeDirection::LongBaseShortQuote) == entering;
+        let flat_bps = flat_fill_bps(&self.config.backtest);
+        let base = self.book_slippage.fill_cost(
+            bar.timestamp,
+            Symbol::Base,
+            buy_base,
+            notional_base,
+            backtest.impact_bps,
+            flat_bps,
+        );
+        let quote = self.book_slippage.fill_cost(
+            bar.timestamp,
+            Symbol::Quote,
+            !buy_base,
+            notional_quote,
+            backtest.impact_bps,
+            flat_bps,
+        );
+        Some((base, quote))
+    }
+
+    pub async fn run_simulated(
+        &self,
+        bars: &[BacktestBar],
+    ) -> Result<BacktestResult, BacktestError> {
+        let initial_equity = self.starting_equity()?;
+        let exchange = Arc::new(SimulatedExchange::new(&self.config.backtest));
+        let execution = ExecutionEngine::new(
+            exchange.clone(),
+            RetryConfig {
+                max_attempts: 1,
+                base_delay_ms: 0,
+            },
+        );
+        let mut engine = StrategyEngine::new(self.config.clone(), execution)
+            .map_err(|err| BacktestError::Strategy(err.to_string()))?
+            .with_fill_source(exchange.clone());
+
+        let mut trades = Vec::new();
+        let mut equity_curve = Vec::new();
+        let mut bar_logs = Vec::new();
+        let mut margin = Vec::new();
+        let mut equity = initial_equity;
+        let mut open_trade = SimulatedTrade::default();
+        // Last close funding was accrued through while a position is open.
+        let mut funding_marked_at = None;
+
+        for (index, bar) in bars.iter().enumerate() {
+            exchange.advance_to(bar.timestamp, bar.base_price, bar.quote_price);
+            let exposure = exchange
+                .fetch_pair_exposure()
+                .await
+                .map_err(|err| BacktestError::Strategy(err.to_string()))?;
+            // Orders decided at this close execute under the configured execution delay.
+            let (fill_base, fill_quote) = delayed_fill_prices(
+                self.config.backtest.execution_delay,
+                self.config.backtest.execution_delay_secs,
+                bars,
+                index,
+                &self.minute_bars,
+            );
+            exchange.reprice(fill_base, fill_quote);
+            engine
+                .reconcile_exchange_position(
+                    &exposure,
+                    bar.timestamp,
+                    bar.base_price,
+                    bar.quote_price,
+                )
+                .await
+                .map_err(|err| BacktestError::Strategy(err.to_string()))?;
+            // Funding settling at this close is owed on the position carried into the bar.
+            if let (Some(position), Some(marked_at)) =
+                (engine.state().state().position.as_ref(), funding_marked_at)
+            {
+                if self.config.backtest.include_funding {
+                    let funding = accrued_funding(
+                        position,
+                        marked_at,
+                        bar,
+                        self.config.backtest.funding_interval_hours,
+                    );
+                    open_trade.funding += funding;
+                    equity -= funding;
+                }
+                funding_marked_at = Some(bar.timestamp);
+            }
+            let outcome = engine
+                .process_bar(StrategyBar {
+                    timestamp: bar.timestamp,
+                    base_price: bar.base_price,
+                    quote_price: bar.quote_price,
+                    equity: Some(equity),
+                    funding_base: bar.funding_base,
+                    funding_quote: bar.funding_quote,
+                    funding_interval_hours: Some(self.config.backtest.funding_interval_hours),
+                })
+                .await
+                .map_err(|err| BacktestError::Strategy(err.to_string()))?;
+
+            let mut closing_exit = None;
+            for log in &outcome.trade_logs {
+                equity += log.realized_pnl;
+                match log.event {
+                    TradeEvent::Entry | TradeEvent::ScaleIn => {
+                        if open_trade.entry_zscore.is_none() {
+                            open_trade.entry_zscore = outcome.bar_log.zscore;
+                        }
+                        open_trade.record_fill(log);
+                        open_trade.base_qty += log.base_qty;
+                        open_trade.quote_qty += log.quote_qty;
+                        open_trade.base_notional += log.base_qty.abs() * log.base_price;
+                        open_trade.quote_notional += log.quote_qty.abs() * log.quote_price;
+                    }
+                    TradeEvent::Exit(reason) => {
+                        // A scaled-in group exit leaves the rest of the position open, so the
+                        // trade is only booked once the position is flat.
+                        open_trade.record_fill(log);
+                        closing_exit = Some((log, reason));
+                    }
+                    TradeEvent::ResidualRepair => {
+                        // A partial entry adopted from the exchange has no entry log.
+                        if open_trade.base_notional + open_trade.quote_notional == Decimal::ZERO {
+                            open_trade.base_notional = log.base_qty.abs() * log.entry_base_price;
+                            open_trade.quote_notional = log.quote_qty.abs() * log.entry_quote_price;
+                        }
+                        open_trade.record_fill(log);
+                        trades.push(std::mem::take(&mut open_trade).close(
+                            log,
+                            TradeExitReason::ResidualRepair,
+                            outcome.bar_log.zscore,
+                        ));
+                    }
+                }
+            }
+            if let Some((log, reason)) = closing_exit
+                && outcome.bar_log.position.is_none()
+            {
+                trades.push(std::mem::take(&mut open_trade).close(
+                    log,
+                    reason.into(),
+                    outcome.bar_log.zscore,
+                ));
+            }
+            funding_marked_at = match outcome.bar_log.position {
+                Some(_) => funding_marked_at.or(Some(bar.timestamp)),
+                None => None,
+            };
+
+            equity_curve.push(EquityPoint {
+                timestamp: bar.timestamp,
+                equity,
+            });
+            margin.push(mark_margin(
+                &self.config,
+                equity,
+                outcome.bar_log.position.as_ref(),
+                bar,
+            ));
+            bar_logs.push(outcome.bar_log);
+        }
+
+        let mut metrics = compute_metrics(&trades, &equity_curve, Decimal::ZERO)?;
+        apply_margin_metrics(&mut metrics, &margin);
+
+        Ok(BacktestResult {
+            trades,
+            equity_curve,
+            bar_logs,
+            margin,
+            metrics,
+        })
+    }
+}
+
+/// Fills accumulated for the simulated-exchange trade currently open, across all of its
+/// scaled-in groups.
+#[derive(Default)]
+struct SimulatedTrade {
+    pnl: Decimal,
+    fee: Decimal,
+    /// Signed leg sizes opened by entries and scale-ins.
+    base_qty: Decimal,
+    quote_qty: Decimal,
+    /// Funding accrued at each settlement while open; positive is paid.
+    funding: Decimal,
+    base_notional: Decimal,
+    quote_notional: Decimal,
+    base_slippage: Decimal,
+    quote_slippage: Decimal,
+    entry_zscore: Option<Decimal>,
 }
 
-struct TradeInput<'a> {
+impl SimulatedTrade {
+    fn record_fill(&mut self, log: &TradeLog) {
+        let bps = Decimal::new(10000, 0);
+        self.pnl += log.realized_pnl;
+        self.fee += log.fee;
+        self.base_slippage += log.base_slippage_bps.unwrap_or(Decimal::ZERO) / bps
+            * log.base_qty.abs()
+            * log.base_price;
+        self.quote_slippage += log.quote_slippage_bps.unwrap_or(Decimal::ZERO) / bps
+            * log.quote_qty.abs()
+            * log.quote_price;
+    }
+
+    /// Books the accumulated fills as a trade closed by `log`. Sizes and entry prices cover
+    /// every group opened; a position adopted without entry fills falls back to `log`.
+    fn close(
+        self,
+        log: &TradeLog,
+        exit_reason: TradeExitReason,
+        exit_zscore: Option<Decimal>,
+    ) -> Trade {
+        let slippage = self.base_slippage + self.quote_slippage;
+        let leg_bps = |cost: Decimal, notional: Decimal| {
+            (notional > Decimal::ZERO).then(|| cost / notional * Decimal::new(10000, 0))
+        };
+        let opened = self.base_qty != Decimal::ZERO && self.quote_qty != Decimal::ZERO;
+        let (base_qty, quote_qty, entry_base_price, entry_quote_price) = if opened {
+            (
+                self.base_qty,
+                self.quote_qty,
+                self.base_notional / self.base_qty.abs(),
+                self.quote_notional / self.quote_qty.abs(),
+            )
+        } else {
+            (
+                log.base_qty,
+                log.quote_qty,
+                log.entry_base_price,
+                log.entry_quote_price,
+            )
+        };
+        Trade {
+            pair: log.pair.clone(),
+            direction: log.direction,
+            entry_time: log.entry_time,
+            exit_time: log.timestamp,
+            base_qty,
+            quote_qty,
+            entry_base_price,
+            entry_quote_price,
+            base_price: log.base_price,
+            quote_price: log.quote_price,
+            entry_zscore: self.entry_zscore,
+            exit_zscore,
+            pnl: self.pnl - self.funding,
+            gross_pnl: self.pnl + self.fee + slippage,
+            exit_reason,
+            notional: self.base_notional + self.quote_notional,
+            fee: self.fee,
+            slippage,
+            base_slippage_bps: leg_bps(self.base_slippage, self.base_notional),
+            quote_slippage_bps: leg_bps(self.quote_slippage, self.quote_notional),
+            funding: self.funding,
+            holding_hours: seconds_to_hours((log.timestamp - log.entry_time).num_seconds()),
+        }
+    }
+}
+
+/// Position held by the native engine between its entry and exit bars.
+struct OpenTrade {
+    position: PositionSnapshot,
+    entry_base: Decimal,
+    entry_quote: Decimal,
+    entry_slippage: Option<(Decimal, Decimal)>,
+    entry_zscore: Decimal,
+    /// Funding accrued so far; positive is paid.
+    funding: Decimal,
+    /// Last close funding was accrued through.
+    marked_at: DateTime<Utc>,
+}
+
+struct TradeInput {
     direction: TradeDirection,
-    entry_eth: Decimal,
-    entry_btc: Decimal,
-    exit_eth: Decimal,
-    exit_btc: Decimal,
-    notional_eth: Decimal,
-    notional_btc: Decimal,
-    bar: &'a BacktestBar,
-    holding_hours: u32,
-}
-
-fn compute_trade_pnl(input: TradeInput<'_>, config: &Config) -> Result<Decimal, BacktestError> {
-    let pnl_eth = match input.direction {
-        TradeDirection::LongEthShortBtc => {
-            (input.exit_eth - input.entry_eth) / input.entry_eth * input.notional_eth
+    entry_base: Decimal,
+    entry_quote: Decimal,
+    exit_base: Decimal,
+    exit_quote: Decimal,
+    notional_base: Decimal,
+    notional_quote: Decimal,
+    /// Funding accrued over the holding period; positive is paid.
+    funding: Decimal,
+    /// Precomputed ETH and BTC entry plus exit slippage; `None` charges the flat
+    /// per-fill rate on the entry and exit notionals.
+    slippage: Option<(Decimal, Decimal)>,
+}
+
+struct TradePnl {
+    pnl: Decimal,
+    gross_pnl: Decimal,
+    fee: Decimal,
+    base_slippage: Decimal,
+    quote_slippage: Decimal,
+    funding: Decimal,
+}
+
+/// Flat slippage per fill: `slippage_bps` covers a round trip, so each fill pays half.
+fn flat_fill_bps(config: &BacktestConfig) -> Decimal {
+    Decimal::from(config.slippage_bps) / Decimal::TWO
+}
+
+/// Notional of a leg at `exit`, holding the quantity opened at `entry`; rounded so slippage
+/// sums stay exact in equity.
+fn exit_notional(notional: Decimal, entry: Decimal, exit: Decimal) -> Decimal {
+    (notional * exit / entry).round_dp(12)
+}
+
+fn compute_trade_pnl(input: TradeInput, config: &Config) -> TradePnl {
+    let pnl_base = match input.direction {
+        TradeDirection::LongBaseShortQuote => {
+            (input.exit_base - input.entry_base) / input.entry_base * input.notional_base
         }
-        TradeDirection::ShortEthLongBtc => {
-            (input.entry_eth - input.exit_eth) / input.entry_eth * input.notional_eth
+        TradeDirection::ShortBaseLongQuote => {
+            (input.entry_base - input.exit_base) / input.entry_base * input.notional_base
         }
     };
-    let pnl_btc = match input.direction {
-        TradeDirection::LongEthShortBtc => {
-            (input.entry_btc - input.exit_btc) / input.entry_btc * input.notional_btc
+    let pnl_quote = match input.direction {
+        TradeDirection::LongBaseShortQuote => {
+            (input.entry_quote - input.exit_quote) / input.entry_quote * input.notional_quote
         }
-        TradeDirection::ShortEthLongBtc => {
-            (input.exit_btc - input.entry_btc) / input.entry_btc * input.notional_btc
+        TradeDirection::ShortBaseLongQuote => {
+            (input.exit_quote - input.entry_quote) / input.entry_quote * input.notional_quote
         }
     };
-    let mut pnl = pnl_eth + pnl_btc;
+    let gross_pnl = pnl_base + pnl_quote;
 
-    let total_notional = input.notional_eth + input.notional_btc;
+    let total_notional = input.notional_base + input.notional_quote;
     let fee_bps = Decimal::from(config.backtest.fee_bps) / Decimal::new(10000, 0);
-    let slippage_bps = Decimal::from(config.backtest.slippage_bps) / Decimal::new(10000, 0);
-    let mut cost = Decimal::ZERO;
+    let mut fee = Decimal::ZERO;
     if config.backtest.include_fees {
-        cost += total_notional * fee_bps;
-    }
-    if config.backtest.include_slippage {
-        cost += total_notional * slippage_bps;
-    }
-    pnl -= cost;
-
-    if config.backtest.include_funding
-        && let (Some(funding_eth), Some(funding_btc)) =
-            (input.bar.funding_eth, input.bar.funding_btc)
-    {
-        let eth_rate = FundingRate {
-            symbol: Symbol::EthPerp,
-            rate: funding_eth,
-            timestamp: input.bar.timestamp,
-            interval_hours: 8,
-        };
-        let btc_rate = FundingRate {
-            symbol: Symbol::BtcPerp,
-            rate: funding_btc,
-            timestamp: input.bar.timestamp,
-            interval_hours: 8,
-        };
-        let estimate = estimate_funding_cost(
-            input.direction,
-            input.notional_eth,
-            input.notional_btc,
-            &eth_rate,
-            &btc_rate,
-            input.holding_hours,
-        )
-        .map_err(|err| BacktestError::Funding(err.to_string()))?;
-        pnl -= estimate.cost_est;
+        fee = total_notional * fee_bps;
     }
+    let (base_slippage, quote_slippage) = match input.slippage {
+        Some(legs) => legs,
+        None if config.backtest.include_slippage => {
+            let bps = flat_fill_bps(&config.backtest);
+            let fill = |notional: Decimal| notional * bps / Decimal::new(10000, 0);
+            (
+                fill(input.notional_base)
+                    + fill(exit_notional(
+                        input.notional_base,
+                        input.entry_base,
+                        input.exit_base,
+                    )),
+                fill(input.notional_quote)
+                    + fill(exit_notional(
+                        input.notional_quote,
+                        input.entry_quote,
+                        input.exit_quote,
+                    )),
+            )
+        }
+        None => (Decimal::ZERO, Decimal::ZERO),
+    };
+
+    let funding = if config.backtest.include_funding {
+        input.funding
+    } else {
+        Decimal::ZERO
+    };
 
-    Ok(pnl)
+    TradePnl {
+        pnl: gross_pnl - fee - base_slippage - quote_slippage - funding,
+        gross_pnl,
+        fee,
+        base_slippage,
+        quote_slippage,
+        funding,
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::Utc;
     use rust_decimal_macros::dec;
 
     #[test]
-    fn funding_cost_uses_holding_hours() {
+    fn accrued_funding_is_charged_to_pnl() {
         let mut config = Config::default();
         config.backtest.include_fees = false;
         config.backtest.include_slippage = false;
         config.backtest.include_funding = true;
-        config.backtest.fee_bps = 0;
-        config.backtest.slippage_bps = 0;
-
-        let bar = BacktestBar {
-            timestamp: Utc::now(),
-            eth_price: dec!(100),
-            btc_price: dec!(100),
-            funding_eth: Some(dec!(0.001)),
-            funding_btc: Some(dec!(0.01)),
-        };
 
-        let input = TradeInput {
-            direction: TradeDirection::ShortEthLongBtc,
-            entry_eth: dec!(100),
-            entry_btc: dec!(100),
-            exit_eth: dec!(100),
-            exit_btc: dec!(100),
-            notional_eth: dec!(50),
-            notional_btc: dec!(50),
-            bar: &bar,
-            holding_hours: 2,
+        let input = || TradeInput {
+            direction: TradeDirection::ShortBaseLongQuote,
+            entry_base: dec!(100),
+            entry_quote: dec!(100),
+            exit_base: dec!(100),
+            exit_quote: dec!(100),
+            notional_base: dec!(50),
+            notional_quote: dec!(50),
+            funding: dec!(-0.45),
+            slippage: None,
         };
 
-        let expected = estimate_funding_cost(
-            input.direction,
-            input.notional_eth,
-            input.notional_btc,
-            &FundingRate {
-                symbol: Symbol::EthPerp,
-                rate: dec!(0.001),
-                timestamp: bar.timestamp,
-                interval_hours: 8,
-            },
-            &FundingRate {
-                symbol: Symbol::BtcPerp,
-                rate: dec!(0.01),
-                timestamp: bar.timestamp,
-                interval_hours: 8,
-            },
-            input.holding_hours,
-        )
-        .unwrap();
+        let trade_pnl = compute_trade_pnl(input(), &config);
+        assert_eq!(trade_pnl.pnl, dec!(0.45));
+        assert_eq!(trade_pnl.funding, dec!(-0.45));
 
-        let pnl = compute_trade_pnl(input, &config).unwrap();
-        assert_eq!(pnl, -expected.cost_est);
+        config.backtest.include_funding = false;
+        let trade_pnl = compute_trade_pnl(input(), &config);
+        assert_eq!(trade_pnl.pnl, Decimal::ZERO);
+        assert_eq!(trade_pnl.funding, Decimal::ZERO);
     }
 
     #[test]
@@ -481,35 +1225,45 @@ mod tests {
         config.backtest.fee_bps = 100;
         config.backtest.slippage_bps = 100;
 
-        let bar = BacktestBar {
-            timestamp: Utc::now(),
-            eth_price: dec!(100),
-            btc_price: dec!(100),
-            funding_eth: None,
-            funding_btc: None,
-        };
-
         let input = TradeInput {
-            direction: TradeDirection::LongEthShortBtc,
-            entry_eth: dec!(100),
-            entry_btc: dec!(100),
-            exit_eth: dec!(100),
-            exit_btc: dec!(100),
-            notional_eth: dec!(50),
-            notional_btc: dec!(50),
-            bar: &bar,
-            holding_hours: 1,
+            direction: TradeDirection::LongBaseShortQuote,
+            entry_base: dec!(100),
+            entry_quote: dec!(100),
+            exit_base: dec!(100),
+            exit_quote: dec!(100),
+            notional_base: dec!(50),
+            notional_quote: dec!(50),
+            funding: Decimal::ZERO,
+            slippage: None,
         };
 
-        let pnl = compute_trade_pnl(input, &config).unwrap();
-        assert_eq!(pnl, Decimal::ZERO);
+        let trade_pnl = compute_trade_pnl(input, &config);
+        assert_eq!(trade_pnl.pnl, Decimal::ZERO);
+        assert_eq!(trade_pnl.fee, Decimal::ZERO);
+        assert_eq!(
+            trade_pnl.base_slippage + trade_pnl.quote_slippage,
+            Decimal::ZERO
+        );
     }
 }
 
+fn apply_margin_metrics(metrics: &mut Metrics, margin: &[MarginPoint]) {
+    metrics.liquidation_bars = margin
+        .iter()
+        .filter(|point| !point.liquidated.is_empty())
+        .count();
+    metrics.max_margin_usage = margin
+        .iter()
+        .filter(|point| point.account_equity > Decimal::ZERO)
+        .map(|point| point.used_margin / point.account_equity)
+        .max()
+        .unwrap_or(Decimal::ZERO);
+}
+
 pub fn compute_metrics(
     trades: &[Trade],
     equity_curve: &[EquityPoint],
-    _risk_free_rate: Decimal,
+    risk_free_rate: Decimal,
 ) -> Result<Metrics, BacktestError> {
     if trades.is_empty() || equity_curve.len() < 2 {
         return Ok(Metrics::default());
@@ -563,10 +1317,15 @@ pub fn compute_metrics(
         };
 
     let mut peak = Decimal::ZERO;
+    let mut peak_time = start.timestamp;
     let mut max_drawdown = Decimal::ZERO;
+    let mut max_drawdown_secs = 0i64;
     for point in equity_curve {
-        if point.equity > peak {
+        if point.equity >= peak {
             peak = point.equity;
+            peak_time = point.timestamp;
+        } else {
+            max_drawdown_secs = max_drawdown_secs.max((point.timestamp - peak_time).num_seconds());
         }
         if peak > Decimal::ZERO {
             let drawdown = (peak - point.equity) / peak;
@@ -586,7 +1345,7 @@ pub fn compute_metrics(
             total_delta += (next.timestamp - prev.timestamp).num_seconds();
         }
     }
-    let sharpe_ratio = if returns.len() >= 2 && total_delta > 0 {
+    let (sharpe_ratio, sortino_ratio) = if returns.len() >= 2 && total_delta > 0 {
         let valid_returns: Vec<f64> = returns.into_iter().flatten().collect();
         if valid_returns.len() >= 2 {
             let mean = valid_returns.iter().copied().sum::<f64>() / valid_returns.len() as f64;
@@ -610,25 +1369,100 @@ pub fn compute_metrics(
             } else {
                 0.0
             };
-            let risk_free_rate = _risk_free_rate.to_f64().unwrap_or(0.0);
+            let risk_free_rate = risk_free_rate.to_f64().unwrap_or(0.0);
             let rf_per_period = if periods_per_year > 0.0 {
                 (1.0 + risk_free_rate).powf(1.0 / periods_per_year) - 1.0
             } else {
                 0.0
             };
-            if std > 0.0 {
+            let sharpe = if std > 0.0 {
                 let sharpe = (mean - rf_per_period) / std * periods_per_year.sqrt();
                 Decimal::from_f64(sharpe).unwrap_or(Decimal::ZERO)
             } else {
                 Decimal::ZERO
-            }
+            };
+            // Downside deviation below the per-period risk-free target.
+            let downside = (valid_returns
+                .iter()
+                .map(|value| (value - rf_per_period).min(0.0).powi(2))
+                .sum::<f64>()
+                / valid_returns.len() as f64)
+                .sqrt();
+            let sortino = if downside > 0.0 {
+                let sortino = (mean - rf_per_period) / downside * periods_per_year.sqrt();
+                Decimal::from_f64(sortino).unwrap_or(Decimal::ZERO)
+            } else {
+                Decimal::ZERO
+            };
+            (sharpe, sortino)
         } else {
-            Decimal::ZERO
+            (Decimal::ZERO, Decimal::ZERO)
         }
+    } else {
+        (Decimal::ZERO, Decimal::ZERO)
+    };
+    let calmar_ratio = if max_drawdown > Decimal::ZERO {
+        annualized_return / max_drawdown
     } else {
         Decimal::ZERO
     };
 
+    let mut intervals: Vec<(DateTime<Utc>, DateTime<Utc>)> = trades
+        .iter()
+        .map(|trade| {
+            (
+                trade.entry_time.max(start.timestamp),
+                trade.exit_time.min(end.timestamp),
+            )
+        })
+        .filter(|(entry, exit)| exit > entry)
+        .collect();
+    intervals.sort();
+    let mut in_market_secs = 0i64;
+    let mut covered_until = start.timestamp;
+    for (entry, exit) in intervals {
+        let from = entry.max(covered_until);
+        if exit > from {
+            in_market_secs += (exit - from).num_seconds();
+            covered_until = exit;
+        }
+    }
+    let time_in_market = if duration_secs > 0 {
+        Decimal::from(in_market_secs) / Decimal::from(duration_secs)
+    } else {
+        Decimal::ZERO
+    };
+
+    let mut holding_hours: Vec<Decimal> = trades
+        .iter()
+        .map(|trade| seconds_to_hours((trade.exit_time - trade.entry_time).num_seconds()))
+        .collect();
+    holding_hours.sort();
+    let avg_holding_hours =
+        holding_hours.iter().copied().sum::<Decimal>() / Decimal::from(holding_hours.len() as u64);
+    let mid = holding_hours.len() / 2;
+    let median_holding_hours = if holding_hours.len().is_multiple_of(2) {
+        (holding_hours[mid - 1] + holding_hours[mid]) / Decimal::TWO
+    } else {
+        holding_hours[mid]
+    };
+
+    let traded_notional = trades
+        .iter()
+        .map(|trade| trade.notional * Decimal::TWO)
+        .sum::<Decimal>();
+    let turnover = if start.equity > Decimal::ZERO {
+        traded_notional / start.equity
+    } else {
+        Decimal::ZERO
+    };
+    let mut pnl_by_exit_reason = BTreeMap::new();
+    for trade in trades {
+        *pnl_by_exit_reason
+            .entry(format!("{:?}", trade.exit_reason))
+            .or_insert(Decimal::ZERO) += trade.pnl;
+    }
+
     Ok(Metrics {
         win_rate,
         profit_factor,
@@ -637,9 +1471,44 @@ pub fn compute_metrics(
         sharpe_ratio,
         max_drawdown,
         trade_count: trades.len(),
+        sortino_ratio,
+        calmar_ratio,
+        max_drawdown_duration_hours: seconds_to_hours(max_drawdown_secs),
+        time_in_market,
+        avg_holding_hours,
+        median_holding_hours,
+        traded_notional,
+        turnover,
+        total_fees: trades.iter().map(|trade| trade.fee).sum(),
+        total_funding: trades.iter().map(|trade| trade.funding).sum(),
+        pnl_by_exit_reason,
+        // Margin metrics come from the engine's margin series.
+        liquidation_bars: 0,
+        max_margin_usage: Decimal::ZERO,
     })
 }
 
+fn seconds_to_hours(seconds: i64) -> Decimal {
+    Decimal::from(seconds) / Decimal::from(3600)
+}
+
+/// Indicator series for `bars` under `config`, shareable by every config that only differs
+/// in signal thresholds, risk or sizing settings.
+pub fn precompute_indicators(
+    config: &Config,
+    bars: &[BacktestBar],
+) -> Result<Vec<IndicatorSnapshot>, BacktestError> {
+    let mut pipeline =
+        SignalPipeline::new(config).map_err(|err| BacktestError::Indicator(err.to_string()))?;
+    bars.iter()
+        .map(|bar| {
+            pipeline
+                .update_indicators(bar.timestamp, bar.base_price, bar.quote_price)
+                .map_err(|err| BacktestError::Indicator(err.to_string()))
+        })
+        .collect()
+}
+
 pub fn export_metrics_json(path: &Path, metrics: &Metrics) -> Result<(), BacktestError> {
     let payload = serde_json::to_string_pretty(metrics)
         .map_err(|err| BacktestError::Serialization(err.to_string()))?;
@@ -656,10 +1525,14 @@ pub fn load_backtest_bars_from_db(
     start: DateTime<Utc>,
     end: DateTime<Utc>,
     price_field: PriceField,
+    pair: &PairConfig,
+    interval: BarInterval,
 ) -> Result<Vec<BacktestBar>, BacktestError> {
-    let start = align_to_bar_close(start).map_err(|err| BacktestError::Storage(err.to_string()))?;
-    let end = align_to_bar_close(end).map_err(|err| BacktestError::Storage(err.to_string()))?;
-    let store = PriceStore::new(path.to_string_lossy().as_ref())
+    let start = align_to_bar_close(start, interval)
+        .map_err(|err| BacktestError::Storage(err.to_string()))?;
+    let end =
+        align_to_bar_close(end, interval).map_err(|err| BacktestError::Storage(err.to_string()))?;
+    let store = PriceStore::new_for_series(path.to_string_lossy().as_ref(), pair, interval)
         .map_err(|err| BacktestError::Storage(err.to_string()))?;
     let records = store
         .load_range(start, end)
@@ -673,26 +1546,32 @@ pub fn load_backtest_bars_from_db(
     }
     let mut bars = Vec::new();
     for record in records {
-        let eth_price = effective_price(
+        let base_price = effective_price(
             price_field,
-            record.eth_mid,
-            record.eth_mark,
-            record.eth_close,
+            record.base_mid,
+            record.base_mark,
+            record.base_close,
         )
-        .ok_or_else(|| BacktestError::Storage("missing ETH price".to_string()))?;
-        let btc_price = effective_price(
+        .ok_or_else(|| BacktestError::Storage("missing base price".to_string()))?;
+        let quote_price = effective_price(
             price_field,
-            record.btc_mid,
-            record.btc_mark,
-            record.btc_close,
+            record.quote_mid,
+            record.quote_mark,
+            record.quote_close,
         )
-        .ok_or_else(|| BacktestError::Storage("missing BTC price".to_string()))?;
+        .ok_or_else(|| BacktestError::Storage("missing quote price".to_string()))?;
         bars.push(BacktestBar {
             timestamp: record.timestamp,
-            eth_price,
-            btc_price,
-            funding_eth: record.funding_eth,
-            funding_btc: record.funding_btc,
+            base_price,
+            quote_price,
+            funding_base: record.funding_base,
+            funding_quote: record.funding_quote,
+            base_open: record.base_open,
+            base_high: record.base_high,
+            base_low: record.base_low,
+            quote_open: record.quote_open,
+            quote_high: record.quote_high,
+            quote_low: record.quote_low,
         });
     }
     Ok(bars)
@@ -712,19 +1591,84 @@ fn effective_price(
 }
 
 pub fn export_trades_csv(path: &Path, trades: &[Trade]) -> Result<(), BacktestError> {
-    let mut contents = String::from("entry_time,exit_time,pnl,exit_reason\n");
+    let optional =
+        |value: Option<Decimal>| value.map(|value| value.to_string()).unwrap_or_default();
+    let mut contents = String::from(
+        "pair,direction,entry_time,exit_time,holding_hours,exit_reason,entry_zscore,exit_zscore,base_qty,quote_qty,entry_base_price,entry_quote_price,base_price,quote_price,notional,gross_pnl,fee,slippage,base_slippage_bps,quote_slippage_bps,funding,pnl\n",
+    );
     for trade in trades {
         contents.push_str(&format!(
-            "{},{},{},{:?}\n",
+            "{},{:?},{},{},{},{:?},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}\n",
+            trade.pair.as_deref().unwrap_or_default(),
+            trade.direction,
             trade.entry_time.to_rfc3339(),
             trade.exit_time.to_rfc3339(),
-            trade.pnl,
-            trade.exit_reason
+            trade.holding_hours,
+            trade.exit_reason,
+            optional(trade.entry_zscore),
+            optional(trade.exit_zscore),
+            trade.base_qty,
+            trade.quote_qty,
+            trade.entry_base_price,
+            trade.entry_quote_price,
+            trade.base_price,
+            trade.quote_price,
+            trade.notional,
+            trade.gross_pnl,
+            trade.fee,
+            trade.slippage,
+            optional(trade.base_slippage_bps),
+            optional(trade.quote_slippage_bps),
+            trade.funding,
+            trade.pnl
         ));
     }
     fs::write(path, contents).map_err(|err| BacktestError::Io(err.to_string()))
 }
 
+/// Reads a `trades.csv` written by `export_trades_csv`. Columns are matched by header, so
+/// files from older runs load with the missing fields defaulted.
+pub fn load_trades_csv(path: &Path) -> Result<Vec<Trade>, BacktestError> {
+    let payload = fs::read_to_string(path).map_err(|err| BacktestError::Io(err.to_string()))?;
+    let mut lines = payload.lines().filter(|line| !line.trim().is_empty());
+    let header: Vec<&str> = lines
+        .next()
+        .map(|line| line.split(',').collect())
+        .unwrap_or_default();
+    lines
+        .map(|line| {
+            let row: serde_json::Map<String, serde_json::Value> = header
+                .iter()
+                .zip(line.split(','))
+                .filter(|(_, value)| !value.is_empty())
+                .map(|(column, value)| {
+                    (
+                        column.to_string(),
+                        serde_json::Value::String(value.to_string()),
+                    )
+                })
+                .collect();
+            serde_json::from_value(serde_json::Value::Object(row))
+                .map_err(|err| BacktestError::Serialization(err.to_string()))
+        })
+        .collect()
+}
+
+/// Writes bar logs as JSON lines, byte-for-byte what a JSON `BarLogFileWriter` produces, so
+/// `analyze-trades --stats-log` can read simulated history.
+pub fn export_bar_logs_jsonl(path: &Path, logs: &[BarLog]) -> Result<(), BacktestError> {
+    let formatter = LogFormatter;
+    let mut contents = String::new();
+    for log in logs {
+        let line = formatter
+            .format_json(log)
+            .map_err(|err| BacktestError::Serialization(err.to_string()))?;
+        contents.push_str(&line);
+        contents.push('\n');
+    }
+    fs::write(path, contents).map_err(|err| BacktestError::Io(err.to_string()))
+}
+
 pub fn export_equity_csv(path: &Path, equity: &[EquityPoint]) -> Result<(), BacktestError> {
     let mut contents = String::from("timestamp,equity\n");
     for point in equity {
@@ -737,6 +1681,30 @@ pub fn export_equity_csv(path: &Path, equity: &[EquityPoint]) -> Result<(), Back
     fs::write(path, contents).map_err(|err| BacktestError::Io(err.to_string()))
 }
 
+pub fn export_margin_csv(path: &Path, margin: &[MarginPoint]) -> Result<(), BacktestError> {
+    let mut contents =
+        String::from("timestamp,account_equity,used_margin,available_margin,liquidated\n");
+    for point in margin {
+        let liquidated: Vec<&str> = point
+            .liquidated
+            .iter()
+            .map(|symbol| match symbol {
+                Symbol::Base => "BASE",
+                Symbol::Quote => "QUOTE",
+            })
+            .collect();
+        contents.push_str(&format!(
+            "{},{},{},{},{}\n",
+            point.timestamp.to_rfc3339(),
+            point.account_equity,
+            point.used_margin,
+            point.available_margin,
+            liquidated.join("|")
+        ));
+    }
+    fs::write(path, contents).map_err(|err| BacktestError::Io(err.to_string()))
+}
+
 pub fn run_sensitivity(
     configs: &[Config],
     bars: &[BacktestBar],
@@ -769,12 +1737,16 @@ pub fn breakdown_monthly(trades: &[Trade]) -> Vec<BreakdownRow> {
         .collect()
 }
 
+/// Runs `bars` twice and requires byte-identical trade lists and equity curves.
 pub fn verify_reproducibility(config: &Config, bars: &[BacktestBar]) -> Result<(), BacktestError> {
     let engine = BacktestEngine::new(config.clone());
     let first = engine.run(bars)?;
     let second = engine.run(bars)?;
-    if first.trades.len() != second.trades.len() {
-        return Err(BacktestError::Position("trade count mismatch".to_string()));
+    if hash_json(&first.trades)? != hash_json(&second.trades)? {
+        return Err(BacktestError::Position("trade list mismatch".to_string()));
+    }
+    if hash_json(&first.equity_curve)? != hash_json(&second.equity_curve)? {
+        return Err(BacktestError::Position("equity curve mismatch".to_string()));
     }
     Ok(())
 }
@@ -0,0 +1,299 @@
+use std::fs;
+use std::path::Path;
+
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+use serde::{Deserialize, Serialize};
+
+use crate::backtest::rng::SplitMix64;
+use crate::backtest::{BacktestError, BacktestResult, EquityPoint, Trade, compute_metrics};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MonteCarloConfig {
+    pub iterations: usize,
+    /// Consecutive trades drawn together by the block bootstrap.
+    pub block_size: usize,
+    pub seed: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ResampleMethod {
+    /// Trades drawn with replacement in circular blocks of `block_size`.
+    BlockBootstrap,
+    /// The original trades in a random order.
+    Shuffle,
+}
+
+impl ResampleMethod {
+    pub fn label(self) -> &'static str {
+        match self {
+            ResampleMethod::BlockBootstrap => "BLOCK_BOOTSTRAP",
+            ResampleMethod::Shuffle => "SHUFFLE",
+        }
+    }
+}
+
+/// Path statistics of one equity curve.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PathMetrics {
+    pub max_drawdown: Decimal,
+    pub annualized_return: Decimal,
+    pub sharpe_ratio: Decimal,
+    /// Longest time below a previous equity peak; an unrecovered drawdown runs to the end.
+    pub time_to_recovery_hours: Decimal,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Distribution {
+    pub mean: Decimal,
+    pub min: Decimal,
+    pub p5: Decimal,
+    pub p25: Decimal,
+    pub p50: Decimal,
+    pub p75: Decimal,
+    pub p95: Decimal,
+    pub max: Decimal,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MethodSummary {
+    pub method: ResampleMethod,
+    pub max_drawdown: Distribution,
+    pub annualized_return: Distribution,
+    pub sharpe_ratio: Distribution,
+    pub time_to_recovery_hours: Distribution,
+    /// Share of paths ending below starting equity.
+    pub loss_probability: Decimal,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MonteCarloSummary {
+    pub config: MonteCarloConfig,
+    pub trade_count: usize,
+    /// The backtest's own trade order, rebuilt the same way as the resampled paths.
+    pub observed: PathMetrics,
+    pub methods: Vec<MethodSummary>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MonteCarloSample {
+    pub method: ResampleMethod,
+    pub iteration: usize,
+    pub final_equity: Decimal,
+    pub metrics: PathMetrics,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonteCarloResult {
+    pub summary: MonteCarloSummary,
+    pub samples: Vec<MonteCarloSample>,
+}
+
+/// Resamples `result.trades` into `iterations` alternative histories per method.
+///
+/// Each path keeps the backtest's timeline: the i-th resampled trade PnL is booked at the
+/// i-th original exit time and the equity curve is rebuilt at every original timestamp, so
+/// the unchanged order reproduces the backtest curve. Metrics are computed with
+/// `compute_metrics`, and a fixed `seed` makes the run reproducible.
+pub fn run_monte_carlo(
+    result: &BacktestResult,
+    config: &MonteCarloConfig,
+) -> Result<MonteCarloResult, BacktestError> {
+    if config.iterations == 0 || config.block_size == 0 {
+        return Err(BacktestError::InvalidConfig(
+            "monte carlo iterations and block_size must be > 0".to_string(),
+        ));
+    }
+    if result.trades.is_empty() || result.equity_curve.len() < 2 {
+        return Err(BacktestError::InvalidConfig(
+            "monte carlo needs at least one trade and two equity points".to_string(),
+        ));
+    }
+
+    let pnls: Vec<Decimal> = result.trades.iter().map(|trade| trade.pnl).collect();
+    let first = &result.equity_curve[0];
+    let start_equity = first.equity
+        - result
+            .trades
+            .iter()
+            .filter(|trade| trade.exit_time <= first.timestamp)
+            .map(|trade| trade.pnl)
+            .sum::<Decimal>();
+    let (observed, _) = path_metrics(result, start_equity, &pnls)?;
+
+    let mut rng = SplitMix64::new(config.seed);
+    let mut samples = Vec::with_capacity(config.iterations * 2);
+    let mut methods = Vec::new();
+    for method in [ResampleMethod::BlockBootstrap, ResampleMethod::Shuffle] {
+        let mut paths = Vec::with_capacity(config.iterations);
+        for iteration in 0..config.iterations {
+            let resampled = match method {
+                ResampleMethod::BlockBootstrap => {
+                    block_bootstrap(&pnls, config.block_size, &mut rng)
+                }
+                ResampleMethod::Shuffle => shuffle(&pnls, &mut rng),
+            };
+            let (metrics, final_equity) = path_metrics(result, start_equity, &resampled)?;
+            paths.push(MonteCarloSample {
+                method,
+                iteration,
+                final_equity,
+                metrics,
+            });
+        }
+        methods.push(summarize(method, &paths, start_equity));
+        samples.extend(paths);
+    }
+
+    Ok(MonteCarloResult {
+        summary: MonteCarloSummary {
+            config: config.clone(),
+            trade_count: pnls.len(),
+            observed,
+            methods,
+        },
+        samples,
+    })
+}
+
+fn path_metrics(
+    result: &BacktestResult,
+    start_equity: Decimal,
+    pnls: &[Decimal],
+) -> Result<(PathMetrics, Decimal), BacktestError> {
+    let trades: Vec<Trade> = result
+        .trades
+        .iter()
+        .zip(pnls)
+        .map(|(trade, pnl)| Trade {
+            pnl: *pnl,
+            ..trade.clone()
+        })
+        .collect();
+    let mut equity = start_equity;
+    let mut booked = 0;
+    let curve: Vec<EquityPoint> = result
+        .equity_curve
+        .iter()
+        .map(|point| {
+            while booked < trades.len() && trades[booked].exit_time <= point.timestamp {
+                equity += trades[booked].pnl;
+                booked += 1;
+            }
+            EquityPoint {
+                timestamp: point.timestamp,
+                equity,
+            }
+        })
+        .collect();
+    let metrics = compute_metrics(&trades, &curve, Decimal::ZERO)?;
+    Ok((
+        PathMetrics {
+            max_drawdown: metrics.max_drawdown,
+            annualized_return: metrics.annualized_return,
+            sharpe_ratio: metrics.sharpe_ratio,
+            time_to_recovery_hours: metrics.max_drawdown_duration_hours,
+        },
+        equity,
+    ))
+}
+
+fn block_bootstrap(pnls: &[Decimal], block_size: usize, rng: &mut SplitMix64) -> Vec<Decimal> {
+    let mut resampled = Vec::with_capacity(pnls.len());
+    while resampled.len() < pnls.len() {
+        let start = rng.below(pnls.len());
+        for offset in 0..block_size.min(pnls.len() - resampled.len()) {
+            resampled.push(pnls[(start + offset) % pnls.len()]);
+        }
+    }
+    resampled
+}
+
+fn shuffle(pnls: &[Decimal], rng: &mut SplitMix64) -> Vec<Decimal> {
+    let mut shuffled = pnls.to_vec();
+    for index in (1..shuffled.len()).rev() {
+        shuffled.swap(index, rng.below(index + 1));
+    }
+    shuffled
+}
+
+fn summarize(
+    method: ResampleMethod,
+    paths: &[MonteCarloSample],
+    start_equity: Decimal,
+) -> MethodSummary {
+    let of = |value: fn(&PathMetrics) -> Decimal| {
+        distribution(paths.iter().map(|path| value(&path.metrics)).collect())
+    };
+    let losses = paths
+        .iter()
+        .filter(|path| path.final_equity < start_equity)
+        .count();
+    MethodSummary {
+        method,
+        max_drawdown: of(|metrics| metrics.max_drawdown),
+        annualized_return: of(|metrics| metrics.annualized_return),
+        sharpe_ratio: of(|metrics| metrics.sharpe_ratio),
+        time_to_recovery_hours: of(|metrics| metrics.time_to_recovery_hours),
+        loss_probability: Decimal::from(losses as u64) / Decimal::from(paths.len() as u64),
+    }
+}
+
+fn distribution(mut values: Vec<Decimal>) -> Distribution {
+    values.sort();
+    let mean = values.iter().copied().sum::<Decimal>() / Decimal::from(values.len() as u64);
+    Distribution {
+        mean,
+        min: values[0],
+        p5: percentile(&values, 5),
+        p25: percentile(&values, 25),
+        p50: percentile(&values, 50),
+        p75: percentile(&values, 75),
+        p95: percentile(&values, 95),
+        max: values[values.len() - 1],
+    }
+}
+
+/// Linear interpolation between the closest ranks of sorted `values`.
+fn percentile(values: &[Decimal], pct: u32) -> Decimal {
+    let rank = Decimal::from(pct) / Decimal::ONE_HUNDRED * Decimal::from(values.len() as u64 - 1);
+    let lower = rank.floor();
+    let index = lower.to_usize().unwrap_or(0);
+    match values.get(index + 1) {
+        Some(upper) => values[index] + (*upper - values[index]) * (rank - lower),
+        None => values[index],
+    }
+}
+
+pub fn export_monte_carlo_json(
+    path: &Path,
+    summary: &MonteCarloSummary,
+) -> Result<(), BacktestError> {
+    let payload = serde_json::to_string_pretty(summary)
+        .map_err(|err| BacktestError::Serialization(err.to_string()))?;
+    fs::write(path, payload).map_err(|err| BacktestError::Io(err.to_string()))
+}
+
+/// One row per simulated path.
+pub fn export_monte_carlo_csv(
+    path: &Path,
+    samples: &[MonteCarloSample],
+) -> Result<(), BacktestError> {
+    let mut contents = String::from(
+        "method,iteration,final_equity,max_drawdown,annualized_return,sharpe_ratio,time_to_recovery_hours\n",
+    );
+    for sample in samples {
+        contents.push_str(&format!(
+            "{},{},{},{},{},{},{}\n",
+            sample.method.label(),
+            sample.iteration,
+            sample.final_equity,
+            sample.metrics.max_drawdown,
+            sample.metrics.annualized_return,
+            sample.metrics.sharpe_ratio,
+            sample.metrics.time_to_recovery_hours
+        ));
+    }
+    fs::write(path, contents).map_err(|err| BacktestError::Io(err.to_string()))
+}
@@ -0,0 +1,326 @@
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::backtest::{BacktestBar, BacktestEngine, BacktestError};
+use crate::config::Config;
+use crate::data::align_to_bar_close;
+use crate::logging::{BarLog, TradeEvent, TradeLog};
+use crate::state::StrategyStatus;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParityConfig {
+    /// Largest absolute z-score difference treated as equal.
+    pub zscore_tolerance: Decimal,
+    /// Largest relative per-leg notional difference treated as equal.
+    pub sizing_tolerance: Decimal,
+}
+
+impl Default for ParityConfig {
+    fn default() -> Self {
+        Self {
+            zscore_tolerance: Decimal::new(1, 2),
+            sizing_tolerance: Decimal::new(1, 2),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ParityField {
+    Zscore,
+    Decision,
+    BlockReason,
+    Sizing,
+    /// A live fill without the matching backtest decision on its bar, or the reverse.
+    Trade,
+}
+
+impl ParityField {
+    pub fn label(self) -> &'static str {
+        match self {
+            ParityField::Zscore => "ZSCORE",
+            ParityField::Decision => "DECISION",
+            ParityField::BlockReason => "BLOCK_REASON",
+            ParityField::Sizing => "SIZING",
+            ParityField::Trade => "TRADE",
+        }
+    }
+}
+
+/// What a bar did to the position, read from the state before and after it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ParityDecision {
+    Hold,
+    Enter,
+    Exit,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParityDivergence {
+    pub timestamp: DateTime<Utc>,
+    pub field: ParityField,
+    pub live: String,
+    pub backtest: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParityReport {
+    /// Live bars with prices, after de-duplicating by timestamp.
+    pub live_bars: usize,
+    pub compared_bars: usize,
+    /// Live bars with no backtest bar at the same timestamp (gaps in `price_bars`).
+    pub missing_bars: Vec<DateTime<Utc>>,
+    pub divergent_bars: usize,
+    pub counts: BTreeMap<String, usize>,
+    pub divergences: Vec<ParityDivergence>,
+}
+
+/// Re-runs the strategy over `bars` and compares it with the live stats log bar by bar.
+///
+/// `bars` should start early enough to warm the indicators. Live rows for another pair,
+/// or without prices (e.g. run errors), are ignored; duplicate timestamps keep the last row.
+/// Decisions come from each stream's own state transitions, so the first live bar has no
+/// decision to compare. With `trades`, every live entry or exit fill must land on a bar where
+/// the backtest makes the same decision, and the reverse.
+pub fn check_parity(
+    config: &Config,
+    bars: &[BacktestBar],
+    live: &[BarLog],
+    trades: &[TradeLog],
+    parity: &ParityConfig,
+) -> Result<ParityReport, BacktestError> {
+    let pair = config.pair.label();
+    let live: BTreeMap<DateTime<Utc>, &BarLog> = live
+        .iter()
+        .filter(|log| log.pair.as_deref().is_none_or(|label| label == pair))
+        .filter(|log| log.base_price.is_some() && log.quote_price.is_some())
+        .map(|log| (log.timestamp, log))
+        .collect();
+    let result = BacktestEngine::new(config.clone()).run(bars)?;
+
+    let mut backtest = BTreeMap::new();
+    let mut previous = None;
+    for log in &result.bar_logs {
+        backtest.insert(
+            log.timestamp,
+            (log, previous.map(|state| decision(state, log.state))),
+        );
+        previous = Some(log.state);
+    }
+
+    let mut divergences = Vec::new();
+    let mut missing_bars = Vec::new();
+    let mut compared = BTreeMap::new();
+    let mut previous = None;
+    for (timestamp, live_log) in &live {
+        let live_decision = previous.map(|state| decision(state, live_log.state));
+        previous = Some(live_log.state);
+        let Some((backtest_log, backtest_decision)) = backtest.get(timestamp) else {
+            missing_bars.push(*timestamp);
+            continue;
+        };
+        compared.insert(*timestamp, *backtest_decision);
+        let mut diverge = |field, live: String, backtest: String| {
+            divergences.push(ParityDivergence {
+                timestamp: *timestamp,
+                field,
+                live,
+                backtest,
+            });
+        };
+
+        if !within(
+            live_log.zscore,
+            backtest_log.zscore,
+            parity.zscore_tolerance,
+            false,
+        ) {
+            diverge(
+                ParityField::Zscore,
+                optional(live_log.zscore),
+                optional(backtest_log.zscore),
+            );
+        }
+        if let (Some(live_decision), Some(backtest_decision)) = (live_decision, backtest_decision)
+            && live_decision != *backtest_decision
+        {
+            diverge(
+                ParityField::Decision,
+                format!("{live_decision:?}"),
+                format!("{backtest_decision:?}"),
+            );
+        }
+        if live_log.entry_block_reason != backtest_log.entry_block_reason {
+            diverge(
+                ParityField::BlockReason,
+                format!("{:?}", live_log.entry_block_reason),
+                format!("{:?}", backtest_log.entry_block_reason),
+            );
+        }
+        let sized = |log: &BarLog| log.notional_base.zip(log.notional_quote);
+        if let (Some((live_base, live_quote)), Some((backtest_base, backtest_quote))) =
+            (sized(live_log), sized(backtest_log))
+            && !(within(
+                Some(live_base),
+                Some(backtest_base),
+                parity.sizing_tolerance,
+                true,
+            ) && within(
+                Some(live_quote),
+                Some(backtest_quote),
+                parity.sizing_tolerance,
+                true,
+            ))
+        {
+            diverge(
+                ParityField::Sizing,
+                format!("{live_base}/{live_quote}"),
+                format!("{backtest_base}/{backtest_quote}"),
+            );
+        }
+    }
+
+    if !trades.is_empty() {
+        let mut fills = BTreeMap::new();
+        for log in trades
+            .iter()
+            .filter(|log| log.pair.as_deref().is_none_or(|label| label == pair))
+        {
+            let expected = match log.event {
+                TradeEvent::Entry => ParityDecision::Enter,
+                TradeEvent::Exit(_) => ParityDecision::Exit,
+                TradeEvent::ScaleIn | TradeEvent::ResidualRepair => continue,
+            };
+            let bar = align_to_bar_close(log.timestamp, config.data.bar_interval)
+                .map_err(|err| BacktestError::InvalidConfig(err.to_string()))?;
+            fills.insert(bar, (expected, format!("{:?}", log.event)));
+        }
+        for (timestamp, backtest_decision) in &compared {
+            let backtest_decision = backtest_decision.unwrap_or(ParityDecision::Hold);
+            let live_fill = fills.get(timestamp);
+            let matches = match live_fill {
+                Some((expected, _)) => *expected == backtest_decision,
+                None => backtest_decision == ParityDecision::Hold,
+            };
+            if !matches {
+                divergences.push(ParityDivergence {
+                    timestamp: *timestamp,
+                    field: ParityField::Trade,
+                    live: live_fill
+                        .map(|(_, event)| event.clone())
+                        .unwrap_or_else(|| "NONE".to_string()),
+                    backtest: format!("{backtest_decision:?}"),
+                });
+            }
+        }
+    }
+
+    divergences.sort_by_key(|divergence| (divergence.timestamp, divergence.field));
+    let mut counts = BTreeMap::new();
+    for divergence in &divergences {
+        *counts
+            .entry(divergence.field.label().to_string())
+            .or_insert(0) += 1;
+    }
+    let mut divergent_bars: Vec<DateTime<Utc>> = divergences
+        .iter()
+        .map(|divergence| divergence.timestamp)
+        .collect();
+    divergent_bars.dedup();
+
+    Ok(ParityReport {
+        live_bars: live.len(),
+        compared_bars: compared.len(),
+        missing_bars,
+        divergent_bars: divergent_bars.len(),
+        counts,
+        divergences,
+    })
+}
+
+pub fn format_parity_text(report: &ParityReport) -> String {
+    let mut output = String::new();
+    let _ = writeln!(
+        output,
+        "parity live_bars={} compared={} missing={} divergent_bars={}",
+        report.live_bars,
+        report.compared_bars,
+        report.missing_bars.len(),
+        report.divergent_bars
+    );
+    for (field, count) in &report.counts {
+        let _ = writeln!(output, "  {field} {count}");
+    }
+    for divergence in &report.divergences {
+        let _ = writeln!(
+            output,
+            "{} {} live={} backtest={}",
+            divergence.timestamp.to_rfc3339(),
+            divergence.field.label(),
+            divergence.live,
+            divergence.backtest
+        );
+    }
+    output
+}
+
+/// Reads JSON trade logs, one object per line; blank lines are skipped.
+pub fn load_trade_logs_jsonl(path: &Path) -> Result<Vec<TradeLog>, BacktestError> {
+    let payload = fs::read_to_string(path).map_err(|err| BacktestError::Io(err.to_string()))?;
+    payload
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            serde_json::from_str(line).map_err(|err| BacktestError::Serialization(err.to_string()))
+        })
+        .collect()
+}
+
+pub(crate) fn decision(before: StrategyStatus, after: StrategyStatus) -> ParityDecision {
+    let held = |status| {
+        matches!(
+            status,
+            StrategyStatus::InPosition | StrategyStatus::PendingEntry
+        )
+    };
+    match (held(before), held(after)) {
+        (false, true) => ParityDecision::Enter,
+        (true, false) => ParityDecision::Exit,
+        _ => ParityDecision::Hold,
+    }
+}
+
+/// Whether two optional values agree within `tolerance`, absolute or relative to the larger.
+fn within(
+    live: Option<Decimal>,
+    backtest: Option<Decimal>,
+    tolerance: Decimal,
+    relative: bool,
+) -> bool {
+    match (live, backtest) {
+        (Some(live), Some(backtest)) => {
+            let diff = (live - backtest).abs();
+            if relative {
+                let scale = live.abs().max(backtest.abs());
+                scale == Decimal::ZERO || diff / scale <= tolerance
+            } else {
+                diff <= tolerance
+            }
+        }
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+fn optional(value: Option<Decimal>) -> String {
+    value
+        .map(|value| value.to_string())
+        .unwrap_or_else(|| "NONE".to_string())
+}
@@ -0,0 +1,437 @@
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+
+use crate::backtest::{BacktestError, BacktestResult, Metrics, breakdown_monthly};
+
+const CHART_WIDTH: f64 = 960.0;
+const CHART_HEIGHT: f64 = 240.0;
+const PAD_LEFT: f64 = 72.0;
+const PAD_RIGHT: f64 = 16.0;
+const PAD_TOP: f64 = 16.0;
+const PAD_BOTTOM: f64 = 28.0;
+/// Line charts are thinned to about this many points to keep the file small.
+const MAX_LINE_POINTS: usize = 2000;
+
+const STYLE: &str = "body{font-family:-apple-system,Segoe UI,Helvetica,Arial,sans-serif;margin:24px;color:#222}\
+h1{font-size:22px}h2{font-size:17px;margin-top:32px}\
+table{border-collapse:collapse;font-size:13px}td,th{border:1px solid #ddd;padding:4px 8px;text-align:right}\
+th{background:#f4f4f4}td.l,th.l{text-align:left}\
+svg{background:#fafafa;border:1px solid #e4e4e4}svg text{font-size:11px;fill:#555}\
+.pos{color:#1a7f37}.neg{color:#c62828}";
+
+struct Marker {
+    timestamp: DateTime<Utc>,
+    value: f64,
+    color: &'static str,
+    label: String,
+}
+
+/// Writes `result` as one offline HTML page with inline SVG charts and no external assets.
+pub fn export_html_report(path: &Path, result: &BacktestResult) -> Result<(), BacktestError> {
+    fs::write(path, render_html_report(result)).map_err(|err| BacktestError::Io(err.to_string()))
+}
+
+pub fn render_html_report(result: &BacktestResult) -> String {
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    html.push_str("<title>Backtest report</title>\n");
+    let _ = writeln!(html, "<style>{STYLE}</style>\n</head>\n<body>");
+    html.push_str("<h1>Backtest report</h1>\n");
+    if let (Some(first), Some(last)) = (result.equity_curve.first(), result.equity_curve.last()) {
+        let _ = writeln!(
+            html,
+            "<p>{} to {} &middot; {} bars &middot; {} trades</p>",
+            first.timestamp.format("%Y-%m-%d %H:%M"),
+            last.timestamp.format("%Y-%m-%d %H:%M"),
+            result.equity_curve.len(),
+            result.trades.len()
+        );
+    }
+
+    html.push_str("<h2>Summary</h2>\n");
+    html.push_str(&metrics_table(&result.metrics));
+
+    let equity: Vec<(DateTime<Utc>, f64)> = result
+        .equity_curve
+        .iter()
+        .map(|point| (point.timestamp, to_f64(point.equity)))
+        .collect();
+    html.push_str("<h2>Equity</h2>\n");
+    html.push_str(&line_chart(&equity, &[], "#1f6feb", false));
+
+    let mut peak = f64::MIN;
+    let drawdown: Vec<(DateTime<Utc>, f64)> = equity
+        .iter()
+        .map(|(timestamp, value)| {
+            peak = peak.max(*value);
+            let drawdown = if peak > 0.0 {
+                (value - peak) / peak * 100.0
+            } else {
+                0.0
+            };
+            (*timestamp, drawdown)
+        })
+        .collect();
+    html.push_str("<h2>Drawdown (%)</h2>\n");
+    html.push_str(&line_chart(&drawdown, &[], "#c62828", true));
+
+    let zscores: BTreeMap<DateTime<Utc>, f64> = result
+        .bar_logs
+        .iter()
+        .filter_map(|log| log.zscore.map(|zscore| (log.timestamp, to_f64(zscore))))
+        .collect();
+    let mut markers = Vec::new();
+    for trade in &result.trades {
+        for (timestamp, color, kind) in [
+            (trade.entry_time, "#1a7f37", "entry"),
+            (trade.exit_time, "#c62828", "exit"),
+        ] {
+            if let Some(value) = zscores.get(&timestamp) {
+                markers.push(Marker {
+                    timestamp,
+                    value: *value,
+                    color,
+                    label: format!("{kind} {}", timestamp.format("%Y-%m-%d %H:%M")),
+                });
+            }
+        }
+    }
+    let zscore_series: Vec<(DateTime<Utc>, f64)> = zscores.into_iter().collect();
+    html.push_str("<h2>Z-score with entries (green) and exits (red)</h2>\n");
+    html.push_str(&line_chart(&zscore_series, &markers, "#6e40c9", true));
+
+    html.push_str("<h2>Monthly PnL</h2>\n");
+    html.push_str(&monthly_chart(result));
+
+    html.push_str("<h2>Exit reasons</h2>\n");
+    html.push_str(&exit_reason_table(result));
+
+    html.push_str("<h2>Trades</h2>\n");
+    html.push_str(&trade_table(result));
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+fn metrics_table(metrics: &Metrics) -> String {
+    let rows = [
+        ("Annualized return", pct(metrics.annualized_return)),
+        ("Sharpe", fixed(metrics.sharpe_ratio, 2)),
+        ("Sortino", fixed(metrics.sortino_ratio, 2)),
+        ("Calmar", fixed(metrics.calmar_ratio, 2)),
+        ("Max drawdown", pct(metrics.max_drawdown)),
+        (
+            "Max drawdown duration (h)",
+            fixed(metrics.max_drawdown_duration_hours, 1),
+        ),
+        ("Win rate", pct(metrics.win_rate)),
+        ("Profit factor", fixed(metrics.profit_factor, 2)),
+        ("Stop-loss rate", pct(metrics.stop_loss_rate)),
+        ("Trades", metrics.trade_count.to_string()),
+        ("Time in market", pct(metrics.time_in_market)),
+        ("Avg holding (h)", fixed(metrics.avg_holding_hours, 1)),
+        ("Turnover", fixed(metrics.turnover, 2)),
+        ("Total fees", fixed(metrics.total_fees, 2)),
+        ("Total funding", fixed(metrics.total_funding, 2)),
+    ];
+    let mut html = String::from("<table>\n");
+    for (label, value) in rows {
+        let _ = writeln!(
+            html,
+            "<tr><th class=\"l\">{label}</th><td>{value}</td></tr>"
+        );
+    }
+    html.push_str("</table>\n");
+    html
+}
+
+fn line_chart(
+    series: &[(DateTime<Utc>, f64)],
+    markers: &[Marker],
+    color: &str,
+    zero_line: bool,
+) -> String {
+    if series.len() < 2 {
+        return "<p>Not enough data.</p>\n".to_string();
+    }
+    let start = series[0].0.timestamp() as f64;
+    let end = series[series.len() - 1].0.timestamp() as f64;
+    let span = (end - start).max(1.0);
+    let (mut low, mut high) = series
+        .iter()
+        .map(|(_, value)| *value)
+        .chain(markers.iter().map(|marker| marker.value))
+        .fold((f64::MAX, f64::MIN), |(low, high), value| {
+            (low.min(value), high.max(value))
+        });
+    if zero_line {
+        low = low.min(0.0);
+        high = high.max(0.0);
+    }
+    if high <= low {
+        low -= 1.0;
+        high += 1.0;
+    }
+    let x = |timestamp: DateTime<Utc>| {
+        PAD_LEFT
+            + (timestamp.timestamp() as f64 - start) / span * (CHART_WIDTH - PAD_LEFT - PAD_RIGHT)
+    };
+    let y = |value: f64| {
+        PAD_TOP + (high - value) / (high - low) * (CHART_HEIGHT - PAD_TOP - PAD_BOTTOM)
+    };
+
+    let mut svg = svg_open();
+    axes(
+        &mut svg,
+        low,
+        high,
+        series[0].0,
+        series[series.len() - 1].0,
+        &y,
+    );
+    if zero_line && low < 0.0 && high > 0.0 {
+        let _ = writeln!(
+            svg,
+            "<line x1=\"{PAD_LEFT}\" x2=\"{:.1}\" y1=\"{:.1}\" y2=\"{:.1}\" stroke=\"#999\" stroke-dasharray=\"4 3\"/>",
+            CHART_WIDTH - PAD_RIGHT,
+            y(0.0),
+            y(0.0)
+        );
+    }
+    let stride = series.len().div_ceil(MAX_LINE_POINTS);
+    let mut points = String::new();
+    for (index, (timestamp, value)) in series.iter().enumerate() {
+        if index % stride == 0 || index == series.len() - 1 {
+            let _ = write!(points, "{:.1},{:.1} ", x(*timestamp), y(*value));
+        }
+    }
+    let _ = writeln!(
+        svg,
+        "<polyline fill=\"none\" stroke=\"{color}\" stroke-width=\"1.5\" points=\"{}\"/>",
+        points.trim_end()
+    );
+    for marker in markers {
+        let _ = writeln!(
+            svg,
+            "<circle cx=\"{:.1}\" cy=\"{:.1}\" r=\"3.5\" fill=\"{}\"><title>{}</title></circle>",
+            x(marker.timestamp),
+            y(marker.value),
+            marker.color,
+            escape(&marker.label)
+        );
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+fn monthly_chart(result: &BacktestResult) -> String {
+    let months = breakdown_monthly(&result.trades);
+    if months.is_empty() {
+        return "<p>No closed trades.</p>\n".to_string();
+    }
+    let values: Vec<f64> = months.iter().map(|row| to_f64(row.pnl)).collect();
+    let high = values.iter().copied().fold(0.0, f64::max);
+    let low = values.iter().copied().fold(0.0, f64::min);
+    let range = if high <= low { 1.0 } else { high - low };
+    let y = |value: f64| PAD_TOP + (high - value) / range * (CHART_HEIGHT - PAD_TOP - PAD_BOTTOM);
+    let slot = (CHART_WIDTH - PAD_LEFT - PAD_RIGHT) / months.len() as f64;
+    let label_every = months.len().div_ceil(24);
+
+    let mut svg = svg_open();
+    let _ = writeln!(
+        svg,
+        "<line x1=\"{PAD_LEFT}\" x2=\"{:.1}\" y1=\"{:.1}\" y2=\"{:.1}\" stroke=\"#999\"/>",
+        CHART_WIDTH - PAD_RIGHT,
+        y(0.0),
+        y(0.0)
+    );
+    let _ = writeln!(
+        svg,
+        "<text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"end\">{}</text>\
+<text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"end\">{}</text>",
+        PAD_LEFT - 6.0,
+        y(high) + 4.0,
+        axis_label(high),
+        PAD_LEFT - 6.0,
+        y(low) + 4.0,
+        axis_label(low)
+    );
+    for (index, (row, value)) in months.iter().zip(&values).enumerate() {
+        let left = PAD_LEFT + slot * index as f64 + slot * 0.15;
+        let top = y(value.max(0.0));
+        let height = (y(value.min(0.0)) - top).max(0.5);
+        let fill = if *value >= 0.0 { "#1a7f37" } else { "#c62828" };
+        let _ = writeln!(
+            svg,
+            "<rect x=\"{left:.1}\" y=\"{top:.1}\" width=\"{:.1}\" height=\"{height:.1}\" fill=\"{fill}\"><title>{}-{:02}: {}</title></rect>",
+            slot * 0.7,
+            row.year,
+            row.month,
+            row.pnl.round_dp(2)
+        );
+        if index % label_every == 0 {
+            let _ = writeln!(
+                svg,
+                "<text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"middle\">{}-{:02}</text>",
+                left + slot * 0.35,
+                CHART_HEIGHT - 8.0,
+                row.year,
+                row.month
+            );
+        }
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+fn exit_reason_table(result: &BacktestResult) -> String {
+    let mut rows: BTreeMap<String, (usize, usize)> = BTreeMap::new();
+    for trade in &result.trades {
+        let entry = rows
+            .entry(format!("{:?}", trade.exit_reason))
+            .or_insert((0, 0));
+        entry.0 += 1;
+        if trade.pnl > Decimal::ZERO {
+            entry.1 += 1;
+        }
+    }
+    let mut html = String::from(
+        "<table>\n<tr><th class=\"l\">Exit reason</th><th>Trades</th><th>Win rate</th><th>PnL</th></tr>\n",
+    );
+    for (reason, (count, wins)) in rows {
+        let pnl = result
+            .metrics
+            .pnl_by_exit_reason
+            .get(&reason)
+            .copied()
+            .unwrap_or(Decimal::ZERO);
+        let _ = writeln!(
+            html,
+            "<tr><td class=\"l\">{}</td><td>{count}</td><td>{:.1}%</td>{}</tr>",
+            escape(&reason),
+            wins as f64 / count as f64 * 100.0,
+            signed_cell(pnl)
+        );
+    }
+    html.push_str("</table>\n");
+    html
+}
+
+fn trade_table(result: &BacktestResult) -> String {
+    let mut html = String::from(
+        "<table>\n<tr><th>#</th><th class=\"l\">Entry</th><th class=\"l\">Exit</th><th>Hours</th>\
+<th class=\"l\">Direction</th><th class=\"l\">Reason</th><th>Entry z</th><th>Exit z</th>\
+<th>Notional</th><th>Gross</th><th>Fees</th><th>Slippage</th><th>Funding</th><th>PnL</th></tr>\n",
+    );
+    let zscore = |value: Option<Decimal>| {
+        value
+            .map(|value| value.round_dp(2).to_string())
+            .unwrap_or_default()
+    };
+    for (index, trade) in result.trades.iter().enumerate() {
+        let _ = writeln!(
+            html,
+            "<tr><td>{}</td><td class=\"l\">{}</td><td class=\"l\">{}</td><td>{}</td>\
+<td class=\"l\">{:?}</td><td class=\"l\">{:?}</td><td>{}</td><td>{}</td><td>{}</td>{}\
+<td>{}</td><td>{}</td><td>{}</td>{}</tr>",
+            index + 1,
+            trade.entry_time.format("%Y-%m-%d %H:%M"),
+            trade.exit_time.format("%Y-%m-%d %H:%M"),
+            trade.holding_hours.round_dp(2),
+            trade.direction,
+            trade.exit_reason,
+            zscore(trade.entry_zscore),
+            zscore(trade.exit_zscore),
+            trade.notional.round_dp(2),
+            signed_cell(trade.gross_pnl),
+            trade.fee.round_dp(2),
+            trade.slippage.round_dp(2),
+            trade.funding.round_dp(2),
+            signed_cell(trade.pnl)
+        );
+    }
+    html.push_str("</table>\n");
+    html
+}
+
+fn svg_open() -> String {
+    format!(
+        "<svg viewBox=\"0 0 {CHART_WIDTH} {CHART_HEIGHT}\" width=\"{CHART_WIDTH}\" height=\"{CHART_HEIGHT}\" role=\"img\">\n"
+    )
+}
+
+fn axes(
+    svg: &mut String,
+    low: f64,
+    high: f64,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    y: &dyn Fn(f64) -> f64,
+) {
+    let bottom = CHART_HEIGHT - PAD_BOTTOM;
+    let _ = writeln!(
+        svg,
+        "<line x1=\"{PAD_LEFT}\" x2=\"{PAD_LEFT}\" y1=\"{PAD_TOP}\" y2=\"{bottom}\" stroke=\"#bbb\"/>\
+<line x1=\"{PAD_LEFT}\" x2=\"{:.1}\" y1=\"{bottom}\" y2=\"{bottom}\" stroke=\"#bbb\"/>",
+        CHART_WIDTH - PAD_RIGHT
+    );
+    let _ = writeln!(
+        svg,
+        "<text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"end\">{}</text>\
+<text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"end\">{}</text>",
+        PAD_LEFT - 6.0,
+        y(high) + 4.0,
+        axis_label(high),
+        PAD_LEFT - 6.0,
+        y(low) + 4.0,
+        axis_label(low)
+    );
+    let _ = writeln!(
+        svg,
+        "<text x=\"{PAD_LEFT}\" y=\"{:.1}\">{}</text>\
+<text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"end\">{}</text>",
+        CHART_HEIGHT - 8.0,
+        start.format("%Y-%m-%d"),
+        CHART_WIDTH - PAD_RIGHT,
+        CHART_HEIGHT - 8.0,
+        end.format("%Y-%m-%d")
+    );
+}
+
+fn axis_label(value: f64) -> String {
+    if value.abs() >= 1000.0 {
+        format!("{value:.0}")
+    } else {
+        format!("{value:.2}")
+    }
+}
+
+fn signed_cell(value: Decimal) -> String {
+    let class = if value < Decimal::ZERO { "neg" } else { "pos" };
+    format!("<td class=\"{class}\">{}</td>", value.round_dp(2))
+}
+
+fn pct(value: Decimal) -> String {
+    format!("{}%", (value * Decimal::ONE_HUNDRED).round_dp(2))
+}
+
+fn fixed(value: Decimal, dp: u32) -> String {
+    value.round_dp(dp).to_string()
+}
+
+fn to_f64(value: Decimal) -> f64 {
+    value.to_f64().unwrap_or(0.0)
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
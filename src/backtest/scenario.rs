@@ -0,0 +1,222 @@
+use chrono::{DateTime, TimeZone, Utc};
+use rust_decimal::Decimal;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use serde::{Deserialize, Serialize};
+
+use crate::backtest::rng::SplitMix64;
+use crate::backtest::{BacktestBar, BacktestError};
+use crate::config::BarInterval;
+
+/// A stretch of bars with one spread behaviour.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpreadRegime {
+    pub bars: usize,
+    /// Ornstein-Uhlenbeck half-life of the spread; `None` is a random walk that never reverts.
+    pub half_life_bars: Option<f64>,
+    /// Standard deviation of the per-bar spread innovation, in log units.
+    pub vol: f64,
+    /// Added to the spread every bar, e.g. for a persistent decoupling.
+    #[serde(default)]
+    pub drift: f64,
+}
+
+/// Random jumps hitting both legs in the same bar.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JumpModel {
+    /// Chance of a jump per bar.
+    pub probability: f64,
+    /// Standard deviation of each leg's log jump.
+    pub size: f64,
+    /// Correlation between the ETH and BTC jumps.
+    pub correlation: f64,
+}
+
+/// A scripted move of `base_return`/`quote_return` (simple returns, e.g. -0.3) spread evenly
+/// over `bars` bars from `start_bar`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PriceShock {
+    pub start_bar: usize,
+    pub bars: usize,
+    pub base_return: f64,
+    pub quote_return: f64,
+}
+
+/// Per-settlement funding rates replacing the base rates for `bars` bars from `start_bar`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FundingSpike {
+    pub start_bar: usize,
+    pub bars: usize,
+    pub base_rate: Decimal,
+    pub quote_rate: Decimal,
+}
+
+/// Bars dropped from the output, as in a feed outage.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BarGap {
+    pub start_bar: usize,
+    pub bars: usize,
+}
+
+/// Synthetic bars on the `interval` grid where `ln(base) = beta * ln(quote) + spread`.
+///
+/// BTC follows a driftless random walk with `quote_vol` per bar. The spread starts at the
+/// level implied by the starting prices and moves through `regimes` in order, the last one
+/// running until `bars`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScenarioConfig {
+    pub start: DateTime<Utc>,
+    #[serde(default)]
+    pub interval: BarInterval,
+    pub bars: usize,
+    pub base_price: Decimal,
+    pub quote_price: Decimal,
+    pub beta: f64,
+    pub quote_vol: f64,
+    pub regimes: Vec<SpreadRegime>,
+    #[serde(default)]
+    pub jumps: Option<JumpModel>,
+    #[serde(default)]
+    pub shocks: Vec<PriceShock>,
+    pub funding_base: Option<Decimal>,
+    pub funding_quote: Option<Decimal>,
+    #[serde(default)]
+    pub funding_spikes: Vec<FundingSpike>,
+    #[serde(default)]
+    pub gaps: Vec<BarGap>,
+    pub seed: u64,
+}
+
+impl Default for ScenarioConfig {
+    fn default() -> Self {
+        Self {
+            start: Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+            interval: BarInterval::default(),
+            bars: 2000,
+            base_price: Decimal::new(3000, 0),
+            quote_price: Decimal::new(60000, 0),
+            beta: 1.0,
+            quote_vol: 0.003,
+            regimes: vec![SpreadRegime {
+                bars: 2000,
+                half_life_bars: Some(48.0),
+                vol: 0.002,
+                drift: 0.0,
+            }],
+            jumps: None,
+            shocks: Vec::new(),
+            funding_base: Some(Decimal::new(125, 7)),
+            funding_quote: Some(Decimal::new(125, 7)),
+            funding_spikes: Vec::new(),
+            gaps: Vec::new(),
+            seed: 0,
+        }
+    }
+}
+
+/// Generates the bars of `scenario`; the same config and seed always give the same bars.
+pub fn generate_bars(scenario: &ScenarioConfig) -> Result<Vec<BacktestBar>, BacktestError> {
+    if scenario.regimes.is_empty() {
+        return Err(BacktestError::InvalidConfig(
+            "scenario needs at least one spread regime".to_string(),
+        ));
+    }
+    if scenario.base_price <= Decimal::ZERO || scenario.quote_price <= Decimal::ZERO {
+        return Err(BacktestError::InvalidConfig(
+            "scenario prices must be > 0".to_string(),
+        ));
+    }
+    let log_price = |price: Decimal| {
+        price
+            .to_f64()
+            .map(f64::ln)
+            .ok_or_else(|| BacktestError::InvalidConfig(format!("invalid price {price}")))
+    };
+    let base_start = log_price(scenario.base_price)?;
+    let mut quote = log_price(scenario.quote_price)?;
+    let base = base_start - scenario.beta * quote;
+
+    let mut rng = SplitMix64::new(scenario.seed);
+    let mut spread = 0.0;
+    let mut bars = Vec::with_capacity(scenario.bars);
+    for index in 0..scenario.bars {
+        if index > 0 {
+            let regime = regime_at(&scenario.regimes, index);
+            let diffusion = scenario.quote_vol * rng.normal();
+            let mut quote_move = 0.0;
+            let mut base_move = 0.0;
+            if let Some(jumps) = scenario.jumps.as_ref()
+                && rng.uniform() < jumps.probability
+            {
+                let common = rng.normal();
+                let own = rng.normal();
+                let correlation = jumps.correlation.clamp(-1.0, 1.0);
+                quote_move += jumps.size * common;
+                base_move += jumps.size
+                    * (correlation * common + (1.0 - correlation * correlation).sqrt() * own);
+            }
+            for shock in &scenario.shocks {
+                if shock.bars > 0
+                    && (shock.start_bar..shock.start_bar + shock.bars).contains(&index)
+                {
+                    let bars = shock.bars as f64;
+                    base_move += (1.0 + shock.base_return).ln() / bars;
+                    quote_move += (1.0 + shock.quote_return).ln() / bars;
+                }
+            }
+            // ETH already carries `beta` of every BTC move, so only the excess of the
+            // scripted and jump moves lands in the spread.
+            let excess = base_move - scenario.beta * quote_move;
+            quote += diffusion + quote_move;
+            let reversion = regime
+                .half_life_bars
+                .map(|half_life| 0.5f64.powf(1.0 / half_life.max(f64::EPSILON)))
+                .unwrap_or(1.0);
+            spread = reversion * spread + regime.vol * rng.normal() + regime.drift + excess;
+        }
+        if scenario
+            .gaps
+            .iter()
+            .any(|gap| (gap.start_bar..gap.start_bar + gap.bars).contains(&index))
+        {
+            continue;
+        }
+        let spike = scenario
+            .funding_spikes
+            .iter()
+            .find(|spike| (spike.start_bar..spike.start_bar + spike.bars).contains(&index));
+        let price = |log: f64| {
+            Decimal::from_f64(log.exp())
+                .map(|price| price.round_dp(6))
+                .ok_or_else(|| {
+                    BacktestError::InvalidConfig(format!("price overflow at bar {index}"))
+                })
+        };
+        bars.push(BacktestBar {
+            timestamp: scenario.start + scenario.interval.duration() * index as i32,
+            base_price: price(base + scenario.beta * quote + spread)?,
+            quote_price: price(quote)?,
+            funding_base: spike.map(|spike| spike.base_rate).or(scenario.funding_base),
+            funding_quote: spike
+                .map(|spike| spike.quote_rate)
+                .or(scenario.funding_quote),
+            base_open: None,
+            base_high: None,
+            base_low: None,
+            quote_open: None,
+            quote_high: None,
+            quote_low: None,
+        });
+    }
+    Ok(bars)
+}
+
+fn regime_at(regimes: &[SpreadRegime], index: usize) -> &SpreadRegime {
+    let mut end = 0;
+    for regime in regimes {
+        end += regime.bars;
+        if index < end {
+            return regime;
+        }
+    }
+    &regimes[regimes.len() - 1]
+}
@@ -0,0 +1,355 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+
+use crate::account::{
+    AccountError, AccountFillSource, AccountPositionSource, ExchangeFill, ExchangePosition,
+    PairExposure,
+};
+use crate::backtest::flat_fill_bps;
+use crate::config::{BacktestConfig, OrderType, Symbol};
+use crate::execution::{
+    ExecutionError, OrderExecutor, OrderFill, OrderRequest, OrderSide, OrderSubmitResult,
+};
+
+#[derive(Debug, Clone, Copy, Default)]
+struct SimulatedPosition {
+    qty: Decimal,
+    entry_price: Decimal,
+}
+
+#[derive(Debug, Clone)]
+struct RestingOrder {
+    symbol: Symbol,
+    side: OrderSide,
+    qty: Decimal,
+    limit_price: Decimal,
+    expires_after: Option<u64>,
+}
+
+#[derive(Debug, Default)]
+struct SimulatedExchangeState {
+    timestamp: Option<DateTime<Utc>>,
+    prices: HashMap<Symbol, Decimal>,
+    positions: HashMap<Symbol, SimulatedPosition>,
+    resting: BTreeMap<u64, RestingOrder>,
+    fills: Vec<ExchangeFill>,
+    next_oid: u64,
+    next_tid: u64,
+}
+
+/// In-process exchange used to drive `StrategyEngine` over historical bars.
+///
+/// Market and crossing limit orders fill at the current bar price adjusted by half the
+/// configured round-trip backtest slippage; post-only orders rest until a later bar trades
+/// through their limit price or they expire.
+#[derive(Debug)]
+pub struct SimulatedExchange {
+    fee_rate: Decimal,
+    slippage_rate: Decimal,
+    state: Mutex<SimulatedExchangeState>,
+}
+
+impl SimulatedExchange {
+    pub fn new(config: &BacktestConfig) -> Self {
+        let fee_rate = if config.include_fees {
+            Decimal::from(config.fee_bps) / Decimal::new(10000, 0)
+        } else {
+            Decimal::ZERO
+        };
+        // `slippage_bps` is a round trip; each fill pays half, as in the native engine.
+        let slippage_rate = if config.include_slippage {
+            flat_fill_bps(config) / Decimal::new(10000, 0)
+        } else {
+            Decimal::ZERO
+        };
+        Self {
+            fee_rate,
+            slippage_rate,
+            state: Mutex::new(SimulatedExchangeState {
+                next_oid: 1,
+                next_tid: 1,
+                ..SimulatedExchangeState::default()
+            }),
+        }
+    }
+
+    pub fn advance_to(&self, timestamp: DateTime<Utc>, base_price: Decimal, quote_price: Decimal) {
+        let mut state = self.state.lock().expect("simulated exchange lock poisoned");
+        // Resting orders were live for part of the interval since the previous bar,
+        // so only orders that expired before that bar are dropped without a fill check.
+        let previous_ms = state
+            .timestamp
+            .map(|previous| previous.timestamp_millis() as u64);
+        state.timestamp = Some(timestamp);
+        state.prices.insert(Symbol::Base, base_price);
+        state.prices.insert(Symbol::Quote, quote_price);
+
+        let resting = std::mem::take(&mut state.resting);
+        for (oid, order) in resting {
+            if let (Some(expiry), Some(previous_ms)) = (order.expires_after, previous_ms)
+                && expiry <= previous_ms
+            {
+                continue;
+            }
+            let price = state.prices[&order.symbol];
+            let crosses = match order.side {
+                OrderSide::Buy => price <= order.limit_price,
+                OrderSide::Sell => price >= order.limit_price,
+            };
+            if crosses {
+                self.record_fill(
+                    &mut state,
+                    order.symbol,
+                    order.side,
+                    order.qty,
+                    order.limit_price,
+                    oid,
+                );
+            } else {
+                state.resting.insert(oid, order);
+            }
+        }
+    }
+
+    /// Moves the prices new orders execute against, leaving resting orders unchecked.
+    pub fn reprice(&self, base_price: Decimal, quote_price: Decimal) {
+        let mut state = self.state.lock().expect("simulated exchange lock poisoned");
+        state.prices.insert(Symbol::Base, base_price);
+        state.prices.insert(Symbol::Quote, quote_price);
+    }
+
+    pub fn exposure(&self) -> PairExposure {
+        let state = self.state.lock().expect("simulated exchange lock poisoned");
+        let leg = |symbol: Symbol| {
+            state
+                .positions
+                .get(&symbol)
+                .filter(|position| position.qty != Decimal::ZERO)
+                .map(|position| ExchangePosition {
+                    qty: position.qty,
+                    entry_price: position.entry_price,
+                    notional: position.qty.abs() * position.entry_price,
+                })
+        };
+        PairExposure {
+            base: leg(Symbol::Base),
+            quote: leg(Symbol::Quote),
+        }
+    }
+
+    pub fn fills(&self) -> Vec<ExchangeFill> {
+        self.state
+            .lock()
+            .expect("simulated exchange lock poisoned")
+            .fills
+            .clone()
+    }
+
+    fn execute(&self, order: &OrderRequest) -> Result<OrderSubmitResult, ExecutionError> {
+        if order.qty <= Decimal::ZERO {
+            return Err(ExecutionError::Fatal(
+                "order quantity must be positive".to_string(),
+            ));
+        }
+        let mut state = self.state.lock().expect("simulated exchange lock poisoned");
+        let price = *state.prices.get(&order.symbol).ok_or_else(|| {
+            ExecutionError::Fatal(format!("no simulated price for {:?}", order.symbol))
+        })?;
+        let oid = state.next_oid;
+        state.next_oid += 1;
+
+        let crosses = |limit: Decimal| match order.side {
+            OrderSide::Buy => limit >= price,
+            OrderSide::Sell => limit <= price,
+        };
+        match order.order_type {
+            OrderType::Market => {
+                let fill_price = self.taker_price(order.side, price);
+                Ok(OrderSubmitResult::Filled(self.record_fill(
+                    &mut state,
+                    order.symbol,
+                    order.side,
+                    order.qty,
+                    fill_price,
+                    oid,
+                )))
+            }
+            OrderType::Limit => {
+                let limit = order.limit_price.unwrap_or(price);
+                if crosses(limit) {
+                    let fill_price = self.taker_price(order.side, price);
+                    Ok(OrderSubmitResult::Filled(self.record_fill(
+                        &mut state,
+                        order.symbol,
+                        order.side,
+                        order.qty,
+                        fill_price,
+                        oid,
+                    )))
+                } else {
+                    self.rest(&mut state, order, limit, oid)
+                }
+            }
+            OrderType::PostOnly => {
+                let limit = order.limit_price.ok_or_else(|| {
+                    ExecutionError::Fatal("limit_price required for post-only order".to_string())
+                })?;
+                if crosses(limit) {
+                    return Err(ExecutionError::Fatal(
+                        "Post only order would have immediately matched".to_string(),
+                    ));
+                }
+                self.rest(&mut state, order, limit, oid)
+            }
+        }
+    }
+
+    fn rest(
+        &self,
+        state: &mut SimulatedExchangeState,
+        order: &OrderRequest,
+        limit_price: Decimal,
+        oid: u64,
+    ) -> Result<OrderSubmitResult, ExecutionError> {
+        state.resting.insert(
+            oid,
+            RestingOrder {
+                symbol: order.symbol,
+                side: order.side,
+                qty: order.qty,
+                limit_price,
+                expires_after: order.expires_after,
+            },
+        );
+        Ok(OrderSubmitResult::Resting { oid })
+    }
+
+    fn taker_price(&self, side: OrderSide, price: Decimal) -> Decimal {
+        match side {
+            OrderSide::Buy => price * (Decimal::ONE + self.slippage_rate),
+            OrderSide::Sell => price * (Decimal::ONE - self.slippage_rate),
+        }
+    }
+
+    fn record_fill(
+        &self,
+        state: &mut SimulatedExchangeState,
+        symbol: Symbol,
+        side: OrderSide,
+        qty: Decimal,
+        price: Decimal,
+        oid: u64,
+    ) -> OrderFill {
+        let signed_qty = match side {
+            OrderSide::Buy => qty,
+            OrderSide::Sell => -qty,
+        };
+        let position = state.positions.entry(symbol).or_default();
+        let mut closed_pnl = Decimal::ZERO;
+        if position.qty == Decimal::ZERO
+            || (position.qty > Decimal::ZERO) == (signed_qty > Decimal::ZERO)
+        {
+            let total = position.qty.abs() + qty;
+            position.entry_price =
+                (position.qty.abs() * position.entry_price + qty * price) / total;
+            position.qty += signed_qty;
+        } else {
+            let closed = qty.min(position.qty.abs());
+            closed_pnl = if position.qty > Decimal::ZERO {
+                closed * (price - position.entry_price)
+            } else {
+                closed * (position.entry_price - price)
+            };
+            let remaining = position.qty + signed_qty;
+            if remaining == Decimal::ZERO {
+                *position = SimulatedPosition::default();
+            } else if (remaining > Decimal::ZERO) != (position.qty > Decimal::ZERO) {
+                position.qty = remaining;
+                position.entry_price = price;
+            } else {
+                position.qty = remaining;
+            }
+        }
+
+        let tid = state.next_tid;
+        state.next_tid += 1;
+        state.fills.push(ExchangeFill {
+            coin: symbol,
+            price,
+            size: qty,
+            fee: qty * price * self.fee_rate,
+            closed_pnl,
+            timestamp: state.timestamp.unwrap_or_else(Utc::now),
+            oid: Some(oid),
+            tid: Some(tid),
+        });
+        OrderFill {
+            qty,
+            avg_price: Some(price),
+            oid: Some(oid),
+        }
+    }
+}
+
+#[async_trait]
+impl OrderExecutor for SimulatedExchange {
+    async fn submit(&self, order: &OrderRequest) -> Result<Decimal, ExecutionError> {
+        self.submit_result(order).await.map(|result| match result {
+            OrderSubmitResult::Filled(fill) => fill.qty,
+            OrderSubmitResult::Resting { .. } => Decimal::ZERO,
+        })
+    }
+
+    async fn close(&self, order: &OrderRequest) -> Result<Decimal, ExecutionError> {
+        self.submit(order).await
+    }
+
+    async fn cancel(&self, _symbol: Symbol, oid: u64) -> Result<(), ExecutionError> {
+        self.state
+            .lock()
+            .expect("simulated exchange lock poisoned")
+            .resting
+            .remove(&oid);
+        Ok(())
+    }
+
+    async fn submit_result(
+        &self,
+        order: &OrderRequest,
+    ) -> Result<OrderSubmitResult, ExecutionError> {
+        self.execute(order)
+    }
+
+    async fn close_result(
+        &self,
+        order: &OrderRequest,
+    ) -> Result<OrderSubmitResult, ExecutionError> {
+        self.execute(order)
+    }
+}
+
+#[async_trait]
+impl AccountPositionSource for SimulatedExchange {
+    async fn fetch_pair_exposure(&self) -> Result<PairExposure, AccountError> {
+        Ok(self.exposure())
+    }
+}
+
+#[async_trait]
+impl AccountFillSource for SimulatedExchange {
+    async fn fetch_user_fills_by_time(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<ExchangeFill>, AccountError> {
+        Ok(self
+            .fills()
+            .into_iter()
+            .filter(|fill| fill.timestamp >= start && fill.timestamp <= end)
+            .collect())
+    }
+}
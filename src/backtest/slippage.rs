@@ -0,0 +1,158 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::{Decimal, MathematicalOps};
+use serde::{Deserialize, Serialize};
+
+use crate::backtest::BacktestError;
+use crate::config::Symbol;
+use crate::data::OrderBookSnapshot;
+use crate::logging::BarLog;
+
+/// Top of book for one leg, as recorded in live `BarLog`s.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LegBook {
+    pub best_bid: Decimal,
+    pub best_ask: Decimal,
+    pub bid_size: Decimal,
+    pub ask_size: Decimal,
+}
+
+impl From<&OrderBookSnapshot> for LegBook {
+    fn from(snapshot: &OrderBookSnapshot) -> Self {
+        Self {
+            best_bid: snapshot.best_bid,
+            best_ask: snapshot.best_ask,
+            bid_size: snapshot.bid_size,
+            ask_size: snapshot.ask_size,
+        }
+    }
+}
+
+impl LegBook {
+    fn from_fields(
+        best_bid: Option<Decimal>,
+        best_ask: Option<Decimal>,
+        bid_size: Option<Decimal>,
+        ask_size: Option<Decimal>,
+    ) -> Option<Self> {
+        let book = Self {
+            best_bid: best_bid?,
+            best_ask: best_ask?,
+            bid_size: bid_size?,
+            ask_size: ask_size?,
+        };
+        (book.best_bid > Decimal::ZERO && book.best_ask > book.best_bid).then_some(book)
+    }
+
+    pub fn half_spread_bps(&self) -> Decimal {
+        let mid = (self.best_bid + self.best_ask) / Decimal::TWO;
+        (self.best_ask - self.best_bid) / mid * Decimal::from(5_000u32)
+    }
+
+    /// Notional resting on the side a `buy` (ask) or sell (bid) consumes.
+    pub fn depth_notional(&self, buy: bool) -> Decimal {
+        if buy {
+            self.ask_size * self.best_ask
+        } else {
+            self.bid_size * self.best_bid
+        }
+    }
+}
+
+/// Per-bar book snapshots calibrated from recorded bar logs.
+///
+/// A fill costs half the recorded spread plus `impact_bps * sqrt(notional / depth)`, where
+/// depth is the top-of-book notional on the side the order consumes. Fills on bars (or legs)
+/// without a usable book pay the flat per-fill rate, half of `slippage_bps`, instead.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BookSlippageModel {
+    books: BTreeMap<DateTime<Utc>, (Option<LegBook>, Option<LegBook>)>,
+}
+
+impl BookSlippageModel {
+    pub fn from_bar_logs(logs: &[BarLog]) -> Self {
+        let mut books = BTreeMap::new();
+        for log in logs {
+            let base = LegBook::from_fields(
+                log.base_best_bid,
+                log.base_best_ask,
+                log.base_bid_size,
+                log.base_ask_size,
+            );
+            let quote = LegBook::from_fields(
+                log.quote_best_bid,
+                log.quote_best_ask,
+                log.quote_bid_size,
+                log.quote_ask_size,
+            );
+            if base.is_some() || quote.is_some() {
+                books.insert(log.timestamp, (base, quote));
+            }
+        }
+        Self { books }
+    }
+
+    /// Adds a stored book snapshot, e.g. an `l2Book` capture, for one leg.
+    pub fn insert(&mut self, timestamp: DateTime<Utc>, symbol: Symbol, book: LegBook) {
+        let entry = self.books.entry(timestamp).or_insert((None, None));
+        match symbol {
+            Symbol::Base => entry.0 = Some(book),
+            Symbol::Quote => entry.1 = Some(book),
+        }
+    }
+
+    /// Bars with a usable book for at least one leg.
+    pub fn len(&self) -> usize {
+        self.books.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.books.is_empty()
+    }
+
+    pub fn book(&self, timestamp: DateTime<Utc>, symbol: Symbol) -> Option<LegBook> {
+        let (base, quote) = self.books.get(&timestamp)?;
+        match symbol {
+            Symbol::Base => *base,
+            Symbol::Quote => *quote,
+        }
+    }
+
+    /// Slippage in quote currency for filling `notional` of `symbol` at `timestamp`.
+    pub fn fill_cost(
+        &self,
+        timestamp: DateTime<Utc>,
+        symbol: Symbol,
+        buy: bool,
+        notional: Decimal,
+        impact_bps: Decimal,
+        flat_bps: Decimal,
+    ) -> Decimal {
+        let notional = notional.abs();
+        let bps = match self.book(timestamp, symbol) {
+            Some(book) if book.depth_notional(buy) > Decimal::ZERO => {
+                let impact = (notional / book.depth_notional(buy))
+                    .sqrt()
+                    .unwrap_or(Decimal::ZERO);
+                book.half_spread_bps() + impact_bps * impact
+            }
+            _ => flat_bps,
+        };
+        notional * bps / Decimal::new(10000, 0)
+    }
+}
+
+/// Reads JSON bar logs, one object per line; blank lines are skipped.
+pub fn load_bar_logs_jsonl(path: &Path) -> Result<Vec<BarLog>, BacktestError> {
+    let payload = fs::read_to_string(path).map_err(|err| BacktestError::Io(err.to_string()))?;
+    payload
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            serde_json::from_str(line).map_err(|err| BacktestError::Serialization(err.to_string()))
+        })
+        .collect()
+}
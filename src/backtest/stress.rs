@@ -0,0 +1,349 @@
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::backtest::scenario::{
+    FundingSpike, PriceShock, ScenarioConfig, SpreadRegime, generate_bars,
+};
+use crate::backtest::{BacktestEngine, BacktestError, TradeExitReason};
+use crate::config::{BarInterval, Config};
+use crate::state::StrategyStatus;
+
+/// Calm bars ahead of each built-in event, enough to warm the default z-score and
+/// volatility windows.
+pub const STRESS_WARMUP_BARS: usize = 1000;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StressScenario {
+    pub name: String,
+    pub description: String,
+    /// Bar index where the stress starts.
+    pub event_bar: usize,
+    pub config: ScenarioConfig,
+}
+
+/// The built-in scenarios on `interval` bars, all sharing `seed`.
+///
+/// Event timings are fixed in hours and converted to bars, and per-bar volatility and drift
+/// are scaled from their 15m values, so every interval sees the same stress. The price
+/// scenarios run with flat zero funding so the funding filter cannot mask how the exits
+/// behave.
+pub fn default_stress_scenarios(seed: u64, interval: BarInterval) -> Vec<StressScenario> {
+    let per_15m = interval.seconds() as f64 / 900.0;
+    let hours = |value: f64| value * 4.0 / per_15m;
+    let bars = |value: f64| (hours(value).round() as usize).max(1);
+    let calm = SpreadRegime {
+        bars: STRESS_WARMUP_BARS,
+        half_life_bars: Some(hours(12.0)),
+        vol: 0.002 * per_15m.sqrt(),
+        drift: 0.0,
+    };
+    let event = STRESS_WARMUP_BARS;
+    let stress = bars(200.0);
+    let base = ScenarioConfig {
+        interval,
+        bars: event + stress,
+        quote_vol: ScenarioConfig::default().quote_vol * per_15m.sqrt(),
+        seed,
+        ..ScenarioConfig::default()
+    };
+    vec![
+        StressScenario {
+            name: "ETH_CRASH_30".to_string(),
+            description: "ETH slips 3% against BTC, then crashes 30% in an hour while BTC holds"
+                .to_string(),
+            event_bar: event,
+            config: ScenarioConfig {
+                regimes: vec![
+                    calm.clone(),
+                    SpreadRegime {
+                        bars: stress,
+                        half_life_bars: Some(hours(48.0)),
+                        ..calm.clone()
+                    },
+                ],
+                shocks: vec![
+                    PriceShock {
+                        start_bar: event,
+                        bars: bars(2.0),
+                        base_return: -0.03,
+                        quote_return: 0.0,
+                    },
+                    PriceShock {
+                        start_bar: event + bars(6.0),
+                        bars: bars(1.0),
+                        base_return: -0.30,
+                        quote_return: 0.0,
+                    },
+                ],
+                funding_base: Some(Decimal::ZERO),
+                funding_quote: Some(Decimal::ZERO),
+                ..base.clone()
+            },
+        },
+        StressScenario {
+            name: "DECOUPLING".to_string(),
+            description: "The spread stops mean reverting and drifts 0.16% an hour for 200 hours"
+                .to_string(),
+            event_bar: event,
+            config: ScenarioConfig {
+                regimes: vec![
+                    calm.clone(),
+                    SpreadRegime {
+                        bars: stress,
+                        half_life_bars: None,
+                        drift: -0.0004 * per_15m,
+                        ..calm.clone()
+                    },
+                ],
+                funding_base: Some(Decimal::ZERO),
+                funding_quote: Some(Decimal::ZERO),
+                ..base.clone()
+            },
+        },
+        StressScenario {
+            name: "FUNDING_FLIP".to_string(),
+            description: "ETH funding flips to +0.1%/h and BTC to -0.1%/h for 200 hours"
+                .to_string(),
+            event_bar: event,
+            config: ScenarioConfig {
+                regimes: vec![calm],
+                funding_spikes: vec![FundingSpike {
+                    start_bar: event,
+                    bars: stress,
+                    base_rate: Decimal::new(1, 3),
+                    quote_rate: Decimal::new(-1, 3),
+                }],
+                ..base
+            },
+        },
+    ]
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StressEventKind {
+    Entry,
+    Exit(TradeExitReason),
+    CooldownStart,
+    CooldownEnd,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StressEvent {
+    pub timestamp: DateTime<Utc>,
+    pub kind: StressEventKind,
+    pub zscore: Option<Decimal>,
+    /// Net PnL of the trade, on exits.
+    pub pnl: Option<Decimal>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StressReport {
+    pub scenario: String,
+    pub description: String,
+    pub event_time: DateTime<Utc>,
+    pub bars: usize,
+    pub trades: usize,
+    pub take_profits: usize,
+    pub stop_losses: usize,
+    pub time_stops: usize,
+    pub net_pnl: Decimal,
+    pub worst_trade_pnl: Option<Decimal>,
+    pub max_drawdown: Decimal,
+    pub max_holding_hours: Decimal,
+    /// Funding across closed trades; positive is paid.
+    pub funding_paid: Decimal,
+    pub max_abs_zscore: Option<Decimal>,
+    pub cooldown_periods: usize,
+    pub cooldown_hours: Decimal,
+    /// Bars per entry block reason from the event onwards.
+    pub entry_blocks: BTreeMap<String, usize>,
+    /// Bars where a leg would have been liquidated under `execution.leverage`.
+    pub liquidation_bars: usize,
+    /// Whether a position was still open on the last bar.
+    pub open_at_end: bool,
+    pub events: Vec<StressEvent>,
+}
+
+/// Generates each scenario's bars and runs the native engine over them with `config`.
+pub fn run_stress(
+    config: &Config,
+    scenarios: &[StressScenario],
+) -> Result<Vec<StressReport>, BacktestError> {
+    scenarios
+        .iter()
+        .map(|scenario| run_scenario(config, scenario))
+        .collect()
+}
+
+fn run_scenario(config: &Config, scenario: &StressScenario) -> Result<StressReport, BacktestError> {
+    let bars = generate_bars(&scenario.config)?;
+    let result = BacktestEngine::new(config.clone()).run(&bars)?;
+    let interval = scenario.config.interval;
+    let event_time = scenario.config.start + interval.duration() * scenario.event_bar as i32;
+
+    let exits = |reason: TradeExitReason| {
+        result
+            .trades
+            .iter()
+            .filter(|trade| trade.exit_reason == reason)
+            .count()
+    };
+    let mut events = Vec::new();
+    for trade in &result.trades {
+        events.push(StressEvent {
+            timestamp: trade.entry_time,
+            kind: StressEventKind::Entry,
+            zscore: trade.entry_zscore,
+            pnl: None,
+        });
+        events.push(StressEvent {
+            timestamp: trade.exit_time,
+            kind: StressEventKind::Exit(trade.exit_reason.clone()),
+            zscore: trade.exit_zscore,
+            pnl: Some(trade.pnl),
+        });
+    }
+
+    let mut cooldown_periods = 0;
+    let mut cooldown_bars = 0u32;
+    let mut entry_blocks = BTreeMap::new();
+    let mut previous = None;
+    for log in &result.bar_logs {
+        let cooling = log.state == StrategyStatus::Cooldown;
+        let was_cooling = previous == Some(StrategyStatus::Cooldown);
+        if cooling {
+            cooldown_bars += 1;
+        }
+        if cooling != was_cooling {
+            if cooling {
+                cooldown_periods += 1;
+            }
+            events.push(StressEvent {
+                timestamp: log.timestamp,
+                kind: if cooling {
+                    StressEventKind::CooldownStart
+                } else {
+                    StressEventKind::CooldownEnd
+                },
+                zscore: log.zscore,
+                pnl: None,
+            });
+        }
+        previous = Some(log.state);
+        if log.timestamp >= event_time
+            && let Some(reason) = log.entry_block_reason.as_ref()
+        {
+            *entry_blocks.entry(format!("{reason:?}")).or_insert(0) += 1;
+        }
+    }
+    // Stable sort keeps an exit ahead of the cooldown it starts.
+    events.sort_by_key(|event| event.timestamp);
+
+    Ok(StressReport {
+        scenario: scenario.name.clone(),
+        description: scenario.description.clone(),
+        event_time,
+        bars: bars.len(),
+        trades: result.trades.len(),
+        take_profits: exits(TradeExitReason::TakeProfit),
+        stop_losses: exits(TradeExitReason::StopLoss),
+        time_stops: exits(TradeExitReason::TimeStop),
+        net_pnl: result.trades.iter().map(|trade| trade.pnl).sum(),
+        worst_trade_pnl: result.trades.iter().map(|trade| trade.pnl).min(),
+        max_drawdown: result.metrics.max_drawdown,
+        max_holding_hours: result
+            .trades
+            .iter()
+            .map(|trade| trade.holding_hours)
+            .max()
+            .unwrap_or(Decimal::ZERO),
+        funding_paid: result.trades.iter().map(|trade| trade.funding).sum(),
+        max_abs_zscore: result
+            .bar_logs
+            .iter()
+            .filter_map(|log| log.zscore.map(|zscore| zscore.abs()))
+            .max(),
+        cooldown_periods,
+        cooldown_hours: Decimal::from(cooldown_bars) * Decimal::from(interval.seconds())
+            / Decimal::from(3600u32),
+        entry_blocks,
+        liquidation_bars: result.metrics.liquidation_bars,
+        open_at_end: result.bar_logs.last().is_some_and(|log| {
+            matches!(
+                log.state,
+                StrategyStatus::InPosition | StrategyStatus::PendingEntry
+            )
+        }),
+        events,
+    })
+}
+
+pub fn format_stress_text(reports: &[StressReport]) -> String {
+    let optional = |value: Option<Decimal>| {
+        value
+            .map(|value| value.round_dp(4).to_string())
+            .unwrap_or_else(|| "-".to_string())
+    };
+    let mut output = String::new();
+    for report in reports {
+        let _ = writeln!(
+            output,
+            "{} ({}): {}",
+            report.scenario,
+            report.event_time.to_rfc3339(),
+            report.description
+        );
+        let _ = writeln!(
+            output,
+            "  trades={} tp={} sl={} time_stop={} open_at_end={}",
+            report.trades,
+            report.take_profits,
+            report.stop_losses,
+            report.time_stops,
+            report.open_at_end
+        );
+        let _ = writeln!(
+            output,
+            "  net_pnl={} worst_trade={} max_drawdown={} max_hold_h={} funding_paid={} max_abs_z={}",
+            report.net_pnl.round_dp(2),
+            optional(report.worst_trade_pnl.map(|pnl| pnl.round_dp(2))),
+            report.max_drawdown.round_dp(4),
+            report.max_holding_hours.round_dp(2),
+            report.funding_paid.round_dp(2),
+            optional(report.max_abs_zscore)
+        );
+        let _ = writeln!(
+            output,
+            "  cooldowns={} cooldown_h={} liquidation_bars={}",
+            report.cooldown_periods, report.cooldown_hours, report.liquidation_bars
+        );
+        if !report.entry_blocks.is_empty() {
+            let blocks: Vec<String> = report
+                .entry_blocks
+                .iter()
+                .map(|(reason, bars)| format!("{reason}={bars}"))
+                .collect();
+            let _ = writeln!(output, "  entry_blocks {}", blocks.join(" "));
+        }
+        for event in report
+            .events
+            .iter()
+            .filter(|event| event.timestamp >= report.event_time)
+        {
+            let _ = writeln!(
+                output,
+                "  {} {:?} z={} pnl={}",
+                event.timestamp.to_rfc3339(),
+                event.kind,
+                optional(event.zscore),
+                optional(event.pnl.map(|pnl| pnl.round_dp(2)))
+            );
+        }
+    }
+    output
+}
@@ -0,0 +1,262 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::backtest::grid::{
+    GridSearchConfig, ParameterGrid, ParameterSet, SearchObjective, run_grid_search,
+};
+use crate::backtest::{
+    BacktestBar, BacktestEngine, BacktestError, EquityPoint, Metrics, Trade, compute_metrics,
+};
+use crate::config::Config;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WalkForwardConfig {
+    pub train_bars: usize,
+    pub test_bars: usize,
+    /// Bars between window starts; defaults to `test_bars` and must not be smaller, so test
+    /// windows never overlap.
+    pub step_bars: Option<usize>,
+    pub objective: SearchObjective,
+    /// Train-window trades a candidate needs before it can be selected.
+    pub min_trades: usize,
+    pub grid: ParameterGrid,
+    /// Worker threads for each train-window grid search.
+    pub threads: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WalkForwardWindow {
+    pub index: usize,
+    pub train_start: DateTime<Utc>,
+    pub train_end: DateTime<Utc>,
+    pub test_start: DateTime<Utc>,
+    pub test_end: DateTime<Utc>,
+    pub parameters: ParameterSet,
+    pub candidates: usize,
+    pub train_score: Decimal,
+    pub train_trades: usize,
+    pub test_trades: usize,
+    pub test_pnl: Decimal,
+    pub test_metrics: Metrics,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ParameterStability {
+    pub parameter: String,
+    pub values: Vec<String>,
+    /// Windows whose selection differs from the previous window.
+    pub changes: usize,
+    pub most_common: String,
+    pub most_common_share: Decimal,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WalkForwardReport {
+    pub objective: SearchObjective,
+    pub windows: Vec<WalkForwardWindow>,
+    pub oos_trades: Vec<Trade>,
+    pub oos_equity_curve: Vec<EquityPoint>,
+    pub oos_metrics: Metrics,
+    pub stability: Vec<ParameterStability>,
+}
+
+/// Rolls train/test windows over `bars`, selecting parameters in-sample and trading them
+/// out-of-sample.
+///
+/// Each test run is replayed over its train window first so indicators are warm, but only
+/// trades from the test window: it starts flat at the test window, sized from the stitched
+/// out-of-sample equity so far. Positions still open at the end of a test window are
+/// dropped. The out-of-sample equity curve stitches those trades onto the configured
+/// starting capital.
+pub fn run_walk_forward(
+    base: &Config,
+    bars: &[BacktestBar],
+    config: &WalkForwardConfig,
+) -> Result<WalkForwardReport, BacktestError> {
+    if config.train_bars == 0 || config.test_bars == 0 {
+        return Err(BacktestError::InvalidConfig(
+            "train_bars and test_bars must be > 0".to_string(),
+        ));
+    }
+    let step = config.step_bars.unwrap_or(config.test_bars);
+    if step < config.test_bars {
+        return Err(BacktestError::InvalidConfig(
+            "step_bars must be >= test_bars".to_string(),
+        ));
+    }
+    if bars.len() < config.train_bars + config.test_bars {
+        return Err(BacktestError::InvalidConfig(format!(
+            "walk-forward needs at least {} bars, got {}",
+            config.train_bars + config.test_bars,
+            bars.len()
+        )));
+    }
+    let search = GridSearchConfig {
+        grid: config.grid.clone(),
+        objective: config.objective,
+        min_trades: config.min_trades,
+        threads: config.threads,
+    };
+
+    let starting_equity = BacktestEngine::new(base.clone()).starting_equity()?;
+    let mut equity = starting_equity;
+    let mut windows = Vec::new();
+    let mut oos_trades = Vec::new();
+    let mut oos_equity_curve = Vec::new();
+    let mut start = 0;
+    while start + config.train_bars + config.test_bars <= bars.len() {
+        let train = &bars[start..start + config.train_bars];
+        let test_start_index = start + config.train_bars;
+        let test = &bars[test_start_index..test_start_index + config.test_bars];
+
+        let ranked = run_grid_search(base, train, &search)?;
+        let best = &ranked[0];
+        let parameters = &best.parameters;
+
+        let test_begin = test[0].timestamp;
+        let replay = &bars[start..test_start_index + config.test_bars];
+        let result = BacktestEngine::new(parameters.apply(base))
+            .with_trading_start(test_begin)
+            .with_starting_equity(equity)
+            .run(replay)?;
+        let mut window_trades: Vec<Trade> = result.trades;
+        window_trades.sort_by_key(|trade| trade.exit_time);
+
+        let window_start_equity = equity;
+        let mut window_curve = Vec::with_capacity(test.len());
+        let mut closed = window_trades.iter().peekable();
+        for bar in test {
+            while let Some(trade) = closed.next_if(|trade| trade.exit_time <= bar.timestamp) {
+                equity += trade.pnl;
+            }
+            window_curve.push(EquityPoint {
+                timestamp: bar.timestamp,
+                equity,
+            });
+        }
+        let mut window_metrics_curve = vec![EquityPoint {
+            timestamp: test_begin,
+            equity: window_start_equity,
+        }];
+        window_metrics_curve.extend(window_curve.iter().cloned());
+        let test_metrics = compute_metrics(&window_trades, &window_metrics_curve, Decimal::ZERO)?;
+
+        windows.push(WalkForwardWindow {
+            index: windows.len(),
+            train_start: train[0].timestamp,
+            train_end: train[train.len() - 1].timestamp,
+            test_start: test_begin,
+            test_end: test[test.len() - 1].timestamp,
+            parameters: parameters.clone(),
+            candidates: ranked.len(),
+            train_score: best.score,
+            train_trades: best.metrics.trade_count,
+            test_trades: window_trades.len(),
+            test_pnl: window_trades.iter().map(|trade| trade.pnl).sum(),
+            test_metrics,
+        });
+        oos_equity_curve.extend(window_curve);
+        oos_trades.extend(window_trades);
+        start += step;
+    }
+
+    let oos_metrics = compute_metrics(&oos_trades, &oos_equity_curve, Decimal::ZERO)?;
+    let stability = parameter_stability(&windows);
+    Ok(WalkForwardReport {
+        objective: config.objective,
+        windows,
+        oos_trades,
+        oos_equity_curve,
+        oos_metrics,
+        stability,
+    })
+}
+
+type ParameterLabel = fn(&ParameterSet) -> String;
+
+fn parameter_stability(windows: &[WalkForwardWindow]) -> Vec<ParameterStability> {
+    let columns: [(&str, ParameterLabel); 6] = [
+        ("entry_z", |params| params.entry_z.normalize().to_string()),
+        ("tp_z", |params| params.tp_z.normalize().to_string()),
+        ("sl_z", |params| params.sl_z.normalize().to_string()),
+        ("n_z", |params| params.n_z.to_string()),
+        ("cooldown_hours", |params| params.cooldown_hours.to_string()),
+        ("sigma_floor_mode", |params| {
+            format!("{:?}", params.sigma_floor_mode)
+        }),
+    ];
+    columns
+        .into_iter()
+        .map(|(parameter, value_of)| {
+            let values: Vec<String> = windows
+                .iter()
+                .map(|window| value_of(&window.parameters))
+                .collect();
+            let changes = values.windows(2).filter(|pair| pair[0] != pair[1]).count();
+            let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+            for value in &values {
+                *counts.entry(value.as_str()).or_default() += 1;
+            }
+            let (most_common, count) = counts
+                .iter()
+                .max_by(|left, right| left.1.cmp(right.1).then_with(|| right.0.cmp(left.0)))
+                .map(|(value, count)| (value.to_string(), *count))
+                .unwrap_or_default();
+            let most_common_share = if values.is_empty() {
+                Decimal::ZERO
+            } else {
+                Decimal::from(count as u64) / Decimal::from(values.len() as u64)
+            };
+            ParameterStability {
+                parameter: parameter.to_string(),
+                values,
+                changes,
+                most_common,
+                most_common_share,
+            }
+        })
+        .collect()
+}
+
+pub fn format_walk_forward_text(report: &WalkForwardReport) -> String {
+    let mut output = String::new();
+    output.push_str("walk-forward optimization\n");
+    output.push_str(&format!(
+        "objective={:?} windows={} oos_trades={} oos_sharpe={} oos_max_drawdown={}\n",
+        report.objective,
+        report.windows.len(),
+        report.oos_trades.len(),
+        report.oos_metrics.sharpe_ratio.round_dp(4),
+        report.oos_metrics.max_drawdown.round_dp(4),
+    ));
+    output.push_str(
+        "window test_start test_end train_score train_trades test_trades test_pnl parameters\n",
+    );
+    for window in &report.windows {
+        output.push_str(&format!(
+            "{} {} {} {} {} {} {} {}\n",
+            window.index,
+            window.test_start.to_rfc3339(),
+            window.test_end.to_rfc3339(),
+            window.train_score.round_dp(4),
+            window.train_trades,
+            window.test_trades,
+            window.test_pnl.round_dp(4),
+            window.parameters,
+        ));
+    }
+    output.push_str("parameter changes most_common share\n");
+    for row in &report.stability {
+        output.push_str(&format!(
+            "{} {} {} {}\n",
+            row.parameter,
+            row.changes,
+            row.most_common,
+            row.most_common_share.round_dp(4),
+        ));
+    }
+    output
+}
@@ -3,6 +3,7 @@ use std::path::PathBuf;
 use clap::{Args, Parser, Subcommand, ValueEnum};
 use rust_decimal::Decimal;
 
+use crate::backtest::grid::SearchObjective;
 use crate::config::Symbol;
 use crate::execution::OrderSide;
 
@@ -41,8 +42,13 @@ pub struct Cli {
 #[derive(Debug, Subcommand)]
 pub enum Command {
     Backtest(BacktestArgs),
+    BacktestDiff(BacktestDiffArgs),
+    WalkForward(WalkForwardArgs),
+    GridSearch(GridSearchArgs),
     Download(DownloadArgs),
     AnalyzeTrades(AnalyzeTradesArgs),
+    Parity(ParityArgs),
+    Stress(StressArgs),
     OrderTest(OrderTestArgs),
     MarketTest(MarketTestArgs),
     CancelOrder(CancelOrderArgs),
@@ -60,6 +66,146 @@ pub struct BacktestArgs {
     pub end: Option<String>,
     #[arg(long, value_name = "DIR")]
     pub output_dir: Option<PathBuf>,
+    #[arg(long)]
+    pub simulated_exchange: bool,
+    /// 1m bars (JSON) used for `execution_delay = "NEXT_MINUTE"` fills.
+    #[arg(long, value_name = "PATH")]
+    pub minute_bars: Option<PathBuf>,
+    /// Live JSON bar logs whose book fields drive `slippage_model = "BOOK_DEPTH"`.
+    #[arg(long, value_name = "PATH")]
+    pub book_logs: Option<PathBuf>,
+    /// Resample the trades into this many paths per method for confidence intervals.
+    #[arg(long, value_name = "COUNT")]
+    pub monte_carlo: Option<usize>,
+    #[arg(long, default_value_t = 5, value_name = "TRADES")]
+    pub monte_carlo_block_size: usize,
+    #[arg(long, default_value_t = 0, value_name = "SEED")]
+    pub monte_carlo_seed: u64,
+    /// Write a self-contained HTML report with charts and the trade table.
+    #[arg(long, value_name = "PATH")]
+    pub report: Option<PathBuf>,
+    /// Write the per-bar logs as JSON lines in the live stats-log format.
+    #[arg(long, value_name = "PATH")]
+    pub bar_log: Option<PathBuf>,
+    #[command(subcommand)]
+    pub action: Option<BacktestAction>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum BacktestAction {
+    /// Re-run a saved `--output-dir` from its manifest and check the outputs are identical.
+    Verify(BacktestVerifyArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct BacktestVerifyArgs {
+    #[arg(value_name = "DIR")]
+    pub dir: PathBuf,
+    /// Read the bars from here instead of the recorded file path.
+    #[arg(long, value_name = "PATH")]
+    pub bars: Option<PathBuf>,
+    /// Read the bars from here instead of the recorded price DB.
+    #[arg(long, value_name = "PATH")]
+    pub db: Option<PathBuf>,
+    #[arg(long, value_enum, default_value_t = AnalyzeOutputFormat::Text)]
+    pub format: AnalyzeOutputFormat,
+}
+
+/// Diffs two backtest runs trade by trade. Each side is a config run over the shared bars
+/// or a saved `backtest --output-dir`; the baseline defaults to `--config`.
+#[derive(Debug, Args)]
+pub struct BacktestDiffArgs {
+    #[arg(long, value_name = "PATH")]
+    pub bars: Option<PathBuf>,
+    #[arg(long, value_name = "PATH")]
+    pub db: Option<PathBuf>,
+    #[arg(long, value_name = "RFC3339")]
+    pub start: Option<String>,
+    #[arg(long, value_name = "RFC3339")]
+    pub end: Option<String>,
+    #[arg(long, value_name = "PATH", conflicts_with = "baseline_dir")]
+    pub baseline_config: Option<PathBuf>,
+    #[arg(long, value_name = "DIR")]
+    pub baseline_dir: Option<PathBuf>,
+    #[arg(long, value_name = "PATH", conflicts_with = "candidate_dir")]
+    pub candidate_config: Option<PathBuf>,
+    #[arg(long, value_name = "DIR")]
+    pub candidate_dir: Option<PathBuf>,
+    #[arg(long, value_enum, default_value_t = AnalyzeOutputFormat::Text)]
+    pub format: AnalyzeOutputFormat,
+}
+
+#[derive(Debug, Args)]
+pub struct WalkForwardArgs {
+    #[arg(long, value_name = "PATH")]
+    pub bars: Option<PathBuf>,
+    #[arg(long, value_name = "PATH")]
+    pub db: Option<PathBuf>,
+    #[arg(long, value_name = "RFC3339")]
+    pub start: Option<String>,
+    #[arg(long, value_name = "RFC3339")]
+    pub end: Option<String>,
+    #[arg(long, default_value_t = 2880, value_name = "BARS")]
+    pub train_bars: usize,
+    #[arg(long, default_value_t = 672, value_name = "BARS")]
+    pub test_bars: usize,
+    #[arg(long, value_name = "BARS")]
+    pub step_bars: Option<usize>,
+    #[arg(long, default_value = "SHARPE", value_name = "OBJECTIVE")]
+    pub objective: SearchObjective,
+    #[arg(long, default_value_t = 1, value_name = "COUNT")]
+    pub min_trades: usize,
+    #[command(flatten)]
+    pub grid: ParameterGridArgs,
+    #[arg(long, value_name = "COUNT")]
+    pub threads: Option<usize>,
+    #[arg(long, value_enum, default_value_t = AnalyzeOutputFormat::Text)]
+    pub format: AnalyzeOutputFormat,
+    #[arg(long, value_name = "DIR")]
+    pub output_dir: Option<PathBuf>,
+}
+
+#[derive(Debug, Args)]
+pub struct GridSearchArgs {
+    #[arg(long, value_name = "PATH")]
+    pub bars: Option<PathBuf>,
+    #[arg(long, value_name = "PATH")]
+    pub db: Option<PathBuf>,
+    #[arg(long, value_name = "RFC3339")]
+    pub start: Option<String>,
+    #[arg(long, value_name = "RFC3339")]
+    pub end: Option<String>,
+    #[arg(long, default_value = "SHARPE", value_name = "OBJECTIVE")]
+    pub objective: SearchObjective,
+    #[arg(long, default_value_t = 1, value_name = "COUNT")]
+    pub min_trades: usize,
+    #[command(flatten)]
+    pub grid: ParameterGridArgs,
+    #[arg(long, value_name = "COUNT")]
+    pub threads: Option<usize>,
+    #[arg(long, default_value_t = 20, value_name = "COUNT")]
+    pub top: usize,
+    #[arg(long, value_enum, default_value_t = AnalyzeOutputFormat::Text)]
+    pub format: AnalyzeOutputFormat,
+    #[arg(long, value_name = "DIR")]
+    pub output_dir: Option<PathBuf>,
+}
+
+/// Comma-separated candidate values; omitted flags keep the config value.
+#[derive(Debug, Args)]
+pub struct ParameterGridArgs {
+    #[arg(long, value_name = "CSV")]
+    pub entry_zs: Option<String>,
+    #[arg(long, value_name = "CSV")]
+    pub tp_zs: Option<String>,
+    #[arg(long, value_name = "CSV")]
+    pub sl_zs: Option<String>,
+    #[arg(long, value_name = "CSV")]
+    pub n_zs: Option<String>,
+    #[arg(long, value_name = "CSV")]
+    pub cooldown_hours: Option<String>,
+    #[arg(long, value_name = "CSV")]
+    pub sigma_floor_modes: Option<String>,
 }
 
 #[derive(Debug, Args)]
@@ -70,6 +216,9 @@ pub struct DownloadArgs {
     pub end: String,
     #[arg(long, value_name = "PATH")]
     pub output: PathBuf,
+    /// Skip the funding history download; funding columns stay empty.
+    #[arg(long)]
+    pub skip_funding: bool,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
@@ -116,6 +265,43 @@ pub struct AnalyzeTradesArgs {
     pub funding_carry_interval_hours: u32,
 }
 
+/// Replays the strategy over `price_bars` and diffs it against a live stats log.
+#[derive(Debug, Args)]
+pub struct ParityArgs {
+    #[arg(long, value_name = "PATH")]
+    pub stats_log: PathBuf,
+    /// Live trade log (JSON lines) whose entry and exit fills are matched to backtest decisions.
+    #[arg(long, value_name = "PATH")]
+    pub trade_log: Option<PathBuf>,
+    #[arg(long, value_name = "PATH")]
+    pub db: PathBuf,
+    /// Bars loaded before the first live bar to warm the indicators.
+    #[arg(long, default_value_t = 2880, value_name = "BARS")]
+    pub warmup_bars: u32,
+    #[arg(long, default_value = "0.01", value_name = "Z")]
+    pub z_tolerance: Decimal,
+    /// Relative per-leg notional difference, e.g. 0.01 for 1%.
+    #[arg(long, default_value = "0.01", value_name = "RATIO")]
+    pub sizing_tolerance: Decimal,
+    #[arg(long, value_enum, default_value_t = AnalyzeOutputFormat::Text)]
+    pub format: AnalyzeOutputFormat,
+}
+
+/// Runs the backtest over synthetic stress scenarios.
+#[derive(Debug, Args)]
+pub struct StressArgs {
+    /// Scenario names to run (default: all).
+    #[arg(long, value_delimiter = ',', value_name = "NAME")]
+    pub scenario: Vec<String>,
+    #[arg(long, default_value_t = 0, value_name = "SEED")]
+    pub seed: u64,
+    /// Write each scenario's generated bars to `<DIR>/<NAME>.json` for use with `--bars`.
+    #[arg(long, value_name = "DIR")]
+    pub bars_dir: Option<PathBuf>,
+    #[arg(long, value_enum, default_value_t = AnalyzeOutputFormat::Text)]
+    pub format: AnalyzeOutputFormat,
+}
+
 #[derive(Debug, Args)]
 pub struct OrderTestArgs {
     #[arg(long, value_name = "SYMBOL")]
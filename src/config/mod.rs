@@ -23,16 +23,21 @@ pub enum ConfigError {
     TomlParse(#[from] toml::de::Error),
 }
 
+// Pair legs; the exchange coins traded for each leg come from `[pair]`. The
+// `ETH_PERP`/`BTC_PERP` aliases keep configs and logs from the ETH/BTC-only
+// releases loading.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum Symbol {
-    EthPerp,
-    BtcPerp,
+    #[serde(alias = "ETH_PERP")]
+    Base,
+    #[serde(alias = "BTC_PERP")]
+    Quote,
 }
 
 impl Symbol {
     pub fn all() -> &'static [Symbol] {
-        const ALL: [Symbol; 2] = [Symbol::EthPerp, Symbol::BtcPerp];
+        const ALL: [Symbol; 2] = [Symbol::Base, Symbol::Quote];
         &ALL
     }
 }
@@ -42,8 +47,8 @@ impl FromStr for Symbol {
 
     fn from_str(value: &str) -> Result<Self, Self::Err> {
         match value.trim().to_uppercase().as_str() {
-            "ETH-PERP" | "ETH_PERP" => Ok(Symbol::EthPerp),
-            "BTC-PERP" | "BTC_PERP" => Ok(Symbol::BtcPerp),
+            "BASE" | "ETH-PERP" | "ETH_PERP" => Ok(Symbol::Base),
+            "QUOTE" | "BTC-PERP" | "BTC_PERP" => Ok(Symbol::Quote),
             _ => Err(ConfigError::InvalidValue {
                 field: "symbol",
                 message: format!("unsupported symbol: {value}"),
@@ -52,6 +57,48 @@ impl FromStr for Symbol {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PairConfig {
+    pub base_coin: String,
+    pub quote_coin: String,
+}
+
+impl Default for PairConfig {
+    fn default() -> Self {
+        Self {
+            base_coin: "ETH".to_string(),
+            quote_coin: "BTC".to_string(),
+        }
+    }
+}
+
+impl PairConfig {
+    pub fn coin(&self, symbol: Symbol) -> &str {
+        match symbol {
+            Symbol::Base => &self.base_coin,
+            Symbol::Quote => &self.quote_coin,
+        }
+    }
+
+    pub fn symbol_for_coin(&self, coin: &str) -> Option<Symbol> {
+        if coin == self.base_coin {
+            Some(Symbol::Base)
+        } else if coin == self.quote_coin {
+            Some(Symbol::Quote)
+        } else {
+            None
+        }
+    }
+
+    pub fn label(&self) -> String {
+        format!("{}/{}", self.base_coin, self.quote_coin)
+    }
+
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum PriceField {
@@ -77,6 +124,76 @@ impl FromStr for PriceField {
     }
 }
 
+/// Candle interval of the bar grid. Bars are keyed by their open time on the UTC grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum BarInterval {
+    #[serde(rename = "1m")]
+    M1,
+    #[serde(rename = "5m")]
+    M5,
+    #[default]
+    #[serde(rename = "15m")]
+    M15,
+    #[serde(rename = "30m")]
+    M30,
+    #[serde(rename = "1h")]
+    H1,
+    #[serde(rename = "4h")]
+    H4,
+}
+
+impl BarInterval {
+    /// The Hyperliquid candle interval name.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BarInterval::M1 => "1m",
+            BarInterval::M5 => "5m",
+            BarInterval::M15 => "15m",
+            BarInterval::M30 => "30m",
+            BarInterval::H1 => "1h",
+            BarInterval::H4 => "4h",
+        }
+    }
+
+    pub fn seconds(&self) -> i64 {
+        match self {
+            BarInterval::M1 => 60,
+            BarInterval::M5 => 300,
+            BarInterval::M15 => 900,
+            BarInterval::M30 => 1800,
+            BarInterval::H1 => 3600,
+            BarInterval::H4 => 14_400,
+        }
+    }
+
+    pub fn duration(&self) -> chrono::Duration {
+        chrono::Duration::seconds(self.seconds())
+    }
+
+    pub fn bars_per_day(&self) -> usize {
+        (86_400 / self.seconds()) as usize
+    }
+}
+
+impl FromStr for BarInterval {
+    type Err = ConfigError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_lowercase().as_str() {
+            "1m" => Ok(BarInterval::M1),
+            "5m" => Ok(BarInterval::M5),
+            "15m" => Ok(BarInterval::M15),
+            "30m" => Ok(BarInterval::M30),
+            "1h" => Ok(BarInterval::H1),
+            "4h" => Ok(BarInterval::H4),
+            _ => Err(ConfigError::InvalidValue {
+                field: "data.bar_interval",
+                message: format!("unsupported bar interval: {value}"),
+            }),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum SigmaFloorMode {
@@ -102,6 +219,116 @@ impl FromStr for SigmaFloorMode {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SpreadModel {
+    #[default]
+    Fixed,
+    RollingBeta,
+    Kalman,
+}
+
+impl FromStr for SpreadModel {
+    type Err = ConfigError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_uppercase().as_str() {
+            "FIXED" => Ok(SpreadModel::Fixed),
+            "ROLLING_BETA" => Ok(SpreadModel::RollingBeta),
+            "KALMAN" => Ok(SpreadModel::Kalman),
+            _ => Err(ConfigError::InvalidValue {
+                field: "spread.model",
+                message: format!("unsupported spread model: {value}"),
+            }),
+        }
+    }
+}
+
+/// When backtest orders fill relative to the close of the bar that generated them.
+///
+/// Only the fill price moves: order quantities are still sized from the signal close, so a
+/// delayed entry's `avg_price` can differ from its notional over quantity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ExecutionDelay {
+    /// Fill at the signal bar's close.
+    #[default]
+    None,
+    /// Fill at the following bar's open, or its close when the bar has no open.
+    NextBar,
+    /// Fill at the close of the first 1m bar after the signal.
+    NextMinute,
+    /// Fill `execution_delay_secs` after the close, interpolating between bar closes.
+    FixedOffset,
+}
+
+impl FromStr for ExecutionDelay {
+    type Err = ConfigError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_uppercase().as_str() {
+            "NONE" => Ok(ExecutionDelay::None),
+            "NEXT_BAR" => Ok(ExecutionDelay::NextBar),
+            "NEXT_MINUTE" => Ok(ExecutionDelay::NextMinute),
+            "FIXED_OFFSET" => Ok(ExecutionDelay::FixedOffset),
+            _ => Err(ConfigError::InvalidValue {
+                field: "backtest.execution_delay",
+                message: format!("unsupported execution delay: {value}"),
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SlippageModel {
+    /// Flat `slippage_bps` on the trade notional.
+    #[default]
+    Flat,
+    /// Half-spread plus square-root impact from recorded top-of-book data, per fill.
+    BookDepth,
+}
+
+impl FromStr for SlippageModel {
+    type Err = ConfigError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_uppercase().as_str() {
+            "FLAT" => Ok(SlippageModel::Flat),
+            "BOOK_DEPTH" => Ok(SlippageModel::BookDepth),
+            _ => Err(ConfigError::InvalidValue {
+                field: "backtest.slippage_model",
+                message: format!("unsupported slippage model: {value}"),
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MarginPolicy {
+    /// Skip entries whose initial margin exceeds the available margin.
+    #[default]
+    Reject,
+    /// Scale entries down to the available margin.
+    Shrink,
+}
+
+impl FromStr for MarginPolicy {
+    type Err = ConfigError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_uppercase().as_str() {
+            "REJECT" => Ok(MarginPolicy::Reject),
+            "SHRINK" => Ok(MarginPolicy::Shrink),
+            _ => Err(ConfigError::InvalidValue {
+                field: "backtest.margin_policy",
+                message: format!("unsupported margin policy: {value}"),
+            }),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum CapitalMode {
@@ -291,8 +518,10 @@ impl Default for StaleCrossConfig {
 pub struct PersistentExtremeConfig {
     pub enabled: bool,
     pub min_abs_z: Decimal,
-    pub allow_long_eth_short_btc: bool,
-    pub allow_short_eth_long_btc: bool,
+    #[serde(alias = "allow_long_eth_short_btc")]
+    pub allow_long_base_short_quote: bool,
+    #[serde(alias = "allow_short_eth_long_btc")]
+    pub allow_short_base_long_quote: bool,
 }
 
 impl Default for PersistentExtremeConfig {
@@ -300,8 +529,8 @@ impl Default for PersistentExtremeConfig {
         Self {
             enabled: false,
             min_abs_z: Decimal::new(15, 1),
-            allow_long_eth_short_btc: false,
-            allow_short_eth_long_btc: false,
+            allow_long_base_short_quote: false,
+            allow_short_base_long_quote: false,
         }
     }
 }
@@ -336,6 +565,7 @@ pub struct PositionConfig {
     pub max_notional: Option<Decimal>,
     pub n_vol: usize,
     pub max_position_groups: u32,
+    pub scale_in_step_z: Decimal,
     pub min_size_policy: MinSizePolicy,
 }
 
@@ -349,6 +579,7 @@ impl Default for PositionConfig {
             max_notional: None,
             n_vol: 672,
             max_position_groups: 1,
+            scale_in_step_z: Decimal::new(5, 1),
             min_size_policy: MinSizePolicy::Skip,
         }
     }
@@ -356,15 +587,17 @@ impl Default for PositionConfig {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DirectionalSizingConfig {
-    pub long_eth_short_btc_multiplier: Decimal,
-    pub short_eth_long_btc_multiplier: Decimal,
+    #[serde(alias = "long_eth_short_btc_multiplier")]
+    pub long_base_short_quote_multiplier: Decimal,
+    #[serde(alias = "short_eth_long_btc_multiplier")]
+    pub short_base_long_quote_multiplier: Decimal,
 }
 
 impl Default for DirectionalSizingConfig {
     fn default() -> Self {
         Self {
-            long_eth_short_btc_multiplier: Decimal::ONE,
-            short_eth_long_btc_multiplier: Decimal::ONE,
+            long_base_short_quote_multiplier: Decimal::ONE,
+            short_base_long_quote_multiplier: Decimal::ONE,
         }
     }
 }
@@ -399,8 +632,10 @@ pub struct CostGateConfig {
     pub exit_fee_bps: Decimal,
     pub slippage_bps: Decimal,
     pub spread_bps: Decimal,
-    pub long_eth_short_btc_extra_bps: Decimal,
-    pub short_eth_long_btc_extra_bps: Decimal,
+    #[serde(alias = "long_eth_short_btc_extra_bps")]
+    pub long_base_short_quote_extra_bps: Decimal,
+    #[serde(alias = "short_eth_long_btc_extra_bps")]
+    pub short_base_long_quote_extra_bps: Decimal,
 }
 
 impl Default for CostGateConfig {
@@ -413,8 +648,30 @@ impl Default for CostGateConfig {
             exit_fee_bps: Decimal::ZERO,
             slippage_bps: Decimal::ZERO,
             spread_bps: Decimal::ZERO,
-            long_eth_short_btc_extra_bps: Decimal::ZERO,
-            short_eth_long_btc_extra_bps: Decimal::ZERO,
+            long_base_short_quote_extra_bps: Decimal::ZERO,
+            short_base_long_quote_extra_bps: Decimal::ZERO,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpreadConfig {
+    pub model: SpreadModel,
+    pub beta_lookback_bars: usize,
+    pub kalman_delta: Decimal,
+    pub kalman_observation_var: Decimal,
+    /// One day of bars unless set; follows `data.bar_interval`.
+    pub kalman_warmup_bars: usize,
+}
+
+impl Default for SpreadConfig {
+    fn default() -> Self {
+        Self {
+            model: SpreadModel::Fixed,
+            beta_lookback_bars: 672,
+            kalman_delta: Decimal::new(1, 7),
+            kalman_observation_var: Decimal::new(1, 5),
+            kalman_warmup_bars: BarInterval::M15.bars_per_day(),
         }
     }
 }
@@ -456,12 +713,16 @@ impl Default for RiskConfig {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DataConfig {
     pub price_field: PriceField,
+    /// Bar grid shared by data fetch, storage, indicators and the runtime schedule.
+    #[serde(default)]
+    pub bar_interval: BarInterval,
 }
 
 impl Default for DataConfig {
     fn default() -> Self {
         Self {
             price_field: PriceField::Mid,
+            bar_interval: BarInterval::M15,
         }
     }
 }
@@ -557,6 +818,20 @@ pub struct BacktestConfig {
     pub include_slippage: bool,
     pub slippage_bps: u32,
     pub include_funding: bool,
+    /// Hours between funding settlements, aligned to 00:00 UTC.
+    pub funding_interval_hours: u32,
+    /// Evaluate SL/TP against a worst-case intrabar spread path when bars carry OHLC.
+    pub intrabar_exits: bool,
+    pub execution_delay: ExecutionDelay,
+    pub execution_delay_secs: u32,
+    pub slippage_model: SlippageModel,
+    /// Impact in bps for an order equal to the displayed top-of-book depth.
+    pub impact_bps: Decimal,
+    /// What to do with entries that need more margin than is available under
+    /// `execution.leverage`.
+    pub margin_policy: MarginPolicy,
+    /// Maintenance margin as a fraction of a leg's notional.
+    pub maintenance_margin_rate: Decimal,
 }
 
 impl Default for BacktestConfig {
@@ -567,10 +842,41 @@ impl Default for BacktestConfig {
             include_slippage: true,
             slippage_bps: 5,
             include_funding: true,
+            funding_interval_hours: 1,
+            intrabar_exits: false,
+            execution_delay: ExecutionDelay::None,
+            execution_delay_secs: 0,
+            slippage_model: SlippageModel::Flat,
+            impact_bps: Decimal::new(10, 0),
+            margin_policy: MarginPolicy::Reject,
+            maintenance_margin_rate: Decimal::new(2, 2),
         }
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PortfolioStrategyConfig {
+    pub name: String,
+    pub config_path: String,
+    pub allocation: Decimal,
+    #[serde(default)]
+    pub state_path: Option<String>,
+}
+
+/// Shared capital and risk budget for running several strategy configs in one process.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PortfolioConfig {
+    pub equity_budget: Option<Decimal>,
+    pub max_gross_notional: Option<Decimal>,
+    pub strategies: Vec<PortfolioStrategyConfig>,
+}
+
+impl PortfolioConfig {
+    pub fn is_enabled(&self) -> bool {
+        !self.strategies.is_empty()
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InstrumentConstraints {
     pub min_qty: Decimal,
@@ -598,10 +904,12 @@ impl Default for InstrumentConstraints {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Config {
+    pub pair: PairConfig,
     pub strategy: StrategyConfig,
     pub stale_cross: StaleCrossConfig,
     pub persistent_extreme: PersistentExtremeConfig,
     pub sigma_floor: SigmaFloorConfig,
+    pub spread: SpreadConfig,
     pub position: PositionConfig,
     pub directional_sizing: DirectionalSizingConfig,
     pub funding: FundingConfig,
@@ -615,20 +923,23 @@ pub struct Config {
     pub logging: LoggingConfig,
     pub alerts: AlertsConfig,
     pub backtest: BacktestConfig,
+    pub portfolio: PortfolioConfig,
     pub instrument_constraints: HashMap<Symbol, InstrumentConstraints>,
 }
 
 impl Default for Config {
     fn default() -> Self {
         let mut instrument_constraints = HashMap::new();
-        instrument_constraints.insert(Symbol::EthPerp, InstrumentConstraints::default());
-        instrument_constraints.insert(Symbol::BtcPerp, InstrumentConstraints::default());
+        instrument_constraints.insert(Symbol::Base, InstrumentConstraints::default());
+        instrument_constraints.insert(Symbol::Quote, InstrumentConstraints::default());
 
         Self {
+            pair: PairConfig::default(),
             strategy: StrategyConfig::default(),
             stale_cross: StaleCrossConfig::default(),
             persistent_extreme: PersistentExtremeConfig::default(),
             sigma_floor: SigmaFloorConfig::default(),
+            spread: SpreadConfig::default(),
             position: PositionConfig::default(),
             directional_sizing: DirectionalSizingConfig::default(),
             funding: FundingConfig::default(),
@@ -642,6 +953,7 @@ impl Default for Config {
             logging: LoggingConfig::default(),
             alerts: AlertsConfig::default(),
             backtest: BacktestConfig::default(),
+            portfolio: PortfolioConfig::default(),
             instrument_constraints,
         }
     }
@@ -649,6 +961,24 @@ impl Default for Config {
 
 impl Config {
     pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.pair.base_coin.trim().is_empty() {
+            return Err(ConfigError::InvalidValue {
+                field: "pair.base_coin",
+                message: "must be non-empty".to_string(),
+            });
+        }
+        if self.pair.quote_coin.trim().is_empty() {
+            return Err(ConfigError::InvalidValue {
+                field: "pair.quote_coin",
+                message: "must be non-empty".to_string(),
+            });
+        }
+        if self.pair.base_coin == self.pair.quote_coin {
+            return Err(ConfigError::InvalidValue {
+                field: "pair.quote_coin",
+                message: "must differ from pair.base_coin".to_string(),
+            });
+        }
         if self.strategy.n_z == 0 {
             return Err(ConfigError::InvalidValue {
                 field: "strategy.n_z",
@@ -687,8 +1017,8 @@ impl Config {
                         .to_string(),
                 });
             }
-            if !self.persistent_extreme.allow_long_eth_short_btc
-                && !self.persistent_extreme.allow_short_eth_long_btc
+            if !self.persistent_extreme.allow_long_base_short_quote
+                && !self.persistent_extreme.allow_short_base_long_quote
             {
                 return Err(ConfigError::InvalidValue {
                     field: "persistent_extreme",
@@ -759,15 +1089,32 @@ impl Config {
                 message: "must be >= 1".to_string(),
             });
         }
-        if self.directional_sizing.long_eth_short_btc_multiplier <= Decimal::ZERO {
+        if self.position.max_position_groups > 1 {
+            if self.position.scale_in_step_z <= Decimal::ZERO {
+                return Err(ConfigError::InvalidValue {
+                    field: "position.scale_in_step_z",
+                    message: "must be > 0 when max_position_groups > 1".to_string(),
+                });
+            }
+            let last_level = self.strategy.entry_z
+                + self.position.scale_in_step_z
+                    * Decimal::from(self.position.max_position_groups - 1);
+            if last_level >= self.strategy.sl_z {
+                return Err(ConfigError::InvalidValue {
+                    field: "position.scale_in_step_z",
+                    message: "last scale-in level must be < strategy.sl_z".to_string(),
+                });
+            }
+        }
+        if self.directional_sizing.long_base_short_quote_multiplier <= Decimal::ZERO {
             return Err(ConfigError::InvalidValue {
-                field: "directional_sizing.long_eth_short_btc_multiplier",
+                field: "directional_sizing.long_base_short_quote_multiplier",
                 message: "must be > 0".to_string(),
             });
         }
-        if self.directional_sizing.short_eth_long_btc_multiplier <= Decimal::ZERO {
+        if self.directional_sizing.short_base_long_quote_multiplier <= Decimal::ZERO {
             return Err(ConfigError::InvalidValue {
-                field: "directional_sizing.short_eth_long_btc_multiplier",
+                field: "directional_sizing.short_base_long_quote_multiplier",
                 message: "must be > 0".to_string(),
             });
         }
@@ -788,13 +1135,34 @@ impl Config {
         validate_non_negative_bps("cost_gate.slippage_bps", self.cost_gate.slippage_bps)?;
         validate_non_negative_bps("cost_gate.spread_bps", self.cost_gate.spread_bps)?;
         validate_non_negative_bps(
-            "cost_gate.long_eth_short_btc_extra_bps",
-            self.cost_gate.long_eth_short_btc_extra_bps,
+            "cost_gate.long_base_short_quote_extra_bps",
+            self.cost_gate.long_base_short_quote_extra_bps,
         )?;
         validate_non_negative_bps(
-            "cost_gate.short_eth_long_btc_extra_bps",
-            self.cost_gate.short_eth_long_btc_extra_bps,
+            "cost_gate.short_base_long_quote_extra_bps",
+            self.cost_gate.short_base_long_quote_extra_bps,
         )?;
+        if self.spread.model == SpreadModel::RollingBeta && self.spread.beta_lookback_bars < 3 {
+            return Err(ConfigError::InvalidValue {
+                field: "spread.beta_lookback_bars",
+                message: "must be >= 3 when spread.model = ROLLING_BETA".to_string(),
+            });
+        }
+        if self.spread.model == SpreadModel::Kalman {
+            if self.spread.kalman_delta <= Decimal::ZERO || self.spread.kalman_delta >= Decimal::ONE
+            {
+                return Err(ConfigError::InvalidValue {
+                    field: "spread.kalman_delta",
+                    message: "must be in (0,1)".to_string(),
+                });
+            }
+            if self.spread.kalman_observation_var <= Decimal::ZERO {
+                return Err(ConfigError::InvalidValue {
+                    field: "spread.kalman_observation_var",
+                    message: "must be > 0".to_string(),
+                });
+            }
+        }
         if self.regime_gate.enabled && self.regime_gate.lookback_bars < 3 {
             return Err(ConfigError::InvalidValue {
                 field: "regime_gate.lookback_bars",
@@ -830,6 +1198,15 @@ impl Config {
                 message: "must be > 0".to_string(),
             });
         }
+        if self.runtime.interval_secs > self.data.bar_interval.seconds() as u64 {
+            return Err(ConfigError::InvalidValue {
+                field: "runtime.interval_secs",
+                message: format!(
+                    "must not exceed the {} bar interval",
+                    self.data.bar_interval.as_str()
+                ),
+            });
+        }
         if let Some(path) = &self.runtime.state_path
             && path.trim().is_empty()
         {
@@ -872,6 +1249,82 @@ impl Config {
                 message: "must be non-empty when provided".to_string(),
             });
         }
+        if self.backtest.execution_delay == ExecutionDelay::FixedOffset
+            && self.backtest.execution_delay_secs == 0
+        {
+            return Err(ConfigError::InvalidValue {
+                field: "backtest.execution_delay_secs",
+                message: "must be > 0 when backtest.execution_delay = FIXED_OFFSET".to_string(),
+            });
+        }
+        validate_non_negative_bps("backtest.impact_bps", self.backtest.impact_bps)?;
+        if self.backtest.maintenance_margin_rate < Decimal::ZERO
+            || self.backtest.maintenance_margin_rate >= Decimal::ONE
+        {
+            return Err(ConfigError::InvalidValue {
+                field: "backtest.maintenance_margin_rate",
+                message: "must be >= 0 and < 1".to_string(),
+            });
+        }
+        if self.backtest.funding_interval_hours == 0 {
+            return Err(ConfigError::InvalidValue {
+                field: "backtest.funding_interval_hours",
+                message: "must be > 0".to_string(),
+            });
+        }
+        if let Some(value) = self.portfolio.equity_budget
+            && value <= Decimal::ZERO
+        {
+            return Err(ConfigError::InvalidValue {
+                field: "portfolio.equity_budget",
+                message: "must be > 0".to_string(),
+            });
+        }
+        if let Some(value) = self.portfolio.max_gross_notional
+            && value <= Decimal::ZERO
+        {
+            return Err(ConfigError::InvalidValue {
+                field: "portfolio.max_gross_notional",
+                message: "must be > 0".to_string(),
+            });
+        }
+        let mut total_allocation = Decimal::ZERO;
+        for (index, strategy) in self.portfolio.strategies.iter().enumerate() {
+            if strategy.name.trim().is_empty() {
+                return Err(ConfigError::InvalidValue {
+                    field: "portfolio.strategies.name",
+                    message: "must be non-empty".to_string(),
+                });
+            }
+            if self.portfolio.strategies[..index]
+                .iter()
+                .any(|other| other.name == strategy.name)
+            {
+                return Err(ConfigError::InvalidValue {
+                    field: "portfolio.strategies.name",
+                    message: format!("duplicate strategy name {}", strategy.name),
+                });
+            }
+            if strategy.config_path.trim().is_empty() {
+                return Err(ConfigError::InvalidValue {
+                    field: "portfolio.strategies.config_path",
+                    message: format!("{} must be non-empty", strategy.name),
+                });
+            }
+            if strategy.allocation <= Decimal::ZERO {
+                return Err(ConfigError::InvalidValue {
+                    field: "portfolio.strategies.allocation",
+                    message: format!("{} must be > 0", strategy.name),
+                });
+            }
+            total_allocation += strategy.allocation;
+        }
+        if total_allocation > Decimal::ONE {
+            return Err(ConfigError::InvalidValue {
+                field: "portfolio.strategies.allocation",
+                message: "allocations must sum to <= 1".to_string(),
+            });
+        }
         for symbol in Symbol::all() {
             if !self.instrument_constraints.contains_key(symbol) {
                 return Err(ConfigError::MissingValue {
@@ -911,6 +1364,12 @@ impl Config {
     }
 
     fn apply_overrides(&mut self, overrides: ConfigOverrides) {
+        if let Some(value) = overrides.pair.base_coin {
+            self.pair.base_coin = value;
+        }
+        if let Some(value) = overrides.pair.quote_coin {
+            self.pair.quote_coin = value;
+        }
         if let Some(value) = overrides.strategy.n_z {
             self.strategy.n_z = value;
         }
@@ -938,11 +1397,11 @@ impl Config {
         if let Some(value) = overrides.persistent_extreme.min_abs_z {
             self.persistent_extreme.min_abs_z = value;
         }
-        if let Some(value) = overrides.persistent_extreme.allow_long_eth_short_btc {
-            self.persistent_extreme.allow_long_eth_short_btc = value;
+        if let Some(value) = overrides.persistent_extreme.allow_long_base_short_quote {
+            self.persistent_extreme.allow_long_base_short_quote = value;
         }
-        if let Some(value) = overrides.persistent_extreme.allow_short_eth_long_btc {
-            self.persistent_extreme.allow_short_eth_long_btc = value;
+        if let Some(value) = overrides.persistent_extreme.allow_short_base_long_quote {
+            self.persistent_extreme.allow_short_base_long_quote = value;
         }
         if let Some(value) = overrides.sigma_floor.mode {
             self.sigma_floor.mode = value;
@@ -980,14 +1439,23 @@ impl Config {
         if let Some(value) = overrides.position.max_position_groups {
             self.position.max_position_groups = value;
         }
+        if let Some(value) = overrides.position.scale_in_step_z {
+            self.position.scale_in_step_z = value;
+        }
         if let Some(value) = overrides.position.min_size_policy {
             self.position.min_size_policy = value;
         }
-        if let Some(value) = overrides.directional_sizing.long_eth_short_btc_multiplier {
-            self.directional_sizing.long_eth_short_btc_multiplier = value;
+        if let Some(value) = overrides
+            .directional_sizing
+            .long_base_short_quote_multiplier
+        {
+            self.directional_sizing.long_base_short_quote_multiplier = value;
         }
-        if let Some(value) = overrides.directional_sizing.short_eth_long_btc_multiplier {
-            self.directional_sizing.short_eth_long_btc_multiplier = value;
+        if let Some(value) = overrides
+            .directional_sizing
+            .short_base_long_quote_multiplier
+        {
+            self.directional_sizing.short_base_long_quote_multiplier = value;
         }
         if let Some(value) = overrides.funding.modes {
             self.funding.modes = value;
@@ -1025,11 +1493,26 @@ impl Config {
         if let Some(value) = overrides.cost_gate.spread_bps {
             self.cost_gate.spread_bps = value;
         }
-        if let Some(value) = overrides.cost_gate.long_eth_short_btc_extra_bps {
-            self.cost_gate.long_eth_short_btc_extra_bps = value;
+        if let Some(value) = overrides.cost_gate.long_base_short_quote_extra_bps {
+            self.cost_gate.long_base_short_quote_extra_bps = value;
+        }
+        if let Some(value) = overrides.cost_gate.short_base_long_quote_extra_bps {
+            self.cost_gate.short_base_long_quote_extra_bps = value;
+        }
+        if let Some(value) = overrides.spread.model {
+            self.spread.model = value;
+        }
+        if let Some(value) = overrides.spread.beta_lookback_bars {
+            self.spread.beta_lookback_bars = value;
         }
-        if let Some(value) = overrides.cost_gate.short_eth_long_btc_extra_bps {
-            self.cost_gate.short_eth_long_btc_extra_bps = value;
+        if let Some(value) = overrides.spread.kalman_delta {
+            self.spread.kalman_delta = value;
+        }
+        if let Some(value) = overrides.spread.kalman_observation_var {
+            self.spread.kalman_observation_var = value;
+        }
+        if let Some(value) = overrides.spread.kalman_warmup_bars {
+            self.spread.kalman_warmup_bars = value;
         }
         if let Some(value) = overrides.regime_gate.enabled {
             self.regime_gate.enabled = value;
@@ -1052,6 +1535,16 @@ impl Config {
         if let Some(value) = overrides.data.price_field {
             self.data.price_field = value;
         }
+        if let Some(value) = overrides.data.bar_interval {
+            self.data.bar_interval = value;
+            // The loop follows the bar grid unless it is scheduled explicitly.
+            if overrides.runtime.interval_secs.is_none() {
+                self.runtime.interval_secs = value.seconds() as u64;
+            }
+            if overrides.spread.kalman_warmup_bars.is_none() {
+                self.spread.kalman_warmup_bars = value.bars_per_day();
+            }
+        }
         if let Some(value) = overrides.execution.order_type {
             self.execution.order_type = value;
         }
@@ -1139,6 +1632,39 @@ impl Config {
         if let Some(value) = overrides.backtest.include_funding {
             self.backtest.include_funding = value;
         }
+        if let Some(value) = overrides.backtest.funding_interval_hours {
+            self.backtest.funding_interval_hours = value;
+        }
+        if let Some(value) = overrides.backtest.intrabar_exits {
+            self.backtest.intrabar_exits = value;
+        }
+        if let Some(value) = overrides.backtest.execution_delay {
+            self.backtest.execution_delay = value;
+        }
+        if let Some(value) = overrides.backtest.execution_delay_secs {
+            self.backtest.execution_delay_secs = value;
+        }
+        if let Some(value) = overrides.backtest.slippage_model {
+            self.backtest.slippage_model = value;
+        }
+        if let Some(value) = overrides.backtest.impact_bps {
+            self.backtest.impact_bps = value;
+        }
+        if let Some(value) = overrides.backtest.margin_policy {
+            self.backtest.margin_policy = value;
+        }
+        if let Some(value) = overrides.backtest.maintenance_margin_rate {
+            self.backtest.maintenance_margin_rate = value;
+        }
+        if let Some(value) = overrides.portfolio.equity_budget {
+            self.portfolio.equity_budget = Some(value);
+        }
+        if let Some(value) = overrides.portfolio.max_gross_notional {
+            self.portfolio.max_gross_notional = Some(value);
+        }
+        if let Some(value) = overrides.portfolio.strategies {
+            self.portfolio.strategies = value;
+        }
         if let Some(overrides) = overrides.instrument_constraints {
             for (symbol, override_value) in overrides {
                 let entry = self.instrument_constraints.entry(symbol).or_default();
@@ -1180,6 +1706,8 @@ fn validate_non_negative_bps(field: &'static str, value: Decimal) -> Result<(),
 
 #[derive(Debug, Default, Deserialize)]
 pub struct ConfigOverrides {
+    #[serde(default)]
+    pub pair: PairOverrides,
     #[serde(default)]
     pub strategy: StrategyOverrides,
     #[serde(default)]
@@ -1189,6 +1717,8 @@ pub struct ConfigOverrides {
     #[serde(default)]
     pub sigma_floor: SigmaFloorOverrides,
     #[serde(default)]
+    pub spread: SpreadOverrides,
+    #[serde(default)]
     pub position: PositionOverrides,
     #[serde(default)]
     pub directional_sizing: DirectionalSizingOverrides,
@@ -1214,9 +1744,17 @@ pub struct ConfigOverrides {
     pub alerts: AlertsOverrides,
     #[serde(default)]
     pub backtest: BacktestOverrides,
+    #[serde(default)]
+    pub portfolio: PortfolioOverrides,
     pub instrument_constraints: Option<HashMap<Symbol, InstrumentConstraintsOverrides>>,
 }
 
+#[derive(Debug, Default, Deserialize)]
+pub struct PairOverrides {
+    pub base_coin: Option<String>,
+    pub quote_coin: Option<String>,
+}
+
 #[derive(Debug, Default, Deserialize)]
 pub struct StrategyOverrides {
     pub n_z: Option<usize>,
@@ -1236,8 +1774,10 @@ pub struct StaleCrossOverrides {
 pub struct PersistentExtremeOverrides {
     pub enabled: Option<bool>,
     pub min_abs_z: Option<Decimal>,
-    pub allow_long_eth_short_btc: Option<bool>,
-    pub allow_short_eth_long_btc: Option<bool>,
+    #[serde(alias = "allow_long_eth_short_btc")]
+    pub allow_long_base_short_quote: Option<bool>,
+    #[serde(alias = "allow_short_eth_long_btc")]
+    pub allow_short_base_long_quote: Option<bool>,
 }
 
 #[derive(Debug, Default, Deserialize)]
@@ -1258,13 +1798,16 @@ pub struct PositionOverrides {
     pub max_notional: Option<Decimal>,
     pub n_vol: Option<usize>,
     pub max_position_groups: Option<u32>,
+    pub scale_in_step_z: Option<Decimal>,
     pub min_size_policy: Option<MinSizePolicy>,
 }
 
 #[derive(Debug, Default, Deserialize)]
 pub struct DirectionalSizingOverrides {
-    pub long_eth_short_btc_multiplier: Option<Decimal>,
-    pub short_eth_long_btc_multiplier: Option<Decimal>,
+    #[serde(alias = "long_eth_short_btc_multiplier")]
+    pub long_base_short_quote_multiplier: Option<Decimal>,
+    #[serde(alias = "short_eth_long_btc_multiplier")]
+    pub short_base_long_quote_multiplier: Option<Decimal>,
 }
 
 #[derive(Debug, Default, Deserialize)]
@@ -1285,8 +1828,19 @@ pub struct CostGateOverrides {
     pub exit_fee_bps: Option<Decimal>,
     pub slippage_bps: Option<Decimal>,
     pub spread_bps: Option<Decimal>,
-    pub long_eth_short_btc_extra_bps: Option<Decimal>,
-    pub short_eth_long_btc_extra_bps: Option<Decimal>,
+    #[serde(alias = "long_eth_short_btc_extra_bps")]
+    pub long_base_short_quote_extra_bps: Option<Decimal>,
+    #[serde(alias = "short_eth_long_btc_extra_bps")]
+    pub short_base_long_quote_extra_bps: Option<Decimal>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct SpreadOverrides {
+    pub model: Option<SpreadModel>,
+    pub beta_lookback_bars: Option<usize>,
+    pub kalman_delta: Option<Decimal>,
+    pub kalman_observation_var: Option<Decimal>,
+    pub kalman_warmup_bars: Option<usize>,
 }
 
 #[derive(Debug, Default, Deserialize)]
@@ -1306,6 +1860,7 @@ pub struct RiskOverrides {
 #[derive(Debug, Default, Deserialize)]
 pub struct DataOverrides {
     pub price_field: Option<PriceField>,
+    pub bar_interval: Option<BarInterval>,
 }
 
 #[derive(Debug, Default, Deserialize)]
@@ -1359,6 +1914,21 @@ pub struct BacktestOverrides {
     pub include_slippage: Option<bool>,
     pub slippage_bps: Option<u32>,
     pub include_funding: Option<bool>,
+    pub funding_interval_hours: Option<u32>,
+    pub intrabar_exits: Option<bool>,
+    pub execution_delay: Option<ExecutionDelay>,
+    pub execution_delay_secs: Option<u32>,
+    pub slippage_model: Option<SlippageModel>,
+    pub impact_bps: Option<Decimal>,
+    pub margin_policy: Option<MarginPolicy>,
+    pub maintenance_margin_rate: Option<Decimal>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct PortfolioOverrides {
+    pub equity_budget: Option<Decimal>,
+    pub max_gross_notional: Option<Decimal>,
+    pub strategies: Option<Vec<PortfolioStrategyConfig>>,
 }
 
 #[derive(Debug, Default, Deserialize)]
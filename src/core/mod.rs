@@ -6,17 +6,19 @@ pub mod strategy;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TradeDirection {
-    LongEthShortBtc,
-    ShortEthLongBtc,
+    #[serde(alias = "LongEthShortBtc")]
+    LongBaseShortQuote,
+    #[serde(alias = "ShortEthLongBtc")]
+    ShortBaseLongQuote,
 }
 
 impl TradeDirection {
-    pub fn is_eth_long(&self) -> bool {
-        matches!(self, TradeDirection::LongEthShortBtc)
+    pub fn is_base_long(&self) -> bool {
+        matches!(self, TradeDirection::LongBaseShortQuote)
     }
 
-    pub fn is_btc_long(&self) -> bool {
-        matches!(self, TradeDirection::ShortEthLongBtc)
+    pub fn is_quote_long(&self) -> bool {
+        matches!(self, TradeDirection::ShortBaseLongQuote)
     }
 }
 
@@ -1,37 +1,69 @@
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 
-use crate::config::Config;
+use crate::config::{Config, SpreadModel};
 use crate::core::{EntrySignal, ExitSignal};
 use crate::indicators::{
-    IndicatorError, VolatilityCalculator, VolatilitySnapshot, ZScoreCalculator, ZScoreSnapshot,
-    relative_price,
+    IndicatorError, KalmanHedgeFilter, KalmanState, SpreadCalculator, VolatilityCalculator,
+    VolatilitySnapshot, ZScoreCalculator, ZScoreSnapshot, relative_price,
 };
-use crate::signals::{EntrySignalDetector, ExitSignalDetector};
-use crate::state::{PositionSnapshot, StrategyStatus};
+use crate::signals::{EntrySignalDetector, ExitSignalDetector, ScaleInSignalDetector};
+use crate::state::{PositionGroup, PositionSnapshot, StrategyStatus};
 
 #[derive(Debug, Clone)]
 pub struct SignalOutput {
+    /// Traded spread: `ln(base) - ln(quote)` for the fixed model, the hedged residual otherwise.
     pub r: Decimal,
+    pub beta: Option<Decimal>,
     pub z_snapshot: ZScoreSnapshot,
     pub vol_snapshot: VolatilitySnapshot,
     pub entry_signal: Option<EntrySignal>,
     pub exit_signal: Option<ExitSignal>,
+    pub scale_in_signal: Option<EntrySignal>,
+    /// Scaled-in groups to close this bar, by group index; empty when `exit_signal` is set.
+    pub group_exits: Vec<(usize, ExitSignal)>,
+}
+
+/// Per-bar indicator values; they depend only on prices and the indicator settings
+/// (`spread`, `n_z`, `sigma_floor`, `n_vol`), not on signal thresholds or position state.
+#[derive(Debug, Clone)]
+pub struct IndicatorSnapshot {
+    pub beta: Option<Decimal>,
+    pub z_snapshot: ZScoreSnapshot,
+    pub vol_snapshot: VolatilitySnapshot,
+}
+
+#[derive(Debug, Clone)]
+enum SpreadEstimator {
+    Ols(SpreadCalculator),
+    Kalman(KalmanHedgeFilter),
 }
 
 #[derive(Debug, Clone)]
 pub struct SignalPipeline {
+    spread: SpreadEstimator,
     zcalc: ZScoreCalculator,
     volcalc: VolatilityCalculator,
     entry_detector: EntrySignalDetector,
     exit_detector: ExitSignalDetector,
+    scale_in_detector: ScaleInSignalDetector,
 }
 
 impl SignalPipeline {
     pub fn new(config: &Config) -> Result<Self, IndicatorError> {
-        let zcalc = ZScoreCalculator::new(config.strategy.n_z, config.sigma_floor.clone(), 96)?;
+        let zcalc = ZScoreCalculator::new(
+            config.strategy.n_z,
+            config.sigma_floor.clone(),
+            config.data.bar_interval.bars_per_day(),
+        )?;
         let volcalc = VolatilityCalculator::new(config.position.n_vol)?;
         Ok(Self {
+            spread: match config.spread.model {
+                SpreadModel::Kalman => {
+                    SpreadEstimator::Kalman(KalmanHedgeFilter::new(&config.spread)?)
+                }
+                _ => SpreadEstimator::Ols(SpreadCalculator::new(&config.spread)?),
+            },
             zcalc,
             volcalc,
             entry_detector: EntrySignalDetector::with_entry_controls(
@@ -39,31 +71,143 @@ impl SignalPipeline {
                 config.stale_cross.clone(),
                 config.persistent_extreme.clone(),
             ),
-            exit_detector: ExitSignalDetector::new(config.strategy.clone(), config.risk.clone()),
+            exit_detector: ExitSignalDetector::new(config.strategy.clone(), config.risk.clone())
+                .with_scale_in_step_z(config.position.scale_in_step_z),
+            scale_in_detector: ScaleInSignalDetector::new(
+                config.strategy.clone(),
+                config.position.clone(),
+            ),
         })
     }
 
+    /// Filter state to checkpoint; `None` unless the spread model is `KALMAN`.
+    pub fn kalman_state(&self) -> Option<&KalmanState> {
+        match &self.spread {
+            SpreadEstimator::Kalman(filter) => Some(filter.state()),
+            SpreadEstimator::Ols(_) => None,
+        }
+    }
+
+    pub fn restore_kalman_state(&mut self, state: KalmanState) {
+        if let SpreadEstimator::Kalman(filter) = &mut self.spread {
+            filter.restore(state);
+        }
+    }
+
     pub fn update(
         &mut self,
         timestamp: DateTime<Utc>,
-        eth_price: Decimal,
-        btc_price: Decimal,
+        base_price: Decimal,
+        quote_price: Decimal,
         status: StrategyStatus,
         position: Option<&PositionSnapshot>,
+        groups: &[PositionGroup],
     ) -> Result<SignalOutput, IndicatorError> {
-        let r = relative_price(eth_price, btc_price)?;
-        let z_snapshot = self.zcalc.update(r)?;
-        let vol_snapshot = self.volcalc.update(eth_price, btc_price)?;
+        let indicators = self.update_indicators(timestamp, base_price, quote_price)?;
+        Ok(self.evaluate(timestamp, indicators, status, position, groups))
+    }
+
+    /// Advances only the indicator state; pair with `evaluate` to reuse a shared series.
+    pub fn update_indicators(
+        &mut self,
+        timestamp: DateTime<Utc>,
+        base_price: Decimal,
+        quote_price: Decimal,
+    ) -> Result<IndicatorSnapshot, IndicatorError> {
+        let unfitted = |base_price, quote_price| -> Result<ZScoreSnapshot, IndicatorError> {
+            Ok(ZScoreSnapshot {
+                r: relative_price(base_price, quote_price)?,
+                mean: None,
+                sigma: None,
+                sigma_floor: None,
+                sigma_eff: None,
+                zscore: None,
+            })
+        };
+        let (z_snapshot, beta) = match &mut self.spread {
+            SpreadEstimator::Ols(calculator) => {
+                let spread = calculator.update(base_price, quote_price)?;
+                let z_snapshot = match spread.spread {
+                    Some(value) => self.zcalc.update(value)?,
+                    // The rolling beta is not fitted yet; report the fixed spread without a z-score.
+                    None => unfitted(base_price, quote_price)?,
+                };
+                (z_snapshot, spread.beta)
+            }
+            // The Kalman innovation is already standardized by its own variance.
+            SpreadEstimator::Kalman(filter) => {
+                match filter.update(timestamp, base_price, quote_price)? {
+                    Some(snapshot) => (
+                        ZScoreSnapshot {
+                            r: snapshot.residual,
+                            mean: Some(Decimal::ZERO),
+                            sigma: Some(snapshot.innovation_std),
+                            sigma_floor: None,
+                            sigma_eff: Some(snapshot.innovation_std),
+                            zscore: snapshot.zscore,
+                        },
+                        Some(snapshot.beta),
+                    ),
+                    // Bar already applied before a checkpoint.
+                    None => (
+                        unfitted(base_price, quote_price)?,
+                        Some(filter.state().beta),
+                    ),
+                }
+            }
+        };
+        let vol_snapshot = self.volcalc.update(base_price, quote_price)?;
+        Ok(IndicatorSnapshot {
+            beta,
+            z_snapshot,
+            vol_snapshot,
+        })
+    }
+
+    /// Runs the signal detectors on indicator values for one bar.
+    pub fn evaluate(
+        &mut self,
+        timestamp: DateTime<Utc>,
+        indicators: IndicatorSnapshot,
+        status: StrategyStatus,
+        position: Option<&PositionSnapshot>,
+        groups: &[PositionGroup],
+    ) -> SignalOutput {
+        let IndicatorSnapshot {
+            beta,
+            z_snapshot,
+            vol_snapshot,
+        } = indicators;
+        let r = z_snapshot.r;
         let entry_signal = self.entry_detector.update(z_snapshot.zscore, status);
         let exit_signal =
             self.exit_detector
                 .evaluate(z_snapshot.zscore, status, position, timestamp);
-        Ok(SignalOutput {
+        let scale_in_signal =
+            self.scale_in_detector
+                .update(z_snapshot.zscore, status, position, groups.len());
+        let group_exits = if exit_signal.is_none() && status == StrategyStatus::InPosition {
+            groups
+                .iter()
+                .enumerate()
+                .filter_map(|(index, group)| {
+                    self.exit_detector
+                        .evaluate_group(z_snapshot.zscore, index, group, timestamp)
+                        .map(|signal| (index, signal))
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+        SignalOutput {
             r,
+            beta,
             z_snapshot,
             vol_snapshot,
             entry_signal,
             exit_signal,
-        })
+            scale_in_signal,
+            group_exits,
+        }
     }
 }
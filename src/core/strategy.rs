@@ -8,17 +8,18 @@ use thiserror::Error;
 
 use crate::account::{AccountFillSource, ExchangeFill, PairExposure};
 use crate::config::{CapitalMode, Config, FundingMode, OrderType, PriceField, Symbol};
-use crate::core::TradeDirection;
 use crate::core::pipeline::SignalPipeline;
+use crate::core::{ExitReason, TradeDirection};
 use crate::execution::{
     ExecutionEngine, ExecutionError, OrderFill, OrderRequest, OrderSide, PairFill, PairOpenOutcome,
 };
 use crate::funding::{FundingRate, apply_funding_controls, estimate_funding_cost};
+use crate::indicators::{KalmanState, relative_price};
 use crate::logging::{BarLog, EntryBlockReason, LogEvent, PnlSource, TradeEvent, TradeLog};
-use crate::position::{PositionError, SizeConverter, compute_capital, risk_parity_weights};
+use crate::position::{PositionError, SizeConverter, compute_capital, spread_weights};
 use crate::state::{
-    PendingEntrySnapshot, PositionLeg, PositionSnapshot, StateMachine, StrategyState,
-    StrategyStatus,
+    PendingEntrySnapshot, PositionGroup, PositionLeg, PositionSnapshot, StateMachine,
+    StrategyState, StrategyStatus,
 };
 use crate::storage::PriceBarRecord;
 use tracing::{info, warn};
@@ -26,14 +27,21 @@ use tracing::{info, warn};
 #[derive(Debug, Clone)]
 pub struct StrategyBar {
     pub timestamp: DateTime<Utc>,
-    pub eth_price: Decimal,
-    pub btc_price: Decimal,
+    pub base_price: Decimal,
+    pub quote_price: Decimal,
     pub equity: Option<Decimal>,
-    pub funding_eth: Option<Decimal>,
-    pub funding_btc: Option<Decimal>,
+    pub funding_base: Option<Decimal>,
+    pub funding_quote: Option<Decimal>,
     pub funding_interval_hours: Option<u32>,
 }
 
+/// Capital and gross-notional headroom handed to the engine by a portfolio runner.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EntryBudget {
+    pub capital: Decimal,
+    pub max_gross_notional: Option<Decimal>,
+}
+
 #[derive(Debug, Clone)]
 pub struct StrategyOutcome {
     pub state: StrategyStatus,
@@ -62,7 +70,10 @@ pub struct StrategyEngine {
     state_machine: StateMachine,
     execution: ExecutionEngine,
     fill_source: Option<Arc<dyn AccountFillSource>>,
+    shared_account: bool,
     regime_tracker: SpreadHalfLifeTracker,
+    entry_budget: Option<EntryBudget>,
+    spread_beta: Option<Decimal>,
     cumulative_realized_pnl: Decimal,
     pending_events: Vec<LogEvent>,
     pending_trade_logs: Vec<TradeLog>,
@@ -70,8 +81,8 @@ pub struct StrategyEngine {
 
 #[derive(Debug, Clone)]
 struct FillAccounting {
-    eth_price: Decimal,
-    btc_price: Decimal,
+    base_price: Decimal,
+    quote_price: Decimal,
     fee: Decimal,
     exchange_closed_pnl: Option<Decimal>,
     realized_pnl: Decimal,
@@ -79,35 +90,35 @@ struct FillAccounting {
 }
 
 #[derive(Debug, Clone, Copy)]
-struct CostGateDecision {
-    expected_edge_bps: Decimal,
-    estimated_cost_bps: Decimal,
-    estimated_net_edge_bps: Decimal,
-    required_net_edge_bps: Decimal,
-    pass: bool,
+pub(crate) struct CostGateDecision {
+    pub(crate) expected_edge_bps: Decimal,
+    pub(crate) estimated_cost_bps: Decimal,
+    pub(crate) estimated_net_edge_bps: Decimal,
+    pub(crate) required_net_edge_bps: Decimal,
+    pub(crate) pass: bool,
 }
 
 #[derive(Debug, Clone)]
-struct RegimeGateSnapshot {
-    half_life_bars: Option<f64>,
-    pass: Option<bool>,
+pub(crate) struct RegimeGateSnapshot {
+    pub(crate) half_life_bars: Option<f64>,
+    pub(crate) pass: Option<bool>,
 }
 
 #[derive(Debug, Clone)]
-struct SpreadHalfLifeTracker {
+pub(crate) struct SpreadHalfLifeTracker {
     lookback_bars: usize,
     values: VecDeque<Decimal>,
 }
 
 impl SpreadHalfLifeTracker {
-    fn new(lookback_bars: usize) -> Self {
+    pub(crate) fn new(lookback_bars: usize) -> Self {
         Self {
             lookback_bars: lookback_bars.max(3),
             values: VecDeque::with_capacity(lookback_bars.max(3)),
         }
     }
 
-    fn push(
+    pub(crate) fn push(
         &mut self,
         value: Decimal,
         max_half_life_bars: f64,
@@ -155,6 +166,9 @@ impl StrategyEngine {
             config,
             execution,
             fill_source: None,
+            shared_account: false,
+            entry_budget: None,
+            spread_beta: None,
             cumulative_realized_pnl: Decimal::ZERO,
             pending_events: Vec::new(),
             pending_trade_logs: Vec::new(),
@@ -166,10 +180,35 @@ impl StrategyEngine {
         self
     }
 
+    /// Marks the exchange account as shared with other strategies. Fills keep their exchange
+    /// prices and fees, but realized PnL is measured against this engine's own entries: the
+    /// exchange's closed PnL nets every position on the account.
+    pub fn with_shared_account(mut self) -> Self {
+        self.shared_account = true;
+        self
+    }
+
     pub fn state(&self) -> &StateMachine {
         &self.state_machine
     }
 
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    pub fn kalman_state(&self) -> Option<&KalmanState> {
+        self.pipeline.kalman_state()
+    }
+
+    /// Restores a checkpointed Kalman filter; ignored for other spread models.
+    pub fn restore_kalman_state(&mut self, state: KalmanState) {
+        self.pipeline.restore_kalman_state(state);
+    }
+
+    pub fn set_entry_budget(&mut self, budget: Option<EntryBudget>) {
+        self.entry_budget = budget;
+    }
+
     pub fn apply_state(&mut self, state: StrategyState) -> Result<(), StrategyError> {
         let cumulative_realized_pnl = state.cumulative_realized_pnl;
         self.state_machine
@@ -183,8 +222,8 @@ impl StrategyEngine {
         &mut self,
         exposure: &PairExposure,
         timestamp: DateTime<Utc>,
-        eth_price: Decimal,
-        btc_price: Decimal,
+        base_price: Decimal,
+        quote_price: Decimal,
     ) -> Result<(), StrategyError> {
         let local_state = self.state_machine.state().clone();
 
@@ -207,8 +246,8 @@ impl StrategyEngine {
             }
             let position = self.exposure_to_position(exposure, timestamp)?;
             warn!(
-                eth_qty = %position.eth.qty,
-                btc_qty = %position.btc.qty,
+                base_qty = %position.base.qty,
+                quote_qty = %position.quote.qty,
                 "exchange residual leg detected; attempting repair"
             );
             let repair_fill = self
@@ -220,8 +259,8 @@ impl StrategyEngine {
                 .residual_repair_trade_log(
                     &position,
                     timestamp,
-                    eth_price,
-                    btc_price,
+                    base_price,
+                    quote_price,
                     repair_fill.as_ref(),
                 )
                 .await;
@@ -241,24 +280,24 @@ impl StrategyEngine {
                 })?;
                 let accounting = self
                     .fill_accounting_for_order_ids(
-                        &[Some(pending.eth_order_id), Some(pending.btc_order_id)],
-                        &[Symbol::EthPerp, Symbol::BtcPerp],
+                        &[Some(pending.base_order_id), Some(pending.quote_order_id)],
+                        &[Symbol::Base, Symbol::Quote],
                         pending.submitted_at,
                         timestamp,
-                        position.eth.avg_price,
-                        position.btc.avg_price,
+                        position.base.avg_price,
+                        position.quote.avg_price,
                         Decimal::ZERO,
                     )
                     .await
                     .unwrap_or_else(|| {
                         Self::model_accounting(
-                            position.eth.avg_price,
-                            position.btc.avg_price,
+                            position.base.avg_price,
+                            position.quote.avg_price,
                             Decimal::ZERO,
                         )
                     });
-                position.eth.avg_price = accounting.eth_price;
-                position.btc.avg_price = accounting.btc_price;
+                position.base.avg_price = accounting.base_price;
+                position.quote.avg_price = accounting.quote_price;
                 self.add_realized_pnl(accounting.realized_pnl);
                 self.state_machine
                     .confirm_pending_entry(position.clone())
@@ -266,31 +305,32 @@ impl StrategyEngine {
                 self.pending_events.push(LogEvent::Entry);
                 self.pending_trade_logs.push(TradeLog {
                     timestamp,
+                    pair: Some(self.config.pair.label()),
                     event: TradeEvent::Entry,
                     direction: position.direction,
-                    eth_qty: position.eth.qty,
-                    btc_qty: position.btc.qty,
-                    eth_price: accounting.eth_price,
-                    btc_price: accounting.btc_price,
+                    base_qty: position.base.qty,
+                    quote_qty: position.quote.qty,
+                    base_price: accounting.base_price,
+                    quote_price: accounting.quote_price,
                     entry_time: timestamp,
-                    entry_eth_price: accounting.eth_price,
-                    entry_btc_price: accounting.btc_price,
+                    entry_base_price: accounting.base_price,
+                    entry_quote_price: accounting.quote_price,
                     realized_pnl: accounting.realized_pnl,
                     cumulative_realized_pnl: self.cumulative_realized_pnl,
                     fee: accounting.fee,
                     exchange_closed_pnl: accounting.exchange_closed_pnl,
                     pnl_source: accounting.source,
-                    eth_ref_price: Some(eth_price),
-                    btc_ref_price: Some(btc_price),
-                    eth_slippage_bps: Some(slippage_bps_for_side(
-                        eth_price,
-                        accounting.eth_price,
-                        entry_eth_side(position.direction),
+                    base_ref_price: Some(base_price),
+                    quote_ref_price: Some(quote_price),
+                    base_slippage_bps: Some(slippage_bps_for_side(
+                        base_price,
+                        accounting.base_price,
+                        entry_base_side(position.direction),
                     )),
-                    btc_slippage_bps: Some(slippage_bps_for_side(
-                        btc_price,
-                        accounting.btc_price,
-                        entry_btc_side(position.direction),
+                    quote_slippage_bps: Some(slippage_bps_for_side(
+                        quote_price,
+                        accounting.quote_price,
+                        entry_quote_side(position.direction),
                     )),
                 });
                 Ok(())
@@ -303,21 +343,21 @@ impl StrategyEngine {
                 };
                 if !Self::exposure_matches_position(exposure, position) {
                     return Err(StrategyError::Execution(format!(
-                        "exchange position mismatch with local state: local eth_qty={} btc_qty={}, remote eth_qty={} btc_qty={}",
-                        position.eth.qty,
-                        position.btc.qty,
-                        exposure.eth_qty(),
-                        exposure.btc_qty(),
+                        "exchange position mismatch with local state: local base_qty={} quote_qty={}, remote base_qty={} quote_qty={}",
+                        position.base.qty,
+                        position.quote.qty,
+                        exposure.base_qty(),
+                        exposure.quote_qty(),
                     )));
                 }
                 Ok(())
             }
             StrategyStatus::Flat | StrategyStatus::Cooldown => {
                 Err(StrategyError::Execution(format!(
-                    "exchange position exists while local state is {:?}: remote eth_qty={} btc_qty={}",
+                    "exchange position exists while local state is {:?}: remote base_qty={} quote_qty={}",
                     local_state.status,
-                    exposure.eth_qty(),
-                    exposure.btc_qty(),
+                    exposure.base_qty(),
+                    exposure.quote_qty(),
                 )))
             }
         }
@@ -331,11 +371,11 @@ impl StrategyEngine {
         sorted.sort_by_key(|r| r.timestamp);
         for record in sorted {
             self.state_machine.update(record.timestamp);
-            let eth_price = select_price(
+            let base_price = select_price(
                 self.config.data.price_field,
-                record.eth_mid,
-                record.eth_mark,
-                record.eth_close,
+                record.base_mid,
+                record.base_mark,
+                record.base_close,
             )
             .ok_or_else(|| {
                 StrategyError::Data(format!(
@@ -343,11 +383,11 @@ impl StrategyEngine {
                     record.timestamp
                 ))
             })?;
-            let btc_price = select_price(
+            let quote_price = select_price(
                 self.config.data.price_field,
-                record.btc_mid,
-                record.btc_mark,
-                record.btc_close,
+                record.quote_mid,
+                record.quote_mark,
+                record.quote_close,
             )
             .ok_or_else(|| {
                 StrategyError::Data(format!(
@@ -359,10 +399,11 @@ impl StrategyEngine {
                 .pipeline
                 .update(
                     record.timestamp,
-                    eth_price,
-                    btc_price,
+                    base_price,
+                    quote_price,
                     self.state_machine.state().status,
                     self.state_machine.state().position.as_ref(),
+                    &self.state_machine.state().position_groups(),
                 )
                 .map_err(|err| StrategyError::Indicator(err.to_string()))?;
             self.regime_tracker.push(
@@ -385,11 +426,11 @@ impl StrategyEngine {
             && bar.timestamp >= pending.expires_at
         {
             self.execution
-                .cancel_order(Symbol::EthPerp, pending.eth_order_id)
+                .cancel_order(Symbol::Base, pending.base_order_id)
                 .await
                 .map_err(|err| StrategyError::Execution(err.to_string()))?;
             self.execution
-                .cancel_order(Symbol::BtcPerp, pending.btc_order_id)
+                .cancel_order(Symbol::Quote, pending.quote_order_id)
                 .await
                 .map_err(|err| StrategyError::Execution(err.to_string()))?;
             self.state_machine.force_flat();
@@ -401,8 +442,8 @@ impl StrategyEngine {
             && position.has_residual()
         {
             warn!(
-                eth_qty = %position.eth.qty,
-                btc_qty = %position.btc.qty,
+                base_qty = %position.base.qty,
+                quote_qty = %position.quote.qty,
                 "residual leg detected; attempting repair"
             );
             let repair_fill = self
@@ -414,8 +455,8 @@ impl StrategyEngine {
                 .residual_repair_trade_log(
                     &position,
                     bar.timestamp,
-                    bar.eth_price,
-                    bar.btc_price,
+                    bar.base_price,
+                    bar.quote_price,
                     repair_fill.as_ref(),
                 )
                 .await;
@@ -427,26 +468,34 @@ impl StrategyEngine {
             .pipeline
             .update(
                 bar.timestamp,
-                bar.eth_price,
-                bar.btc_price,
+                bar.base_price,
+                bar.quote_price,
                 self.state_machine.state().status,
                 self.state_machine.state().position.as_ref(),
+                &self.state_machine.state().position_groups(),
             )
             .map_err(|err| StrategyError::Indicator(err.to_string()))?;
+        self.spread_beta = output.beta;
         let z_snapshot = output.z_snapshot;
         let vol_snapshot = output.vol_snapshot;
-        let entry_signal = output.entry_signal;
         let exit_signal = output.exit_signal;
+        let group_exits = output.group_exits;
+        let scaling_in = self.state_machine.state().status == StrategyStatus::InPosition;
+        let entry_signal = if scaling_in {
+            output.scale_in_signal.filter(|_| exit_signal.is_none())
+        } else {
+            output.entry_signal
+        };
         let regime_snapshot = self.regime_tracker.push(
             output.r,
             self.config.regime_gate.max_half_life_bars,
             self.config.regime_gate.enabled,
         );
 
-        let mut w_eth = None;
-        let mut w_btc = None;
-        let mut notional_eth = None;
-        let mut notional_btc = None;
+        let mut w_base = None;
+        let mut w_quote = None;
+        let mut notional_base = None;
+        let mut notional_quote = None;
         let mut funding_cost_est = None;
         let mut funding_skip = None;
         let regime_half_life_bars = regime_snapshot.half_life_bars;
@@ -458,13 +507,13 @@ impl StrategyEngine {
         let mut cost_gate_pass = None;
         let mut entry_block_reason = None;
 
-        if let Some(vol_eth) = vol_snapshot.vol_eth
-            && let Some(vol_btc) = vol_snapshot.vol_btc
+        if let Some(vol_base) = vol_snapshot.vol_base
+            && let Some(vol_quote) = vol_snapshot.vol_quote
         {
-            let weights = risk_parity_weights(vol_eth, vol_btc)
+            let weights = spread_weights(&self.config.spread, vol_base, vol_quote, output.beta)
                 .map_err(|err| StrategyError::Position(err.to_string()))?;
-            w_eth = Some(weights.w_eth);
-            w_btc = Some(weights.w_btc);
+            w_base = Some(weights.w_base);
+            w_quote = Some(weights.w_quote);
         }
 
         if self.state_machine.state().status == StrategyStatus::Flat {
@@ -472,32 +521,64 @@ impl StrategyEngine {
                 entry_block_reason = Some(EntryBlockReason::ZscoreUnavailable);
             } else if entry_signal.is_none() {
                 entry_block_reason = Some(EntryBlockReason::NoCross);
-            } else if vol_snapshot.vol_eth.is_none() || vol_snapshot.vol_btc.is_none() {
+            } else if vol_snapshot.vol_base.is_none() || vol_snapshot.vol_quote.is_none() {
                 entry_block_reason = Some(EntryBlockReason::VolatilityUnavailable);
             }
         }
 
         if let Some(signal) = entry_signal
-            && let Some(vol_eth) = vol_snapshot.vol_eth
-            && let Some(vol_btc) = vol_snapshot.vol_btc
+            && let Some(vol_base) = vol_snapshot.vol_base
+            && let Some(vol_quote) = vol_snapshot.vol_quote
         {
-            let equity = match self.config.position.c_mode {
-                CapitalMode::FixedNotional => self.config.position.c_value.unwrap_or(Decimal::ZERO),
-                CapitalMode::EquityRatio => {
-                    if let Some(value) = bar.equity {
-                        value
-                    } else if let Some(value) = self.config.position.equity_value {
-                        value
-                    } else {
-                        return Err(StrategyError::Position(
-                            "equity unavailable for equity ratio mode".to_string(),
-                        ));
-                    }
+            let base_capital = match self.entry_budget {
+                Some(budget) => budget.capital,
+                None => {
+                    let equity = match self.config.position.c_mode {
+                        CapitalMode::FixedNotional => {
+                            self.config.position.c_value.unwrap_or(Decimal::ZERO)
+                        }
+                        CapitalMode::EquityRatio => {
+                            if let Some(value) = bar.equity {
+                                value
+                            } else if let Some(value) = self.config.position.equity_value {
+                                value
+                            } else {
+                                return Err(StrategyError::Position(
+                                    "equity unavailable for equity ratio mode".to_string(),
+                                ));
+                            }
+                        }
+                    };
+                    compute_capital(&self.config.position, equity)
+                        .map_err(|err| StrategyError::Position(err.to_string()))?
                 }
             };
-            let base_capital = compute_capital(&self.config.position, equity)
-                .map_err(|err| StrategyError::Position(err.to_string()))?;
-            let capital = base_capital * self.directional_size_multiplier(signal.direction);
+            let capital =
+                base_capital * directional_size_multiplier(&self.config, signal.direction);
+            if self.entry_budget.is_some() && capital <= Decimal::ZERO {
+                entry_block_reason = Some(EntryBlockReason::PortfolioBudget);
+                return Ok(self.build_outcome(
+                    bar,
+                    z_snapshot,
+                    vol_snapshot,
+                    events,
+                    w_base,
+                    w_quote,
+                    None,
+                    None,
+                    funding_cost_est,
+                    funding_skip,
+                    regime_half_life_bars,
+                    regime_gate_pass,
+                    expected_edge_bps,
+                    estimated_cost_bps,
+                    estimated_net_edge_bps,
+                    cost_gate_required_net_edge_bps,
+                    cost_gate_pass,
+                    entry_block_reason,
+                    trade_logs,
+                ));
+            }
             if let Some(max_notional) = self.config.position.max_notional
                 && capital > max_notional
             {
@@ -505,35 +586,36 @@ impl StrategyEngine {
                     "capital {capital} exceeds max_notional {max_notional}"
                 )));
             }
-            let weights = risk_parity_weights(vol_eth, vol_btc)
+            let weights = spread_weights(&self.config.spread, vol_base, vol_quote, output.beta)
                 .map_err(|err| StrategyError::Position(err.to_string()))?;
-            let notional_eth_value = capital * weights.w_eth;
-            let notional_btc_value = capital * weights.w_btc;
-            w_eth = Some(weights.w_eth);
-            w_btc = Some(weights.w_btc);
-            notional_eth = Some(notional_eth_value);
-            notional_btc = Some(notional_btc_value);
-
-            if let (Some(funding_eth), Some(funding_btc)) = (bar.funding_eth, bar.funding_btc) {
+            let notional_base_value = capital * weights.w_base;
+            let notional_quote_value = capital * weights.w_quote;
+            w_base = Some(weights.w_base);
+            w_quote = Some(weights.w_quote);
+            notional_base = Some(notional_base_value);
+            notional_quote = Some(notional_quote_value);
+
+            if let (Some(funding_base), Some(funding_quote)) = (bar.funding_base, bar.funding_quote)
+            {
                 let interval_hours = bar.funding_interval_hours.unwrap_or(8);
-                let eth_rate = FundingRate {
-                    symbol: Symbol::EthPerp,
-                    rate: funding_eth,
+                let base_rate = FundingRate {
+                    symbol: Symbol::Base,
+                    rate: funding_base,
                     timestamp: bar.timestamp,
                     interval_hours,
                 };
-                let btc_rate = FundingRate {
-                    symbol: Symbol::BtcPerp,
-                    rate: funding_btc,
+                let quote_rate = FundingRate {
+                    symbol: Symbol::Quote,
+                    rate: funding_quote,
                     timestamp: bar.timestamp,
                     interval_hours,
                 };
                 let estimate = estimate_funding_cost(
                     signal.direction,
-                    notional_eth_value,
-                    notional_btc_value,
-                    &eth_rate,
-                    &btc_rate,
+                    notional_base_value,
+                    notional_quote_value,
+                    &base_rate,
+                    &quote_rate,
                     self.config.risk.max_hold_hours,
                 )
                 .map_err(|err| StrategyError::Funding(err.to_string()))?;
@@ -553,10 +635,10 @@ impl StrategyEngine {
                         z_snapshot,
                         vol_snapshot,
                         events,
-                        w_eth,
-                        w_btc,
-                        Some(notional_eth_value),
-                        Some(notional_btc_value),
+                        w_base,
+                        w_quote,
+                        Some(notional_base_value),
+                        Some(notional_quote_value),
                         funding_cost_est,
                         funding_skip,
                         regime_half_life_bars,
@@ -579,10 +661,10 @@ impl StrategyEngine {
                         z_snapshot,
                         vol_snapshot,
                         events,
-                        w_eth,
-                        w_btc,
-                        Some(notional_eth_value),
-                        Some(notional_btc_value),
+                        w_base,
+                        w_quote,
+                        Some(notional_base_value),
+                        Some(notional_quote_value),
                         funding_cost_est,
                         funding_skip,
                         regime_half_life_bars,
@@ -605,10 +687,10 @@ impl StrategyEngine {
                     z_snapshot,
                     vol_snapshot,
                     events,
-                    w_eth,
-                    w_btc,
-                    Some(notional_eth_value),
-                    Some(notional_btc_value),
+                    w_base,
+                    w_quote,
+                    Some(notional_base_value),
+                    Some(notional_quote_value),
                     funding_cost_est,
                     funding_skip,
                     regime_half_life_bars,
@@ -623,7 +705,8 @@ impl StrategyEngine {
                 ));
             }
 
-            if let Some(decision) = self.cost_gate_decision(
+            if let Some(decision) = cost_gate_decision(
+                &self.config,
                 signal.direction,
                 signal.zscore,
                 z_snapshot.sigma_eff,
@@ -642,10 +725,10 @@ impl StrategyEngine {
                         z_snapshot,
                         vol_snapshot,
                         events,
-                        w_eth,
-                        w_btc,
-                        Some(notional_eth_value),
-                        Some(notional_btc_value),
+                        w_base,
+                        w_quote,
+                        Some(notional_base_value),
+                        Some(notional_quote_value),
                         funding_cost_est,
                         funding_skip,
                         regime_half_life_bars,
@@ -661,88 +744,122 @@ impl StrategyEngine {
                 }
             }
 
-            let eth_converter = SizeConverter::new(
+            let base_converter = SizeConverter::new(
                 self.config
                     .instrument_constraints
-                    .get(&Symbol::EthPerp)
+                    .get(&Symbol::Base)
                     .cloned()
                     .unwrap_or_default(),
                 self.config.position.min_size_policy,
             );
-            let btc_converter = SizeConverter::new(
+            let quote_converter = SizeConverter::new(
                 self.config
                     .instrument_constraints
-                    .get(&Symbol::BtcPerp)
+                    .get(&Symbol::Quote)
                     .cloned()
                     .unwrap_or_default(),
                 self.config.position.min_size_policy,
             );
-            let eth_order = match eth_converter.convert_notional(notional_eth_value, bar.eth_price)
-            {
-                Ok(order) => order,
-                Err(PositionError::BelowMinimum(_)) => {
-                    entry_block_reason = Some(EntryBlockReason::BelowMinSizeEth);
-                    return Ok(self.build_outcome(
-                        bar,
-                        z_snapshot,
-                        vol_snapshot,
-                        events,
-                        w_eth,
-                        w_btc,
-                        Some(notional_eth_value),
-                        Some(notional_btc_value),
-                        funding_cost_est,
-                        funding_skip,
-                        regime_half_life_bars,
-                        regime_gate_pass,
-                        expected_edge_bps,
-                        estimated_cost_bps,
-                        estimated_net_edge_bps,
-                        cost_gate_required_net_edge_bps,
-                        cost_gate_pass,
-                        entry_block_reason,
-                        trade_logs,
-                    ));
-                }
-                Err(err) => return Err(StrategyError::Position(err.to_string())),
-            };
-            let btc_order = match btc_converter.convert_notional(notional_btc_value, bar.btc_price)
+            let base_order =
+                match base_converter.convert_notional(notional_base_value, bar.base_price) {
+                    Ok(order) => order,
+                    Err(PositionError::BelowMinimum(_)) => {
+                        entry_block_reason = Some(EntryBlockReason::BelowMinSizeBase);
+                        return Ok(self.build_outcome(
+                            bar,
+                            z_snapshot,
+                            vol_snapshot,
+                            events,
+                            w_base,
+                            w_quote,
+                            Some(notional_base_value),
+                            Some(notional_quote_value),
+                            funding_cost_est,
+                            funding_skip,
+                            regime_half_life_bars,
+                            regime_gate_pass,
+                            expected_edge_bps,
+                            estimated_cost_bps,
+                            estimated_net_edge_bps,
+                            cost_gate_required_net_edge_bps,
+                            cost_gate_pass,
+                            entry_block_reason,
+                            trade_logs,
+                        ));
+                    }
+                    Err(err) => return Err(StrategyError::Position(err.to_string())),
+                };
+            let quote_order =
+                match quote_converter.convert_notional(notional_quote_value, bar.quote_price) {
+                    Ok(order) => order,
+                    Err(PositionError::BelowMinimum(_)) => {
+                        entry_block_reason = Some(EntryBlockReason::BelowMinSizeQuote);
+                        return Ok(self.build_outcome(
+                            bar,
+                            z_snapshot,
+                            vol_snapshot,
+                            events,
+                            w_base,
+                            w_quote,
+                            Some(notional_base_value),
+                            Some(notional_quote_value),
+                            funding_cost_est,
+                            funding_skip,
+                            regime_half_life_bars,
+                            regime_gate_pass,
+                            expected_edge_bps,
+                            estimated_cost_bps,
+                            estimated_net_edge_bps,
+                            cost_gate_required_net_edge_bps,
+                            cost_gate_pass,
+                            entry_block_reason,
+                            trade_logs,
+                        ));
+                    }
+                    Err(err) => return Err(StrategyError::Position(err.to_string())),
+                };
+
+            if let Some(max_gross_notional) = self
+                .entry_budget
+                .and_then(|budget| budget.max_gross_notional)
+                && base_order.notional + quote_order.notional > max_gross_notional
             {
-                Ok(order) => order,
-                Err(PositionError::BelowMinimum(_)) => {
-                    entry_block_reason = Some(EntryBlockReason::BelowMinSizeBtc);
-                    return Ok(self.build_outcome(
-                        bar,
-                        z_snapshot,
-                        vol_snapshot,
-                        events,
-                        w_eth,
-                        w_btc,
-                        Some(notional_eth_value),
-                        Some(notional_btc_value),
-                        funding_cost_est,
-                        funding_skip,
-                        regime_half_life_bars,
-                        regime_gate_pass,
-                        expected_edge_bps,
-                        estimated_cost_bps,
-                        estimated_net_edge_bps,
-                        cost_gate_required_net_edge_bps,
-                        cost_gate_pass,
-                        entry_block_reason,
-                        trade_logs,
-                    ));
-                }
-                Err(err) => return Err(StrategyError::Position(err.to_string())),
-            };
+                entry_block_reason = Some(EntryBlockReason::PortfolioGrossCap);
+                return Ok(self.build_outcome(
+                    bar,
+                    z_snapshot,
+                    vol_snapshot,
+                    events,
+                    w_base,
+                    w_quote,
+                    Some(notional_base_value),
+                    Some(notional_quote_value),
+                    funding_cost_est,
+                    funding_skip,
+                    regime_half_life_bars,
+                    regime_gate_pass,
+                    expected_edge_bps,
+                    estimated_cost_bps,
+                    estimated_net_edge_bps,
+                    cost_gate_required_net_edge_bps,
+                    cost_gate_pass,
+                    entry_block_reason,
+                    trade_logs,
+                ));
+            }
 
-            let (eth_side, btc_side) = match signal.direction {
-                TradeDirection::LongEthShortBtc => (OrderSide::Buy, OrderSide::Sell),
-                TradeDirection::ShortEthLongBtc => (OrderSide::Sell, OrderSide::Buy),
+            let (base_side, quote_side) = match signal.direction {
+                TradeDirection::LongBaseShortQuote => (OrderSide::Buy, OrderSide::Sell),
+                TradeDirection::ShortBaseLongQuote => (OrderSide::Sell, OrderSide::Buy),
             };
-            let entry_order_type = self.entry_order_type();
-            let eth_limit_price = self.limit_price(entry_order_type, eth_side, bar.eth_price);
-            let btc_limit_price = self.limit_price(entry_order_type, btc_side, bar.btc_price);
+            // Scale-ins add to a live position, so they take liquidity instead of resting.
+            let entry_order_type = if scaling_in {
+                self.exit_order_type()
+            } else {
+                self.entry_order_type()
+            };
+            let base_limit_price = self.limit_price(entry_order_type, base_side, bar.base_price);
+            let quote_limit_price = self.limit_price(entry_order_type, quote_side, bar.quote_price);
             let expires_after = matches!(entry_order_type, OrderType::PostOnly).then(|| {
                 (bar.timestamp.timestamp_millis() as u64)
                     + self.config.execution.post_only_ttl_secs * 1000
@@ -751,31 +868,31 @@ impl StrategyEngine {
                 timestamp = %bar.timestamp.to_rfc3339(),
                 direction = ?signal.direction,
                 zscore = %signal.zscore,
-                eth_side = ?eth_side,
-                eth_qty = %eth_order.qty,
-                eth_limit_price = %eth_limit_price,
-                btc_side = ?btc_side,
-                btc_qty = %btc_order.qty,
-                btc_limit_price = %btc_limit_price,
+                base_side = ?base_side,
+                base_qty = %base_order.qty,
+                base_limit_price = %base_limit_price,
+                quote_side = ?quote_side,
+                quote_qty = %quote_order.qty,
+                quote_limit_price = %quote_limit_price,
                 "entry order attempt"
             );
             let open_outcome = match self
                 .execution
                 .open_pair(
                     OrderRequest {
-                        symbol: Symbol::EthPerp,
-                        side: eth_side,
-                        qty: eth_order.qty,
+                        symbol: Symbol::Base,
+                        side: base_side,
+                        qty: base_order.qty,
                         order_type: entry_order_type,
-                        limit_price: Some(eth_limit_price),
+                        limit_price: Some(base_limit_price),
                         expires_after,
                     },
                     OrderRequest {
-                        symbol: Symbol::BtcPerp,
-                        side: btc_side,
-                        qty: btc_order.qty,
+                        symbol: Symbol::Quote,
+                        side: quote_side,
+                        qty: quote_order.qty,
                         order_type: entry_order_type,
-                        limit_price: Some(btc_limit_price),
+                        limit_price: Some(quote_limit_price),
                         expires_after,
                     },
                 )
@@ -792,10 +909,10 @@ impl StrategyEngine {
                         z_snapshot,
                         vol_snapshot,
                         events,
-                        w_eth,
-                        w_btc,
-                        notional_eth,
-                        notional_btc,
+                        w_base,
+                        w_quote,
+                        notional_base,
+                        notional_quote,
                         funding_cost_est,
                         funding_skip,
                         regime_half_life_bars,
@@ -817,15 +934,15 @@ impl StrategyEngine {
                         .fill_accounting_for_pair_fill(
                             &pair_fill,
                             bar.timestamp,
-                            pair_fill.eth.avg_price.unwrap_or(bar.eth_price),
-                            pair_fill.btc.avg_price.unwrap_or(bar.btc_price),
+                            pair_fill.base.avg_price.unwrap_or(bar.base_price),
+                            pair_fill.quote.avg_price.unwrap_or(bar.quote_price),
                             Decimal::ZERO,
                         )
                         .await
                         .unwrap_or_else(|| {
                             Self::model_accounting(
-                                pair_fill.eth.avg_price.unwrap_or(bar.eth_price),
-                                pair_fill.btc.avg_price.unwrap_or(bar.btc_price),
+                                pair_fill.base.avg_price.unwrap_or(bar.base_price),
+                                pair_fill.quote.avg_price.unwrap_or(bar.quote_price),
                                 Decimal::ZERO,
                             )
                         });
@@ -833,88 +950,117 @@ impl StrategyEngine {
                     let position = PositionSnapshot {
                         direction: signal.direction,
                         entry_time: bar.timestamp,
-                        eth: PositionLeg {
-                            qty: if signal.direction == TradeDirection::LongEthShortBtc {
-                                pair_fill.eth.qty
+                        base: PositionLeg {
+                            qty: if signal.direction == TradeDirection::LongBaseShortQuote {
+                                pair_fill.base.qty
                             } else {
-                                -pair_fill.eth.qty
+                                -pair_fill.base.qty
                             },
-                            avg_price: accounting.eth_price,
-                            notional: pair_fill.eth.qty.abs() * accounting.eth_price,
+                            avg_price: accounting.base_price,
+                            notional: pair_fill.base.qty.abs() * accounting.base_price,
                         },
-                        btc: PositionLeg {
-                            qty: if signal.direction == TradeDirection::LongEthShortBtc {
-                                -pair_fill.btc.qty
+                        quote: PositionLeg {
+                            qty: if signal.direction == TradeDirection::LongBaseShortQuote {
+                                -pair_fill.quote.qty
                             } else {
-                                pair_fill.btc.qty
+                                pair_fill.quote.qty
                             },
-                            avg_price: accounting.btc_price,
-                            notional: pair_fill.btc.qty.abs() * accounting.btc_price,
+                            avg_price: accounting.quote_price,
+                            notional: pair_fill.quote.qty.abs() * accounting.quote_price,
                         },
                     };
-                    self.state_machine
-                        .enter(position, bar.timestamp)
-                        .map_err(|err| StrategyError::Position(err.to_string()))?;
-                    events.push(LogEvent::Entry);
+                    if scaling_in {
+                        self.state_machine
+                            .scale_in(PositionGroup::from_snapshot(&position, Some(signal.zscore)))
+                            .map_err(|err| StrategyError::Position(err.to_string()))?;
+                        events.push(LogEvent::ScaleIn);
+                    } else {
+                        self.state_machine
+                            .enter_with_entry_z(position, Some(signal.zscore), bar.timestamp)
+                            .map_err(|err| StrategyError::Position(err.to_string()))?;
+                        events.push(LogEvent::Entry);
+                    }
                     trade_logs.push(TradeLog {
                         timestamp: bar.timestamp,
-                        event: TradeEvent::Entry,
+                        pair: Some(self.config.pair.label()),
+                        event: if scaling_in {
+                            TradeEvent::ScaleIn
+                        } else {
+                            TradeEvent::Entry
+                        },
                         direction: signal.direction,
-                        eth_qty: if signal.direction == TradeDirection::LongEthShortBtc {
-                            pair_fill.eth.qty
+                        base_qty: if signal.direction == TradeDirection::LongBaseShortQuote {
+                            pair_fill.base.qty
                         } else {
-                            -pair_fill.eth.qty
+                            -pair_fill.base.qty
                         },
-                        btc_qty: if signal.direction == TradeDirection::LongEthShortBtc {
-                            -pair_fill.btc.qty
+                        quote_qty: if signal.direction == TradeDirection::LongBaseShortQuote {
+                            -pair_fill.quote.qty
                         } else {
-                            pair_fill.btc.qty
+                            pair_fill.quote.qty
                         },
-                        eth_price: accounting.eth_price,
-                        btc_price: accounting.btc_price,
+                        base_price: accounting.base_price,
+                        quote_price: accounting.quote_price,
                         entry_time: bar.timestamp,
-                        entry_eth_price: accounting.eth_price,
-                        entry_btc_price: accounting.btc_price,
+                        entry_base_price: accounting.base_price,
+                        entry_quote_price: accounting.quote_price,
                         realized_pnl: accounting.realized_pnl,
                         cumulative_realized_pnl: self.cumulative_realized_pnl,
                         fee: accounting.fee,
                         exchange_closed_pnl: accounting.exchange_closed_pnl,
                         pnl_source: accounting.source,
-                        eth_ref_price: Some(bar.eth_price),
-                        btc_ref_price: Some(bar.btc_price),
-                        eth_slippage_bps: Some(slippage_bps_for_side(
-                            bar.eth_price,
-                            accounting.eth_price,
-                            eth_side,
+                        base_ref_price: Some(bar.base_price),
+                        quote_ref_price: Some(bar.quote_price),
+                        base_slippage_bps: Some(slippage_bps_for_side(
+                            bar.base_price,
+                            accounting.base_price,
+                            base_side,
                         )),
-                        btc_slippage_bps: Some(slippage_bps_for_side(
-                            bar.btc_price,
-                            accounting.btc_price,
-                            btc_side,
+                        quote_slippage_bps: Some(slippage_bps_for_side(
+                            bar.quote_price,
+                            accounting.quote_price,
+                            quote_side,
                         )),
                     });
                 }
+                PairOpenOutcome::Resting(resting) if scaling_in => {
+                    warn!(
+                        base_oid = resting.base_oid,
+                        quote_oid = resting.quote_oid,
+                        "scale-in orders rested; cancelling"
+                    );
+                    self.execution
+                        .cancel_order(Symbol::Base, resting.base_oid)
+                        .await
+                        .map_err(|err| StrategyError::Execution(err.to_string()))?;
+                    self.execution
+                        .cancel_order(Symbol::Quote, resting.quote_oid)
+                        .await
+                        .map_err(|err| StrategyError::Execution(err.to_string()))?;
+                    events.push(LogEvent::EntryCancelled);
+                }
                 PairOpenOutcome::Resting(resting) => {
                     self.state_machine
                         .enter_pending(PendingEntrySnapshot {
                             direction: signal.direction,
-                            eth_qty: if signal.direction == TradeDirection::LongEthShortBtc {
-                                eth_order.qty
+                            base_qty: if signal.direction == TradeDirection::LongBaseShortQuote {
+                                base_order.qty
                             } else {
-                                -eth_order.qty
+                                -base_order.qty
                             },
-                            btc_qty: if signal.direction == TradeDirection::LongEthShortBtc {
-                                -btc_order.qty
+                            quote_qty: if signal.direction == TradeDirection::LongBaseShortQuote {
+                                -quote_order.qty
                             } else {
-                                btc_order.qty
+                                quote_order.qty
                             },
-                            eth_order_id: resting.eth_oid,
-                            btc_order_id: resting.btc_oid,
+                            base_order_id: resting.base_oid,
+                            quote_order_id: resting.quote_oid,
                             submitted_at: bar.timestamp,
                             expires_at: bar.timestamp
                                 + chrono::Duration::seconds(
                                     self.config.execution.post_only_ttl_secs as i64,
                                 ),
+                            entry_z: Some(signal.zscore),
                         })
                         .map_err(|err| StrategyError::Position(err.to_string()))?;
                     events.push(LogEvent::EntrySubmitted);
@@ -925,119 +1071,41 @@ impl StrategyEngine {
         if let Some(exit_signal) = exit_signal
             && let Some(position) = self.state_machine.state().position.clone()
         {
-            let eth_side = OrderSide::close_for_qty(position.eth.qty);
-            let btc_side = OrderSide::close_for_qty(position.btc.qty);
-            let exit_order_type = self.exit_order_type();
-            let eth_order = OrderRequest {
-                symbol: Symbol::EthPerp,
-                side: eth_side,
-                qty: position.eth.qty.abs(),
-                order_type: exit_order_type,
-                limit_price: Some(self.limit_price(exit_order_type, eth_side, bar.eth_price)),
-                expires_after: None,
-            };
-            let btc_order = OrderRequest {
-                symbol: Symbol::BtcPerp,
-                side: btc_side,
-                qty: position.btc.qty.abs(),
-                order_type: exit_order_type,
-                limit_price: Some(self.limit_price(exit_order_type, btc_side, bar.btc_price)),
-                expires_after: None,
-            };
-            info!(
-                timestamp = %bar.timestamp.to_rfc3339(),
-                reason = ?exit_signal.reason,
-                direction = ?position.direction,
-                eth_side = ?eth_side,
-                eth_qty = %eth_order.qty,
-                eth_limit_price = %eth_order.limit_price.unwrap_or(bar.eth_price),
-                btc_side = ?btc_side,
-                btc_qty = %btc_order.qty,
-                btc_limit_price = %btc_order.limit_price.unwrap_or(bar.btc_price),
-                "exit order attempt"
-            );
-            let pair_fill = self
-                .execution
-                .close_pair(eth_order, btc_order)
-                .await
-                .map_err(|err| StrategyError::Execution(err.to_string()))?;
-            let close_eth_price = pair_fill.eth.avg_price.unwrap_or(bar.eth_price);
-            let close_btc_price = pair_fill.btc.avg_price.unwrap_or(bar.btc_price);
-            let mut model_realized_pnl =
-                compute_position_pnl(&position, close_eth_price, close_btc_price);
-            if let (Some(funding_eth), Some(funding_btc)) = (bar.funding_eth, bar.funding_btc) {
-                let interval_hours = bar
-                    .funding_interval_hours
-                    .filter(|value| *value > 0)
-                    .unwrap_or(8);
-                let holding_hours = (bar.timestamp - position.entry_time).num_hours().max(0) as u32;
-                let estimate = estimate_funding_cost(
-                    position.direction,
-                    position.eth.notional,
-                    position.btc.notional,
-                    &FundingRate {
-                        symbol: Symbol::EthPerp,
-                        rate: funding_eth,
-                        timestamp: bar.timestamp,
-                        interval_hours,
-                    },
-                    &FundingRate {
-                        symbol: Symbol::BtcPerp,
-                        rate: funding_btc,
-                        timestamp: bar.timestamp,
-                        interval_hours,
-                    },
-                    holding_hours,
-                )
-                .map_err(|err| StrategyError::Funding(err.to_string()))?;
-                model_realized_pnl -= estimate.cost_est;
-            }
-            let accounting = self
-                .fill_accounting_for_pair_fill(
-                    &pair_fill,
-                    bar.timestamp,
-                    close_eth_price,
-                    close_btc_price,
-                    model_realized_pnl,
-                )
-                .await
-                .unwrap_or_else(|| {
-                    Self::model_accounting(close_eth_price, close_btc_price, model_realized_pnl)
-                });
-            self.add_realized_pnl(accounting.realized_pnl);
-            trade_logs.push(TradeLog {
-                timestamp: bar.timestamp,
-                event: TradeEvent::Exit(exit_signal.reason),
-                direction: position.direction,
-                eth_qty: position.eth.qty,
-                btc_qty: position.btc.qty,
-                eth_price: accounting.eth_price,
-                btc_price: accounting.btc_price,
-                entry_time: position.entry_time,
-                entry_eth_price: position.eth.avg_price,
-                entry_btc_price: position.btc.avg_price,
-                realized_pnl: accounting.realized_pnl,
-                cumulative_realized_pnl: self.cumulative_realized_pnl,
-                fee: accounting.fee,
-                exchange_closed_pnl: accounting.exchange_closed_pnl,
-                pnl_source: accounting.source,
-                eth_ref_price: Some(bar.eth_price),
-                btc_ref_price: Some(bar.btc_price),
-                eth_slippage_bps: Some(slippage_bps_for_side(
-                    bar.eth_price,
-                    accounting.eth_price,
-                    eth_side,
-                )),
-                btc_slippage_bps: Some(slippage_bps_for_side(
-                    bar.btc_price,
-                    accounting.btc_price,
-                    btc_side,
-                )),
-            });
+            let trade_log = self
+                .close_position(&position, exit_signal.reason, &bar)
+                .await?;
+            trade_logs.push(trade_log);
             self.state_machine
                 .exit(exit_signal.reason, bar.timestamp)
                 .map_err(|err| StrategyError::Position(err.to_string()))?;
             events.push(LogEvent::Exit(exit_signal.reason));
+        } else if let Some(direction) = self
+            .state_machine
+            .state()
+            .position
+            .as_ref()
+            .map(|position| position.direction)
+        {
+            // Close later groups first so earlier group indices stay valid.
+            for (index, group_exit) in group_exits.into_iter().rev() {
+                let Some(group) = self
+                    .state_machine
+                    .state()
+                    .position_groups()
+                    .get(index)
+                    .cloned()
+                else {
+                    continue;
+                };
+                let trade_log = self
+                    .close_position(&group.to_snapshot(direction), group_exit.reason, &bar)
+                    .await?;
+                trade_logs.push(trade_log);
+                self.state_machine
+                    .exit_group(index)
+                    .map_err(|err| StrategyError::Position(err.to_string()))?;
+                events.push(LogEvent::Exit(group_exit.reason));
+            }
         }
 
         Ok(self.build_outcome(
@@ -1045,10 +1113,10 @@ impl StrategyEngine {
             z_snapshot,
             vol_snapshot,
             events,
-            w_eth,
-            w_btc,
-            notional_eth,
-            notional_btc,
+            w_base,
+            w_quote,
+            notional_base,
+            notional_quote,
             funding_cost_est,
             funding_skip,
             regime_half_life_bars,
@@ -1063,6 +1131,124 @@ impl StrategyEngine {
         ))
     }
 
+    async fn close_position(
+        &mut self,
+        position: &PositionSnapshot,
+        reason: ExitReason,
+        bar: &StrategyBar,
+    ) -> Result<TradeLog, StrategyError> {
+        let base_side = OrderSide::close_for_qty(position.base.qty);
+        let quote_side = OrderSide::close_for_qty(position.quote.qty);
+        let exit_order_type = self.exit_order_type();
+        let base_order = OrderRequest {
+            symbol: Symbol::Base,
+            side: base_side,
+            qty: position.base.qty.abs(),
+            order_type: exit_order_type,
+            limit_price: Some(self.limit_price(exit_order_type, base_side, bar.base_price)),
+            expires_after: None,
+        };
+        let quote_order = OrderRequest {
+            symbol: Symbol::Quote,
+            side: quote_side,
+            qty: position.quote.qty.abs(),
+            order_type: exit_order_type,
+            limit_price: Some(self.limit_price(exit_order_type, quote_side, bar.quote_price)),
+            expires_after: None,
+        };
+        info!(
+            timestamp = %bar.timestamp.to_rfc3339(),
+            reason = ?reason,
+            direction = ?position.direction,
+            base_side = ?base_side,
+            base_qty = %base_order.qty,
+            base_limit_price = %base_order.limit_price.unwrap_or(bar.base_price),
+            quote_side = ?quote_side,
+            quote_qty = %quote_order.qty,
+            quote_limit_price = %quote_order.limit_price.unwrap_or(bar.quote_price),
+            "exit order attempt"
+        );
+        let pair_fill = self
+            .execution
+            .close_pair(base_order, quote_order)
+            .await
+            .map_err(|err| StrategyError::Execution(err.to_string()))?;
+        let close_base_price = pair_fill.base.avg_price.unwrap_or(bar.base_price);
+        let close_quote_price = pair_fill.quote.avg_price.unwrap_or(bar.quote_price);
+        let mut model_realized_pnl =
+            compute_position_pnl(position, close_base_price, close_quote_price);
+        if let (Some(funding_base), Some(funding_quote)) = (bar.funding_base, bar.funding_quote) {
+            let interval_hours = bar
+                .funding_interval_hours
+                .filter(|value| *value > 0)
+                .unwrap_or(8);
+            let holding_hours = (bar.timestamp - position.entry_time).num_hours().max(0) as u32;
+            let estimate = estimate_funding_cost(
+                position.direction,
+                position.base.notional,
+                position.quote.notional,
+                &FundingRate {
+                    symbol: Symbol::Base,
+                    rate: funding_base,
+                    timestamp: bar.timestamp,
+                    interval_hours,
+                },
+                &FundingRate {
+                    symbol: Symbol::Quote,
+                    rate: funding_quote,
+                    timestamp: bar.timestamp,
+                    interval_hours,
+                },
+                holding_hours,
+            )
+            .map_err(|err| StrategyError::Funding(err.to_string()))?;
+            model_realized_pnl -= estimate.cost_est;
+        }
+        let accounting = self
+            .fill_accounting_for_pair_fill(
+                &pair_fill,
+                bar.timestamp,
+                close_base_price,
+                close_quote_price,
+                model_realized_pnl,
+            )
+            .await
+            .unwrap_or_else(|| {
+                Self::model_accounting(close_base_price, close_quote_price, model_realized_pnl)
+            });
+        self.add_realized_pnl(accounting.realized_pnl);
+        Ok(TradeLog {
+            timestamp: bar.timestamp,
+            pair: Some(self.config.pair.label()),
+            event: TradeEvent::Exit(reason),
+            direction: position.direction,
+            base_qty: position.base.qty,
+            quote_qty: position.quote.qty,
+            base_price: accounting.base_price,
+            quote_price: accounting.quote_price,
+            entry_time: position.entry_time,
+            entry_base_price: position.base.avg_price,
+            entry_quote_price: position.quote.avg_price,
+            realized_pnl: accounting.realized_pnl,
+            cumulative_realized_pnl: self.cumulative_realized_pnl,
+            fee: accounting.fee,
+            exchange_closed_pnl: accounting.exchange_closed_pnl,
+            pnl_source: accounting.source,
+            base_ref_price: Some(bar.base_price),
+            quote_ref_price: Some(bar.quote_price),
+            base_slippage_bps: Some(slippage_bps_for_side(
+                bar.base_price,
+                accounting.base_price,
+                base_side,
+            )),
+            quote_slippage_bps: Some(slippage_bps_for_side(
+                bar.quote_price,
+                accounting.quote_price,
+                quote_side,
+            )),
+        })
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn build_outcome(
         &self,
@@ -1070,10 +1256,10 @@ impl StrategyEngine {
         z_snapshot: crate::indicators::ZScoreSnapshot,
         vol_snapshot: crate::indicators::VolatilitySnapshot,
         events: Vec<LogEvent>,
-        w_eth: Option<Decimal>,
-        w_btc: Option<Decimal>,
-        notional_eth: Option<Decimal>,
-        notional_btc: Option<Decimal>,
+        w_base: Option<Decimal>,
+        w_quote: Option<Decimal>,
+        notional_base: Option<Decimal>,
+        notional_quote: Option<Decimal>,
         funding_cost_est: Option<Decimal>,
         funding_skip: Option<bool>,
         regime_half_life_bars: Option<f64>,
@@ -1091,28 +1277,31 @@ impl StrategyEngine {
             .state()
             .position
             .as_ref()
-            .map(|position| compute_position_pnl(position, bar.eth_price, bar.btc_price))
+            .map(|position| compute_position_pnl(position, bar.base_price, bar.quote_price))
             .unwrap_or(Decimal::ZERO);
         StrategyOutcome {
             state: self.state_machine.state().status,
             events: events.clone(),
             bar_log: BarLog {
                 timestamp: bar.timestamp,
-                eth_price: Some(bar.eth_price),
-                btc_price: Some(bar.btc_price),
-                r: Some(z_snapshot.r),
+                pair: Some(self.config.pair.label()),
+                base_price: Some(bar.base_price),
+                quote_price: Some(bar.quote_price),
+                r: relative_price(bar.base_price, bar.quote_price).ok(),
+                spread: self.spread_beta.map(|_| z_snapshot.r),
+                beta: self.spread_beta,
                 mu: z_snapshot.mean,
                 sigma: z_snapshot.sigma,
                 sigma_eff: z_snapshot.sigma_eff,
                 zscore: z_snapshot.zscore,
-                vol_eth: vol_snapshot.vol_eth,
-                vol_btc: vol_snapshot.vol_btc,
-                w_eth,
-                w_btc,
-                notional_eth,
-                notional_btc,
-                funding_eth: bar.funding_eth,
-                funding_btc: bar.funding_btc,
+                vol_base: vol_snapshot.vol_base,
+                vol_quote: vol_snapshot.vol_quote,
+                w_base,
+                w_quote,
+                notional_base,
+                notional_quote,
+                funding_base: bar.funding_base,
+                funding_quote: bar.funding_quote,
                 funding_cost_est,
                 funding_skip,
                 regime_half_life_bars,
@@ -1122,16 +1311,16 @@ impl StrategyEngine {
                 estimated_net_edge_bps,
                 cost_gate_required_net_edge_bps,
                 cost_gate_pass,
-                eth_best_bid: None,
-                eth_best_ask: None,
-                eth_bid_size: None,
-                eth_ask_size: None,
-                eth_spread_bps: None,
-                btc_best_bid: None,
-                btc_best_ask: None,
-                btc_bid_size: None,
-                btc_ask_size: None,
-                btc_spread_bps: None,
+                base_best_bid: None,
+                base_best_ask: None,
+                base_bid_size: None,
+                base_ask_size: None,
+                base_spread_bps: None,
+                quote_best_bid: None,
+                quote_best_ask: None,
+                quote_bid_size: None,
+                quote_ask_size: None,
+                quote_spread_bps: None,
                 entry_block_reason,
                 run_error: None,
                 unrealized_pnl,
@@ -1176,71 +1365,17 @@ impl StrategyEngine {
         }
     }
 
-    fn cost_gate_decision(
-        &self,
-        direction: TradeDirection,
-        zscore: Decimal,
-        sigma_eff: Option<Decimal>,
-        capital: Decimal,
-        funding_cost_est: Option<Decimal>,
-    ) -> Option<CostGateDecision> {
-        if !self.config.cost_gate.enabled {
-            return None;
-        }
-        let sigma_eff = sigma_eff?;
-        let gross_z_edge = (zscore.abs() - self.config.strategy.tp_z).max(Decimal::ZERO);
-        let expected_edge_bps = gross_z_edge * sigma_eff * Decimal::from(10_000u32);
-        let funding_bps = if capital > Decimal::ZERO {
-            funding_cost_est.unwrap_or(Decimal::ZERO) / capital * Decimal::from(10_000u32)
-        } else {
-            Decimal::ZERO
-        };
-        let estimated_cost_bps = self.config.cost_gate.entry_fee_bps
-            + self.config.cost_gate.exit_fee_bps
-            + self.config.cost_gate.slippage_bps
-            + self.config.cost_gate.spread_bps
-            + funding_bps;
-        let estimated_net_edge_bps = expected_edge_bps - estimated_cost_bps;
-        let required_net_edge_bps = self.config.cost_gate.min_net_edge_bps
-            + match direction {
-                TradeDirection::LongEthShortBtc => {
-                    self.config.cost_gate.long_eth_short_btc_extra_bps
-                }
-                TradeDirection::ShortEthLongBtc => {
-                    self.config.cost_gate.short_eth_long_btc_extra_bps
-                }
-            };
-        Some(CostGateDecision {
-            expected_edge_bps,
-            estimated_cost_bps,
-            estimated_net_edge_bps,
-            required_net_edge_bps,
-            pass: estimated_net_edge_bps >= required_net_edge_bps,
-        })
-    }
-
-    fn directional_size_multiplier(&self, direction: TradeDirection) -> Decimal {
-        match direction {
-            TradeDirection::LongEthShortBtc => {
-                self.config.directional_sizing.long_eth_short_btc_multiplier
-            }
-            TradeDirection::ShortEthLongBtc => {
-                self.config.directional_sizing.short_eth_long_btc_multiplier
-            }
-        }
-    }
-
     fn exposure_to_position(
         &self,
         exposure: &PairExposure,
         timestamp: DateTime<Utc>,
     ) -> Result<PositionSnapshot, StrategyError> {
-        let eth_qty = exposure.eth_qty();
-        let btc_qty = exposure.btc_qty();
-        let direction = if eth_qty > Decimal::ZERO || btc_qty < Decimal::ZERO {
-            TradeDirection::LongEthShortBtc
-        } else if eth_qty < Decimal::ZERO || btc_qty > Decimal::ZERO {
-            TradeDirection::ShortEthLongBtc
+        let base_qty = exposure.base_qty();
+        let quote_qty = exposure.quote_qty();
+        let direction = if base_qty > Decimal::ZERO || quote_qty < Decimal::ZERO {
+            TradeDirection::LongBaseShortQuote
+        } else if base_qty < Decimal::ZERO || quote_qty > Decimal::ZERO {
+            TradeDirection::ShortBaseLongQuote
         } else {
             return Err(StrategyError::Position(
                 "cannot infer direction from flat exchange exposure".to_string(),
@@ -1250,28 +1385,28 @@ impl StrategyEngine {
         Ok(PositionSnapshot {
             direction,
             entry_time: timestamp,
-            eth: PositionLeg {
-                qty: eth_qty,
+            base: PositionLeg {
+                qty: base_qty,
                 avg_price: exposure
-                    .eth
+                    .base
                     .as_ref()
                     .map(|position| position.entry_price)
                     .unwrap_or(Decimal::ZERO),
                 notional: exposure
-                    .eth
+                    .base
                     .as_ref()
                     .map(|position| position.notional)
                     .unwrap_or(Decimal::ZERO),
             },
-            btc: PositionLeg {
-                qty: btc_qty,
+            quote: PositionLeg {
+                qty: quote_qty,
                 avg_price: exposure
-                    .btc
+                    .quote
                     .as_ref()
                     .map(|position| position.entry_price)
                     .unwrap_or(Decimal::ZERO),
                 notional: exposure
-                    .btc
+                    .quote
                     .as_ref()
                     .map(|position| position.notional)
                     .unwrap_or(Decimal::ZERO),
@@ -1287,8 +1422,8 @@ impl StrategyEngine {
 
     async fn cancel_pending_entry_orders(&self, pending: &PendingEntrySnapshot) {
         for (symbol, oid) in [
-            (Symbol::EthPerp, pending.eth_order_id),
-            (Symbol::BtcPerp, pending.btc_order_id),
+            (Symbol::Base, pending.base_order_id),
+            (Symbol::Quote, pending.quote_order_id),
         ] {
             if let Err(err) = self.execution.cancel_order(symbol, oid).await {
                 warn!(
@@ -1302,13 +1437,13 @@ impl StrategyEngine {
     }
 
     fn model_accounting(
-        eth_price: Decimal,
-        btc_price: Decimal,
+        base_price: Decimal,
+        quote_price: Decimal,
         realized_pnl: Decimal,
     ) -> FillAccounting {
         FillAccounting {
-            eth_price,
-            btc_price,
+            base_price,
+            quote_price,
             fee: Decimal::ZERO,
             exchange_closed_pnl: None,
             realized_pnl,
@@ -1320,31 +1455,32 @@ impl StrategyEngine {
         &self,
         pair_fill: &PairFill,
         timestamp: DateTime<Utc>,
-        fallback_eth_price: Decimal,
-        fallback_btc_price: Decimal,
+        fallback_base_price: Decimal,
+        fallback_quote_price: Decimal,
         fallback_realized_pnl: Decimal,
     ) -> Option<FillAccounting> {
         self.fill_accounting_for_order_ids(
-            &[pair_fill.eth.oid, pair_fill.btc.oid],
-            &[Symbol::EthPerp, Symbol::BtcPerp],
+            &[pair_fill.base.oid, pair_fill.quote.oid],
+            &[Symbol::Base, Symbol::Quote],
             timestamp - Duration::minutes(5),
             timestamp,
-            fallback_eth_price,
-            fallback_btc_price,
+            fallback_base_price,
+            fallback_quote_price,
             fallback_realized_pnl,
         )
         .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn fill_accounting_for_order_ids(
         &self,
         order_ids: &[Option<u64>],
         expected_symbols: &[Symbol],
         start: DateTime<Utc>,
         end_hint: DateTime<Utc>,
-        fallback_eth_price: Decimal,
-        fallback_btc_price: Decimal,
-        _fallback_realized_pnl: Decimal,
+        fallback_base_price: Decimal,
+        fallback_quote_price: Decimal,
+        fallback_realized_pnl: Decimal,
     ) -> Option<FillAccounting> {
         let source = self.fill_source.as_ref()?;
         let order_ids: HashSet<u64> = order_ids.iter().copied().flatten().collect();
@@ -1383,22 +1519,23 @@ impl StrategyEngine {
                 return None;
             }
         }
-        Some(Self::summarize_exchange_fills(
-            &matched,
-            fallback_eth_price,
-            fallback_btc_price,
-        ))
+        let mut accounting =
+            Self::summarize_exchange_fills(&matched, fallback_base_price, fallback_quote_price);
+        if self.shared_account {
+            accounting.realized_pnl = fallback_realized_pnl - accounting.fee;
+        }
+        Some(accounting)
     }
 
     fn summarize_exchange_fills(
         fills: &[ExchangeFill],
-        fallback_eth_price: Decimal,
-        fallback_btc_price: Decimal,
+        fallback_base_price: Decimal,
+        fallback_quote_price: Decimal,
     ) -> FillAccounting {
-        let mut eth_px_sz = Decimal::ZERO;
-        let mut eth_sz = Decimal::ZERO;
-        let mut btc_px_sz = Decimal::ZERO;
-        let mut btc_sz = Decimal::ZERO;
+        let mut base_px_sz = Decimal::ZERO;
+        let mut base_sz = Decimal::ZERO;
+        let mut quote_px_sz = Decimal::ZERO;
+        let mut quote_sz = Decimal::ZERO;
         let mut fee = Decimal::ZERO;
         let mut closed_pnl = Decimal::ZERO;
 
@@ -1406,27 +1543,27 @@ impl StrategyEngine {
             fee += fill.fee;
             closed_pnl += fill.closed_pnl;
             match fill.coin {
-                Symbol::EthPerp => {
-                    eth_px_sz += fill.price * fill.size;
-                    eth_sz += fill.size;
+                Symbol::Base => {
+                    base_px_sz += fill.price * fill.size;
+                    base_sz += fill.size;
                 }
-                Symbol::BtcPerp => {
-                    btc_px_sz += fill.price * fill.size;
-                    btc_sz += fill.size;
+                Symbol::Quote => {
+                    quote_px_sz += fill.price * fill.size;
+                    quote_sz += fill.size;
                 }
             }
         }
 
         FillAccounting {
-            eth_price: if eth_sz > Decimal::ZERO {
-                eth_px_sz / eth_sz
+            base_price: if base_sz > Decimal::ZERO {
+                base_px_sz / base_sz
             } else {
-                fallback_eth_price
+                fallback_base_price
             },
-            btc_price: if btc_sz > Decimal::ZERO {
-                btc_px_sz / btc_sz
+            quote_price: if quote_sz > Decimal::ZERO {
+                quote_px_sz / quote_sz
             } else {
-                fallback_btc_price
+                fallback_quote_price
             },
             fee,
             exchange_closed_pnl: Some(closed_pnl),
@@ -1436,65 +1573,124 @@ impl StrategyEngine {
     }
 
     fn exposure_matches_position(exposure: &PairExposure, position: &PositionSnapshot) -> bool {
-        exposure.eth_qty() == position.eth.qty && exposure.btc_qty() == position.btc.qty
+        exposure.base_qty() == position.base.qty && exposure.quote_qty() == position.quote.qty
     }
 
     async fn residual_repair_trade_log(
         &mut self,
         position: &PositionSnapshot,
         timestamp: DateTime<Utc>,
-        eth_price: Decimal,
-        btc_price: Decimal,
+        base_price: Decimal,
+        quote_price: Decimal,
         repair_fill: Option<&(Symbol, OrderFill)>,
     ) -> TradeLog {
-        let fallback_eth_price = match repair_fill {
-            Some((Symbol::EthPerp, fill)) => fill.avg_price.unwrap_or(eth_price),
-            _ => eth_price,
+        let fallback_base_price = match repair_fill {
+            Some((Symbol::Base, fill)) => fill.avg_price.unwrap_or(base_price),
+            _ => base_price,
         };
-        let fallback_btc_price = match repair_fill {
-            Some((Symbol::BtcPerp, fill)) => fill.avg_price.unwrap_or(btc_price),
-            _ => btc_price,
+        let fallback_quote_price = match repair_fill {
+            Some((Symbol::Quote, fill)) => fill.avg_price.unwrap_or(quote_price),
+            _ => quote_price,
         };
         let model_realized_pnl =
-            compute_position_pnl(position, fallback_eth_price, fallback_btc_price);
+            compute_position_pnl(position, fallback_base_price, fallback_quote_price);
         let accounting = if let Some((symbol, fill)) = repair_fill {
             self.fill_accounting_for_order_ids(
                 &[fill.oid],
                 &[*symbol],
                 timestamp - Duration::minutes(5),
                 timestamp,
-                fallback_eth_price,
-                fallback_btc_price,
+                fallback_base_price,
+                fallback_quote_price,
                 model_realized_pnl,
             )
             .await
             .unwrap_or_else(|| {
-                Self::model_accounting(fallback_eth_price, fallback_btc_price, model_realized_pnl)
+                Self::model_accounting(
+                    fallback_base_price,
+                    fallback_quote_price,
+                    model_realized_pnl,
+                )
             })
         } else {
-            Self::model_accounting(fallback_eth_price, fallback_btc_price, model_realized_pnl)
+            Self::model_accounting(
+                fallback_base_price,
+                fallback_quote_price,
+                model_realized_pnl,
+            )
         };
         self.add_realized_pnl(accounting.realized_pnl);
         TradeLog {
             timestamp,
+            pair: Some(self.config.pair.label()),
             event: TradeEvent::ResidualRepair,
             direction: position.direction,
-            eth_qty: position.eth.qty,
-            btc_qty: position.btc.qty,
-            eth_price: accounting.eth_price,
-            btc_price: accounting.btc_price,
+            base_qty: position.base.qty,
+            quote_qty: position.quote.qty,
+            base_price: accounting.base_price,
+            quote_price: accounting.quote_price,
             entry_time: position.entry_time,
-            entry_eth_price: position.eth.avg_price,
-            entry_btc_price: position.btc.avg_price,
+            entry_base_price: position.base.avg_price,
+            entry_quote_price: position.quote.avg_price,
             realized_pnl: accounting.realized_pnl,
             cumulative_realized_pnl: self.cumulative_realized_pnl,
             fee: accounting.fee,
             exchange_closed_pnl: accounting.exchange_closed_pnl,
             pnl_source: accounting.source,
-            eth_ref_price: None,
-            btc_ref_price: None,
-            eth_slippage_bps: None,
-            btc_slippage_bps: None,
+            base_ref_price: None,
+            quote_ref_price: None,
+            base_slippage_bps: None,
+            quote_slippage_bps: None,
+        }
+    }
+}
+
+pub(crate) fn cost_gate_decision(
+    config: &Config,
+    direction: TradeDirection,
+    zscore: Decimal,
+    sigma_eff: Option<Decimal>,
+    capital: Decimal,
+    funding_cost_est: Option<Decimal>,
+) -> Option<CostGateDecision> {
+    if !config.cost_gate.enabled {
+        return None;
+    }
+    let sigma_eff = sigma_eff?;
+    let gross_z_edge = (zscore.abs() - config.strategy.tp_z).max(Decimal::ZERO);
+    let expected_edge_bps = gross_z_edge * sigma_eff * Decimal::from(10_000u32);
+    let funding_bps = if capital > Decimal::ZERO {
+        funding_cost_est.unwrap_or(Decimal::ZERO) / capital * Decimal::from(10_000u32)
+    } else {
+        Decimal::ZERO
+    };
+    let estimated_cost_bps = config.cost_gate.entry_fee_bps
+        + config.cost_gate.exit_fee_bps
+        + config.cost_gate.slippage_bps
+        + config.cost_gate.spread_bps
+        + funding_bps;
+    let estimated_net_edge_bps = expected_edge_bps - estimated_cost_bps;
+    let required_net_edge_bps = config.cost_gate.min_net_edge_bps
+        + match direction {
+            TradeDirection::LongBaseShortQuote => config.cost_gate.long_base_short_quote_extra_bps,
+            TradeDirection::ShortBaseLongQuote => config.cost_gate.short_base_long_quote_extra_bps,
+        };
+    Some(CostGateDecision {
+        expected_edge_bps,
+        estimated_cost_bps,
+        estimated_net_edge_bps,
+        required_net_edge_bps,
+        pass: estimated_net_edge_bps >= required_net_edge_bps,
+    })
+}
+
+pub(crate) fn directional_size_multiplier(config: &Config, direction: TradeDirection) -> Decimal {
+    match direction {
+        TradeDirection::LongBaseShortQuote => {
+            config.directional_sizing.long_base_short_quote_multiplier
+        }
+        TradeDirection::ShortBaseLongQuote => {
+            config.directional_sizing.short_base_long_quote_multiplier
         }
     }
 }
@@ -1549,17 +1745,17 @@ fn estimate_half_life_bars_decimal(values: &VecDeque<Decimal>) -> Option<f64> {
     }
 }
 
-fn entry_eth_side(direction: TradeDirection) -> OrderSide {
+fn entry_base_side(direction: TradeDirection) -> OrderSide {
     match direction {
-        TradeDirection::LongEthShortBtc => OrderSide::Buy,
-        TradeDirection::ShortEthLongBtc => OrderSide::Sell,
+        TradeDirection::LongBaseShortQuote => OrderSide::Buy,
+        TradeDirection::ShortBaseLongQuote => OrderSide::Sell,
     }
 }
 
-fn entry_btc_side(direction: TradeDirection) -> OrderSide {
+fn entry_quote_side(direction: TradeDirection) -> OrderSide {
     match direction {
-        TradeDirection::LongEthShortBtc => OrderSide::Sell,
-        TradeDirection::ShortEthLongBtc => OrderSide::Buy,
+        TradeDirection::LongBaseShortQuote => OrderSide::Sell,
+        TradeDirection::ShortBaseLongQuote => OrderSide::Buy,
     }
 }
 
@@ -1580,10 +1776,10 @@ fn slippage_bps_for_side(
 
 fn compute_position_pnl(
     position: &PositionSnapshot,
-    eth_price: Decimal,
-    btc_price: Decimal,
+    base_price: Decimal,
+    quote_price: Decimal,
 ) -> Decimal {
-    let eth_pnl = position.eth.qty * (eth_price - position.eth.avg_price);
-    let btc_pnl = position.btc.qty * (btc_price - position.btc.avg_price);
-    eth_pnl + btc_pnl
+    let base_pnl = position.base.qty * (base_price - position.base.avg_price);
+    let quote_pnl = position.quote.qty * (quote_price - position.quote.avg_price);
+    base_pnl + quote_pnl
 }
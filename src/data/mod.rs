@@ -9,7 +9,7 @@ use serde_json::Value;
 use thiserror::Error;
 use tokio::sync::Mutex;
 
-use crate::config::{PriceField, Symbol};
+use crate::config::{BarInterval, PairConfig, PriceField, Symbol};
 use crate::util::http::{HyperliquidHttpTimeouts, hyperliquid_reqwest_client};
 use crate::util::rate_limiter::{FixedRateLimiter, RateLimiter};
 
@@ -42,6 +42,9 @@ pub struct PriceBar {
     pub mid: Option<Decimal>,
     pub mark: Option<Decimal>,
     pub close: Option<Decimal>,
+    pub open: Option<Decimal>,
+    pub high: Option<Decimal>,
+    pub low: Option<Decimal>,
 }
 
 impl PriceBar {
@@ -58,9 +61,24 @@ impl PriceBar {
             mid,
             mark,
             close,
+            open: None,
+            high: None,
+            low: None,
         }
     }
 
+    pub fn with_ohlc(
+        mut self,
+        open: Option<Decimal>,
+        high: Option<Decimal>,
+        low: Option<Decimal>,
+    ) -> Self {
+        self.open = open;
+        self.high = high;
+        self.low = low;
+        self
+    }
+
     pub fn effective_price(&self, preferred: PriceField) -> Option<Decimal> {
         match preferred {
             PriceField::Mid => self.mid.or(self.mark).or(self.close),
@@ -74,6 +92,9 @@ impl PriceBar {
             ("mid", self.mid),
             ("mark", self.mark),
             ("close", self.close),
+            ("open", self.open),
+            ("high", self.high),
+            ("low", self.low),
         ] {
             if let Some(price) = value
                 && price <= Decimal::ZERO
@@ -81,6 +102,13 @@ impl PriceBar {
                 return Err(DataError::InvalidPrice(format!("{} must be > 0", label)));
             }
         }
+        if let (Some(high), Some(low)) = (self.high, self.low)
+            && high < low
+        {
+            return Err(DataError::InconsistentData(
+                "high must be >= low".to_string(),
+            ));
+        }
         Ok(())
     }
 }
@@ -143,8 +171,8 @@ impl OrderBookSnapshot {
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct PairOrderBookSnapshot {
-    pub eth: OrderBookSnapshot,
-    pub btc: OrderBookSnapshot,
+    pub base: OrderBookSnapshot,
+    pub quote: OrderBookSnapshot,
 }
 
 #[async_trait::async_trait]
@@ -222,6 +250,8 @@ pub struct HyperliquidPriceSource {
     base_url: String,
     http: Arc<dyn HttpClient>,
     rate_limiter: Arc<dyn RateLimiter>,
+    pair: PairConfig,
+    interval: BarInterval,
 }
 
 impl HyperliquidPriceSource {
@@ -230,6 +260,8 @@ impl HyperliquidPriceSource {
             base_url: base_url.into(),
             http: Arc::new(ReqwestHttpClient::new()),
             rate_limiter: Arc::new(FixedRateLimiter::new(Duration::from_millis(200))),
+            pair: PairConfig::default(),
+            interval: BarInterval::default(),
         }
     }
 
@@ -250,26 +282,36 @@ impl HyperliquidPriceSource {
             base_url: base_url.into(),
             http,
             rate_limiter,
+            pair: PairConfig::default(),
+            interval: BarInterval::default(),
         }
     }
 
+    pub fn with_pair(mut self, pair: PairConfig) -> Self {
+        self.pair = pair;
+        self
+    }
+
+    pub fn with_interval(mut self, interval: BarInterval) -> Self {
+        self.interval = interval;
+        self
+    }
+
     fn endpoint_url(&self) -> String {
         format!("{}/info", self.base_url.trim_end_matches('/'))
     }
 
-    fn symbol_string(symbol: Symbol) -> &'static str {
-        match symbol {
-            Symbol::EthPerp => "ETH",
-            Symbol::BtcPerp => "BTC",
-        }
+    fn symbol_string(&self, symbol: Symbol) -> &str {
+        self.pair.coin(symbol)
     }
 
     fn normalize_range(
+        &self,
         start: DateTime<Utc>,
         end: DateTime<Utc>,
     ) -> Result<(DateTime<Utc>, DateTime<Utc>), DataError> {
-        let start = align_to_bar_close(start)?;
-        let end = align_to_bar_close(end)?;
+        let start = align_to_bar_close(start, self.interval)?;
+        let end = align_to_bar_close(end, self.interval)?;
         if end < start {
             return Err(DataError::InvalidTimestamp(
                 "end must be >= start".to_string(),
@@ -337,7 +379,9 @@ impl HyperliquidPriceSource {
                 .get("c")
                 .ok_or_else(|| DataError::MissingData("candle close missing".to_string()))?;
             let close = Self::parse_decimal(close)?;
-            let bar = PriceBar::new(symbol, timestamp, Some(close), Some(close), Some(close));
+            let optional = |key: &str| candle.get(key).map(Self::parse_decimal).transpose();
+            let bar = PriceBar::new(symbol, timestamp, Some(close), Some(close), Some(close))
+                .with_ohlc(optional("o")?, optional("h")?, optional("l")?);
             bar.validate()?;
             bars.push(bar);
         }
@@ -399,7 +443,7 @@ impl PriceSource for HyperliquidPriceSource {
         symbol: Symbol,
         timestamp: DateTime<Utc>,
     ) -> Result<PriceBar, DataError> {
-        let aligned = align_to_bar_close(timestamp)?;
+        let aligned = align_to_bar_close(timestamp, self.interval)?;
         let mut bars = self.fetch_history(symbol, aligned, aligned).await?;
         let bar = bars
             .iter()
@@ -408,7 +452,7 @@ impl PriceSource for HyperliquidPriceSource {
             .ok_or_else(|| {
                 DataError::MissingData(format!(
                     "bar not found for {} at {}",
-                    Self::symbol_string(symbol),
+                    self.symbol_string(symbol),
                     aligned.to_rfc3339()
                 ))
             })?;
@@ -421,9 +465,9 @@ impl PriceSource for HyperliquidPriceSource {
         start: DateTime<Utc>,
         end: DateTime<Utc>,
     ) -> Result<Vec<PriceBar>, DataError> {
-        let (start, end) = Self::normalize_range(start, end)?;
+        let (start, end) = self.normalize_range(start, end)?;
         let url = self.endpoint_url();
-        let interval_ms = 900_000i64;
+        let interval_ms = self.interval.seconds() * 1000;
         let max_bars = 5_000i64;
         let max_span_ms = interval_ms * (max_bars - 1);
 
@@ -439,8 +483,8 @@ impl PriceSource for HyperliquidPriceSource {
             let body = serde_json::json!({
                 "type": "candleSnapshot",
                 "req": {
-                    "coin": Self::symbol_string(symbol),
-                    "interval": "15m",
+                    "coin": self.symbol_string(symbol),
+                    "interval": self.interval.as_str(),
                     "startTime": start_ms,
                     "endTime": end_ms,
                 }
@@ -475,7 +519,7 @@ impl BookSource for HyperliquidPriceSource {
         let url = self.endpoint_url();
         let body = serde_json::json!({
             "type": "l2Book",
-            "coin": Self::symbol_string(symbol),
+            "coin": self.symbol_string(symbol),
         });
         self.rate_limiter.wait().await;
         let response = self.http.post(&url, body).await?;
@@ -493,22 +537,23 @@ pub type VariationalPriceSource = HyperliquidPriceSource;
 #[derive(Debug, Clone, PartialEq)]
 pub struct PriceSnapshot {
     pub timestamp: DateTime<Utc>,
-    pub eth: Decimal,
-    pub btc: Decimal,
+    pub base: Decimal,
+    pub quote: Decimal,
     pub field: PriceField,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct PriceBarsSnapshot {
     pub snapshot: PriceSnapshot,
-    pub eth_bar: PriceBar,
-    pub btc_bar: PriceBar,
+    pub base_bar: PriceBar,
+    pub quote_bar: PriceBar,
 }
 
 #[derive(Clone)]
 pub struct PriceFetcher {
     source: Arc<dyn PriceSource>,
     price_field: PriceField,
+    interval: BarInterval,
     last_snapshot: Arc<Mutex<Option<PriceSnapshot>>>,
 }
 
@@ -517,10 +562,16 @@ impl PriceFetcher {
         Self {
             source,
             price_field,
+            interval: BarInterval::default(),
             last_snapshot: Arc::new(Mutex::new(None)),
         }
     }
 
+    pub fn with_interval(mut self, interval: BarInterval) -> Self {
+        self.interval = interval;
+        self
+    }
+
     pub async fn fetch_pair_prices(
         &self,
         timestamp: DateTime<Utc>,
@@ -532,55 +583,55 @@ impl PriceFetcher {
         &self,
         timestamp: DateTime<Utc>,
     ) -> Result<PriceBarsSnapshot, DataError> {
-        let aligned = align_to_bar_close(timestamp)?;
-        let eth_bar = self.source.fetch_bar(Symbol::EthPerp, aligned).await?;
-        let btc_bar = self.source.fetch_bar(Symbol::BtcPerp, aligned).await?;
-        eth_bar.validate()?;
-        btc_bar.validate()?;
+        let aligned = align_to_bar_close(timestamp, self.interval)?;
+        let base_bar = self.source.fetch_bar(Symbol::Base, aligned).await?;
+        let quote_bar = self.source.fetch_bar(Symbol::Quote, aligned).await?;
+        base_bar.validate()?;
+        quote_bar.validate()?;
 
-        if eth_bar.symbol != Symbol::EthPerp || btc_bar.symbol != Symbol::BtcPerp {
+        if base_bar.symbol != Symbol::Base || quote_bar.symbol != Symbol::Quote {
             return Err(DataError::InconsistentData(
                 "unexpected symbols in price bars".to_string(),
             ));
         }
-        if eth_bar.timestamp != btc_bar.timestamp {
+        if base_bar.timestamp != quote_bar.timestamp {
             return Err(DataError::InconsistentData(
                 "timestamp mismatch between ETH and BTC".to_string(),
             ));
         }
-        if eth_bar.timestamp != aligned {
+        if base_bar.timestamp != aligned {
             return Err(DataError::InconsistentData(
                 "bar timestamp does not match requested close".to_string(),
             ));
         }
 
         let mut last_snapshot = self.last_snapshot.lock().await;
-        let eth_price = match eth_bar.effective_price(self.price_field) {
+        let base_price = match base_bar.effective_price(self.price_field) {
             Some(price) => price,
             None => last_snapshot
                 .as_ref()
-                .map(|snapshot| snapshot.eth)
+                .map(|snapshot| snapshot.base)
                 .ok_or_else(|| DataError::MissingData("ETH price missing".to_string()))?,
         };
-        let btc_price = match btc_bar.effective_price(self.price_field) {
+        let quote_price = match quote_bar.effective_price(self.price_field) {
             Some(price) => price,
             None => last_snapshot
                 .as_ref()
-                .map(|snapshot| snapshot.btc)
+                .map(|snapshot| snapshot.quote)
                 .ok_or_else(|| DataError::MissingData("BTC price missing".to_string()))?,
         };
 
         let snapshot = PriceSnapshot {
             timestamp: aligned,
-            eth: eth_price,
-            btc: btc_price,
+            base: base_price,
+            quote: quote_price,
             field: self.price_field,
         };
         *last_snapshot = Some(snapshot.clone());
         Ok(PriceBarsSnapshot {
             snapshot,
-            eth_bar,
-            btc_bar,
+            base_bar,
+            quote_bar,
         })
     }
 }
@@ -596,9 +647,9 @@ impl BookFetcher {
     }
 
     pub async fn fetch_pair_books(&self) -> Result<PairOrderBookSnapshot, DataError> {
-        let eth = self.source.fetch_book(Symbol::EthPerp).await?;
-        let btc = self.source.fetch_book(Symbol::BtcPerp).await?;
-        Ok(PairOrderBookSnapshot { eth, btc })
+        let base = self.source.fetch_book(Symbol::Base).await?;
+        let quote = self.source.fetch_book(Symbol::Quote).await?;
+        Ok(PairOrderBookSnapshot { base, quote })
     }
 }
 
@@ -794,8 +845,8 @@ pub struct PriceHistorySet {
     z_capacity: usize,
     vol_capacity: usize,
     sigma_capacity: usize,
-    eth: SymbolHistory,
-    btc: SymbolHistory,
+    base: SymbolHistory,
+    quote: SymbolHistory,
     last_timestamp: Option<DateTime<Utc>>,
 }
 
@@ -814,59 +865,60 @@ impl PriceHistorySet {
             z_capacity,
             vol_capacity,
             sigma_capacity,
-            eth: SymbolHistory::new(z_capacity, vol_capacity, sigma_capacity),
-            btc: SymbolHistory::new(z_capacity, vol_capacity, sigma_capacity),
+            base: SymbolHistory::new(z_capacity, vol_capacity, sigma_capacity),
+            quote: SymbolHistory::new(z_capacity, vol_capacity, sigma_capacity),
             last_timestamp: None,
         })
     }
 
-    pub fn push_pair(&mut self, eth_bar: PriceBar, btc_bar: PriceBar) -> Result<(), DataError> {
-        if eth_bar.symbol != Symbol::EthPerp || btc_bar.symbol != Symbol::BtcPerp {
+    pub fn push_pair(&mut self, base_bar: PriceBar, quote_bar: PriceBar) -> Result<(), DataError> {
+        if base_bar.symbol != Symbol::Base || quote_bar.symbol != Symbol::Quote {
             return Err(DataError::InconsistentData(
                 "expected ETH and BTC bars".to_string(),
             ));
         }
-        if eth_bar.timestamp != btc_bar.timestamp {
+        if base_bar.timestamp != quote_bar.timestamp {
             return Err(DataError::InconsistentData(
                 "timestamp mismatch between ETH and BTC".to_string(),
             ));
         }
         if let Some(last) = self.last_timestamp
-            && eth_bar.timestamp <= last
+            && base_bar.timestamp <= last
         {
             return Err(DataError::InvalidTimestamp(
                 "timestamp must be strictly increasing".to_string(),
             ));
         }
-        eth_bar.validate()?;
-        btc_bar.validate()?;
+        base_bar.validate()?;
+        quote_bar.validate()?;
 
-        self.eth.push(eth_bar.clone());
-        self.btc.push(btc_bar.clone());
-        self.last_timestamp = Some(eth_bar.timestamp);
+        self.base.push(base_bar.clone());
+        self.quote.push(quote_bar.clone());
+        self.last_timestamp = Some(base_bar.timestamp);
         Ok(())
     }
 
     pub fn is_warmed_up(&self, window: PriceWindow) -> bool {
         match window {
             PriceWindow::ZScore => {
-                self.eth.zscore.len() >= self.z_capacity && self.btc.zscore.len() >= self.z_capacity
+                self.base.zscore.len() >= self.z_capacity
+                    && self.quote.zscore.len() >= self.z_capacity
             }
             PriceWindow::Volatility => {
-                self.eth.volatility.len() >= self.vol_capacity
-                    && self.btc.volatility.len() >= self.vol_capacity
+                self.base.volatility.len() >= self.vol_capacity
+                    && self.quote.volatility.len() >= self.vol_capacity
             }
             PriceWindow::SigmaQuantile => {
-                self.eth.sigma.len() >= self.sigma_capacity
-                    && self.btc.sigma.len() >= self.sigma_capacity
+                self.base.sigma.len() >= self.sigma_capacity
+                    && self.quote.sigma.len() >= self.sigma_capacity
             }
         }
     }
 
     pub fn window(&self, symbol: Symbol, window: PriceWindow) -> impl Iterator<Item = &PriceBar> {
         let history = match symbol {
-            Symbol::EthPerp => &self.eth,
-            Symbol::BtcPerp => &self.btc,
+            Symbol::Base => &self.base,
+            Symbol::Quote => &self.quote,
         };
         match window {
             PriceWindow::ZScore => history.zscore.iter(),
@@ -876,9 +928,13 @@ impl PriceHistorySet {
     }
 }
 
-pub fn align_to_bar_close(timestamp: DateTime<Utc>) -> Result<DateTime<Utc>, DataError> {
+/// Floors `timestamp` to the start of its bar on the `interval` grid.
+pub fn align_to_bar_close(
+    timestamp: DateTime<Utc>,
+    interval: BarInterval,
+) -> Result<DateTime<Utc>, DataError> {
     let seconds = timestamp.timestamp();
-    let aligned = seconds - seconds.rem_euclid(900);
+    let aligned = seconds - seconds.rem_euclid(interval.seconds());
     Utc.timestamp_opt(aligned, 0)
         .single()
         .ok_or_else(|| DataError::InvalidTimestamp("aligned timestamp must be valid".to_string()))
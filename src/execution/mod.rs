@@ -17,7 +17,7 @@ use thiserror::Error;
 use tokio::sync::Mutex as AsyncMutex;
 use tokio::time::sleep;
 
-use crate::config::{OrderType, Symbol};
+use crate::config::{OrderType, PairConfig, Symbol};
 use crate::state::PositionSnapshot;
 use crate::util::http::{HyperliquidHttpTimeouts, hyperliquid_reqwest_client};
 use crate::util::rate_limiter::{FixedRateLimiter, RateLimiter};
@@ -631,6 +631,7 @@ pub struct LiveOrderExecutor {
     is_testnet: bool,
     leverage: Option<u32>,
     is_cross: bool,
+    pair: PairConfig,
 }
 
 impl LiveOrderExecutor {
@@ -676,6 +677,7 @@ impl LiveOrderExecutor {
             is_testnet,
             leverage: None,
             is_cross: true,
+            pair: PairConfig::default(),
         }
     }
 
@@ -711,6 +713,11 @@ impl LiveOrderExecutor {
         self
     }
 
+    pub fn with_pair(mut self, pair: PairConfig) -> Self {
+        self.pair = pair;
+        self
+    }
+
     fn infer_testnet(base_url: &str) -> bool {
         base_url.contains("testnet")
     }
@@ -734,10 +741,8 @@ impl LiveOrderExecutor {
                     .map_err(|err| ExecutionError::Fatal(err.to_string()))?;
                 let mut specs = HashMap::new();
                 for (index, asset) in meta.universe.into_iter().enumerate() {
-                    let symbol = match asset.name.as_str() {
-                        "ETH" => Symbol::EthPerp,
-                        "BTC" => Symbol::BtcPerp,
-                        _ => continue,
+                    let Some(symbol) = self.pair.symbol_for_coin(&asset.name) else {
+                        continue;
                     };
                     specs.insert(
                         symbol,
@@ -747,10 +752,11 @@ impl LiveOrderExecutor {
                         },
                     );
                 }
-                if !specs.contains_key(&Symbol::EthPerp) || !specs.contains_key(&Symbol::BtcPerp) {
-                    return Err(ExecutionError::Fatal(
-                        "meta response missing ETH/BTC assets".to_string(),
-                    ));
+                if !specs.contains_key(&Symbol::Base) || !specs.contains_key(&Symbol::Quote) {
+                    return Err(ExecutionError::Fatal(format!(
+                        "meta response missing {} assets",
+                        self.pair.label()
+                    )));
                 }
                 Ok(specs)
             }
@@ -1034,14 +1040,14 @@ pub struct ExecutionEngine {
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RestingPairOrders {
-    pub eth_oid: u64,
-    pub btc_oid: u64,
+    pub base_oid: u64,
+    pub quote_oid: u64,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PairFill {
-    pub eth: OrderFill,
-    pub btc: OrderFill,
+    pub base: OrderFill,
+    pub quote: OrderFill,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -1057,29 +1063,31 @@ impl ExecutionEngine {
 
     pub async fn open_pair(
         &self,
-        eth_order: OrderRequest,
-        btc_order: OrderRequest,
+        base_order: OrderRequest,
+        quote_order: OrderRequest,
     ) -> Result<PairOpenOutcome, ExecutionError> {
-        let eth_result = self.retry_submit_result(&eth_order).await?;
-        match eth_result {
-            OrderSubmitResult::Filled(eth_fill) => match self.retry_submit_result(&btc_order).await
-            {
-                Ok(OrderSubmitResult::Filled(btc_fill)) => Ok(PairOpenOutcome::Filled(PairFill {
-                    eth: eth_fill,
-                    btc: btc_fill,
-                })),
-                Ok(OrderSubmitResult::Resting { .. }) => {
-                    let rollback = self
-                        .retry_close(&OrderRequest {
-                            symbol: eth_order.symbol,
-                            side: OrderSide::close_for_qty(eth_fill.qty),
-                            qty: eth_fill.qty.abs(),
-                            order_type: OrderType::Market,
-                            limit_price: eth_order.limit_price,
-                            expires_after: None,
-                        })
-                        .await;
-                    match rollback {
+        let base_result = self.retry_submit_result(&base_order).await?;
+        match base_result {
+            OrderSubmitResult::Filled(base_fill) => {
+                match self.retry_submit_result(&quote_order).await {
+                    Ok(OrderSubmitResult::Filled(quote_fill)) => {
+                        Ok(PairOpenOutcome::Filled(PairFill {
+                            base: base_fill,
+                            quote: quote_fill,
+                        }))
+                    }
+                    Ok(OrderSubmitResult::Resting { .. }) => {
+                        let rollback = self
+                            .retry_close(&OrderRequest {
+                                symbol: base_order.symbol,
+                                side: OrderSide::close_for_qty(base_fill.qty),
+                                qty: base_fill.qty.abs(),
+                                order_type: OrderType::Market,
+                                limit_price: base_order.limit_price,
+                                expires_after: None,
+                            })
+                            .await;
+                        match rollback {
                         Ok(_) => Err(ExecutionError::PartialFill(
                             "open second leg is resting while first leg filled; rollback executed"
                                 .to_string(),
@@ -1088,44 +1096,45 @@ impl ExecutionEngine {
                             "open second leg is resting while first leg filled; rollback failed: {rollback_err}"
                         ))),
                     }
-                }
-                Err(err) => {
-                    let rollback = self
-                        .retry_close(&OrderRequest {
-                            symbol: eth_order.symbol,
-                            side: OrderSide::close_for_qty(eth_fill.qty),
-                            qty: eth_fill.qty.abs(),
-                            order_type: OrderType::Market,
-                            limit_price: eth_order.limit_price,
-                            expires_after: None,
-                        })
-                        .await;
-                    match rollback {
-                        Ok(_) => Err(ExecutionError::PartialFill(format!(
-                            "open second leg failed: {err}; rollback executed"
-                        ))),
-                        Err(rollback_err) => Err(ExecutionError::PartialFill(format!(
-                            "open second leg failed: {err}; rollback failed: {rollback_err}"
-                        ))),
+                    }
+                    Err(err) => {
+                        let rollback = self
+                            .retry_close(&OrderRequest {
+                                symbol: base_order.symbol,
+                                side: OrderSide::close_for_qty(base_fill.qty),
+                                qty: base_fill.qty.abs(),
+                                order_type: OrderType::Market,
+                                limit_price: base_order.limit_price,
+                                expires_after: None,
+                            })
+                            .await;
+                        match rollback {
+                            Ok(_) => Err(ExecutionError::PartialFill(format!(
+                                "open second leg failed: {err}; rollback executed"
+                            ))),
+                            Err(rollback_err) => Err(ExecutionError::PartialFill(format!(
+                                "open second leg failed: {err}; rollback failed: {rollback_err}"
+                            ))),
+                        }
                     }
                 }
-            },
-            OrderSubmitResult::Resting { oid: eth_oid } => {
-                match self.retry_submit_result(&btc_order).await {
-                    Ok(OrderSubmitResult::Resting { oid: btc_oid }) => {
+            }
+            OrderSubmitResult::Resting { oid: base_oid } => {
+                match self.retry_submit_result(&quote_order).await {
+                    Ok(OrderSubmitResult::Resting { oid: quote_oid }) => {
                         Ok(PairOpenOutcome::Resting(RestingPairOrders {
-                            eth_oid,
-                            btc_oid,
+                            base_oid,
+                            quote_oid,
                         }))
                     }
-                    Ok(OrderSubmitResult::Filled(btc_fill)) => {
+                    Ok(OrderSubmitResult::Filled(quote_fill)) => {
                         let rollback = self
                             .retry_close(&OrderRequest {
-                                symbol: btc_order.symbol,
-                                side: OrderSide::close_for_qty(btc_fill.qty),
-                                qty: btc_fill.qty.abs(),
+                                symbol: quote_order.symbol,
+                                side: OrderSide::close_for_qty(quote_fill.qty),
+                                qty: quote_fill.qty.abs(),
                                 order_type: OrderType::Market,
-                                limit_price: btc_order.limit_price,
+                                limit_price: quote_order.limit_price,
                                 expires_after: None,
                             })
                             .await;
@@ -1140,7 +1149,7 @@ impl ExecutionEngine {
                         }
                     }
                     Err(err) => {
-                        let cancel = self.retry_cancel(eth_order.symbol, eth_oid).await;
+                        let cancel = self.retry_cancel(base_order.symbol, base_oid).await;
                         match cancel {
                             Ok(()) => Err(ExecutionError::Fatal(format!(
                                 "open second leg failed while first leg was resting; first leg cancelled: {err}"
@@ -1157,22 +1166,22 @@ impl ExecutionEngine {
 
     pub async fn close_pair(
         &self,
-        eth_order: OrderRequest,
-        btc_order: OrderRequest,
+        base_order: OrderRequest,
+        quote_order: OrderRequest,
     ) -> Result<PairFill, ExecutionError> {
-        let eth_fill = match self.retry_close(&eth_order).await {
+        let base_fill = match self.retry_close(&base_order).await {
             Ok(fill) => fill,
             Err(err) => return Err(err),
         };
-        let btc_fill = match self.retry_close(&btc_order).await {
+        let quote_fill = match self.retry_close(&quote_order).await {
             Ok(fill) => fill,
             Err(err) => {
                 let rollback_order = OrderRequest {
-                    symbol: eth_order.symbol,
-                    side: OrderSide::close_for_qty(eth_fill.qty),
-                    qty: eth_fill.qty.abs(),
-                    order_type: eth_order.order_type,
-                    limit_price: eth_order.limit_price,
+                    symbol: base_order.symbol,
+                    side: OrderSide::close_for_qty(base_fill.qty),
+                    qty: base_fill.qty.abs(),
+                    order_type: base_order.order_type,
+                    limit_price: base_order.limit_price,
                     expires_after: None,
                 };
                 let rollback = self.retry_submit(&rollback_order).await;
@@ -1187,8 +1196,8 @@ impl ExecutionEngine {
             }
         };
         Ok(PairFill {
-            eth: eth_fill,
-            btc: btc_fill,
+            base: base_fill,
+            quote: quote_fill,
         })
     }
 
@@ -1196,33 +1205,33 @@ impl ExecutionEngine {
         &self,
         position: &PositionSnapshot,
     ) -> Result<Option<(Symbol, OrderFill)>, ExecutionError> {
-        if position.eth.qty != Decimal::ZERO && position.btc.qty == Decimal::ZERO {
+        if position.base.qty != Decimal::ZERO && position.quote.qty == Decimal::ZERO {
             let order = OrderRequest {
-                symbol: Symbol::EthPerp,
-                side: OrderSide::close_for_qty(position.eth.qty),
-                qty: position.eth.qty.abs(),
+                symbol: Symbol::Base,
+                side: OrderSide::close_for_qty(position.base.qty),
+                qty: position.base.qty.abs(),
                 order_type: OrderType::Market,
-                limit_price: Some(position.eth.avg_price),
+                limit_price: Some(position.base.avg_price),
                 expires_after: None,
             };
             return self
                 .retry_close(&order)
                 .await
-                .map(|fill| Some((Symbol::EthPerp, fill)));
+                .map(|fill| Some((Symbol::Base, fill)));
         }
-        if position.btc.qty != Decimal::ZERO && position.eth.qty == Decimal::ZERO {
+        if position.quote.qty != Decimal::ZERO && position.base.qty == Decimal::ZERO {
             let order = OrderRequest {
-                symbol: Symbol::BtcPerp,
-                side: OrderSide::close_for_qty(position.btc.qty),
-                qty: position.btc.qty.abs(),
+                symbol: Symbol::Quote,
+                side: OrderSide::close_for_qty(position.quote.qty),
+                qty: position.quote.qty.abs(),
                 order_type: OrderType::Market,
-                limit_price: Some(position.btc.avg_price),
+                limit_price: Some(position.quote.avg_price),
                 expires_after: None,
             };
             return self
                 .retry_close(&order)
                 .await
-                .map(|fill| Some((Symbol::BtcPerp, fill)));
+                .map(|fill| Some((Symbol::Quote, fill)));
         }
         Ok(None)
     }
@@ -2,12 +2,12 @@ use std::collections::{HashMap, VecDeque};
 use std::str::FromStr;
 use std::sync::Arc;
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, DurationRound, TimeDelta, TimeZone, Utc};
 use rust_decimal::Decimal;
 use serde_json::Value;
 use thiserror::Error;
 
-use crate::config::{FundingConfig, FundingMode, Symbol};
+use crate::config::{FundingConfig, FundingMode, PairConfig, Symbol};
 use crate::core::TradeDirection;
 use crate::util::http::{HyperliquidHttpTimeouts, hyperliquid_reqwest_client};
 
@@ -23,6 +23,9 @@ pub enum FundingError {
     InvalidConfig(String),
 }
 
+/// Entries Hyperliquid returns per `fundingHistory` request.
+const FUNDING_HISTORY_PAGE_SIZE: usize = 500;
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct FundingRate {
     pub symbol: Symbol,
@@ -44,8 +47,8 @@ impl FundingRate {
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct FundingSnapshot {
-    pub eth: FundingRate,
-    pub btc: FundingRate,
+    pub base: FundingRate,
+    pub quote: FundingRate,
     pub interval_hours: u32,
 }
 
@@ -113,6 +116,7 @@ pub struct HyperliquidFundingSource {
     base_url: String,
     http: Arc<dyn FundingHttpClient>,
     interval_hours: u32,
+    pair: PairConfig,
 }
 
 impl HyperliquidFundingSource {
@@ -121,6 +125,7 @@ impl HyperliquidFundingSource {
             base_url: base_url.into(),
             http: Arc::new(ReqwestFundingClient::new()),
             interval_hours: 1,
+            pair: PairConfig::default(),
         }
     }
 
@@ -129,18 +134,21 @@ impl HyperliquidFundingSource {
             base_url: base_url.into(),
             http,
             interval_hours: 1,
+            pair: PairConfig::default(),
         }
     }
 
+    pub fn with_pair(mut self, pair: PairConfig) -> Self {
+        self.pair = pair;
+        self
+    }
+
     fn endpoint_url(&self) -> String {
         format!("{}/info", self.base_url.trim_end_matches('/'))
     }
 
-    fn symbol_string(symbol: Symbol) -> &'static str {
-        match symbol {
-            Symbol::EthPerp => "ETH",
-            Symbol::BtcPerp => "BTC",
-        }
+    fn symbol_string(&self, symbol: Symbol) -> &str {
+        self.pair.coin(symbol)
     }
 
     fn parse_decimal(value: &Value) -> Result<Decimal, FundingError> {
@@ -193,10 +201,8 @@ impl HyperliquidFundingSource {
                 .get("name")
                 .and_then(|value| value.as_str())
                 .ok_or_else(|| FundingError::InvalidRate("asset name missing".to_string()))?;
-            let symbol = match name {
-                "ETH" => Symbol::EthPerp,
-                "BTC" => Symbol::BtcPerp,
-                _ => continue,
+            let Some(symbol) = self.pair.symbol_for_coin(name) else {
+                continue;
             };
             let ctx = ctxs
                 .get(index)
@@ -245,7 +251,7 @@ impl FundingSource for HyperliquidFundingSource {
         rates.get(&symbol).cloned().ok_or_else(|| {
             FundingError::MissingData(format!(
                 "funding rate not found for {} at {}",
-                Self::symbol_string(symbol),
+                self.symbol_string(symbol),
                 timestamp.to_rfc3339()
             ))
         })
@@ -257,20 +263,93 @@ impl FundingSource for HyperliquidFundingSource {
         start: DateTime<Utc>,
         end: DateTime<Utc>,
     ) -> Result<Vec<FundingRate>, FundingError> {
-        let rates = self.fetch_snapshot(end).await?;
-        let rate = rates.get(&symbol).cloned().ok_or_else(|| {
-            FundingError::MissingData(format!(
-                "funding rate not found for {} at {}",
-                Self::symbol_string(symbol),
-                end.to_rfc3339()
-            ))
-        })?;
-        let _ = start;
-        Ok(vec![rate])
+        let url = self.endpoint_url();
+        let end_ms = end.timestamp_millis();
+        let mut cursor_ms = start.timestamp_millis();
+        let mut history: Vec<FundingRate> = Vec::new();
+        while cursor_ms <= end_ms {
+            let body = serde_json::json!({
+                "type": "fundingHistory",
+                "coin": self.symbol_string(symbol),
+                "startTime": cursor_ms,
+                "endTime": end_ms,
+            });
+            let response = self.http.post(&url, body).await?;
+            let page = match response.status {
+                200 => self.parse_history(symbol, &response.body)?,
+                429 => return Err(FundingError::RateLimited),
+                status => {
+                    return Err(FundingError::InvalidRate(format!(
+                        "unexpected status {status}"
+                    )));
+                }
+            };
+            let Some(last_ms) = page.iter().map(|(time_ms, _)| *time_ms).max() else {
+                break;
+            };
+            let full_page = page.len() >= FUNDING_HISTORY_PAGE_SIZE;
+            for (_, rate) in page {
+                if rate.timestamp <= end
+                    && history
+                        .last()
+                        .is_none_or(|previous| previous.timestamp < rate.timestamp)
+                {
+                    history.push(rate);
+                }
+            }
+            if !full_page || last_ms < cursor_ms {
+                break;
+            }
+            cursor_ms = last_ms + 1;
+        }
+        Ok(history)
     }
 }
 
 impl HyperliquidFundingSource {
+    /// Parses `fundingHistory` entries as `(raw time ms, rate)` sorted by time. Settlement
+    /// times are reported a few milliseconds past the hour, so rates are stamped with the
+    /// hour they settle on.
+    fn parse_history(
+        &self,
+        symbol: Symbol,
+        body: &str,
+    ) -> Result<Vec<(i64, FundingRate)>, FundingError> {
+        let value: Value =
+            serde_json::from_str(body).map_err(|err| FundingError::InvalidRate(err.to_string()))?;
+        let entries = value.as_array().ok_or_else(|| {
+            FundingError::InvalidRate("unexpected funding history response".to_string())
+        })?;
+        let mut rates = Vec::with_capacity(entries.len());
+        for entry in entries {
+            let time_ms = entry
+                .get("time")
+                .and_then(Value::as_i64)
+                .ok_or_else(|| FundingError::MissingData("funding time missing".to_string()))?;
+            let rate = entry
+                .get("fundingRate")
+                .ok_or_else(|| FundingError::MissingData("funding rate missing".to_string()))?;
+            let timestamp = Utc
+                .timestamp_millis_opt(time_ms)
+                .single()
+                .and_then(|time| time.duration_trunc(TimeDelta::hours(1)).ok())
+                .ok_or_else(|| {
+                    FundingError::InvalidRate(format!("invalid funding time {time_ms}"))
+                })?;
+            rates.push((
+                time_ms,
+                FundingRate {
+                    symbol,
+                    rate: Self::parse_decimal(rate)?,
+                    timestamp,
+                    interval_hours: self.interval_hours,
+                },
+            ));
+        }
+        rates.sort_by_key(|(time_ms, _)| *time_ms);
+        Ok(rates)
+    }
+
     async fn fetch_snapshot(
         &self,
         timestamp: DateTime<Utc>,
@@ -307,19 +386,19 @@ impl FundingFetcher {
         &self,
         timestamp: DateTime<Utc>,
     ) -> Result<FundingSnapshot, FundingError> {
-        let eth = self.source.fetch_rate(Symbol::EthPerp, timestamp).await?;
-        let btc = self.source.fetch_rate(Symbol::BtcPerp, timestamp).await?;
-        eth.validate()?;
-        btc.validate()?;
-        if eth.interval_hours != btc.interval_hours {
+        let base = self.source.fetch_rate(Symbol::Base, timestamp).await?;
+        let quote = self.source.fetch_rate(Symbol::Quote, timestamp).await?;
+        base.validate()?;
+        quote.validate()?;
+        if base.interval_hours != quote.interval_hours {
             return Err(FundingError::InvalidRate(
                 "funding intervals must match".to_string(),
             ));
         }
         Ok(FundingSnapshot {
-            interval_hours: eth.interval_hours,
-            eth,
-            btc,
+            interval_hours: base.interval_hours,
+            base,
+            quote,
         })
     }
 }
@@ -478,24 +557,24 @@ pub struct FundingCostEstimate {
 
 pub fn estimate_funding_cost(
     direction: TradeDirection,
-    notional_eth: Decimal,
-    notional_btc: Decimal,
-    eth_rate: &FundingRate,
-    btc_rate: &FundingRate,
+    notional_base: Decimal,
+    notional_quote: Decimal,
+    base_rate: &FundingRate,
+    quote_rate: &FundingRate,
     max_hold_hours: u32,
 ) -> Result<FundingCostEstimate, FundingError> {
-    if eth_rate.interval_hours == 0 {
+    if base_rate.interval_hours == 0 {
         return Err(FundingError::InvalidRate(
             "interval_hours must be > 0".to_string(),
         ));
     }
-    let intervals = max_hold_hours.div_ceil(eth_rate.interval_hours);
+    let intervals = max_hold_hours.div_ceil(base_rate.interval_hours);
     let per_interval = match direction {
-        TradeDirection::LongEthShortBtc => {
-            eth_rate.rate * notional_eth - btc_rate.rate * notional_btc
+        TradeDirection::LongBaseShortQuote => {
+            base_rate.rate * notional_base - quote_rate.rate * notional_quote
         }
-        TradeDirection::ShortEthLongBtc => {
-            -eth_rate.rate * notional_eth + btc_rate.rate * notional_btc
+        TradeDirection::ShortBaseLongQuote => {
+            -base_rate.rate * notional_base + quote_rate.rate * notional_quote
         }
     };
     let total_cost = per_interval * Decimal::from(intervals as u64);
@@ -504,7 +583,7 @@ pub fn estimate_funding_cost(
     } else {
         Decimal::ZERO
     };
-    let total_notional = notional_eth + notional_btc;
+    let total_notional = notional_base + notional_quote;
     let normalized = if total_notional > Decimal::ZERO {
         cost_est / total_notional
     } else {
@@ -513,7 +592,7 @@ pub fn estimate_funding_cost(
     Ok(FundingCostEstimate {
         cost_est,
         normalized,
-        interval_hours: eth_rate.interval_hours,
+        interval_hours: base_rate.interval_hours,
     })
 }
 
@@ -1,11 +1,13 @@
 use std::collections::VecDeque;
 
+use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use rust_decimal::MathematicalOps;
 use rust_decimal::prelude::ToPrimitive;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use crate::config::{SigmaFloorConfig, SigmaFloorMode};
+use crate::config::{SigmaFloorConfig, SigmaFloorMode, SpreadConfig, SpreadModel};
 
 #[derive(Debug, Error)]
 pub enum IndicatorError {
@@ -96,19 +98,41 @@ impl RollingWindow {
     }
 }
 
-pub fn relative_price(eth: Decimal, btc: Decimal) -> Result<Decimal, IndicatorError> {
-    if eth <= Decimal::ZERO || btc <= Decimal::ZERO {
+pub fn relative_price(base: Decimal, quote: Decimal) -> Result<Decimal, IndicatorError> {
+    if base <= Decimal::ZERO || quote <= Decimal::ZERO {
         return Err(IndicatorError::InvalidPrice(
             "prices must be > 0".to_string(),
         ));
     }
-    let eth_ln = eth
+    let base_ln = base
         .checked_ln()
         .ok_or_else(|| IndicatorError::Math("ln unavailable for ETH price".to_string()))?;
-    let btc_ln = btc
+    let quote_ln = quote
         .checked_ln()
         .ok_or_else(|| IndicatorError::Math("ln unavailable for BTC price".to_string()))?;
-    Ok(eth_ln - btc_ln)
+    Ok(base_ln - quote_ln)
+}
+
+/// Ordinary least squares fit of `y = alpha + beta * x`, returned as `(alpha, beta)`.
+pub fn ols_alpha_beta(x: &[Decimal], y: &[Decimal]) -> Option<(Decimal, Decimal)> {
+    if x.len() != y.len() || x.len() < 2 {
+        return None;
+    }
+    let count = Decimal::from(x.len() as u64);
+    let x_mean = x.iter().copied().sum::<Decimal>() / count;
+    let y_mean = y.iter().copied().sum::<Decimal>() / count;
+    let mut cov = Decimal::ZERO;
+    let mut var = Decimal::ZERO;
+    for (x_value, y_value) in x.iter().zip(y) {
+        let dx = *x_value - x_mean;
+        cov += dx * (*y_value - y_mean);
+        var += dx * dx;
+    }
+    if var == Decimal::ZERO {
+        return None;
+    }
+    let beta = cov / var;
+    Some((y_mean - beta * x_mean, beta))
 }
 
 pub fn log_return(current: Decimal, previous: Decimal) -> Result<Decimal, IndicatorError> {
@@ -278,19 +302,250 @@ impl ZScoreCalculator {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct SpreadSnapshot {
+    /// Spread fed to the z-score; `None` while the rolling beta is still warming up.
+    pub spread: Option<Decimal>,
+    pub alpha: Option<Decimal>,
+    pub beta: Option<Decimal>,
+}
+
+/// Builds the traded spread from ETH/BTC prices.
+///
+/// `FIXED` is `ln(ETH) - ln(BTC)`. `ROLLING_BETA` regresses `ln(ETH)` on `ln(BTC)` over
+/// the previous `beta_lookback_bars` bars and returns the current bar's residual.
+#[derive(Debug, Clone)]
+pub struct SpreadCalculator {
+    model: SpreadModel,
+    lookback: usize,
+    log_base: RollingWindow,
+    log_quote: RollingWindow,
+}
+
+impl SpreadCalculator {
+    pub fn new(config: &SpreadConfig) -> Result<Self, IndicatorError> {
+        if config.model == SpreadModel::Kalman {
+            return Err(IndicatorError::InvalidConfig(
+                "KALMAN spreads are built by KalmanHedgeFilter".to_string(),
+            ));
+        }
+        if config.model == SpreadModel::RollingBeta && config.beta_lookback_bars < 3 {
+            return Err(IndicatorError::InvalidConfig(
+                "beta_lookback_bars must be >= 3".to_string(),
+            ));
+        }
+        let lookback = config.beta_lookback_bars.max(1);
+        Ok(Self {
+            model: config.model,
+            lookback,
+            log_base: RollingWindow::new(lookback)?,
+            log_quote: RollingWindow::new(lookback)?,
+        })
+    }
+
+    pub fn update(
+        &mut self,
+        base_price: Decimal,
+        quote_price: Decimal,
+    ) -> Result<SpreadSnapshot, IndicatorError> {
+        if self.model == SpreadModel::Fixed {
+            return Ok(SpreadSnapshot {
+                spread: Some(relative_price(base_price, quote_price)?),
+                alpha: None,
+                beta: None,
+            });
+        }
+        if base_price <= Decimal::ZERO || quote_price <= Decimal::ZERO {
+            return Err(IndicatorError::InvalidPrice(
+                "prices must be > 0".to_string(),
+            ));
+        }
+        let base_ln = base_price
+            .checked_ln()
+            .ok_or_else(|| IndicatorError::Math("ln unavailable for ETH price".to_string()))?;
+        let quote_ln = quote_price
+            .checked_ln()
+            .ok_or_else(|| IndicatorError::Math("ln unavailable for BTC price".to_string()))?;
+        let fit = if self.log_base.len() >= self.lookback {
+            ols_alpha_beta(self.log_quote.as_slice(), self.log_base.as_slice())
+        } else {
+            None
+        };
+        self.log_base.push(base_ln);
+        self.log_quote.push(quote_ln);
+        Ok(match fit {
+            Some((alpha, beta)) => SpreadSnapshot {
+                spread: Some(base_ln - (alpha + beta * quote_ln)),
+                alpha: Some(alpha),
+                beta: Some(beta),
+            },
+            None => SpreadSnapshot {
+                spread: None,
+                alpha: None,
+                beta: None,
+            },
+        })
+    }
+}
+
+/// Checkpointable state of `KalmanHedgeFilter`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KalmanState {
+    pub alpha: Decimal,
+    pub beta: Decimal,
+    /// Covariance of `[alpha, beta]`.
+    pub covariance: [[Decimal; 2]; 2],
+    pub updates: u64,
+    pub last_timestamp: Option<DateTime<Utc>>,
+}
+
+impl Default for KalmanState {
+    fn default() -> Self {
+        Self {
+            alpha: Decimal::ZERO,
+            beta: Decimal::ONE,
+            covariance: [[Decimal::ONE, Decimal::ZERO], [Decimal::ZERO, Decimal::ONE]],
+            updates: 0,
+            last_timestamp: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct KalmanSnapshot {
+    /// Innovation `ln(ETH) - (alpha + beta * ln(BTC))` using the prior estimate.
+    pub residual: Decimal,
+    pub innovation_std: Decimal,
+    /// `residual / innovation_std`; `None` until `kalman_warmup_bars` updates.
+    pub zscore: Option<Decimal>,
+    pub alpha: Decimal,
+    pub beta: Decimal,
+    pub covariance: [[Decimal; 2]; 2],
+}
+
+/// Kalman filter for `ln(ETH) = alpha + beta * ln(BTC)` with random-walk `alpha`/`beta`.
+///
+/// State noise is `delta / (1 - delta)` on each coefficient and the observation noise is
+/// `kalman_observation_var`. Bars at or before the last applied timestamp are skipped, so a
+/// filter restored from a checkpoint can replay overlapping warm-up history.
+#[derive(Debug, Clone)]
+pub struct KalmanHedgeFilter {
+    state_noise: Decimal,
+    observation_var: Decimal,
+    warmup_bars: u64,
+    state: KalmanState,
+}
+
+impl KalmanHedgeFilter {
+    pub fn new(config: &SpreadConfig) -> Result<Self, IndicatorError> {
+        if config.kalman_delta <= Decimal::ZERO || config.kalman_delta >= Decimal::ONE {
+            return Err(IndicatorError::InvalidConfig(
+                "kalman_delta must be in (0,1)".to_string(),
+            ));
+        }
+        if config.kalman_observation_var <= Decimal::ZERO {
+            return Err(IndicatorError::InvalidConfig(
+                "kalman_observation_var must be > 0".to_string(),
+            ));
+        }
+        Ok(Self {
+            state_noise: config.kalman_delta / (Decimal::ONE - config.kalman_delta),
+            observation_var: config.kalman_observation_var,
+            warmup_bars: config.kalman_warmup_bars as u64,
+            state: KalmanState::default(),
+        })
+    }
+
+    pub fn state(&self) -> &KalmanState {
+        &self.state
+    }
+
+    pub fn restore(&mut self, state: KalmanState) {
+        self.state = state;
+    }
+
+    pub fn update(
+        &mut self,
+        timestamp: DateTime<Utc>,
+        base_price: Decimal,
+        quote_price: Decimal,
+    ) -> Result<Option<KalmanSnapshot>, IndicatorError> {
+        if base_price <= Decimal::ZERO || quote_price <= Decimal::ZERO {
+            return Err(IndicatorError::InvalidPrice(
+                "prices must be > 0".to_string(),
+            ));
+        }
+        if self
+            .state
+            .last_timestamp
+            .is_some_and(|last| timestamp <= last)
+        {
+            return Ok(None);
+        }
+        let y = base_price
+            .checked_ln()
+            .ok_or_else(|| IndicatorError::Math("ln unavailable for ETH price".to_string()))?;
+        let x = quote_price
+            .checked_ln()
+            .ok_or_else(|| IndicatorError::Math("ln unavailable for BTC price".to_string()))?;
+        if self.state.updates == 0 {
+            // Start from a unit hedge through the first observation.
+            self.state.alpha = y - x;
+        }
+
+        let state = &mut self.state;
+        let mut p = state.covariance;
+        p[0][0] += self.state_noise;
+        p[1][1] += self.state_noise;
+
+        // H = [1, x]; PH' and innovation variance Q = H P H' + R.
+        let ph = [p[0][0] + p[0][1] * x, p[1][0] + p[1][1] * x];
+        let q = ph[0] + ph[1] * x + self.observation_var;
+        if q <= Decimal::ZERO {
+            return Err(IndicatorError::Math(
+                "kalman innovation variance must be > 0".to_string(),
+            ));
+        }
+        let residual = y - (state.alpha + state.beta * x);
+        let innovation_std = q
+            .sqrt()
+            .ok_or_else(|| IndicatorError::Math("sqrt unavailable".to_string()))?;
+        let gain = [ph[0] / q, ph[1] / q];
+        state.alpha += gain[0] * residual;
+        state.beta += gain[1] * residual;
+        // P = P - K H P, with H P = (P H')' for symmetric P.
+        state.covariance = [
+            [p[0][0] - gain[0] * ph[0], p[0][1] - gain[0] * ph[1]],
+            [p[1][0] - gain[1] * ph[0], p[1][1] - gain[1] * ph[1]],
+        ];
+        state.updates += 1;
+        state.last_timestamp = Some(timestamp);
+
+        let zscore = (state.updates > self.warmup_bars).then(|| residual / innovation_std);
+        Ok(Some(KalmanSnapshot {
+            residual,
+            innovation_std,
+            zscore,
+            alpha: state.alpha,
+            beta: state.beta,
+            covariance: state.covariance,
+        }))
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct VolatilitySnapshot {
-    pub vol_eth: Option<Decimal>,
-    pub vol_btc: Option<Decimal>,
+    pub vol_base: Option<Decimal>,
+    pub vol_quote: Option<Decimal>,
 }
 
 #[derive(Debug, Clone)]
 pub struct VolatilityCalculator {
     n_vol: usize,
-    eth_returns: RollingWindow,
-    btc_returns: RollingWindow,
-    last_eth: Option<Decimal>,
-    last_btc: Option<Decimal>,
+    base_returns: RollingWindow,
+    quote_returns: RollingWindow,
+    last_base: Option<Decimal>,
+    last_quote: Option<Decimal>,
 }
 
 impl VolatilityCalculator {
@@ -302,44 +557,47 @@ impl VolatilityCalculator {
         }
         Ok(Self {
             n_vol,
-            eth_returns: RollingWindow::new(n_vol)?,
-            btc_returns: RollingWindow::new(n_vol)?,
-            last_eth: None,
-            last_btc: None,
+            base_returns: RollingWindow::new(n_vol)?,
+            quote_returns: RollingWindow::new(n_vol)?,
+            last_base: None,
+            last_quote: None,
         })
     }
 
     pub fn update(
         &mut self,
-        eth_price: Decimal,
-        btc_price: Decimal,
+        base_price: Decimal,
+        quote_price: Decimal,
     ) -> Result<VolatilitySnapshot, IndicatorError> {
-        if eth_price <= Decimal::ZERO || btc_price <= Decimal::ZERO {
+        if base_price <= Decimal::ZERO || quote_price <= Decimal::ZERO {
             return Err(IndicatorError::InvalidPrice(
                 "prices must be > 0".to_string(),
             ));
         }
-        if let Some(last) = self.last_eth {
-            let ret = log_return(eth_price, last)?;
-            self.eth_returns.push(ret);
+        if let Some(last) = self.last_base {
+            let ret = log_return(base_price, last)?;
+            self.base_returns.push(ret);
         }
-        if let Some(last) = self.last_btc {
-            let ret = log_return(btc_price, last)?;
-            self.btc_returns.push(ret);
+        if let Some(last) = self.last_quote {
+            let ret = log_return(quote_price, last)?;
+            self.quote_returns.push(ret);
         }
-        self.last_eth = Some(eth_price);
-        self.last_btc = Some(btc_price);
+        self.last_base = Some(base_price);
+        self.last_quote = Some(quote_price);
 
-        let vol_eth = if self.eth_returns.len() >= self.n_vol {
-            self.eth_returns.std()
+        let vol_base = if self.base_returns.len() >= self.n_vol {
+            self.base_returns.std()
         } else {
             None
         };
-        let vol_btc = if self.btc_returns.len() >= self.n_vol {
-            self.btc_returns.std()
+        let vol_quote = if self.quote_returns.len() >= self.n_vol {
+            self.quote_returns.std()
         } else {
             None
         };
-        Ok(VolatilitySnapshot { vol_eth, vol_btc })
+        Ok(VolatilitySnapshot {
+            vol_base,
+            vol_quote,
+        })
     }
 }
@@ -20,6 +20,7 @@ pub enum LogEvent {
     EntrySubmitted,
     EntryCancelled,
     Entry,
+    ScaleIn,
     Exit(ExitReason),
     CooldownStart,
     CooldownEnd,
@@ -36,14 +37,20 @@ pub enum EntryBlockReason {
     FundingThreshold,
     CostGate,
     RegimeGate,
-    BelowMinSizeEth,
-    BelowMinSizeBtc,
+    #[serde(alias = "BELOW_MIN_SIZE_ETH")]
+    BelowMinSizeBase,
+    #[serde(alias = "BELOW_MIN_SIZE_BTC")]
+    BelowMinSizeQuote,
     PostOnlyWouldTake,
+    PortfolioBudget,
+    PortfolioGrossCap,
+    InsufficientMargin,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TradeEvent {
     Entry,
+    ScaleIn,
     Exit(ExitReason),
     ResidualRepair,
 }
@@ -58,44 +65,74 @@ pub enum PnlSource {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TradeLog {
     pub timestamp: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pair: Option<String>,
     pub event: TradeEvent,
     pub direction: TradeDirection,
-    pub eth_qty: Decimal,
-    pub btc_qty: Decimal,
-    pub eth_price: Decimal,
-    pub btc_price: Decimal,
+    #[serde(alias = "eth_qty")]
+    pub base_qty: Decimal,
+    #[serde(alias = "btc_qty")]
+    pub quote_qty: Decimal,
+    #[serde(alias = "eth_price")]
+    pub base_price: Decimal,
+    #[serde(alias = "btc_price")]
+    pub quote_price: Decimal,
     pub entry_time: DateTime<Utc>,
-    pub entry_eth_price: Decimal,
-    pub entry_btc_price: Decimal,
+    #[serde(alias = "entry_eth_price")]
+    pub entry_base_price: Decimal,
+    #[serde(alias = "entry_btc_price")]
+    pub entry_quote_price: Decimal,
     pub realized_pnl: Decimal,
     pub cumulative_realized_pnl: Decimal,
     pub fee: Decimal,
     pub exchange_closed_pnl: Option<Decimal>,
     pub pnl_source: PnlSource,
-    pub eth_ref_price: Option<Decimal>,
-    pub btc_ref_price: Option<Decimal>,
-    pub eth_slippage_bps: Option<Decimal>,
-    pub btc_slippage_bps: Option<Decimal>,
+    #[serde(alias = "eth_ref_price")]
+    pub base_ref_price: Option<Decimal>,
+    #[serde(alias = "btc_ref_price")]
+    pub quote_ref_price: Option<Decimal>,
+    #[serde(alias = "eth_slippage_bps")]
+    pub base_slippage_bps: Option<Decimal>,
+    #[serde(alias = "btc_slippage_bps")]
+    pub quote_slippage_bps: Option<Decimal>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BarLog {
     pub timestamp: DateTime<Utc>,
-    pub eth_price: Option<Decimal>,
-    pub btc_price: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pair: Option<String>,
+    #[serde(alias = "eth_price")]
+    pub base_price: Option<Decimal>,
+    #[serde(alias = "btc_price")]
+    pub quote_price: Option<Decimal>,
+    /// Log price ratio `ln(base) - ln(quote)`, whatever the spread model.
     pub r: Option<Decimal>,
+    /// Hedged residual the z-score is computed on; only set for `ROLLING_BETA` and `KALMAN`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spread: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub beta: Option<Decimal>,
     pub mu: Option<Decimal>,
     pub sigma: Option<Decimal>,
     pub sigma_eff: Option<Decimal>,
     pub zscore: Option<Decimal>,
-    pub vol_eth: Option<Decimal>,
-    pub vol_btc: Option<Decimal>,
-    pub w_eth: Option<Decimal>,
-    pub w_btc: Option<Decimal>,
-    pub notional_eth: Option<Decimal>,
-    pub notional_btc: Option<Decimal>,
-    pub funding_eth: Option<Decimal>,
-    pub funding_btc: Option<Decimal>,
+    #[serde(alias = "vol_eth")]
+    pub vol_base: Option<Decimal>,
+    #[serde(alias = "vol_btc")]
+    pub vol_quote: Option<Decimal>,
+    #[serde(alias = "w_eth")]
+    pub w_base: Option<Decimal>,
+    #[serde(alias = "w_btc")]
+    pub w_quote: Option<Decimal>,
+    #[serde(alias = "notional_eth")]
+    pub notional_base: Option<Decimal>,
+    #[serde(alias = "notional_btc")]
+    pub notional_quote: Option<Decimal>,
+    #[serde(alias = "funding_eth")]
+    pub funding_base: Option<Decimal>,
+    #[serde(alias = "funding_btc")]
+    pub funding_quote: Option<Decimal>,
     pub funding_cost_est: Option<Decimal>,
     pub funding_skip: Option<bool>,
     pub regime_half_life_bars: Option<f64>,
@@ -105,16 +142,26 @@ pub struct BarLog {
     pub estimated_net_edge_bps: Option<Decimal>,
     pub cost_gate_required_net_edge_bps: Option<Decimal>,
     pub cost_gate_pass: Option<bool>,
-    pub eth_best_bid: Option<Decimal>,
-    pub eth_best_ask: Option<Decimal>,
-    pub eth_bid_size: Option<Decimal>,
-    pub eth_ask_size: Option<Decimal>,
-    pub eth_spread_bps: Option<Decimal>,
-    pub btc_best_bid: Option<Decimal>,
-    pub btc_best_ask: Option<Decimal>,
-    pub btc_bid_size: Option<Decimal>,
-    pub btc_ask_size: Option<Decimal>,
-    pub btc_spread_bps: Option<Decimal>,
+    #[serde(alias = "eth_best_bid")]
+    pub base_best_bid: Option<Decimal>,
+    #[serde(alias = "eth_best_ask")]
+    pub base_best_ask: Option<Decimal>,
+    #[serde(alias = "eth_bid_size")]
+    pub base_bid_size: Option<Decimal>,
+    #[serde(alias = "eth_ask_size")]
+    pub base_ask_size: Option<Decimal>,
+    #[serde(alias = "eth_spread_bps")]
+    pub base_spread_bps: Option<Decimal>,
+    #[serde(alias = "btc_best_bid")]
+    pub quote_best_bid: Option<Decimal>,
+    #[serde(alias = "btc_best_ask")]
+    pub quote_best_ask: Option<Decimal>,
+    #[serde(alias = "btc_bid_size")]
+    pub quote_bid_size: Option<Decimal>,
+    #[serde(alias = "btc_ask_size")]
+    pub quote_ask_size: Option<Decimal>,
+    #[serde(alias = "btc_spread_bps")]
+    pub quote_spread_bps: Option<Decimal>,
     pub entry_block_reason: Option<EntryBlockReason>,
     pub run_error: Option<String>,
     pub unrealized_pnl: Decimal,
@@ -138,12 +185,12 @@ impl LogFormatter {
     }
 
     pub fn format_text(&self, bar: &BarLog) -> String {
-        let eth = bar
-            .eth_price
+        let base = bar
+            .base_price
             .map(|value| value.to_string())
             .unwrap_or_else(|| "NA".to_string());
-        let btc = bar
-            .btc_price
+        let quote = bar
+            .quote_price
             .map(|value| value.to_string())
             .unwrap_or_else(|| "NA".to_string());
         let z = bar
@@ -157,10 +204,10 @@ impl LogFormatter {
             .unwrap_or_else(|| "NONE".to_string());
         let run_error = bar.run_error.as_deref().unwrap_or("NONE");
         format!(
-            "[{}] ETH={} BTC={} Z={} UPNL={} STATE={:?} BLOCK={} RUN_ERROR={}",
+            "[{}] BASE={} QUOTE={} Z={} UPNL={} STATE={:?} BLOCK={} RUN_ERROR={}",
             bar.timestamp.to_rfc3339(),
-            eth,
-            btc,
+            base,
+            quote,
             z,
             bar.unrealized_pnl,
             bar.state,
@@ -215,26 +262,26 @@ impl TradeLogFormatter {
 
     pub fn format_text(&self, log: &TradeLog) -> String {
         format!(
-            "[{}] EVENT={:?} DIR={:?} ETH_QTY={} BTC_QTY={} ETH_PX={} BTC_PX={} ENTRY_TIME={} ENTRY_ETH_PX={} ENTRY_BTC_PX={} REALIZED_PNL={} CUM_REALIZED_PNL={} FEE={} EXCHANGE_CLOSED_PNL={:?} PNL_SOURCE={:?} ETH_REF_PX={:?} BTC_REF_PX={:?} ETH_SLIP_BPS={:?} BTC_SLIP_BPS={:?}",
+            "[{}] EVENT={:?} DIR={:?} BASE_QTY={} QUOTE_QTY={} BASE_PX={} QUOTE_PX={} ENTRY_TIME={} ENTRY_BASE_PX={} ENTRY_QUOTE_PX={} REALIZED_PNL={} CUM_REALIZED_PNL={} FEE={} EXCHANGE_CLOSED_PNL={:?} PNL_SOURCE={:?} BASE_REF_PX={:?} QUOTE_REF_PX={:?} BASE_SLIP_BPS={:?} QUOTE_SLIP_BPS={:?}",
             log.timestamp.to_rfc3339(),
             log.event,
             log.direction,
-            log.eth_qty,
-            log.btc_qty,
-            log.eth_price,
-            log.btc_price,
+            log.base_qty,
+            log.quote_qty,
+            log.base_price,
+            log.quote_price,
             log.entry_time.to_rfc3339(),
-            log.entry_eth_price,
-            log.entry_btc_price,
+            log.entry_base_price,
+            log.entry_quote_price,
             log.realized_pnl,
             log.cumulative_realized_pnl,
             log.fee,
             log.exchange_closed_pnl,
             log.pnl_source,
-            log.eth_ref_price,
-            log.btc_ref_price,
-            log.eth_slippage_bps,
-            log.btc_slippage_bps,
+            log.base_ref_price,
+            log.quote_ref_price,
+            log.base_slippage_bps,
+            log.quote_slippage_bps,
         )
     }
 }
@@ -1,10 +1,11 @@
-use std::path::PathBuf;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Duration;
 
 use anyhow::{Context, anyhow};
-use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use chrono::{DateTime, Utc};
 use clap::Parser;
 use rust_decimal::{Decimal, RoundingStrategy};
 use serde_json::{Value, json};
@@ -24,13 +25,41 @@ use eth_btc_strategy::analysis::{
     replay_funding_carry_stats_log, replay_stats_log, study_residual_regimes,
     sweep_residual_regime_parameters,
 };
+use eth_btc_strategy::backtest::diff::{diff_backtests, format_backtest_diff_text};
 use eth_btc_strategy::backtest::download::{HyperliquidDownloader, write_bars_to_output};
+use eth_btc_strategy::backtest::grid::{
+    GridSearchConfig, ParameterGrid, export_grid_results_csv, export_grid_results_json,
+    format_grid_results_text, run_grid_search,
+};
+use eth_btc_strategy::backtest::manifest::{
+    BarSource, InputFile, RunInputs, RunManifest, export_manifest, format_verification_text,
+    load_manifest,
+};
+use eth_btc_strategy::backtest::monte_carlo::{
+    MonteCarloConfig, export_monte_carlo_csv, export_monte_carlo_json, run_monte_carlo,
+};
+use eth_btc_strategy::backtest::parity::{
+    ParityConfig, check_parity, format_parity_text, load_trade_logs_jsonl,
+};
+use eth_btc_strategy::backtest::report::export_html_report;
+use eth_btc_strategy::backtest::scenario::generate_bars;
+use eth_btc_strategy::backtest::slippage::{BookSlippageModel, load_bar_logs_jsonl};
+use eth_btc_strategy::backtest::stress::{
+    default_stress_scenarios, format_stress_text, run_stress,
+};
+use eth_btc_strategy::backtest::walk_forward::{
+    WalkForwardConfig, format_walk_forward_text, run_walk_forward,
+};
 use eth_btc_strategy::backtest::{
-    BacktestEngine, export_equity_csv, export_metrics_json, export_trades_csv, load_backtest_bars,
-    load_backtest_bars_from_db,
+    BacktestBar, BacktestEngine, BacktestResult, Trade, export_bar_logs_jsonl, export_equity_csv,
+    export_margin_csv, export_metrics_json, export_trades_csv, load_backtest_bars,
+    load_backtest_bars_from_db, load_trades_csv,
+};
+use eth_btc_strategy::cli::{AnalyzeOutputFormat, BacktestAction, Cli, Command, ParameterGridArgs};
+use eth_btc_strategy::config::{
+    CapitalMode, Config, ConfigOverrides, ExecutionConfig, OrderType, RuntimeOverrides,
+    SigmaFloorMode, load_config, load_config_with_cli,
 };
-use eth_btc_strategy::cli::{AnalyzeOutputFormat, Cli, Command};
-use eth_btc_strategy::config::{CapitalMode, ExecutionConfig, OrderType, load_config};
 use eth_btc_strategy::core::strategy::StrategyEngine;
 use eth_btc_strategy::data::{
     BookFetcher, HyperliquidPriceSource, PriceFetcher, PriceSource, align_to_bar_close,
@@ -40,14 +69,20 @@ use eth_btc_strategy::execution::{
     PaperOrderExecutor, RetryConfig,
 };
 use eth_btc_strategy::funding::{FundingFetcher, HyperliquidFundingSource};
+use eth_btc_strategy::logging::BarLog;
 use eth_btc_strategy::logging::{BarLogFileWriter, TradeLogFileWriter};
 use eth_btc_strategy::runtime::backfill::{
     ensure_price_history, latest_completed_bar, replay_warmup_gap_window,
 };
+use eth_btc_strategy::runtime::portfolio::PortfolioRunner;
 use eth_btc_strategy::runtime::{LiveRunner, StateStoreWriter, StateWriter};
 use eth_btc_strategy::state::{StateStore, recover_state};
 use eth_btc_strategy::storage::{PriceStore, PriceStoreWriter};
 
+/// Minimum history backfilled before the first live bar, in days of the configured bar
+/// interval; longer z-score or volatility windows extend it.
+const WARMUP_FLOOR_DAYS: usize = 4;
+
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let cli = Cli::parse();
@@ -56,14 +91,23 @@ async fn main() -> anyhow::Result<()> {
         .with_env_filter(EnvFilter::from_default_env())
         .init();
 
-    let config = load_config(cli.config.as_deref()).context("load config")?;
+    // `--interval-secs` goes through the config so it is validated against the bar interval.
+    let cli_overrides = ConfigOverrides {
+        runtime: RuntimeOverrides {
+            interval_secs: cli.interval_secs,
+            ..RuntimeOverrides::default()
+        },
+        ..ConfigOverrides::default()
+    };
+    let config =
+        load_config_with_cli(cli.config.as_deref(), Some(cli_overrides)).context("load config")?;
 
     let runtime = &config.runtime;
     let base_url = cli
         .base_url
         .clone()
         .unwrap_or_else(|| runtime.base_url.clone());
-    let interval_secs = cli.interval_secs.unwrap_or(runtime.interval_secs);
+    let interval_secs = runtime.interval_secs;
     let run_once = if cli.once { true } else { runtime.once };
     let paper = if cli.paper { true } else { runtime.paper };
     let disable_funding = if cli.disable_funding {
@@ -79,26 +123,102 @@ async fn main() -> anyhow::Result<()> {
     if let Some(command) = &cli.command {
         match command {
             Command::Backtest(args) => {
-                let bars = if let Some(db) = args.db.as_ref() {
-                    let start = args
-                        .start
-                        .as_ref()
-                        .ok_or_else(|| anyhow!("--start required when --db is set"))?;
-                    let end = args
-                        .end
-                        .as_ref()
-                        .ok_or_else(|| anyhow!("--end required when --db is set"))?;
-                    let start = parse_rfc3339(start).context("parse --start")?;
-                    let end = parse_rfc3339(end).context("parse --end")?;
-                    load_backtest_bars_from_db(db, start, end, config.data.price_field)
-                        .context("load backtest bars from db")?
-                } else if let Some(bars_path) = args.bars.as_ref() {
-                    load_backtest_bars(bars_path).context("load backtest bars")?
-                } else {
-                    return Err(anyhow!("--bars or --db is required for backtest"));
-                };
-                let engine = BacktestEngine::new(config);
-                let result = engine.run(&bars).context("run backtest")?;
+                if let Some(BacktestAction::Verify(verify)) = args.action.as_ref() {
+                    let (manifest, archived) =
+                        load_manifest(&verify.dir).context("read run manifest")?;
+                    let inputs = &manifest.inputs;
+                    let bars = match &inputs.bars {
+                        BarSource::File { path } => {
+                            load_backtest_bars(verify.bars.as_ref().unwrap_or(path))
+                                .context("load backtest bars")?
+                        }
+                        BarSource::Db { path, start, end } => load_backtest_bars_from_db(
+                            verify.db.as_ref().unwrap_or(path),
+                            *start,
+                            *end,
+                            archived.data.price_field,
+                            &archived.pair,
+                            archived.data.bar_interval,
+                        )
+                        .context("load backtest bars from db")?,
+                    };
+                    let minute_bars = inputs.minute_bars.as_ref().map(|file| file.path.as_path());
+                    let book_logs = inputs.book_logs.as_ref().map(|file| file.path.as_path());
+                    let result = run_cli_backtest(
+                        archived.clone(),
+                        &bars,
+                        minute_bars,
+                        book_logs,
+                        inputs.simulated_exchange,
+                    )
+                    .await?;
+                    let rerun = RunManifest::new(
+                        &archived,
+                        &bars,
+                        run_inputs(
+                            inputs.bars.clone(),
+                            minute_bars,
+                            book_logs,
+                            inputs.simulated_exchange,
+                        )?,
+                        &result,
+                    )
+                    .context("fingerprint re-run")?;
+                    let verification = manifest.compare(&rerun);
+                    match verify.format {
+                        AnalyzeOutputFormat::Text => {
+                            print!("{}", format_verification_text(&verification))
+                        }
+                        AnalyzeOutputFormat::Json => {
+                            let payload = serde_json::to_string_pretty(&verification)
+                                .context("format verification")?;
+                            println!("{payload}");
+                        }
+                    }
+                    if !verification.verified {
+                        return Err(anyhow!(
+                            "backtest in {} did not reproduce",
+                            verify.dir.display()
+                        ));
+                    }
+                    return Ok(());
+                }
+                let bars = load_cli_bars(
+                    &config,
+                    "backtest",
+                    args.bars.as_ref(),
+                    args.db.as_ref(),
+                    args.start.as_deref(),
+                    args.end.as_deref(),
+                )?;
+                let result = run_cli_backtest(
+                    config.clone(),
+                    &bars,
+                    args.minute_bars.as_deref(),
+                    args.book_logs.as_deref(),
+                    args.simulated_exchange,
+                )
+                .await?;
+                let monte_carlo = args
+                    .monte_carlo
+                    .map(|iterations| {
+                        run_monte_carlo(
+                            &result,
+                            &MonteCarloConfig {
+                                iterations,
+                                block_size: args.monte_carlo_block_size,
+                                seed: args.monte_carlo_seed,
+                            },
+                        )
+                    })
+                    .transpose()
+                    .context("run monte carlo")?;
+                if let Some(path) = args.report.as_ref() {
+                    export_html_report(path, &result).context("write html report")?;
+                }
+                if let Some(path) = args.bar_log.as_ref() {
+                    export_bar_logs_jsonl(path, &result.bar_logs).context("write bar logs")?;
+                }
                 if let Some(dir) = args.output_dir.as_ref() {
                     std::fs::create_dir_all(dir).context("create output dir")?;
                     export_metrics_json(&dir.join("metrics.json"), &result.metrics)
@@ -107,22 +227,203 @@ async fn main() -> anyhow::Result<()> {
                         .context("write trades")?;
                     export_equity_csv(&dir.join("equity.csv"), &result.equity_curve)
                         .context("write equity")?;
+                    export_margin_csv(&dir.join("margin.csv"), &result.margin)
+                        .context("write margin")?;
+                    export_bar_logs_jsonl(&dir.join("bar_logs.jsonl"), &result.bar_logs)
+                        .context("write bar logs")?;
+                    let source = match (args.db.as_ref(), args.bars.as_ref()) {
+                        (Some(db), _) => BarSource::Db {
+                            path: db.clone(),
+                            start: parse_rfc3339(args.start.as_deref().unwrap_or_default())?,
+                            end: parse_rfc3339(args.end.as_deref().unwrap_or_default())?,
+                        },
+                        (None, Some(path)) => BarSource::File { path: path.clone() },
+                        (None, None) => return Err(anyhow!("--bars or --db is required")),
+                    };
+                    let manifest = RunManifest::new(
+                        &config,
+                        &bars,
+                        run_inputs(
+                            source,
+                            args.minute_bars.as_deref(),
+                            args.book_logs.as_deref(),
+                            args.simulated_exchange,
+                        )?,
+                        &result,
+                    )
+                    .context("fingerprint run")?;
+                    export_manifest(dir, &manifest, &config).context("write run manifest")?;
+                    if let Some(monte_carlo) = monte_carlo.as_ref() {
+                        export_monte_carlo_json(
+                            &dir.join("monte_carlo.json"),
+                            &monte_carlo.summary,
+                        )
+                        .context("write monte carlo summary")?;
+                        export_monte_carlo_csv(&dir.join("monte_carlo.csv"), &monte_carlo.samples)
+                            .context("write monte carlo paths")?;
+                    }
                 } else {
                     let payload =
                         serde_json::to_string_pretty(&result.metrics).context("format metrics")?;
                     println!("{payload}");
+                    if let Some(monte_carlo) = monte_carlo.as_ref() {
+                        let payload = serde_json::to_string_pretty(&monte_carlo.summary)
+                            .context("format monte carlo")?;
+                        println!("{payload}");
+                    }
+                }
+                return Ok(());
+            }
+            Command::BacktestDiff(args) => {
+                if args.candidate_config.is_none() && args.candidate_dir.is_none() {
+                    return Err(anyhow!(
+                        "--candidate-config or --candidate-dir is required for backtest-diff"
+                    ));
+                }
+                let needs_bars = args.baseline_dir.is_none() || args.candidate_dir.is_none();
+                let bars = if needs_bars {
+                    load_cli_bars(
+                        &config,
+                        "backtest-diff",
+                        args.bars.as_ref(),
+                        args.db.as_ref(),
+                        args.start.as_deref(),
+                        args.end.as_deref(),
+                    )?
+                } else {
+                    Vec::new()
+                };
+                let (baseline_trades, baseline_logs) = load_diff_side(
+                    &config,
+                    &bars,
+                    args.baseline_config.as_deref(),
+                    args.baseline_dir.as_deref(),
+                )
+                .context("load baseline run")?;
+                let (candidate_trades, candidate_logs) = load_diff_side(
+                    &config,
+                    &bars,
+                    args.candidate_config.as_deref(),
+                    args.candidate_dir.as_deref(),
+                )
+                .context("load candidate run")?;
+                let diff = diff_backtests(
+                    &baseline_trades,
+                    &baseline_logs,
+                    &candidate_trades,
+                    &candidate_logs,
+                );
+                match args.format {
+                    AnalyzeOutputFormat::Text => print!("{}", format_backtest_diff_text(&diff)),
+                    AnalyzeOutputFormat::Json => {
+                        let payload =
+                            serde_json::to_string_pretty(&diff).context("format backtest diff")?;
+                        println!("{payload}");
+                    }
+                }
+                return Ok(());
+            }
+            Command::WalkForward(args) => {
+                let bars = load_cli_bars(
+                    &config,
+                    "walk-forward",
+                    args.bars.as_ref(),
+                    args.db.as_ref(),
+                    args.start.as_deref(),
+                    args.end.as_deref(),
+                )?;
+                let grid = parse_parameter_grid(&args.grid)?;
+                let report = run_walk_forward(
+                    &config,
+                    &bars,
+                    &WalkForwardConfig {
+                        train_bars: args.train_bars,
+                        test_bars: args.test_bars,
+                        step_bars: args.step_bars,
+                        objective: args.objective,
+                        min_trades: args.min_trades,
+                        grid,
+                        threads: args.threads,
+                    },
+                )
+                .context("run walk-forward")?;
+                if let Some(dir) = args.output_dir.as_ref() {
+                    std::fs::create_dir_all(dir).context("create output dir")?;
+                    let payload =
+                        serde_json::to_string_pretty(&report).context("format walk-forward")?;
+                    std::fs::write(dir.join("walk_forward.json"), payload)
+                        .context("write walk-forward report")?;
+                    export_trades_csv(&dir.join("oos_trades.csv"), &report.oos_trades)
+                        .context("write out-of-sample trades")?;
+                    export_equity_csv(&dir.join("oos_equity.csv"), &report.oos_equity_curve)
+                        .context("write out-of-sample equity")?;
+                }
+                match args.format {
+                    AnalyzeOutputFormat::Text => print!("{}", format_walk_forward_text(&report)),
+                    AnalyzeOutputFormat::Json => {
+                        let payload =
+                            serde_json::to_string_pretty(&report).context("format walk-forward")?;
+                        println!("{payload}");
+                    }
+                }
+                return Ok(());
+            }
+            Command::GridSearch(args) => {
+                let bars = load_cli_bars(
+                    &config,
+                    "grid-search",
+                    args.bars.as_ref(),
+                    args.db.as_ref(),
+                    args.start.as_deref(),
+                    args.end.as_deref(),
+                )?;
+                let results = run_grid_search(
+                    &config,
+                    &bars,
+                    &GridSearchConfig {
+                        grid: parse_parameter_grid(&args.grid)?,
+                        objective: args.objective,
+                        min_trades: args.min_trades,
+                        threads: args.threads,
+                    },
+                )
+                .context("run grid search")?;
+                if let Some(dir) = args.output_dir.as_ref() {
+                    std::fs::create_dir_all(dir).context("create output dir")?;
+                    export_grid_results_csv(&dir.join("grid_results.csv"), &results)
+                        .context("write grid results csv")?;
+                    export_grid_results_json(&dir.join("grid_results.json"), &results)
+                        .context("write grid results json")?;
+                }
+                match args.format {
+                    AnalyzeOutputFormat::Text => {
+                        print!("{}", format_grid_results_text(&results, args.top))
+                    }
+                    AnalyzeOutputFormat::Json => {
+                        let top: Vec<_> = results.iter().take(args.top).collect();
+                        let payload =
+                            serde_json::to_string_pretty(&top).context("format grid results")?;
+                        println!("{payload}");
+                    }
                 }
                 return Ok(());
             }
             Command::Download(args) => {
                 let start = parse_rfc3339(&args.start).context("parse --start")?;
                 let end = parse_rfc3339(&args.end).context("parse --end")?;
-                let downloader = HyperliquidDownloader::new(base_url.clone());
+                let mut downloader = HyperliquidDownloader::new(base_url.clone())
+                    .with_pair(config.pair.clone())
+                    .with_interval(config.data.bar_interval);
+                if !args.skip_funding {
+                    downloader =
+                        downloader.with_hyperliquid_funding(base_url.clone(), config.pair.clone());
+                }
                 let bars = downloader
                     .fetch_backtest_bars(start, end)
                     .await
                     .context("download bars")?;
-                write_bars_to_output(&bars, &args.output).context("write output")?;
+                write_bars_to_output(&bars, &args.output, &config.pair, config.data.bar_interval)
+                    .context("write output")?;
                 info!(count = bars.len(), path = %args.output.display(), "download complete");
                 return Ok(());
             }
@@ -137,10 +438,17 @@ async fn main() -> anyhow::Result<()> {
                     .map(|value| parse_rfc3339(value))
                     .transpose()
                     .context("parse --since")?;
-                let content = std::fs::read_to_string(&path)
-                    .with_context(|| format!("read trade history {}", path.display()))?;
-                let cycles = analyze_trade_history_csv_since(&content, since)
-                    .context("analyze trade history")?;
+                // Stats-log studies, e.g. over exported backtest bar logs, run without
+                // live trade history.
+                let cycles =
+                    if args.trade_history.is_none() && args.stats_log.is_some() && !path.exists() {
+                        Vec::new()
+                    } else {
+                        let content = std::fs::read_to_string(&path)
+                            .with_context(|| format!("read trade history {}", path.display()))?;
+                        analyze_trade_history_csv_since(&content, since)
+                            .context("analyze trade history")?
+                    };
                 let report = build_trade_attribution_report(cycles);
                 let stats_content = if let Some(stats_path) = args.stats_log.as_ref() {
                     Some(
@@ -152,8 +460,13 @@ async fn main() -> anyhow::Result<()> {
                 };
                 let stats_replay = if let Some(stats_content) = stats_content.as_ref() {
                     Some(
-                        replay_stats_log(stats_content, since, &default_replay_strategy_configs())
-                            .context("replay stats log")?,
+                        replay_stats_log(
+                            stats_content,
+                            since,
+                            &default_replay_strategy_configs(),
+                            config.data.bar_interval,
+                        )
+                        .context("replay stats log")?,
                     )
                 } else {
                     None
@@ -172,6 +485,7 @@ async fn main() -> anyhow::Result<()> {
                                 entry_z: config.strategy.entry_z,
                                 tp_z: config.strategy.tp_z,
                                 sl_z: config.strategy.sl_z,
+                                bar_interval: config.data.bar_interval,
                             },
                         )
                         .context("study residual regimes")?,
@@ -198,6 +512,7 @@ async fn main() -> anyhow::Result<()> {
                                 sl_z: config.strategy.sl_z,
                                 min_trades: args.regime_sweep_min_trades,
                                 top_n: args.regime_sweep_top,
+                                bar_interval: config.data.bar_interval,
                             },
                         )
                         .context("sweep residual regime parameters")?,
@@ -220,6 +535,7 @@ async fn main() -> anyhow::Result<()> {
                                 min_net_edge_bps: args.funding_carry_min_net_edge_bps,
                                 max_hold_hours: args.funding_carry_max_hold_hours,
                                 funding_interval_hours: args.funding_carry_interval_hours,
+                                bar_interval: config.data.bar_interval,
                             },
                         )
                         .context("replay funding carry stats log")?,
@@ -257,6 +573,98 @@ async fn main() -> anyhow::Result<()> {
                 }
                 return Ok(());
             }
+            Command::Parity(args) => {
+                let live = load_bar_logs_jsonl(&args.stats_log)
+                    .with_context(|| format!("read stats log {}", args.stats_log.display()))?;
+                let trades = match args.trade_log.as_ref() {
+                    Some(path) => load_trade_logs_jsonl(path)
+                        .with_context(|| format!("read trade log {}", path.display()))?,
+                    None => Vec::new(),
+                };
+                let (Some(first), Some(last)) = (
+                    live.iter().map(|log| log.timestamp).min(),
+                    live.iter().map(|log| log.timestamp).max(),
+                ) else {
+                    return Err(anyhow!("stats log {} is empty", args.stats_log.display()));
+                };
+                let start = first - config.data.bar_interval.duration() * args.warmup_bars as i32;
+                let bars = load_backtest_bars_from_db(
+                    &args.db,
+                    start,
+                    last,
+                    config.data.price_field,
+                    &config.pair,
+                    config.data.bar_interval,
+                )
+                .context("load backtest bars from db")?;
+                let report = check_parity(
+                    &config,
+                    &bars,
+                    &live,
+                    &trades,
+                    &ParityConfig {
+                        zscore_tolerance: args.z_tolerance,
+                        sizing_tolerance: args.sizing_tolerance,
+                    },
+                )
+                .context("check parity")?;
+                match args.format {
+                    AnalyzeOutputFormat::Text => print!("{}", format_parity_text(&report)),
+                    AnalyzeOutputFormat::Json => {
+                        let payload =
+                            serde_json::to_string_pretty(&report).context("format parity")?;
+                        println!("{payload}");
+                    }
+                }
+                return Ok(());
+            }
+            Command::Stress(args) => {
+                let mut scenarios = default_stress_scenarios(args.seed, config.data.bar_interval);
+                if !args.scenario.is_empty() {
+                    if let Some(unknown) = args.scenario.iter().find(|name| {
+                        !scenarios
+                            .iter()
+                            .any(|scenario| scenario.name.eq_ignore_ascii_case(name))
+                    }) {
+                        let known: Vec<&str> = scenarios
+                            .iter()
+                            .map(|scenario| scenario.name.as_str())
+                            .collect();
+                        return Err(anyhow!(
+                            "unknown scenario {unknown}; expected one of {}",
+                            known.join(", ")
+                        ));
+                    }
+                    scenarios.retain(|scenario| {
+                        args.scenario
+                            .iter()
+                            .any(|name| scenario.name.eq_ignore_ascii_case(name))
+                    });
+                }
+                if let Some(dir) = args.bars_dir.as_ref() {
+                    std::fs::create_dir_all(dir).context("create bars dir")?;
+                    for scenario in &scenarios {
+                        let bars = generate_bars(&scenario.config).context("generate bars")?;
+                        write_bars_to_output(
+                            &bars,
+                            &dir.join(format!("{}.json", scenario.name)),
+                            &config.pair,
+                            config.data.bar_interval,
+                        )
+                        .context("write scenario bars")?;
+                    }
+                }
+                let reports = run_stress(&config, &scenarios).context("run stress scenarios")?;
+                match args.format {
+                    AnalyzeOutputFormat::Text => print!("{}", format_stress_text(&reports)),
+                    AnalyzeOutputFormat::Json => {
+                        let payload =
+                            serde_json::to_string_pretty(&reports).context("format stress")?;
+                        println!("{payload}");
+                    }
+                }
+                return Ok(());
+            }
             Command::OrderTest(args) => {
                 let now = Utc::now();
                 let order = build_order_test_request(args, &config.execution, now);
@@ -287,7 +695,8 @@ async fn main() -> anyhow::Result<()> {
                     .vault_address
                     .or_else(|| config.auth.vault_address.clone());
                 let key = private_key.ok_or_else(|| anyhow!("missing Hyperliquid private key"))?;
-                let mut executor = LiveOrderExecutor::with_private_key(base_url.clone(), key);
+                let mut executor = LiveOrderExecutor::with_private_key(base_url.clone(), key)
+                    .with_pair(config.pair.clone());
                 if let Some(vault) = vault_address {
                     executor = executor.with_vault_address(vault);
                 }
@@ -331,8 +740,11 @@ async fn main() -> anyhow::Result<()> {
                     return Ok(());
                 }
                 let now = Utc::now();
-                let bar_time = align_to_bar_close(now).context("align market-test timestamp")?;
-                let price_source = HyperliquidPriceSource::new(base_url.clone());
+                let bar_time = align_to_bar_close(now, config.data.bar_interval)
+                    .context("align market-test timestamp")?;
+                let price_source = HyperliquidPriceSource::new(base_url.clone())
+                    .with_pair(config.pair.clone())
+                    .with_interval(config.data.bar_interval);
                 let bar = price_source
                     .fetch_bar(args.symbol, bar_time)
                     .await
@@ -357,7 +769,8 @@ async fn main() -> anyhow::Result<()> {
                     .vault_address
                     .or_else(|| config.auth.vault_address.clone());
                 let key = private_key.ok_or_else(|| anyhow!("missing Hyperliquid private key"))?;
-                let mut executor = LiveOrderExecutor::with_private_key(base_url.clone(), key);
+                let mut executor = LiveOrderExecutor::with_private_key(base_url.clone(), key)
+                    .with_pair(config.pair.clone());
                 if let Some(vault) = vault_address {
                     executor = executor.with_vault_address(vault);
                 }
@@ -434,7 +847,8 @@ async fn main() -> anyhow::Result<()> {
                     .vault_address
                     .or_else(|| config.auth.vault_address.clone());
                 let key = private_key.ok_or_else(|| anyhow!("missing Hyperliquid private key"))?;
-                let mut executor = LiveOrderExecutor::with_private_key(base_url.clone(), key);
+                let mut executor = LiveOrderExecutor::with_private_key(base_url.clone(), key)
+                    .with_pair(config.pair.clone());
                 if let Some(vault) = vault_address {
                     executor = executor.with_vault_address(vault);
                 }
@@ -450,18 +864,124 @@ async fn main() -> anyhow::Result<()> {
         }
     }
 
-    let price_source = HyperliquidPriceSource::new(base_url.clone());
-    let price_fetcher = PriceFetcher::new(Arc::new(price_source.clone()), config.data.price_field);
+    let options = LiveOptions {
+        base_url: base_url.clone(),
+        paper,
+        disable_funding,
+        private_key: cli
+            .private_key
+            .or(cli.api_key)
+            .or_else(|| config.auth.private_key.clone()),
+        wallet_address: cli
+            .wallet_address
+            .or_else(|| config.auth.wallet_address.clone()),
+        vault_address: cli
+            .vault_address
+            .or_else(|| config.auth.vault_address.clone()),
+    };
+    if config.portfolio.is_enabled() {
+        return run_portfolio(&config, &options, run_once, interval_secs).await;
+    }
+    let (mut runner, first_run_at) =
+        build_live_runner(&config, &options, state_path.as_deref(), true, false).await?;
+
+    if run_once {
+        runner.run_once_at(first_run_at).await.context("run once")?;
+        return Ok(());
+    }
+
+    let _ = runner
+        .run_once_at(first_run_at)
+        .await
+        .context("run initial bar")?;
+
+    let (shutdown_rx, shutdown_handle) = spawn_shutdown_listener();
+
+    info!(interval_secs, "starting live loop");
+    runner
+        .run_loop(Duration::from_secs(interval_secs), shutdown_rx)
+        .await
+        .context("run loop")?;
+
+    let _ = shutdown_handle.await;
+    Ok(())
+}
+
+#[derive(Clone)]
+struct LiveOptions {
+    base_url: String,
+    paper: bool,
+    disable_funding: bool,
+    private_key: Option<String>,
+    wallet_address: Option<String>,
+    vault_address: Option<String>,
+}
+
+fn resolve_live_wallet(options: &LiveOptions) -> anyhow::Result<(String, String)> {
+    let key = options
+        .private_key
+        .clone()
+        .ok_or_else(|| anyhow!("missing Hyperliquid private key"))?;
+    let signer = PrivateKeySigner::from_str(key.trim_start_matches("0x"))
+        .map_err(|err| anyhow!("invalid private key: {err}"))?;
+    let signer_wallet = signer.address().to_string();
+    let account_wallet = options
+        .wallet_address
+        .clone()
+        .or_else(|| options.vault_address.clone())
+        .unwrap_or_else(|| signer_wallet.clone());
+    let account_wallet_source = if options.wallet_address.is_some() {
+        "wallet_address"
+    } else if options.vault_address.is_some() {
+        "vault_address"
+    } else {
+        "signer_wallet"
+    };
+    info!(
+        wallet_address_configured = options.wallet_address.is_some(),
+        vault_address_configured = options.vault_address.is_some(),
+        account_wallet_source,
+        using_vault_execution = options.vault_address.is_some(),
+        "resolved live trading wallet"
+    );
+    Ok((key, account_wallet))
+}
+
+fn spawn_shutdown_listener() -> (watch::Receiver<bool>, tokio::task::JoinHandle<()>) {
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+    let shutdown_handle = tokio::spawn(async move {
+        if let Err(err) = tokio::signal::ctrl_c().await {
+            warn!(error = ?err, "failed to listen for ctrl-c");
+        }
+        let _ = shutdown_tx.send(true);
+    });
+    (shutdown_rx, shutdown_handle)
+}
+
+async fn build_live_runner(
+    config: &Config,
+    options: &LiveOptions,
+    state_path: Option<&Path>,
+    with_account_equity: bool,
+    shared_account: bool,
+) -> anyhow::Result<(LiveRunner, DateTime<Utc>)> {
+    let interval = config.data.bar_interval;
+    let price_source = HyperliquidPriceSource::new(options.base_url.clone())
+        .with_pair(config.pair.clone())
+        .with_interval(interval);
+    let price_fetcher = PriceFetcher::new(Arc::new(price_source.clone()), config.data.price_field)
+        .with_interval(interval);
     let book_fetcher = BookFetcher::new(Arc::new(price_source.clone()));
 
-    let funding_fetcher = if disable_funding {
+    let funding_fetcher = if options.disable_funding {
         None
     } else {
-        let source = HyperliquidFundingSource::new(base_url.clone());
+        let source =
+            HyperliquidFundingSource::new(options.base_url.clone()).with_pair(config.pair.clone());
         Some(FundingFetcher::new(Arc::new(source)))
     };
 
-    let (execution, account_source, position_source, fill_source) = if paper {
+    let (execution, account_source, position_source, fill_source) = if options.paper {
         (
             ExecutionEngine::new(Arc::new(PaperOrderExecutor), RetryConfig::fast()),
             None,
@@ -469,53 +989,24 @@ async fn main() -> anyhow::Result<()> {
             None,
         )
     } else {
-        let private_key = cli
-            .private_key
-            .or(cli.api_key)
-            .or_else(|| config.auth.private_key.clone());
-        let wallet_address = cli
-            .wallet_address
-            .or_else(|| config.auth.wallet_address.clone());
-        let vault_address = cli
-            .vault_address
-            .or_else(|| config.auth.vault_address.clone());
-        let key = private_key.ok_or_else(|| anyhow!("missing Hyperliquid private key"))?;
-        let signer = PrivateKeySigner::from_str(key.trim_start_matches("0x"))
-            .map_err(|err| anyhow!("invalid private key: {err}"))?;
-        let signer_wallet = signer.address().to_string();
-        let account_wallet = wallet_address
-            .clone()
-            .or_else(|| vault_address.clone())
-            .unwrap_or_else(|| signer_wallet.clone());
-        let account_wallet_source = if wallet_address.is_some() {
-            "wallet_address"
-        } else if vault_address.is_some() {
-            "vault_address"
-        } else {
-            "signer_wallet"
-        };
-        info!(
-            wallet_address_configured = wallet_address.is_some(),
-            vault_address_configured = vault_address.is_some(),
-            account_wallet_source,
-            using_vault_execution = vault_address.is_some(),
-            "resolved live trading wallet"
+        let (key, account_wallet) = resolve_live_wallet(options)?;
+        let live_account_source = Arc::new(
+            HyperliquidAccountSource::new(options.base_url.clone(), account_wallet.clone())
+                .with_pair(config.pair.clone()),
         );
-        let live_account_source = Arc::new(HyperliquidAccountSource::new(
-            base_url.clone(),
-            account_wallet.clone(),
-        ));
         let account_source: Option<Arc<dyn AccountBalanceSource>> =
-            if matches!(config.position.c_mode, CapitalMode::EquityRatio) {
+            if with_account_equity && matches!(config.position.c_mode, CapitalMode::EquityRatio) {
                 Some(live_account_source.clone())
             } else {
                 None
             };
+        // A shared account nets other strategies' legs; the portfolio reconciles it instead.
         let position_source: Option<Arc<dyn AccountPositionSource>> =
-            Some(live_account_source.clone());
+            (!shared_account).then(|| live_account_source.clone() as _);
         let fill_source: Option<Arc<dyn AccountFillSource>> = Some(live_account_source.clone());
-        let mut executor = LiveOrderExecutor::with_private_key(base_url.clone(), key);
-        if let Some(vault) = vault_address {
+        let mut executor = LiveOrderExecutor::with_private_key(options.base_url.clone(), key)
+            .with_pair(config.pair.clone());
+        if let Some(vault) = options.vault_address.clone() {
             executor = executor.with_vault_address(vault);
         }
         if let Some(leverage) = config.execution.leverage {
@@ -533,9 +1024,12 @@ async fn main() -> anyhow::Result<()> {
     if let Some(source) = fill_source {
         engine = engine.with_fill_source(source);
     }
+    if shared_account {
+        engine = engine.with_shared_account();
+    }
 
     let mut state_writer: Option<Arc<dyn StateWriter>> = None;
-    if let Some(path) = state_path.as_ref() {
+    if let Some(path) = state_path {
         let store = StateStore::new(path.to_string_lossy().as_ref()).context("open state store")?;
         if let Some(state) = store.load().context("load state")? {
             let report = recover_state(state, chrono::Utc::now());
@@ -546,6 +1040,10 @@ async fn main() -> anyhow::Result<()> {
                 .apply_state(report.state)
                 .context("apply recovered state")?;
         }
+        // Restored before warm-up so the filter only replays bars after its checkpoint.
+        if let Some(kalman) = store.load_kalman_state().context("load kalman state")? {
+            engine.restore_kalman_state(kalman);
+        }
         state_writer = Some(Arc::new(StateStoreWriter::new(store)));
     }
 
@@ -573,27 +1071,34 @@ async fn main() -> anyhow::Result<()> {
         runner = runner.with_trade_writer(Arc::new(writer));
     }
     if let Some(path) = config.logging.price_db_path.as_ref() {
-        let store = PriceStore::new(path).context("open price db")?;
+        let store =
+            PriceStore::new_for_series(path, &config.pair, interval).context("open price db")?;
         let writer = PriceStoreWriter::new(store);
         runner = runner.with_price_writer(Arc::new(writer));
     }
 
-    let mut first_run_at = align_to_bar_close(Utc::now()).context("align first run")?;
+    let mut first_run_at = align_to_bar_close(Utc::now(), interval).context("align first run")?;
     if let Some(db_path) = config.logging.price_db_path.as_ref() {
-        let warmup_bars = config.strategy.n_z.max(config.position.n_vol).max(384);
+        let warmup_bars = config
+            .strategy
+            .n_z
+            .max(config.position.n_vol)
+            .max(WARMUP_FLOOR_DAYS * interval.bars_per_day());
         ensure_price_history(
             &price_source,
             db_path,
+            &config.pair,
             config.data.price_field,
+            interval,
             warmup_bars,
             first_run_at,
         )
         .await
         .context("backfill price history")?;
-        let end = latest_completed_bar(first_run_at).context("align warmup end")?;
-        let span_secs = 900 * (warmup_bars.saturating_sub(1)) as i64;
-        let start = end - ChronoDuration::seconds(span_secs);
-        let store = PriceStore::new(db_path).context("open price db for warmup")?;
+        let end = latest_completed_bar(first_run_at, interval).context("align warmup end")?;
+        let start = end - interval.duration() * warmup_bars.saturating_sub(1) as i32;
+        let store = PriceStore::new_for_series(db_path, &config.pair, interval)
+            .context("open price db for warmup")?;
         let records = store
             .load_range(start, end)
             .context("load warmup records")?;
@@ -602,19 +1107,22 @@ async fn main() -> anyhow::Result<()> {
             .warm_up_with_records(&records)
             .context("warm up pipeline")?;
 
-        let latest_run_at = align_to_bar_close(Utc::now()).context("align latest first run")?;
+        let latest_run_at =
+            align_to_bar_close(Utc::now(), interval).context("align latest first run")?;
         if latest_run_at > first_run_at {
             ensure_price_history(
                 &price_source,
                 db_path,
+                &config.pair,
                 config.data.price_field,
+                interval,
                 warmup_bars,
                 latest_run_at,
             )
             .await
             .context("backfill price history catchup")?;
             if let Some((gap_start, gap_end)) =
-                replay_warmup_gap_window(first_run_at, latest_run_at)
+                replay_warmup_gap_window(first_run_at, latest_run_at, interval)
             {
                 let catchup = store
                     .load_range(gap_start, gap_end)
@@ -628,34 +1136,243 @@ async fn main() -> anyhow::Result<()> {
         }
     }
 
-    if run_once {
-        runner.run_once_at(first_run_at).await.context("run once")?;
-        return Ok(());
+    Ok((runner, first_run_at))
+}
+
+async fn run_portfolio(
+    config: &Config,
+    options: &LiveOptions,
+    run_once: bool,
+    interval_secs: u64,
+) -> anyhow::Result<()> {
+    let mut portfolio = PortfolioRunner::new(config.position.clone(), config.portfolio.clone());
+    if !options.paper && matches!(config.position.c_mode, CapitalMode::EquityRatio) {
+        let (_, account_wallet) = resolve_live_wallet(options)?;
+        portfolio = portfolio.with_account_source(Arc::new(HyperliquidAccountSource::new(
+            options.base_url.clone(),
+            account_wallet,
+        )));
     }
 
-    let _ = runner
-        .run_once_at(first_run_at)
-        .await
-        .context("run initial bar")?;
+    let mut members = Vec::with_capacity(config.portfolio.strategies.len());
+    for member in &config.portfolio.strategies {
+        let member_config = load_config(Some(Path::new(&member.config_path)))
+            .with_context(|| format!("load config for portfolio strategy {}", member.name))?;
+        if member_config.data.bar_interval != config.data.bar_interval {
+            return Err(anyhow!(
+                "portfolio strategy {} uses {} bars but the portfolio runs on {}",
+                member.name,
+                member_config.data.bar_interval.as_str(),
+                config.data.bar_interval.as_str()
+            ));
+        }
+        let member_options = LiveOptions {
+            private_key: member_config
+                .auth
+                .private_key
+                .clone()
+                .or_else(|| options.private_key.clone()),
+            wallet_address: member_config
+                .auth
+                .wallet_address
+                .clone()
+                .or_else(|| options.wallet_address.clone()),
+            vault_address: member_config
+                .auth
+                .vault_address
+                .clone()
+                .or_else(|| options.vault_address.clone()),
+            ..options.clone()
+        };
+        // Members trading one pair on the same account are reconciled together.
+        let account = if options.paper {
+            None
+        } else {
+            let (_, account_wallet) = resolve_live_wallet(&member_options)?;
+            Some((account_wallet.to_lowercase(), member_config.pair.label()))
+        };
+        members.push((member, member_config, member_options, account));
+    }
 
-    let (shutdown_tx, shutdown_rx) = watch::channel(false);
-    let shutdown_handle = tokio::spawn(async move {
-        if let Err(err) = tokio::signal::ctrl_c().await {
-            warn!(error = ?err, "failed to listen for ctrl-c");
+    let mut shared_accounts: HashMap<(String, String), Vec<String>> = HashMap::new();
+    for (member, _, _, account) in &members {
+        if let Some(account) = account {
+            shared_accounts
+                .entry(account.clone())
+                .or_default()
+                .push(member.name.clone());
         }
-        let _ = shutdown_tx.send(true);
-    });
+    }
+    shared_accounts.retain(|_, names| names.len() > 1);
 
-    info!(interval_secs, "starting live loop");
-    runner
-        .run_loop(Duration::from_secs(interval_secs), shutdown_rx)
+    let mut first_run_at = None;
+    for (member, member_config, member_options, account) in &members {
+        let shared_account = account
+            .as_ref()
+            .is_some_and(|account| shared_accounts.contains_key(account));
+        if shared_account && member_config.execution.order_type == OrderType::PostOnly {
+            return Err(anyhow!(
+                "portfolio strategy {} shares its account with another strategy; POST_ONLY entries need per-strategy position reconciliation",
+                member.name
+            ));
+        }
+        let state_path = member
+            .state_path
+            .clone()
+            .or_else(|| member_config.runtime.state_path.clone())
+            .map(PathBuf::from);
+        let (runner, run_at) = build_live_runner(
+            member_config,
+            member_options,
+            state_path.as_deref(),
+            false,
+            shared_account,
+        )
         .await
-        .context("run loop")?;
+        .with_context(|| format!("build portfolio strategy {}", member.name))?;
+        first_run_at = first_run_at.max(Some(run_at));
+        portfolio = portfolio.with_member(member.name.clone(), member.allocation, runner);
+    }
+    for ((account_wallet, _), names) in shared_accounts {
+        let (_, member_config, _, _) = members
+            .iter()
+            .find(|(member, ..)| member.name == names[0])
+            .expect("shared account member");
+        let source = HyperliquidAccountSource::new(options.base_url.clone(), account_wallet)
+            .with_pair(member_config.pair.clone());
+        portfolio = portfolio.with_shared_account(Arc::new(source), names);
+    }
+    let first_run_at = first_run_at.ok_or_else(|| anyhow!("portfolio has no strategies"))?;
+
+    portfolio.run_once_at(first_run_at).await;
+    if run_once {
+        return Ok(());
+    }
+
+    let (shutdown_rx, shutdown_handle) = spawn_shutdown_listener();
+    info!(
+        interval_secs,
+        strategies = config.portfolio.strategies.len(),
+        "starting portfolio loop"
+    );
+    portfolio
+        .run_loop(Duration::from_secs(interval_secs), shutdown_rx)
+        .await;
 
     let _ = shutdown_handle.await;
     Ok(())
 }
 
+fn load_cli_bars(
+    config: &Config,
+    command: &str,
+    bars: Option<&PathBuf>,
+    db: Option<&PathBuf>,
+    start: Option<&str>,
+    end: Option<&str>,
+) -> anyhow::Result<Vec<BacktestBar>> {
+    if let Some(db) = db {
+        let start = start.ok_or_else(|| anyhow!("--start required when --db is set"))?;
+        let end = end.ok_or_else(|| anyhow!("--end required when --db is set"))?;
+        let start = parse_rfc3339(start).context("parse --start")?;
+        let end = parse_rfc3339(end).context("parse --end")?;
+        load_backtest_bars_from_db(
+            db,
+            start,
+            end,
+            config.data.price_field,
+            &config.pair,
+            config.data.bar_interval,
+        )
+        .context("load backtest bars from db")
+    } else if let Some(bars_path) = bars {
+        load_backtest_bars(bars_path).context("load backtest bars")
+    } else {
+        Err(anyhow!("--bars or --db is required for {command}"))
+    }
+}
+
+/// Runs a CLI backtest with its optional 1m bars, book logs and simulated exchange.
+async fn run_cli_backtest(
+    config: Config,
+    bars: &[BacktestBar],
+    minute_bars: Option<&Path>,
+    book_logs: Option<&Path>,
+    simulated_exchange: bool,
+) -> anyhow::Result<BacktestResult> {
+    let mut engine = BacktestEngine::new(config);
+    if let Some(path) = minute_bars {
+        let minute_bars = load_backtest_bars(path).context("load minute bars")?;
+        engine = engine.with_minute_bars(minute_bars);
+    }
+    if let Some(path) = book_logs {
+        let logs = load_bar_logs_jsonl(path).context("load book logs")?;
+        let model = BookSlippageModel::from_bar_logs(&logs);
+        info!(bars = model.len(), path = %path.display(), "loaded book slippage data");
+        engine = engine.with_book_slippage(model);
+    }
+    if simulated_exchange {
+        engine
+            .run_simulated(bars)
+            .await
+            .context("run simulated backtest")
+    } else {
+        engine.run(bars).context("run backtest")
+    }
+}
+
+fn run_inputs(
+    bars: BarSource,
+    minute_bars: Option<&Path>,
+    book_logs: Option<&Path>,
+    simulated_exchange: bool,
+) -> anyhow::Result<RunInputs> {
+    Ok(RunInputs {
+        bars,
+        minute_bars: minute_bars
+            .map(InputFile::read)
+            .transpose()
+            .context("checksum minute bars")?,
+        book_logs: book_logs
+            .map(InputFile::read)
+            .transpose()
+            .context("checksum book logs")?,
+        simulated_exchange,
+    })
+}
+
+/// Trades and bar logs for one side of `backtest-diff`: a saved `--output-dir` (bar logs
+/// optional) or a native run over `bars` with `config_path`, falling back to `config`.
+fn load_diff_side(
+    config: &Config,
+    bars: &[BacktestBar],
+    config_path: Option<&Path>,
+    dir: Option<&Path>,
+) -> anyhow::Result<(Vec<Trade>, Vec<BarLog>)> {
+    if let Some(dir) = dir {
+        let trades = load_trades_csv(&dir.join("trades.csv"))
+            .with_context(|| format!("read trades from {}", dir.display()))?;
+        let logs_path = dir.join("bar_logs.jsonl");
+        let logs = if logs_path.exists() {
+            load_bar_logs_jsonl(&logs_path)
+                .with_context(|| format!("read bar logs {}", logs_path.display()))?
+        } else {
+            Vec::new()
+        };
+        return Ok((trades, logs));
+    }
+    let config = match config_path {
+        Some(path) => {
+            load_config(Some(path)).with_context(|| format!("load config {}", path.display()))?
+        }
+        None => config.clone(),
+    };
+    let result = BacktestEngine::new(config)
+        .run(bars)
+        .context("run backtest")?;
+    Ok((result.trades, result.bar_logs))
+}
+
 fn parse_rfc3339(value: &str) -> anyhow::Result<DateTime<Utc>> {
     let parsed = DateTime::parse_from_rfc3339(value)
         .with_context(|| format!("invalid RFC3339 timestamp: {value}"))?;
@@ -682,6 +1399,37 @@ fn parse_f64_csv(value: &str) -> anyhow::Result<Vec<f64>> {
     })
 }
 
+fn parse_parameter_grid(args: &ParameterGridArgs) -> anyhow::Result<ParameterGrid> {
+    Ok(ParameterGrid {
+        entry_z: optional_csv(args.entry_zs.as_deref(), parse_decimal_csv)
+            .context("parse --entry-zs")?,
+        tp_z: optional_csv(args.tp_zs.as_deref(), parse_decimal_csv).context("parse --tp-zs")?,
+        sl_z: optional_csv(args.sl_zs.as_deref(), parse_decimal_csv).context("parse --sl-zs")?,
+        n_z: optional_csv(args.n_zs.as_deref(), parse_usize_csv).context("parse --n-zs")?,
+        cooldown_hours: optional_csv(args.cooldown_hours.as_deref(), |value| {
+            parse_csv_items(value, |item| {
+                item.parse::<u32>()
+                    .with_context(|| format!("invalid u32 value: {item}"))
+            })
+        })
+        .context("parse --cooldown-hours")?,
+        sigma_floor_mode: optional_csv(args.sigma_floor_modes.as_deref(), |value| {
+            parse_csv_items(value, |item| {
+                SigmaFloorMode::from_str(item)
+                    .with_context(|| format!("invalid sigma floor mode: {item}"))
+            })
+        })
+        .context("parse --sigma-floor-modes")?,
+    })
+}
+
+fn optional_csv<T>(
+    value: Option<&str>,
+    parse: impl Fn(&str) -> anyhow::Result<Vec<T>>,
+) -> anyhow::Result<Vec<T>> {
+    value.map(parse).transpose().map(Option::unwrap_or_default)
+}
+
 fn parse_decimal_csv(value: &str) -> anyhow::Result<Vec<Decimal>> {
     parse_csv_items(value, |item| {
         Decimal::from_str(item).with_context(|| format!("invalid decimal value: {item}"))
@@ -803,7 +1551,7 @@ mod tests {
     #[test]
     fn build_order_test_request_sets_expiry_for_post_only() {
         let args = OrderTestArgs {
-            symbol: Symbol::EthPerp,
+            symbol: Symbol::Base,
             side: OrderSide::Buy,
             qty: dec!(0.01),
             limit_price: dec!(2000),
@@ -835,10 +1583,10 @@ mod tests {
 
     #[test]
     fn cancel_order_output_reports_cancelled_oid() {
-        let output = cancel_order_output(Symbol::EthPerp, 42);
+        let output = cancel_order_output(Symbol::Base, 42);
 
         assert_eq!(output["status"], "cancelled");
-        assert_eq!(output["symbol"], "ETH_PERP");
+        assert_eq!(output["symbol"], "BASE");
         assert_eq!(output["oid"], 42);
     }
 }
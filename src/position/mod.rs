@@ -2,12 +2,16 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use crate::config::{CapitalMode, InstrumentConstraints, PositionConfig, RoundingMode};
+use crate::config::{
+    CapitalMode, InstrumentConstraints, PositionConfig, RoundingMode, SpreadConfig, SpreadModel,
+};
 
 #[derive(Debug, Error)]
 pub enum RiskParityError {
     #[error("volatility must be positive")]
     InvalidVolatility,
+    #[error("hedge ratio must be non-zero")]
+    InvalidBeta,
 }
 
 #[derive(Debug, Error)]
@@ -28,29 +32,59 @@ pub enum PositionError {
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct RiskParityWeights {
-    pub w_eth: Decimal,
-    pub w_btc: Decimal,
+    pub w_base: Decimal,
+    pub w_quote: Decimal,
 }
 
 pub fn risk_parity_weights(
-    vol_eth: Decimal,
-    vol_btc: Decimal,
+    vol_base: Decimal,
+    vol_quote: Decimal,
 ) -> Result<RiskParityWeights, RiskParityError> {
-    if vol_eth <= Decimal::ZERO || vol_btc <= Decimal::ZERO {
+    if vol_base <= Decimal::ZERO || vol_quote <= Decimal::ZERO {
         return Ok(RiskParityWeights {
-            w_eth: Decimal::new(5, 1),
-            w_btc: Decimal::new(5, 1),
+            w_base: Decimal::new(5, 1),
+            w_quote: Decimal::new(5, 1),
         });
     }
-    let inv_eth = Decimal::ONE / vol_eth;
-    let inv_btc = Decimal::ONE / vol_btc;
-    let total = inv_eth + inv_btc;
+    let inv_base = Decimal::ONE / vol_base;
+    let inv_quote = Decimal::ONE / vol_quote;
+    let total = inv_base + inv_quote;
     if total == Decimal::ZERO {
         return Err(RiskParityError::InvalidVolatility);
     }
-    let w_eth = inv_eth / total;
-    let w_btc = Decimal::ONE - w_eth;
-    Ok(RiskParityWeights { w_eth, w_btc })
+    let w_base = inv_base / total;
+    let w_quote = Decimal::ONE - w_base;
+    Ok(RiskParityWeights { w_base, w_quote })
+}
+
+/// Notional split for the spread `ln(ETH) - beta * ln(BTC)`: the BTC leg carries
+/// `|beta|` of notional per unit of ETH notional.
+pub fn beta_hedge_weights(beta: Decimal) -> Result<RiskParityWeights, RiskParityError> {
+    let hedge = beta.abs();
+    if hedge == Decimal::ZERO {
+        return Err(RiskParityError::InvalidBeta);
+    }
+    let w_base = Decimal::ONE / (Decimal::ONE + hedge);
+    Ok(RiskParityWeights {
+        w_base,
+        w_quote: Decimal::ONE - w_base,
+    })
+}
+
+/// Leg weights for the configured spread model; rolling-beta spreads hedge by the
+/// estimated beta once available and fall back to inverse-volatility weights otherwise.
+pub fn spread_weights(
+    spread: &SpreadConfig,
+    vol_base: Decimal,
+    vol_quote: Decimal,
+    beta: Option<Decimal>,
+) -> Result<RiskParityWeights, RiskParityError> {
+    match (spread.model, beta) {
+        (SpreadModel::RollingBeta | SpreadModel::Kalman, Some(beta)) if beta != Decimal::ZERO => {
+            beta_hedge_weights(beta)
+        }
+        _ => risk_parity_weights(vol_base, vol_quote),
+    }
 }
 
 pub fn compute_capital(config: &PositionConfig, equity: Decimal) -> Result<Decimal, CapitalError> {
@@ -1,15 +1,13 @@
 use std::collections::HashMap;
 
-use chrono::{DateTime, Duration, Utc};
+use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use thiserror::Error;
 
-use crate::config::{PriceField, Symbol};
+use crate::config::{BarInterval, PairConfig, PriceField, Symbol};
 use crate::data::{DataError, PriceSource, align_to_bar_close};
 use crate::storage::{PriceBarRecord, PriceStore, PriceStoreError};
 
-const BAR_SECS: i64 = 900;
-
 #[derive(Debug, Error)]
 pub enum BackfillError {
     #[error("data error: {0}")]
@@ -23,20 +21,24 @@ pub enum BackfillError {
     },
 }
 
-pub fn latest_completed_bar(now: DateTime<Utc>) -> Result<DateTime<Utc>, DataError> {
-    let aligned = align_to_bar_close(now)?;
-    Ok(aligned - Duration::seconds(BAR_SECS))
+pub fn latest_completed_bar(
+    now: DateTime<Utc>,
+    interval: BarInterval,
+) -> Result<DateTime<Utc>, DataError> {
+    let aligned = align_to_bar_close(now, interval)?;
+    Ok(aligned - interval.duration())
 }
 
 pub fn replay_warmup_gap_window(
     warmed_run_bar: DateTime<Utc>,
     target_run_bar: DateTime<Utc>,
+    interval: BarInterval,
 ) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
     if target_run_bar <= warmed_run_bar {
         return None;
     }
     let start = warmed_run_bar;
-    let end = target_run_bar - Duration::seconds(BAR_SECS);
+    let end = target_run_bar - interval.duration();
     if start <= end {
         Some((start, end))
     } else {
@@ -47,66 +49,75 @@ pub fn replay_warmup_gap_window(
 pub async fn ensure_price_history(
     source: &dyn PriceSource,
     db_path: &str,
+    pair: &PairConfig,
     price_field: PriceField,
+    interval: BarInterval,
     bars_needed: usize,
     now: DateTime<Utc>,
 ) -> Result<(), BackfillError> {
     if bars_needed == 0 {
         return Ok(());
     }
-    let end = latest_completed_bar(now)?;
-    let span_secs = BAR_SECS * (bars_needed as i64 - 1);
-    let start = end - Duration::seconds(span_secs);
+    let end = latest_completed_bar(now, interval)?;
+    let start = end - interval.duration() * (bars_needed as i32 - 1);
 
-    let store = PriceStore::new(db_path)?;
+    let store = PriceStore::new_for_series(db_path, pair, interval)?;
     let existing = store.load_range(start, end)?;
     if existing.len() >= bars_needed {
         return Ok(());
     }
 
-    let eth_history = source.fetch_history(Symbol::EthPerp, start, end).await?;
-    let btc_history = source.fetch_history(Symbol::BtcPerp, start, end).await?;
+    let base_history = source.fetch_history(Symbol::Base, start, end).await?;
+    let quote_history = source.fetch_history(Symbol::Quote, start, end).await?;
 
-    let eth_map: HashMap<_, _> = eth_history
+    let base_map: HashMap<_, _> = base_history
         .into_iter()
         .map(|bar| (bar.timestamp, bar))
         .collect();
-    let btc_map: HashMap<_, _> = btc_history
+    let quote_map: HashMap<_, _> = quote_history
         .into_iter()
         .map(|bar| (bar.timestamp, bar))
         .collect();
 
     for idx in 0..bars_needed {
-        let ts = start + Duration::seconds(BAR_SECS * idx as i64);
-        let eth_bar = eth_map.get(&ts).ok_or(BackfillError::MissingBar {
-            symbol: Symbol::EthPerp,
+        let ts = start + interval.duration() * idx as i32;
+        let base_bar = base_map.get(&ts).ok_or(BackfillError::MissingBar {
+            symbol: Symbol::Base,
             timestamp: ts,
         })?;
-        let btc_bar = btc_map.get(&ts).ok_or(BackfillError::MissingBar {
-            symbol: Symbol::BtcPerp,
+        let quote_bar = quote_map.get(&ts).ok_or(BackfillError::MissingBar {
+            symbol: Symbol::Quote,
             timestamp: ts,
         })?;
-        let eth_price = effective_price(price_field, eth_bar.mid, eth_bar.mark, eth_bar.close)
+        let base_price = effective_price(price_field, base_bar.mid, base_bar.mark, base_bar.close)
             .ok_or(BackfillError::MissingBar {
-                symbol: Symbol::EthPerp,
-                timestamp: ts,
-            })?;
-        let btc_price = effective_price(price_field, btc_bar.mid, btc_bar.mark, btc_bar.close)
-            .ok_or(BackfillError::MissingBar {
-                symbol: Symbol::BtcPerp,
+                symbol: Symbol::Base,
                 timestamp: ts,
             })?;
+        let quote_price =
+            effective_price(price_field, quote_bar.mid, quote_bar.mark, quote_bar.close).ok_or(
+                BackfillError::MissingBar {
+                    symbol: Symbol::Quote,
+                    timestamp: ts,
+                },
+            )?;
         let record = PriceBarRecord {
             timestamp: ts,
-            eth_mid: eth_bar.mid.or(Some(eth_price)),
-            eth_mark: eth_bar.mark,
-            eth_close: eth_bar.close,
-            btc_mid: btc_bar.mid.or(Some(btc_price)),
-            btc_mark: btc_bar.mark,
-            btc_close: btc_bar.close,
-            funding_eth: None,
-            funding_btc: None,
+            base_mid: base_bar.mid.or(Some(base_price)),
+            base_mark: base_bar.mark,
+            base_close: base_bar.close,
+            quote_mid: quote_bar.mid.or(Some(quote_price)),
+            quote_mark: quote_bar.mark,
+            quote_close: quote_bar.close,
+            funding_base: None,
+            funding_quote: None,
             funding_interval_hours: None,
+            base_open: base_bar.open,
+            base_high: base_bar.high,
+            base_low: base_bar.low,
+            quote_open: quote_bar.open,
+            quote_high: quote_bar.high,
+            quote_low: quote_bar.low,
         };
         store.save(&record)?;
     }
@@ -147,17 +158,30 @@ mod tests {
     #[test]
     fn latest_completed_bar_returns_previous_bar_for_mid_interval_time() {
         let now = ts(2026, 2, 17, 2, 21, 33);
-        let got = latest_completed_bar(now).expect("aligned timestamp");
+        let got = latest_completed_bar(now, BarInterval::M15).expect("aligned timestamp");
         assert_eq!(got, ts(2026, 2, 17, 2, 0, 0));
     }
 
     #[test]
     fn latest_completed_bar_steps_back_on_bar_boundary() {
         let now = ts(2026, 2, 17, 2, 30, 0);
-        let got = latest_completed_bar(now).expect("aligned timestamp");
+        let got = latest_completed_bar(now, BarInterval::M15).expect("aligned timestamp");
         assert_eq!(got, ts(2026, 2, 17, 2, 15, 0));
     }
 
+    #[test]
+    fn latest_completed_bar_follows_the_configured_interval() {
+        let now = ts(2026, 2, 17, 10, 7, 0);
+        assert_eq!(
+            latest_completed_bar(now, BarInterval::H1).expect("aligned timestamp"),
+            ts(2026, 2, 17, 9, 0, 0)
+        );
+        assert_eq!(
+            latest_completed_bar(now, BarInterval::H4).expect("aligned timestamp"),
+            ts(2026, 2, 17, 4, 0, 0)
+        );
+    }
+
     #[tokio::test]
     async fn ensure_price_history_uses_completed_bar_window() {
         let now = ts(2026, 2, 17, 10, 7, 0);
@@ -167,24 +191,33 @@ mod tests {
 
         let mut source = MockPriceSource::default();
         source.insert_history(
-            Symbol::EthPerp,
+            Symbol::Base,
             vec![
-                PriceBar::new(Symbol::EthPerp, start, Some(dec!(2000)), None, None),
-                PriceBar::new(Symbol::EthPerp, middle, Some(dec!(2001)), None, None),
-                PriceBar::new(Symbol::EthPerp, end, Some(dec!(2002)), None, None),
+                PriceBar::new(Symbol::Base, start, Some(dec!(2000)), None, None),
+                PriceBar::new(Symbol::Base, middle, Some(dec!(2001)), None, None),
+                PriceBar::new(Symbol::Base, end, Some(dec!(2002)), None, None),
             ],
         );
         source.insert_history(
-            Symbol::BtcPerp,
+            Symbol::Quote,
             vec![
-                PriceBar::new(Symbol::BtcPerp, start, Some(dec!(30000)), None, None),
-                PriceBar::new(Symbol::BtcPerp, middle, Some(dec!(30010)), None, None),
-                PriceBar::new(Symbol::BtcPerp, end, Some(dec!(30020)), None, None),
+                PriceBar::new(Symbol::Quote, start, Some(dec!(30000)), None, None),
+                PriceBar::new(Symbol::Quote, middle, Some(dec!(30010)), None, None),
+                PriceBar::new(Symbol::Quote, end, Some(dec!(30020)), None, None),
             ],
         );
 
         let db_path = format!("/tmp/eth_btc_backfill_{}.sqlite", Uuid::new_v4());
-        let result = ensure_price_history(&source, &db_path, PriceField::Mid, 3, now).await;
+        let result = ensure_price_history(
+            &source,
+            &db_path,
+            &PairConfig::default(),
+            PriceField::Mid,
+            BarInterval::M15,
+            3,
+            now,
+        )
+        .await;
         assert!(result.is_ok(), "unexpected error: {result:?}");
 
         let store = PriceStore::new(&db_path).expect("open price store");
@@ -199,7 +232,7 @@ mod tests {
     fn replay_warmup_gap_window_returns_missing_middle_range() {
         let warm = ts(2026, 2, 17, 2, 15, 0);
         let target = ts(2026, 2, 17, 2, 45, 0);
-        let gap = replay_warmup_gap_window(warm, target).expect("gap exists");
+        let gap = replay_warmup_gap_window(warm, target, BarInterval::M15).expect("gap exists");
         assert_eq!(gap.0, ts(2026, 2, 17, 2, 15, 0));
         assert_eq!(gap.1, ts(2026, 2, 17, 2, 30, 0));
     }
@@ -209,7 +242,7 @@ mod tests {
         let warm = ts(2026, 2, 17, 2, 15, 0);
         let target = ts(2026, 2, 17, 2, 30, 0);
         assert_eq!(
-            replay_warmup_gap_window(warm, target),
+            replay_warmup_gap_window(warm, target, BarInterval::M15),
             Some((ts(2026, 2, 17, 2, 15, 0), ts(2026, 2, 17, 2, 15, 0)))
         );
     }
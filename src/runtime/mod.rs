@@ -12,10 +12,12 @@ use crate::account::{AccountBalanceSource, AccountPositionSource};
 use crate::core::strategy::{StrategyBar, StrategyEngine, StrategyError, StrategyOutcome};
 use crate::data::{BookFetcher, DataError, PairOrderBookSnapshot, PriceFetcher};
 use crate::funding::FundingFetcher;
+use crate::indicators::KalmanState;
 use crate::logging::{BarLog, BarLogWriter, TradeLogWriter, redact_wallet_addresses};
 use crate::state::{StateError, StateStore, StrategyState};
 use crate::storage::{PriceBarRecord, PriceBarWriter};
 pub mod backfill;
+pub mod portfolio;
 
 #[derive(Debug, Error)]
 pub enum RunnerError {
@@ -30,6 +32,10 @@ pub enum RunnerError {
 #[async_trait::async_trait]
 pub trait StateWriter: Send + Sync {
     async fn save(&self, state: &StrategyState) -> Result<(), StateError>;
+
+    async fn save_kalman_state(&self, _state: &KalmanState) -> Result<(), StateError> {
+        Ok(())
+    }
 }
 
 pub struct StateStoreWriter {
@@ -50,19 +56,24 @@ impl StateWriter for StateStoreWriter {
         let store = self.store.lock().await;
         store.save(state)
     }
+
+    async fn save_kalman_state(&self, state: &KalmanState) -> Result<(), StateError> {
+        let store = self.store.lock().await;
+        store.save_kalman_state(state)
+    }
 }
 
 fn apply_book_snapshot(bar: &mut BarLog, snapshot: &PairOrderBookSnapshot) {
-    bar.eth_best_bid = Some(snapshot.eth.best_bid);
-    bar.eth_best_ask = Some(snapshot.eth.best_ask);
-    bar.eth_bid_size = Some(snapshot.eth.bid_size);
-    bar.eth_ask_size = Some(snapshot.eth.ask_size);
-    bar.eth_spread_bps = snapshot.eth.spread_bps();
-    bar.btc_best_bid = Some(snapshot.btc.best_bid);
-    bar.btc_best_ask = Some(snapshot.btc.best_ask);
-    bar.btc_bid_size = Some(snapshot.btc.bid_size);
-    bar.btc_ask_size = Some(snapshot.btc.ask_size);
-    bar.btc_spread_bps = snapshot.btc.spread_bps();
+    bar.base_best_bid = Some(snapshot.base.best_bid);
+    bar.base_best_ask = Some(snapshot.base.best_ask);
+    bar.base_bid_size = Some(snapshot.base.bid_size);
+    bar.base_ask_size = Some(snapshot.base.ask_size);
+    bar.base_spread_bps = snapshot.base.spread_bps();
+    bar.quote_best_bid = Some(snapshot.quote.best_bid);
+    bar.quote_best_ask = Some(snapshot.quote.best_ask);
+    bar.quote_bid_size = Some(snapshot.quote.bid_size);
+    bar.quote_ask_size = Some(snapshot.quote.ask_size);
+    bar.quote_spread_bps = snapshot.quote.spread_bps();
 }
 
 pub struct LiveRunner {
@@ -140,6 +151,10 @@ impl LiveRunner {
         self
     }
 
+    pub fn engine(&self) -> &StrategyEngine {
+        &self.engine
+    }
+
     pub fn engine_mut(&mut self) -> &mut StrategyEngine {
         &mut self.engine
     }
@@ -147,10 +162,10 @@ impl LiveRunner {
     async fn record_strategy_failure(
         &mut self,
         timestamp: DateTime<Utc>,
-        eth_price: Decimal,
-        btc_price: Decimal,
-        funding_eth: Option<Decimal>,
-        funding_btc: Option<Decimal>,
+        base_price: Decimal,
+        quote_price: Decimal,
+        funding_base: Option<Decimal>,
+        funding_quote: Option<Decimal>,
         err: &StrategyError,
     ) {
         if let Some(writer) = &self.state_writer
@@ -158,32 +173,40 @@ impl LiveRunner {
         {
             warn!(error = ?save_err, "state save failed after strategy error");
         }
+        if let (Some(writer), Some(kalman)) = (&self.state_writer, self.engine.kalman_state())
+            && let Err(save_err) = writer.save_kalman_state(kalman).await
+        {
+            warn!(error = ?save_err, "kalman state save failed after strategy error");
+        }
         if let Some(writer) = &self.stats_writer {
             let position = self.engine.state().state().position.clone();
             let unrealized_pnl = position
                 .as_ref()
                 .map(|pos| {
-                    pos.eth.qty * (eth_price - pos.eth.avg_price)
-                        + pos.btc.qty * (btc_price - pos.btc.avg_price)
+                    pos.base.qty * (base_price - pos.base.avg_price)
+                        + pos.quote.qty * (quote_price - pos.quote.avg_price)
                 })
                 .unwrap_or(Decimal::ZERO);
             let failed_log = BarLog {
                 timestamp,
-                eth_price: Some(eth_price),
-                btc_price: Some(btc_price),
+                pair: Some(self.engine.config().pair.label()),
+                base_price: Some(base_price),
+                quote_price: Some(quote_price),
                 r: None,
+                spread: None,
+                beta: None,
                 mu: None,
                 sigma: None,
                 sigma_eff: None,
                 zscore: None,
-                vol_eth: None,
-                vol_btc: None,
-                w_eth: None,
-                w_btc: None,
-                notional_eth: None,
-                notional_btc: None,
-                funding_eth,
-                funding_btc,
+                vol_base: None,
+                vol_quote: None,
+                w_base: None,
+                w_quote: None,
+                notional_base: None,
+                notional_quote: None,
+                funding_base,
+                funding_quote,
                 funding_cost_est: None,
                 funding_skip: None,
                 regime_half_life_bars: None,
@@ -193,16 +216,16 @@ impl LiveRunner {
                 estimated_net_edge_bps: None,
                 cost_gate_required_net_edge_bps: None,
                 cost_gate_pass: None,
-                eth_best_bid: None,
-                eth_best_ask: None,
-                eth_bid_size: None,
-                eth_ask_size: None,
-                eth_spread_bps: None,
-                btc_best_bid: None,
-                btc_best_ask: None,
-                btc_bid_size: None,
-                btc_ask_size: None,
-                btc_spread_bps: None,
+                base_best_bid: None,
+                base_best_ask: None,
+                base_bid_size: None,
+                base_ask_size: None,
+                base_spread_bps: None,
+                quote_best_bid: None,
+                quote_best_ask: None,
+                quote_bid_size: None,
+                quote_ask_size: None,
+                quote_spread_bps: None,
                 entry_block_reason: None,
                 run_error: Some(redact_wallet_addresses(&err.to_string())),
                 unrealized_pnl,
@@ -291,15 +314,21 @@ impl LiveRunner {
         if let Some(writer) = &self.price_writer {
             let record = PriceBarRecord {
                 timestamp: snapshot.timestamp,
-                eth_mid: bars_snapshot.eth_bar.mid,
-                eth_mark: bars_snapshot.eth_bar.mark,
-                eth_close: bars_snapshot.eth_bar.close,
-                btc_mid: bars_snapshot.btc_bar.mid,
-                btc_mark: bars_snapshot.btc_bar.mark,
-                btc_close: bars_snapshot.btc_bar.close,
-                funding_eth: funding.as_ref().map(|value| value.eth.rate),
-                funding_btc: funding.as_ref().map(|value| value.btc.rate),
+                base_mid: bars_snapshot.base_bar.mid,
+                base_mark: bars_snapshot.base_bar.mark,
+                base_close: bars_snapshot.base_bar.close,
+                quote_mid: bars_snapshot.quote_bar.mid,
+                quote_mark: bars_snapshot.quote_bar.mark,
+                quote_close: bars_snapshot.quote_bar.close,
+                funding_base: funding.as_ref().map(|value| value.base.rate),
+                funding_quote: funding.as_ref().map(|value| value.quote.rate),
                 funding_interval_hours: funding.as_ref().map(|value| value.interval_hours),
+                base_open: bars_snapshot.base_bar.open,
+                base_high: bars_snapshot.base_bar.high,
+                base_low: bars_snapshot.base_bar.low,
+                quote_open: bars_snapshot.quote_bar.open,
+                quote_high: bars_snapshot.quote_bar.high,
+                quote_low: bars_snapshot.quote_bar.low,
             };
             if let Err(err) = writer.write(&record) {
                 warn!(error = ?err, "price record write failed");
@@ -308,11 +337,11 @@ impl LiveRunner {
 
         let bar = StrategyBar {
             timestamp: snapshot.timestamp,
-            eth_price: snapshot.eth,
-            btc_price: snapshot.btc,
+            base_price: snapshot.base,
+            quote_price: snapshot.quote,
             equity,
-            funding_eth: funding.as_ref().map(|value| value.eth.rate),
-            funding_btc: funding.as_ref().map(|value| value.btc.rate),
+            funding_base: funding.as_ref().map(|value| value.base.rate),
+            funding_quote: funding.as_ref().map(|value| value.quote.rate),
             funding_interval_hours: funding.as_ref().map(|value| value.interval_hours),
         };
 
@@ -324,17 +353,17 @@ impl LiveRunner {
                         .reconcile_exchange_position(
                             &exposure,
                             snapshot.timestamp,
-                            snapshot.eth,
-                            snapshot.btc,
+                            snapshot.base,
+                            snapshot.quote,
                         )
                         .await
                     {
                         self.record_strategy_failure(
                             snapshot.timestamp,
-                            snapshot.eth,
-                            snapshot.btc,
-                            funding.as_ref().map(|value| value.eth.rate),
-                            funding.as_ref().map(|value| value.btc.rate),
+                            snapshot.base,
+                            snapshot.quote,
+                            funding.as_ref().map(|value| value.base.rate),
+                            funding.as_ref().map(|value| value.quote.rate),
                             &err,
                         )
                         .await;
@@ -352,10 +381,10 @@ impl LiveRunner {
             Err(err) => {
                 self.record_strategy_failure(
                     snapshot.timestamp,
-                    snapshot.eth,
-                    snapshot.btc,
-                    funding.as_ref().map(|value| value.eth.rate),
-                    funding.as_ref().map(|value| value.btc.rate),
+                    snapshot.base,
+                    snapshot.quote,
+                    funding.as_ref().map(|value| value.base.rate),
+                    funding.as_ref().map(|value| value.quote.rate),
                     &err,
                 )
                 .await;
@@ -367,6 +396,9 @@ impl LiveRunner {
         }
         if let Some(writer) = &self.state_writer {
             writer.save(self.engine.state().state()).await?;
+            if let Some(kalman) = self.engine.kalman_state() {
+                writer.save_kalman_state(kalman).await?;
+            }
         }
         if let Some(writer) = &self.stats_writer
             && let Err(err) = writer.write(&outcome.bar_log)
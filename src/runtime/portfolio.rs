@@ -0,0 +1,299 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use tokio::sync::watch;
+use tokio::time::interval;
+use tracing::{info, warn};
+
+use crate::account::{AccountBalanceSource, AccountPositionSource};
+use crate::config::{CapitalMode, PortfolioConfig, PositionConfig};
+use crate::core::strategy::{EntryBudget, StrategyOutcome};
+use crate::position::compute_capital;
+use crate::runtime::{LiveRunner, RunnerError};
+use crate::state::{StrategyState, StrategyStatus};
+
+pub struct PortfolioMember {
+    name: String,
+    allocation: Decimal,
+    runner: LiveRunner,
+    last_prices: Option<(Decimal, Decimal)>,
+}
+
+impl PortfolioMember {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn allocation(&self) -> Decimal {
+        self.allocation
+    }
+
+    pub fn runner(&self) -> &LiveRunner {
+        &self.runner
+    }
+
+    pub fn runner_mut(&mut self) -> &mut LiveRunner {
+        &mut self.runner
+    }
+
+    pub fn gross_notional(&self) -> Decimal {
+        gross_notional(self.runner.engine().state().state(), self.last_prices)
+    }
+}
+
+/// Members trading one pair on the same exchange account, reconciled as a group.
+struct SharedAccount {
+    source: Arc<dyn AccountPositionSource>,
+    members: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct PortfolioOutcome {
+    pub name: String,
+    pub result: Result<StrategyOutcome, RunnerError>,
+}
+
+/// Runs several independent strategy runners against one shared capital budget.
+///
+/// Each cycle the portfolio capital is computed once from the shared equity and split by
+/// member allocation; entries that would push the combined gross notional above
+/// `max_gross_notional` are refused by the member engine.
+///
+/// Members sharing an exchange account cannot reconcile against it individually, since the
+/// account nets their positions. Their summed local positions are checked against the
+/// account instead, and a mismatch blocks new entries for the group until it clears.
+pub struct PortfolioRunner {
+    position: PositionConfig,
+    config: PortfolioConfig,
+    members: Vec<PortfolioMember>,
+    account_source: Option<Arc<dyn AccountBalanceSource>>,
+    shared_accounts: Vec<SharedAccount>,
+    now: Arc<dyn Fn() -> DateTime<Utc> + Send + Sync>,
+}
+
+impl PortfolioRunner {
+    pub fn new(position: PositionConfig, config: PortfolioConfig) -> Self {
+        Self {
+            position,
+            config,
+            members: Vec::new(),
+            account_source: None,
+            shared_accounts: Vec::new(),
+            now: Arc::new(Utc::now),
+        }
+    }
+
+    pub fn with_member(
+        mut self,
+        name: impl Into<String>,
+        allocation: Decimal,
+        runner: LiveRunner,
+    ) -> Self {
+        self.members.push(PortfolioMember {
+            name: name.into(),
+            allocation,
+            runner,
+            last_prices: None,
+        });
+        self
+    }
+
+    pub fn with_account_source(mut self, source: Arc<dyn AccountBalanceSource>) -> Self {
+        self.account_source = Some(source);
+        self
+    }
+
+    /// Reconciles the named members, which trade one pair on the account behind `source`,
+    /// against that account's exposure as a group.
+    pub fn with_shared_account(
+        mut self,
+        source: Arc<dyn AccountPositionSource>,
+        members: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.shared_accounts.push(SharedAccount {
+            source,
+            members: members.into_iter().map(Into::into).collect(),
+        });
+        self
+    }
+
+    pub fn with_clock(mut self, now: Arc<dyn Fn() -> DateTime<Utc> + Send + Sync>) -> Self {
+        self.now = now;
+        self
+    }
+
+    pub fn members(&self) -> &[PortfolioMember] {
+        &self.members
+    }
+
+    pub fn members_mut(&mut self) -> &mut [PortfolioMember] {
+        &mut self.members
+    }
+
+    pub fn gross_notional(&self) -> Decimal {
+        self.members
+            .iter()
+            .map(PortfolioMember::gross_notional)
+            .sum()
+    }
+
+    pub async fn run_once(&mut self) -> Vec<PortfolioOutcome> {
+        let now = (self.now)();
+        self.run_once_at(now).await
+    }
+
+    pub async fn run_once_at(&mut self, timestamp: DateTime<Utc>) -> Vec<PortfolioOutcome> {
+        let capital = self.portfolio_capital().await;
+        let unreconciled = self.unreconciled_members().await;
+        let mut outcomes = Vec::with_capacity(self.members.len());
+        for index in 0..self.members.len() {
+            // A member's own exposure counts too, since it can scale into more groups.
+            let gross = self.gross_notional();
+            let member = &mut self.members[index];
+            let budget = EntryBudget {
+                capital: capital
+                    .filter(|_| !unreconciled.contains(&member.name))
+                    .map(|value| value * member.allocation)
+                    .unwrap_or(Decimal::ZERO),
+                max_gross_notional: self
+                    .config
+                    .max_gross_notional
+                    .map(|cap| (cap - gross).max(Decimal::ZERO)),
+            };
+            member.runner.engine_mut().set_entry_budget(Some(budget));
+            let result = member.runner.run_once_at(timestamp).await;
+            match &result {
+                Ok(outcome) => {
+                    if let (Some(base), Some(quote)) =
+                        (outcome.bar_log.base_price, outcome.bar_log.quote_price)
+                    {
+                        member.last_prices = Some((base, quote));
+                    }
+                }
+                Err(err) => {
+                    warn!(strategy = %member.name, error = ?err, "portfolio member run failed");
+                }
+            }
+            outcomes.push(PortfolioOutcome {
+                name: member.name.clone(),
+                result,
+            });
+        }
+        info!(
+            members = outcomes.len(),
+            gross_notional = %self.gross_notional(),
+            "processed portfolio bar"
+        );
+        outcomes
+    }
+
+    pub async fn run_loop(&mut self, tick_interval: Duration, mut shutdown: watch::Receiver<bool>) {
+        let mut ticker = interval(tick_interval);
+        // Consume the immediate tick; caller can run an explicit first iteration.
+        ticker.tick().await;
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {
+                    let _ = self.run_once().await;
+                }
+                _ = shutdown.changed() => {
+                    if *shutdown.borrow() {
+                        info!("shutdown signal received");
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Members of shared accounts whose exposure differs from the members' summed positions,
+    /// or could not be fetched.
+    async fn unreconciled_members(&self) -> HashSet<String> {
+        let mut unreconciled = HashSet::new();
+        for account in &self.shared_accounts {
+            let (base_qty, quote_qty) = self
+                .members
+                .iter()
+                .filter(|member| account.members.contains(&member.name))
+                .filter_map(|member| member.runner.engine().state().state().position.clone())
+                .fold((Decimal::ZERO, Decimal::ZERO), |(base, quote), position| {
+                    (base + position.base.qty, quote + position.quote.qty)
+                });
+            match account.source.fetch_pair_exposure().await {
+                Ok(exposure)
+                    if exposure.base_qty() == base_qty && exposure.quote_qty() == quote_qty =>
+                {
+                    continue;
+                }
+                Ok(exposure) => warn!(
+                    members = ?account.members,
+                    local_base_qty = %base_qty,
+                    local_quote_qty = %quote_qty,
+                    remote_base_qty = %exposure.base_qty(),
+                    remote_quote_qty = %exposure.quote_qty(),
+                    "shared account exposure differs from portfolio members; blocking new entries"
+                ),
+                Err(err) => warn!(
+                    members = ?account.members,
+                    error = ?err,
+                    "shared account exposure fetch failed; blocking new entries"
+                ),
+            }
+            unreconciled.extend(account.members.iter().cloned());
+        }
+        unreconciled
+    }
+
+    async fn portfolio_capital(&self) -> Option<Decimal> {
+        let account_equity = if let Some(source) = &self.account_source {
+            match source.fetch_available_balance().await {
+                Ok(value) => Some(value),
+                Err(err) => {
+                    warn!(error = ?err, "portfolio balance fetch failed; using configured budget");
+                    None
+                }
+            }
+        } else {
+            None
+        };
+        let equity = match (account_equity, self.config.equity_budget) {
+            (Some(account), Some(budget)) => Some(account.min(budget)),
+            (Some(account), None) => Some(account),
+            (None, Some(budget)) => Some(budget),
+            (None, None) => self.position.equity_value,
+        };
+        let equity = match (self.position.c_mode, equity) {
+            (CapitalMode::FixedNotional, _) => equity.unwrap_or(Decimal::ZERO),
+            (CapitalMode::EquityRatio, Some(value)) => value,
+            (CapitalMode::EquityRatio, None) => {
+                warn!("portfolio equity unavailable; blocking new entries");
+                return None;
+            }
+        };
+        match compute_capital(&self.position, equity) {
+            Ok(capital) => Some(capital),
+            Err(err) => {
+                warn!(error = ?err, "portfolio capital unavailable; blocking new entries");
+                None
+            }
+        }
+    }
+}
+
+/// Gross notional held or pending for one strategy; pending legs are valued at `prices`.
+pub fn gross_notional(state: &StrategyState, prices: Option<(Decimal, Decimal)>) -> Decimal {
+    if let Some(position) = state.position.as_ref() {
+        return position.base.qty.abs() * position.base.avg_price
+            + position.quote.qty.abs() * position.quote.avg_price;
+    }
+    if state.status == StrategyStatus::PendingEntry
+        && let Some(pending) = state.pending_entry.as_ref()
+        && let Some((base_price, quote_price)) = prices
+    {
+        return pending.base_qty.abs() * base_price + pending.quote_qty.abs() * quote_price;
+    }
+    Decimal::ZERO
+}
@@ -1,9 +1,11 @@
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 
-use crate::config::{PersistentExtremeConfig, RiskConfig, StaleCrossConfig, StrategyConfig};
+use crate::config::{
+    PersistentExtremeConfig, PositionConfig, RiskConfig, StaleCrossConfig, StrategyConfig,
+};
 use crate::core::{EntrySignal, ExitReason, ExitSignal, TradeDirection};
-use crate::state::{PositionSnapshot, StrategyStatus};
+use crate::state::{PositionGroup, PositionSnapshot, StrategyStatus};
 
 #[derive(Debug, Clone)]
 pub struct EntrySignalDetector {
@@ -96,17 +98,17 @@ impl EntrySignalDetector {
         }
 
         let direction = if zscore >= Decimal::ZERO {
-            TradeDirection::ShortEthLongBtc
+            TradeDirection::ShortBaseLongQuote
         } else {
-            TradeDirection::LongEthShortBtc
+            TradeDirection::LongBaseShortQuote
         };
         if persistent_extreme
             && match direction {
-                TradeDirection::LongEthShortBtc => {
-                    !self.persistent_extreme.allow_long_eth_short_btc
+                TradeDirection::LongBaseShortQuote => {
+                    !self.persistent_extreme.allow_long_base_short_quote
                 }
-                TradeDirection::ShortEthLongBtc => {
-                    !self.persistent_extreme.allow_short_eth_long_btc
+                TradeDirection::ShortBaseLongQuote => {
+                    !self.persistent_extreme.allow_short_base_long_quote
                 }
             }
         {
@@ -116,6 +118,64 @@ impl EntrySignalDetector {
     }
 }
 
+/// Fires when |z| crosses the next scale-in level while a position is open.
+///
+/// Group `k` (zero-based) enters at `entry_z + k * scale_in_step_z`, only in the
+/// direction of the open position and only below `sl_z`.
+#[derive(Debug, Clone)]
+pub struct ScaleInSignalDetector {
+    entry_z: Decimal,
+    sl_z: Decimal,
+    step_z: Decimal,
+    max_groups: u32,
+    prev_z: Option<Decimal>,
+}
+
+impl ScaleInSignalDetector {
+    pub fn new(strategy: StrategyConfig, position: PositionConfig) -> Self {
+        Self {
+            entry_z: strategy.entry_z,
+            sl_z: strategy.sl_z,
+            step_z: position.scale_in_step_z,
+            max_groups: position.max_position_groups,
+            prev_z: None,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        zscore: Option<Decimal>,
+        state: StrategyStatus,
+        position: Option<&PositionSnapshot>,
+        open_groups: usize,
+    ) -> Option<EntrySignal> {
+        let prev_z = self.prev_z;
+        self.prev_z = zscore;
+        let zscore = zscore?;
+        let prev_z = prev_z?;
+        let position = position?;
+        if state != StrategyStatus::InPosition
+            || open_groups == 0
+            || open_groups >= self.max_groups as usize
+        {
+            return None;
+        }
+        let same_side = match position.direction {
+            TradeDirection::ShortBaseLongQuote => zscore > Decimal::ZERO,
+            TradeDirection::LongBaseShortQuote => zscore < Decimal::ZERO,
+        };
+        let level = self.entry_z + self.step_z * Decimal::from(open_groups as u64);
+        let abs_z = zscore.abs();
+        if !same_side || prev_z.abs() >= level || abs_z < level || abs_z >= self.sl_z {
+            return None;
+        }
+        Some(EntrySignal {
+            direction: position.direction,
+            zscore,
+        })
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ExitSignalDetector {
     tp_z: Decimal,
@@ -123,6 +183,7 @@ pub struct ExitSignalDetector {
     max_hold_hours: u32,
     confirm_bars_tp: u32,
     tp_count: u32,
+    scale_in_step_z: Decimal,
 }
 
 impl ExitSignalDetector {
@@ -133,7 +194,43 @@ impl ExitSignalDetector {
             max_hold_hours: risk.max_hold_hours,
             confirm_bars_tp: risk.confirm_bars_tp,
             tp_count: 0,
+            scale_in_step_z: Decimal::ZERO,
+        }
+    }
+
+    pub fn with_scale_in_step_z(mut self, step_z: Decimal) -> Self {
+        self.scale_in_step_z = step_z;
+        self
+    }
+
+    /// Exit rule for a scaled-in group (`index >= 1`): take profit once |z| reverts to
+    /// `tp_z + index * scale_in_step_z`, or time out from the group's own entry time.
+    /// The base group follows `evaluate`, which closes every group.
+    pub fn evaluate_group(
+        &self,
+        zscore: Option<Decimal>,
+        index: usize,
+        group: &PositionGroup,
+        now: DateTime<Utc>,
+    ) -> Option<ExitSignal> {
+        if index == 0 {
+            return None;
+        }
+        let zscore = zscore?;
+        let tp_level = self.tp_z + self.scale_in_step_z * Decimal::from(index as u64);
+        if zscore.abs() <= tp_level {
+            return Some(ExitSignal {
+                reason: ExitReason::TakeProfit,
+                zscore,
+            });
         }
+        if group.holding_hours(now) >= self.max_hold_hours as i64 {
+            return Some(ExitSignal {
+                reason: ExitReason::TimeStop,
+                zscore,
+            });
+        }
+        None
     }
 
     pub fn evaluate(
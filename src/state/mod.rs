@@ -1,10 +1,12 @@
 use chrono::{DateTime, Duration, Utc};
+use rusqlite::OptionalExtension;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::config::RiskConfig;
 use crate::core::{ExitReason, TradeDirection};
+use crate::indicators::KalmanState;
 
 #[derive(Debug, Error)]
 pub enum StateError {
@@ -35,19 +37,21 @@ pub struct PositionLeg {
 pub struct PositionSnapshot {
     pub direction: TradeDirection,
     pub entry_time: DateTime<Utc>,
-    pub eth: PositionLeg,
-    pub btc: PositionLeg,
+    #[serde(alias = "eth")]
+    pub base: PositionLeg,
+    #[serde(alias = "btc")]
+    pub quote: PositionLeg,
 }
 
 impl PositionSnapshot {
     pub fn has_residual(&self) -> bool {
-        let eth_zero = self.eth.qty == Decimal::ZERO;
-        let btc_zero = self.btc.qty == Decimal::ZERO;
-        (eth_zero && !btc_zero) || (!eth_zero && btc_zero)
+        let base_zero = self.base.qty == Decimal::ZERO;
+        let quote_zero = self.quote.qty == Decimal::ZERO;
+        (base_zero && !quote_zero) || (!base_zero && quote_zero)
     }
 
     pub fn is_flat(&self) -> bool {
-        self.eth.qty == Decimal::ZERO && self.btc.qty == Decimal::ZERO
+        self.base.qty == Decimal::ZERO && self.quote.qty == Decimal::ZERO
     }
 
     pub fn holding_hours(&self, now: DateTime<Utc>) -> i64 {
@@ -55,15 +59,93 @@ impl PositionSnapshot {
     }
 }
 
+/// One scaled-in entry; the position snapshot is the aggregate of all open groups.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PositionGroup {
+    pub entry_time: DateTime<Utc>,
+    pub entry_z: Option<Decimal>,
+    pub base: PositionLeg,
+    pub quote: PositionLeg,
+}
+
+impl PositionGroup {
+    pub fn from_snapshot(position: &PositionSnapshot, entry_z: Option<Decimal>) -> Self {
+        Self {
+            entry_time: position.entry_time,
+            entry_z,
+            base: position.base.clone(),
+            quote: position.quote.clone(),
+        }
+    }
+
+    pub fn to_snapshot(&self, direction: TradeDirection) -> PositionSnapshot {
+        PositionSnapshot {
+            direction,
+            entry_time: self.entry_time,
+            base: self.base.clone(),
+            quote: self.quote.clone(),
+        }
+    }
+
+    pub fn holding_hours(&self, now: DateTime<Utc>) -> i64 {
+        (now - self.entry_time).num_hours()
+    }
+}
+
+/// Sums group legs into one snapshot with quantity-weighted entry prices.
+pub fn aggregate_groups(
+    direction: TradeDirection,
+    groups: &[PositionGroup],
+) -> Option<PositionSnapshot> {
+    let entry_time = groups.iter().map(|group| group.entry_time).min()?;
+    let base = aggregate_legs(groups.iter().map(|group| &group.base));
+    let quote = aggregate_legs(groups.iter().map(|group| &group.quote));
+    Some(PositionSnapshot {
+        direction,
+        entry_time,
+        base,
+        quote,
+    })
+}
+
+fn aggregate_legs<'a>(legs: impl Iterator<Item = &'a PositionLeg>) -> PositionLeg {
+    let mut qty = Decimal::ZERO;
+    let mut abs_qty = Decimal::ZERO;
+    let mut cost = Decimal::ZERO;
+    let mut notional = Decimal::ZERO;
+    for leg in legs {
+        qty += leg.qty;
+        abs_qty += leg.qty.abs();
+        cost += leg.qty.abs() * leg.avg_price;
+        notional += leg.notional;
+    }
+    let avg_price = if abs_qty == Decimal::ZERO {
+        Decimal::ZERO
+    } else {
+        cost / abs_qty
+    };
+    PositionLeg {
+        qty,
+        avg_price,
+        notional,
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PendingEntrySnapshot {
     pub direction: TradeDirection,
-    pub eth_qty: Decimal,
-    pub btc_qty: Decimal,
-    pub eth_order_id: u64,
-    pub btc_order_id: u64,
+    #[serde(alias = "eth_qty")]
+    pub base_qty: Decimal,
+    #[serde(alias = "btc_qty")]
+    pub quote_qty: Decimal,
+    #[serde(alias = "eth_order_id")]
+    pub base_order_id: u64,
+    #[serde(alias = "btc_order_id")]
+    pub quote_order_id: u64,
     pub submitted_at: DateTime<Utc>,
     pub expires_at: DateTime<Utc>,
+    #[serde(default)]
+    pub entry_z: Option<Decimal>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -74,6 +156,31 @@ pub struct StrategyState {
     pub cooldown_until: Option<DateTime<Utc>>,
     #[serde(default)]
     pub cumulative_realized_pnl: Decimal,
+    #[serde(default)]
+    pub groups: Vec<PositionGroup>,
+}
+
+impl StrategyState {
+    /// Open entry groups; states saved before scale-in support hold one implicit group.
+    pub fn position_groups(&self) -> Vec<PositionGroup> {
+        match (&self.position, self.groups.is_empty()) {
+            (Some(position), true) => vec![PositionGroup::from_snapshot(position, None)],
+            _ => self.groups.clone(),
+        }
+    }
+
+    /// Whether the persisted groups add up to the aggregate position snapshot.
+    pub fn groups_match_position(&self) -> bool {
+        if self.groups.is_empty() {
+            return true;
+        }
+        let Some(position) = self.position.as_ref() else {
+            return false;
+        };
+        aggregate_groups(position.direction, &self.groups).is_some_and(|aggregate| {
+            aggregate.base.qty == position.base.qty && aggregate.quote.qty == position.quote.qty
+        })
+    }
 }
 
 impl Default for StrategyState {
@@ -84,6 +191,7 @@ impl Default for StrategyState {
             pending_entry: None,
             cooldown_until: None,
             cumulative_realized_pnl: Decimal::ZERO,
+            groups: Vec::new(),
         }
     }
 }
@@ -111,6 +219,7 @@ impl StateMachine {
         self.state.position = None;
         self.state.pending_entry = None;
         self.state.cooldown_until = None;
+        self.state.groups.clear();
     }
 
     pub fn set_cumulative_realized_pnl(&mut self, value: Decimal) {
@@ -140,6 +249,11 @@ impl StateMachine {
                         "in-position state must contain position and no pending entry".to_string(),
                     ));
                 }
+                if !state.groups_match_position() {
+                    return Err(StateError::InvalidTransition(
+                        "position groups do not add up to position".to_string(),
+                    ));
+                }
             }
             StrategyStatus::Cooldown => {
                 if state.cooldown_until.is_none()
@@ -152,6 +266,11 @@ impl StateMachine {
                 }
             }
         }
+        if state.status != StrategyStatus::InPosition && !state.groups.is_empty() {
+            return Err(StateError::InvalidTransition(
+                "position groups require in-position state".to_string(),
+            ));
+        }
         self.state = state;
         Ok(())
     }
@@ -160,6 +279,15 @@ impl StateMachine {
         &mut self,
         position: PositionSnapshot,
         now: DateTime<Utc>,
+    ) -> Result<(), StateError> {
+        self.enter_with_entry_z(position, None, now)
+    }
+
+    pub fn enter_with_entry_z(
+        &mut self,
+        position: PositionSnapshot,
+        entry_z: Option<Decimal>,
+        now: DateTime<Utc>,
     ) -> Result<(), StateError> {
         if self.state.status != StrategyStatus::Flat {
             return Err(StateError::InvalidTransition(
@@ -174,6 +302,7 @@ impl StateMachine {
             ));
         }
         self.state.status = StrategyStatus::InPosition;
+        self.state.groups = vec![PositionGroup::from_snapshot(&position, entry_z)];
         self.state.position = Some(position);
         self.state.pending_entry = None;
         self.state.cooldown_until = None;
@@ -195,6 +324,7 @@ impl StateMachine {
         }
         self.state.status = StrategyStatus::PendingEntry;
         self.state.position = None;
+        self.state.groups.clear();
         self.state.pending_entry = Some(pending);
         self.state.cooldown_until = None;
         Ok(())
@@ -206,13 +336,64 @@ impl StateMachine {
                 "cannot confirm pending entry unless pending".to_string(),
             ));
         }
+        let entry_z = self
+            .state
+            .pending_entry
+            .as_ref()
+            .and_then(|pending| pending.entry_z);
         self.state.status = StrategyStatus::InPosition;
+        self.state.groups = vec![PositionGroup::from_snapshot(&position, entry_z)];
         self.state.position = Some(position);
         self.state.pending_entry = None;
         self.state.cooldown_until = None;
         Ok(())
     }
 
+    /// Adds another entry group to the open position in the same direction.
+    pub fn scale_in(&mut self, group: PositionGroup) -> Result<(), StateError> {
+        if self.state.status != StrategyStatus::InPosition {
+            return Err(StateError::InvalidTransition(
+                "cannot scale in unless in position".to_string(),
+            ));
+        }
+        let mut groups = self.state.position_groups();
+        groups.push(group);
+        self.set_groups(groups)
+    }
+
+    /// Closes one scaled-in group while the remaining groups stay open.
+    pub fn exit_group(&mut self, index: usize) -> Result<PositionGroup, StateError> {
+        if self.state.status != StrategyStatus::InPosition {
+            return Err(StateError::InvalidTransition(
+                "cannot exit group unless in position".to_string(),
+            ));
+        }
+        let mut groups = self.state.position_groups();
+        if groups.len() < 2 || index >= groups.len() {
+            return Err(StateError::InvalidTransition(format!(
+                "cannot exit group {index} of {}; use exit for the last group",
+                groups.len()
+            )));
+        }
+        let group = groups.remove(index);
+        self.set_groups(groups)?;
+        Ok(group)
+    }
+
+    fn set_groups(&mut self, groups: Vec<PositionGroup>) -> Result<(), StateError> {
+        let direction = self
+            .state
+            .position
+            .as_ref()
+            .map(|position| position.direction)
+            .ok_or_else(|| {
+                StateError::InvalidTransition("in-position state missing position".to_string())
+            })?;
+        self.state.position = aggregate_groups(direction, &groups);
+        self.state.groups = groups;
+        Ok(())
+    }
+
     pub fn exit(&mut self, reason: ExitReason, now: DateTime<Utc>) -> Result<(), StateError> {
         if self.state.status != StrategyStatus::InPosition {
             return Err(StateError::InvalidTransition(
@@ -232,6 +413,7 @@ impl StateMachine {
         }
         self.state.position = None;
         self.state.pending_entry = None;
+        self.state.groups.clear();
         Ok(())
     }
 
@@ -275,6 +457,12 @@ impl StateStore {
                 [],
             )
             .map_err(|err| StateError::Persistence(err.to_string()))?;
+        self.conn
+            .execute(
+                "CREATE TABLE IF NOT EXISTS kalman_state (\n                    id INTEGER PRIMARY KEY CHECK (id = 1),\n                    state_json TEXT NOT NULL,\n                    updated_at TEXT NOT NULL\n                )",
+                [],
+            )
+            .map_err(|err| StateError::Persistence(err.to_string()))?;
         Ok(())
     }
 
@@ -313,6 +501,37 @@ impl StateStore {
             Ok(None)
         }
     }
+
+    pub fn save_kalman_state(&self, state: &KalmanState) -> Result<(), StateError> {
+        let payload = serde_json::to_string(state)
+            .map_err(|err| StateError::Serialization(err.to_string()))?;
+        let now = Utc::now().to_rfc3339();
+        self.conn
+            .execute(
+                "INSERT OR REPLACE INTO kalman_state (id, state_json, updated_at) VALUES (1, ?, ?)",
+                [&payload, &now],
+            )
+            .map_err(|err| StateError::Persistence(err.to_string()))?;
+        Ok(())
+    }
+
+    pub fn load_kalman_state(&self) -> Result<Option<KalmanState>, StateError> {
+        let state_json: Option<String> = self
+            .conn
+            .query_row(
+                "SELECT state_json FROM kalman_state WHERE id = 1",
+                [],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(|err| StateError::Persistence(err.to_string()))?;
+        state_json
+            .map(|json| {
+                serde_json::from_str(&json)
+                    .map_err(|err| StateError::Serialization(err.to_string()))
+            })
+            .transpose()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -347,6 +566,7 @@ pub fn recover_state(mut state: StrategyState, now: DateTime<Utc>) -> RecoveryRe
                 state.status = StrategyStatus::Flat;
                 state.position = None;
                 state.pending_entry = None;
+                state.groups.clear();
             }
             Some(position) => {
                 if position.has_residual() {
@@ -355,6 +575,17 @@ pub fn recover_state(mut state: StrategyState, now: DateTime<Utc>) -> RecoveryRe
                 }
             }
         }
+        if !state.groups_match_position() {
+            alerts
+                .push("position groups do not match position; collapsing to one group".to_string());
+            state.groups = state
+                .position
+                .as_ref()
+                .map(|position| vec![PositionGroup::from_snapshot(position, None)])
+                .unwrap_or_default();
+        }
+    } else if !state.groups.is_empty() {
+        state.groups.clear();
     }
 
     RecoveryReport {
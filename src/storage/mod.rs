@@ -7,18 +7,32 @@ use rusqlite::Connection;
 use rust_decimal::Decimal;
 use thiserror::Error;
 
+use crate::config::{BarInterval, PairConfig};
+
+const DEFAULT_PRICE_TABLE: &str = "price_bars";
+/// Columns added after the original schema; older tables are migrated on open.
+const OHLC_COLUMNS: [&str; 6] = [
+    "eth_open", "eth_high", "eth_low", "btc_open", "btc_high", "btc_low",
+];
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct PriceBarRecord {
     pub timestamp: DateTime<Utc>,
-    pub eth_mid: Option<Decimal>,
-    pub eth_mark: Option<Decimal>,
-    pub eth_close: Option<Decimal>,
-    pub btc_mid: Option<Decimal>,
-    pub btc_mark: Option<Decimal>,
-    pub btc_close: Option<Decimal>,
-    pub funding_eth: Option<Decimal>,
-    pub funding_btc: Option<Decimal>,
+    pub base_mid: Option<Decimal>,
+    pub base_mark: Option<Decimal>,
+    pub base_close: Option<Decimal>,
+    pub quote_mid: Option<Decimal>,
+    pub quote_mark: Option<Decimal>,
+    pub quote_close: Option<Decimal>,
+    pub funding_base: Option<Decimal>,
+    pub funding_quote: Option<Decimal>,
     pub funding_interval_hours: Option<u32>,
+    pub base_open: Option<Decimal>,
+    pub base_high: Option<Decimal>,
+    pub base_low: Option<Decimal>,
+    pub quote_open: Option<Decimal>,
+    pub quote_high: Option<Decimal>,
+    pub quote_low: Option<Decimal>,
 }
 
 #[derive(Debug, Error)]
@@ -35,21 +49,69 @@ pub trait PriceBarWriter: Send + Sync {
 
 pub struct PriceStore {
     conn: Connection,
+    table: String,
+}
+
+/// Table holding `interval` bars for `pair`; the default ETH/BTC pair on 15m bars keeps the
+/// original table name, and other intervals add a suffix such as `_1h`.
+pub fn price_table_name(pair: &PairConfig, interval: BarInterval) -> String {
+    let suffix = if interval == BarInterval::default() {
+        String::new()
+    } else {
+        format!("_{}", interval.as_str())
+    };
+    if pair.is_default() {
+        return format!("{DEFAULT_PRICE_TABLE}{suffix}");
+    }
+    let sanitize = |coin: &str| {
+        coin.chars()
+            .map(|ch| {
+                if ch.is_ascii_alphanumeric() {
+                    ch.to_ascii_lowercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect::<String>()
+    };
+    format!(
+        "{DEFAULT_PRICE_TABLE}_{}_{}{suffix}",
+        sanitize(&pair.base_coin),
+        sanitize(&pair.quote_coin)
+    )
 }
 
 impl PriceStore {
     pub fn new(path: &str) -> Result<Self, PriceStoreError> {
+        Self::new_for_pair(path, &PairConfig::default())
+    }
+
+    pub fn new_for_pair(path: &str, pair: &PairConfig) -> Result<Self, PriceStoreError> {
+        Self::new_for_series(path, pair, BarInterval::default())
+    }
+
+    pub fn new_for_series(
+        path: &str,
+        pair: &PairConfig,
+        interval: BarInterval,
+    ) -> Result<Self, PriceStoreError> {
         let conn =
             Connection::open(path).map_err(|err| PriceStoreError::Persistence(err.to_string()))?;
-        let store = Self { conn };
-        store.init_schema()?;
-        Ok(store)
+        Self::with_connection(conn, price_table_name(pair, interval))
     }
 
     pub fn new_in_memory() -> Result<Self, PriceStoreError> {
+        Self::new_in_memory_for_pair(&PairConfig::default())
+    }
+
+    pub fn new_in_memory_for_pair(pair: &PairConfig) -> Result<Self, PriceStoreError> {
         let conn = Connection::open_in_memory()
             .map_err(|err| PriceStoreError::Persistence(err.to_string()))?;
-        let store = Self { conn };
+        Self::with_connection(conn, price_table_name(pair, BarInterval::default()))
+    }
+
+    fn with_connection(conn: Connection, table: String) -> Result<Self, PriceStoreError> {
+        let store = Self { conn, table };
         store.init_schema()?;
         Ok(store)
     }
@@ -57,10 +119,32 @@ impl PriceStore {
     fn init_schema(&self) -> Result<(), PriceStoreError> {
         self.conn
             .execute(
-                "CREATE TABLE IF NOT EXISTS price_bars (\n                    timestamp TEXT PRIMARY KEY,\n                    eth_mid TEXT,\n                    eth_mark TEXT,\n                    eth_close TEXT,\n                    btc_mid TEXT,\n                    btc_mark TEXT,\n                    btc_close TEXT,\n                    funding_eth TEXT,\n                    funding_btc TEXT,\n                    funding_interval_hours INTEGER,\n                    created_at TEXT NOT NULL\n                )",
+                &format!(
+                    "CREATE TABLE IF NOT EXISTS {} (\n                    timestamp TEXT PRIMARY KEY,\n                    eth_mid TEXT,\n                    eth_mark TEXT,\n                    eth_close TEXT,\n                    btc_mid TEXT,\n                    btc_mark TEXT,\n                    btc_close TEXT,\n                    funding_eth TEXT,\n                    funding_btc TEXT,\n                    funding_interval_hours INTEGER,\n                    created_at TEXT NOT NULL,\n                    eth_open TEXT,\n                    eth_high TEXT,\n                    eth_low TEXT,\n                    btc_open TEXT,\n                    btc_high TEXT,\n                    btc_low TEXT\n                )",
+                    self.table
+                ),
                 [],
             )
             .map_err(|err| PriceStoreError::Persistence(err.to_string()))?;
+        let mut stmt = self
+            .conn
+            .prepare(&format!("PRAGMA table_info({})", self.table))
+            .map_err(|err| PriceStoreError::Persistence(err.to_string()))?;
+        let existing = stmt
+            .query_map([], |row| row.get::<_, String>(1))
+            .map_err(|err| PriceStoreError::Persistence(err.to_string()))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| PriceStoreError::Persistence(err.to_string()))?;
+        for column in OHLC_COLUMNS {
+            if !existing.iter().any(|name| name == column) {
+                self.conn
+                    .execute(
+                        &format!("ALTER TABLE {} ADD COLUMN {column} TEXT", self.table),
+                        [],
+                    )
+                    .map_err(|err| PriceStoreError::Persistence(err.to_string()))?;
+            }
+        }
         Ok(())
     }
 
@@ -69,21 +153,30 @@ impl PriceStore {
         let now = Utc::now().to_rfc3339();
         self.conn
             .execute(
-                "INSERT OR REPLACE INTO price_bars (\n                    timestamp,\n                    eth_mid,\n                    eth_mark,\n                    eth_close,\n                    btc_mid,\n                    btc_mark,\n                    btc_close,\n                    funding_eth,\n                    funding_btc,\n                    funding_interval_hours,\n                    created_at\n                ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                &format!(
+                    "INSERT OR REPLACE INTO {} (\n                    timestamp,\n                    eth_mid,\n                    eth_mark,\n                    eth_close,\n                    btc_mid,\n                    btc_mark,\n                    btc_close,\n                    funding_eth,\n                    funding_btc,\n                    funding_interval_hours,\n                    created_at,\n                    eth_open,\n                    eth_high,\n                    eth_low,\n                    btc_open,\n                    btc_high,\n                    btc_low\n                ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                    self.table
+                ),
                 rusqlite::params![
                     timestamp,
-                    Self::decimal_to_string(record.eth_mid),
-                    Self::decimal_to_string(record.eth_mark),
-                    Self::decimal_to_string(record.eth_close),
-                    Self::decimal_to_string(record.btc_mid),
-                    Self::decimal_to_string(record.btc_mark),
-                    Self::decimal_to_string(record.btc_close),
-                    Self::decimal_to_string(record.funding_eth),
-                    Self::decimal_to_string(record.funding_btc),
+                    Self::decimal_to_string(record.base_mid),
+                    Self::decimal_to_string(record.base_mark),
+                    Self::decimal_to_string(record.base_close),
+                    Self::decimal_to_string(record.quote_mid),
+                    Self::decimal_to_string(record.quote_mark),
+                    Self::decimal_to_string(record.quote_close),
+                    Self::decimal_to_string(record.funding_base),
+                    Self::decimal_to_string(record.funding_quote),
                     record
                         .funding_interval_hours
                         .map(|value| value as i64),
                     now,
+                    Self::decimal_to_string(record.base_open),
+                    Self::decimal_to_string(record.base_high),
+                    Self::decimal_to_string(record.base_low),
+                    Self::decimal_to_string(record.quote_open),
+                    Self::decimal_to_string(record.quote_high),
+                    Self::decimal_to_string(record.quote_low),
                 ],
             )
             .map_err(|err| PriceStoreError::Persistence(err.to_string()))?;
@@ -97,7 +190,10 @@ impl PriceStore {
         let mut stmt = self
             .conn
             .prepare(
-                "SELECT timestamp, eth_mid, eth_mark, eth_close, btc_mid, btc_mark, btc_close, funding_eth, funding_btc, funding_interval_hours FROM price_bars WHERE timestamp = ?",
+                &format!(
+                    "SELECT timestamp, eth_mid, eth_mark, eth_close, btc_mid, btc_mark, btc_close, funding_eth, funding_btc, funding_interval_hours, eth_open, eth_high, eth_low, btc_open, btc_high, btc_low FROM {} WHERE timestamp = ?",
+                    self.table
+                ),
             )
             .map_err(|err| PriceStoreError::Persistence(err.to_string()))?;
         let mut rows = stmt
@@ -121,7 +217,10 @@ impl PriceStore {
         let mut stmt = self
             .conn
             .prepare(
-                "SELECT timestamp, eth_mid, eth_mark, eth_close, btc_mid, btc_mark, btc_close, funding_eth, funding_btc, funding_interval_hours FROM price_bars WHERE timestamp >= ? AND timestamp <= ? ORDER BY timestamp",
+                &format!(
+                    "SELECT timestamp, eth_mid, eth_mark, eth_close, btc_mid, btc_mark, btc_close, funding_eth, funding_btc, funding_interval_hours, eth_open, eth_high, eth_low, btc_open, btc_high, btc_low FROM {} WHERE timestamp >= ? AND timestamp <= ? ORDER BY timestamp",
+                    self.table
+                ),
             )
             .map_err(|err| PriceStoreError::Persistence(err.to_string()))?;
         let mut rows = stmt
@@ -157,45 +256,57 @@ impl PriceStore {
         let timestamp = DateTime::parse_from_rfc3339(&timestamp)
             .map_err(|err| PriceStoreError::Parse(err.to_string()))?
             .with_timezone(&Utc);
-        let eth_mid: Option<String> = row
+        let base_mid: Option<String> = row
             .get(1)
             .map_err(|err| PriceStoreError::Persistence(err.to_string()))?;
-        let eth_mark: Option<String> = row
+        let base_mark: Option<String> = row
             .get(2)
             .map_err(|err| PriceStoreError::Persistence(err.to_string()))?;
-        let eth_close: Option<String> = row
+        let base_close: Option<String> = row
             .get(3)
             .map_err(|err| PriceStoreError::Persistence(err.to_string()))?;
-        let btc_mid: Option<String> = row
+        let quote_mid: Option<String> = row
             .get(4)
             .map_err(|err| PriceStoreError::Persistence(err.to_string()))?;
-        let btc_mark: Option<String> = row
+        let quote_mark: Option<String> = row
             .get(5)
             .map_err(|err| PriceStoreError::Persistence(err.to_string()))?;
-        let btc_close: Option<String> = row
+        let quote_close: Option<String> = row
             .get(6)
             .map_err(|err| PriceStoreError::Persistence(err.to_string()))?;
-        let funding_eth: Option<String> = row
+        let funding_base: Option<String> = row
             .get(7)
             .map_err(|err| PriceStoreError::Persistence(err.to_string()))?;
-        let funding_btc: Option<String> = row
+        let funding_quote: Option<String> = row
             .get(8)
             .map_err(|err| PriceStoreError::Persistence(err.to_string()))?;
         let funding_interval_hours: Option<i64> = row
             .get(9)
             .map_err(|err| PriceStoreError::Persistence(err.to_string()))?;
+        let decimal_at = |index: usize| -> Result<Option<Decimal>, PriceStoreError> {
+            let value: Option<String> = row
+                .get(index)
+                .map_err(|err| PriceStoreError::Persistence(err.to_string()))?;
+            Self::string_to_decimal(value)
+        };
 
         Ok(PriceBarRecord {
             timestamp,
-            eth_mid: Self::string_to_decimal(eth_mid)?,
-            eth_mark: Self::string_to_decimal(eth_mark)?,
-            eth_close: Self::string_to_decimal(eth_close)?,
-            btc_mid: Self::string_to_decimal(btc_mid)?,
-            btc_mark: Self::string_to_decimal(btc_mark)?,
-            btc_close: Self::string_to_decimal(btc_close)?,
-            funding_eth: Self::string_to_decimal(funding_eth)?,
-            funding_btc: Self::string_to_decimal(funding_btc)?,
+            base_mid: Self::string_to_decimal(base_mid)?,
+            base_mark: Self::string_to_decimal(base_mark)?,
+            base_close: Self::string_to_decimal(base_close)?,
+            quote_mid: Self::string_to_decimal(quote_mid)?,
+            quote_mark: Self::string_to_decimal(quote_mark)?,
+            quote_close: Self::string_to_decimal(quote_close)?,
+            funding_base: Self::string_to_decimal(funding_base)?,
+            funding_quote: Self::string_to_decimal(funding_quote)?,
             funding_interval_hours: funding_interval_hours.map(|value| value as u32),
+            base_open: decimal_at(10)?,
+            base_high: decimal_at(11)?,
+            base_low: decimal_at(12)?,
+            quote_open: decimal_at(13)?,
+            quote_high: decimal_at(14)?,
+            quote_low: decimal_at(15)?,
         })
     }
 }
@@ -8,7 +8,7 @@ use eth_btc_strategy::account::{
     AccountBalanceSource, AccountFillSource, AccountHttpClient, AccountHttpResponse,
     AccountPositionSource, HyperliquidAccountSource,
 };
-use eth_btc_strategy::config::Symbol;
+use eth_btc_strategy::config::{PairConfig, Symbol};
 use eth_btc_strategy::util::rate_limiter::NoopRateLimiter;
 
 #[derive(Clone)]
@@ -151,11 +151,65 @@ async fn account_source_parses_pair_exposure() {
 
     let exposure = source.fetch_pair_exposure().await.unwrap();
 
-    assert_eq!(exposure.eth_qty(), dec!(-0.088));
-    assert_eq!(exposure.btc_qty(), dec!(0));
+    assert_eq!(exposure.base_qty(), dec!(-0.088));
+    assert_eq!(exposure.quote_qty(), dec!(0));
     assert!(exposure.has_residual());
 }
 
+#[tokio::test]
+async fn account_source_maps_configured_pair_coins_to_legs() {
+    let body = serde_json::json!({
+        "data": {
+            "marginSummary": {
+                "totalRawUsd": "100"
+            },
+            "assetPositions": [
+                {
+                    "position": {
+                        "coin": "BTC",
+                        "szi": "0.5",
+                        "entryPx": "30000",
+                        "positionValue": "15000"
+                    }
+                },
+                {
+                    "position": {
+                        "coin": "SOL",
+                        "szi": "2",
+                        "entryPx": "150",
+                        "positionValue": "300"
+                    }
+                },
+                {
+                    "position": {
+                        "coin": "ETH",
+                        "szi": "-0.1",
+                        "entryPx": "2000",
+                        "positionValue": "200"
+                    }
+                }
+            ]
+        }
+    })
+    .to_string();
+    let client = StaticAccountClient { status: 200, body };
+    let source = HyperliquidAccountSource::with_client_and_rate_limiter(
+        "https://api.hyperliquid.xyz",
+        "0x0000000000000000000000000000000000000000",
+        Arc::new(client),
+        Arc::new(NoopRateLimiter),
+    )
+    .with_pair(PairConfig {
+        base_coin: "SOL".to_string(),
+        quote_coin: "ETH".to_string(),
+    });
+
+    let exposure = source.fetch_pair_exposure().await.unwrap();
+
+    assert_eq!(exposure.base_qty(), dec!(2));
+    assert_eq!(exposure.quote_qty(), dec!(-0.1));
+}
+
 #[tokio::test]
 async fn account_source_fetches_user_fills_by_time_with_fee_and_closed_pnl() {
     let body = serde_json::json!([
@@ -209,7 +263,7 @@ async fn account_source_fetches_user_fills_by_time_with_fee_and_closed_pnl() {
     );
 
     assert_eq!(fills.len(), 1);
-    assert_eq!(fills[0].coin, Symbol::EthPerp);
+    assert_eq!(fills[0].coin, Symbol::Base);
     assert_eq!(fills[0].price, dec!(2314.8));
     assert_eq!(fills[0].size, dec!(0.073));
     assert_eq!(fills[0].fee, dec!(0.072999));
@@ -7,6 +7,7 @@ use eth_btc_strategy::analysis::{
     format_stats_replay_text, replay_funding_carry_stats_log, replay_stats_log,
     study_residual_regimes, summarize_cycles, sweep_residual_regime_parameters,
 };
+use eth_btc_strategy::config::BarInterval;
 
 #[test]
 fn trade_history_analysis_classifies_paired_and_single_leg_cycles() {
@@ -24,7 +25,10 @@ time,coin,dir,px,sz,ntl,fee,closedPnl
 
     assert_eq!(cycles.len(), 2);
     assert_eq!(cycles[0].kind, CycleKind::Paired);
-    assert_eq!(cycles[0].direction, Some(TradeDirection::LongEthShortBtc));
+    assert_eq!(
+        cycles[0].direction,
+        Some(TradeDirection::LongBaseShortQuote)
+    );
     assert_eq!(cycles[0].row_count, 4);
     assert_eq!(cycles[0].net_pnl, dec!(1.92));
     assert_eq!(cycles[0].fees, dec!(0.08));
@@ -64,7 +68,7 @@ fn stats_replay_compares_crossing_and_cooldown_recovery_candidates() {
         },
     ];
 
-    let report = replay_stats_log(stats, None, &configs).unwrap();
+    let report = replay_stats_log(stats, None, &configs, BarInterval::M15).unwrap();
 
     assert_eq!(report.rows, 4);
     assert_eq!(report.strategies.len(), 2);
@@ -82,6 +86,36 @@ fn stats_replay_compares_crossing_and_cooldown_recovery_candidates() {
     assert!(text.contains("cooldown_recovery"));
 }
 
+#[test]
+fn stats_replay_time_exit_follows_the_bar_interval() {
+    // Hourly rows: the position opened at the second row is still open after 48 hours.
+    let stats = (0..60)
+        .map(|hour| {
+            let zscore = if hour == 0 { "0.1" } else { "1.5" };
+            format!(
+                "{{\"timestamp\":\"2026-04-{:02}T{:02}:00:00Z\",\"base_price\":\"100\",\"quote_price\":\"100\",\"zscore\":\"{zscore}\",\"w_base\":\"0.5\",\"w_quote\":\"0.5\",\"state\":\"Flat\"}}\n",
+                20 + hour / 24,
+                hour % 24
+            )
+        })
+        .collect::<String>();
+    let configs = vec![ReplayStrategyConfig {
+        name: "cross".to_string(),
+        entry_z: dec!(1.4),
+        tp_z: dec!(0.45),
+        sl_z: dec!(3.5),
+        cooldown_recovery: false,
+        cooldown_recovery_bars: 0,
+    }];
+
+    let hourly = replay_stats_log(&stats, None, &configs, BarInterval::H1).unwrap();
+    let quarter_hourly = replay_stats_log(&stats, None, &configs, BarInterval::M15).unwrap();
+
+    assert_eq!(hourly.strategies[0].trades, 1);
+    assert_eq!(hourly.strategies[0].exit_reasons.get("TIME"), Some(&1));
+    assert_eq!(quarter_hourly.strategies[0].trades, 0);
+}
+
 #[test]
 fn residual_regime_study_reports_half_life_and_rolling_beta_candidates() {
     let stats = "\
@@ -99,6 +133,7 @@ fn residual_regime_study_reports_half_life_and_rolling_beta_candidates() {
         entry_z: dec!(1.0),
         tp_z: dec!(0.45),
         sl_z: dec!(3.5),
+        bar_interval: BarInterval::M15,
     };
 
     let report = study_residual_regimes(stats, None, &config).unwrap();
@@ -143,6 +178,7 @@ fn residual_regime_sweep_ranks_parameter_candidates() {
         sl_z: dec!(3.5),
         min_trades: 1,
         top_n: 6,
+        bar_interval: BarInterval::M15,
     };
 
     let report = sweep_residual_regime_parameters(stats, None, &config).unwrap();
@@ -180,6 +216,7 @@ fn funding_carry_replay_adds_signed_carry_and_gates_entries() {
         min_net_edge_bps: dec!(0),
         max_hold_hours: 48,
         funding_interval_hours: 1,
+        bar_interval: BarInterval::M15,
     };
 
     let report = replay_funding_carry_stats_log(stats, None, &config).unwrap();
@@ -233,7 +270,7 @@ time,coin,dir,px,sz,ntl,fee,closedPnl
     assert_eq!(
         summary
             .directions
-            .get(&TradeDirection::LongEthShortBtc)
+            .get(&TradeDirection::LongBaseShortQuote)
             .unwrap()
             .cycles,
         1
@@ -241,7 +278,7 @@ time,coin,dir,px,sz,ntl,fee,closedPnl
     assert_eq!(
         summary
             .directions
-            .get(&TradeDirection::ShortEthLongBtc)
+            .get(&TradeDirection::ShortBaseLongQuote)
             .unwrap()
             .cycles,
         1
@@ -1,16 +1,44 @@
 #[path = "backtest/breakdown.rs"]
 mod breakdown;
+#[path = "backtest/carry.rs"]
+mod carry;
 #[path = "backtest/db.rs"]
 mod db;
+#[path = "backtest/diff.rs"]
+mod diff;
 #[path = "backtest/download.rs"]
 mod download;
 #[path = "backtest/engine.rs"]
 mod engine;
 #[path = "backtest/export.rs"]
 mod export;
+#[path = "backtest/grid.rs"]
+mod grid;
+#[path = "backtest/intrabar.rs"]
+mod intrabar;
+#[path = "backtest/latency.rs"]
+mod latency;
+#[path = "backtest/manifest.rs"]
+mod manifest;
+#[path = "backtest/margin.rs"]
+mod margin;
 #[path = "backtest/metrics.rs"]
 mod metrics;
+#[path = "backtest/monte_carlo.rs"]
+mod monte_carlo;
+#[path = "backtest/report.rs"]
+mod report;
 #[path = "backtest/repro.rs"]
 mod repro;
+#[path = "backtest/scenario.rs"]
+mod scenario;
 #[path = "backtest/sensitivity.rs"]
 mod sensitivity;
+#[path = "backtest/simulated.rs"]
+mod simulated;
+#[path = "backtest/slippage.rs"]
+mod slippage;
+#[path = "backtest/stress.rs"]
+mod stress;
+#[path = "backtest/walk_forward.rs"]
+mod walk_forward;
@@ -2,21 +2,58 @@ use chrono::{TimeZone, Utc};
 use rust_decimal_macros::dec;
 
 use eth_btc_strategy::backtest::{Trade, TradeExitReason, breakdown_monthly};
+use eth_btc_strategy::core::TradeDirection;
 
 #[test]
 fn breakdown_groups_by_month() {
     let trades = vec![
         Trade {
+            pair: None,
+            direction: TradeDirection::LongBaseShortQuote,
             entry_time: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
             exit_time: Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap(),
+            base_qty: dec!(0),
+            quote_qty: dec!(0),
+            entry_base_price: dec!(0),
+            entry_quote_price: dec!(0),
+            base_price: dec!(0),
+            quote_price: dec!(0),
+            entry_zscore: None,
+            exit_zscore: None,
             pnl: dec!(10),
+            gross_pnl: dec!(0),
             exit_reason: TradeExitReason::TakeProfit,
+            notional: dec!(0),
+            fee: dec!(0),
+            slippage: dec!(0),
+            base_slippage_bps: None,
+            quote_slippage_bps: None,
+            funding: dec!(0),
+            holding_hours: dec!(0),
         },
         Trade {
+            pair: None,
+            direction: TradeDirection::LongBaseShortQuote,
             entry_time: Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap(),
             exit_time: Utc.with_ymd_and_hms(2024, 2, 2, 0, 0, 0).unwrap(),
+            base_qty: dec!(0),
+            quote_qty: dec!(0),
+            entry_base_price: dec!(0),
+            entry_quote_price: dec!(0),
+            base_price: dec!(0),
+            quote_price: dec!(0),
+            entry_zscore: None,
+            exit_zscore: None,
             pnl: dec!(-5),
+            gross_pnl: dec!(0),
             exit_reason: TradeExitReason::StopLoss,
+            notional: dec!(0),
+            fee: dec!(0),
+            slippage: dec!(0),
+            base_slippage_bps: None,
+            quote_slippage_bps: None,
+            funding: dec!(0),
+            holding_hours: dec!(0),
         },
     ];
 
@@ -0,0 +1,171 @@
+use chrono::{TimeZone, Utc};
+use rust_decimal::{Decimal, MathematicalOps};
+use rust_decimal_macros::dec;
+
+use eth_btc_strategy::backtest::carry::{accrued_funding, settlements_between};
+use eth_btc_strategy::backtest::{BacktestBar, BacktestEngine};
+use eth_btc_strategy::config::{Config, FundingMode, SigmaFloorMode};
+use eth_btc_strategy::core::TradeDirection;
+use eth_btc_strategy::logging::EntryBlockReason;
+use eth_btc_strategy::state::{PositionLeg, PositionSnapshot};
+
+fn bar(timestamp: i64, r: Decimal) -> BacktestBar {
+    BacktestBar {
+        timestamp: Utc.timestamp_opt(timestamp, 0).unwrap(),
+        base_price: dec!(100) * r.exp(),
+        quote_price: dec!(100),
+        funding_base: Some(dec!(0.0001)),
+        funding_quote: Some(dec!(-0.0002)),
+        base_open: None,
+        base_high: None,
+        base_low: None,
+        quote_open: None,
+        quote_high: None,
+        quote_low: None,
+    }
+}
+
+fn position() -> PositionSnapshot {
+    PositionSnapshot {
+        direction: TradeDirection::LongBaseShortQuote,
+        entry_time: Utc.timestamp_opt(0, 0).unwrap(),
+        base: PositionLeg {
+            qty: dec!(2),
+            avg_price: dec!(100),
+            notional: dec!(200),
+        },
+        quote: PositionLeg {
+            qty: dec!(-3),
+            avg_price: dec!(100),
+            notional: dec!(300),
+        },
+    }
+}
+
+#[test]
+fn settlements_follow_the_utc_grid() {
+    let at = |secs| Utc.timestamp_opt(secs, 0).unwrap();
+    assert_eq!(settlements_between(at(0), at(900), 1), 0);
+    assert_eq!(settlements_between(at(2700), at(3600), 1), 1);
+    assert_eq!(settlements_between(at(3600), at(4500), 1), 0);
+    assert_eq!(settlements_between(at(3599), at(4 * 3600), 1), 4);
+    assert_eq!(settlements_between(at(3600), at(8 * 3600), 8), 1);
+}
+
+#[test]
+fn accrual_uses_leg_sign_and_marked_notional() {
+    let position = position();
+    let mut settle = bar(3600, Decimal::ZERO);
+    settle.base_price = dec!(110);
+
+    // Long ETH pays 2 * 110 * 0.0001; short BTC pays 3 * 100 * 0.0002 on a negative rate.
+    let paid = accrued_funding(&position, Utc.timestamp_opt(2700, 0).unwrap(), &settle, 1);
+    assert_eq!(paid, dec!(0.022) + dec!(0.06));
+
+    let off_grid = bar(4500, Decimal::ZERO);
+    let none = accrued_funding(&position, settle.timestamp, &off_grid, 1);
+    assert_eq!(none, Decimal::ZERO);
+
+    let mut missing = settle.clone();
+    missing.funding_quote = None;
+    let base_only = accrued_funding(&position, Utc.timestamp_opt(0, 0).unwrap(), &missing, 1);
+    assert_eq!(base_only, dec!(0.022));
+}
+
+#[test]
+fn backtest_trades_report_accrued_funding() {
+    let mut config = Config::default();
+    config.strategy.n_z = 4;
+    config.strategy.entry_z = dec!(1.5);
+    config.strategy.tp_z = dec!(0.6);
+    config.position.n_vol = 2;
+    config.sigma_floor.mode = SigmaFloorMode::Const;
+
+    let mut bars: Vec<BacktestBar> = (0..4).map(|i| bar(1800 + i * 900, Decimal::ZERO)).collect();
+    bars.extend((4..9).map(|i| bar(1800 + i * 900, dec!(-0.04))));
+    bars.extend((9..12).map(|i| bar(1800 + i * 900, Decimal::ZERO)));
+
+    let result = BacktestEngine::new(config.clone()).run(&bars).unwrap();
+    assert_eq!(result.trades.len(), 1);
+    let trade = &result.trades[0];
+    let entry_index = bars
+        .iter()
+        .position(|bar| bar.timestamp == trade.entry_time)
+        .unwrap();
+    let exit_index = bars
+        .iter()
+        .position(|bar| bar.timestamp == trade.exit_time)
+        .unwrap();
+    let held = result.bar_logs[entry_index].position.clone().unwrap();
+    let expected: Decimal = bars[entry_index + 1..=exit_index]
+        .iter()
+        .filter(|bar| bar.timestamp.timestamp() % 3600 == 0)
+        .map(|bar| {
+            held.base.qty * bar.base_price * dec!(0.0001)
+                + held.quote.qty * bar.quote_price * dec!(-0.0002)
+        })
+        .sum();
+    assert_ne!(expected, Decimal::ZERO);
+    assert_eq!(trade.funding, expected);
+    assert_eq!(result.metrics.total_funding, expected);
+
+    config.backtest.include_funding = false;
+    let without = BacktestEngine::new(config).run(&bars).unwrap();
+    assert_eq!(without.trades[0].funding, Decimal::ZERO);
+    assert_eq!(without.trades[0].pnl - expected, trade.pnl);
+}
+
+#[test]
+fn funding_filter_prices_hourly_rates_per_settlement() {
+    let mut config = Config::default();
+    config.strategy.n_z = 4;
+    config.strategy.entry_z = dec!(1.5);
+    config.strategy.tp_z = dec!(0.6);
+    config.position.n_vol = 2;
+    config.sigma_floor.mode = SigmaFloorMode::Const;
+    config.funding.funding_cost_threshold = None;
+    config.funding.modes.clear();
+
+    let mut bars: Vec<BacktestBar> = (0..4).map(|i| bar(1800 + i * 900, Decimal::ZERO)).collect();
+    bars.extend((4..9).map(|i| bar(1800 + i * 900, dec!(-0.04))));
+    bars.extend((9..12).map(|i| bar(1800 + i * 900, Decimal::ZERO)));
+
+    let unfiltered = BacktestEngine::new(config.clone()).run(&bars).unwrap();
+    assert!(!unfiltered.trades.is_empty());
+    let entry = unfiltered
+        .bar_logs
+        .iter()
+        .find(|log| log.timestamp == unfiltered.trades[0].entry_time)
+        .unwrap();
+    // 48 hourly settlements over `max_hold_hours`.
+    let hourly_cost = entry.funding_cost_est.unwrap();
+    let mut eight_hourly = config.clone();
+    eight_hourly.backtest.funding_interval_hours = 8;
+    let eight_hourly = BacktestEngine::new(eight_hourly).run(&bars).unwrap();
+    let entry = eight_hourly
+        .bar_logs
+        .iter()
+        .find(|log| log.timestamp == eight_hourly.trades[0].entry_time)
+        .unwrap();
+    assert_eq!(hourly_cost, entry.funding_cost_est.unwrap() * dec!(8));
+
+    config.funding.modes = vec![FundingMode::Filter];
+    config.funding.funding_cost_threshold = Some(hourly_cost / dec!(2));
+    let filtered = BacktestEngine::new(config).run(&bars).unwrap();
+    let entry_time = unfiltered.trades[0].entry_time;
+    assert!(
+        filtered
+            .trades
+            .iter()
+            .all(|trade| trade.entry_time != entry_time)
+    );
+    let blocked = filtered
+        .bar_logs
+        .iter()
+        .find(|log| log.timestamp == entry_time)
+        .unwrap();
+    assert_eq!(
+        blocked.entry_block_reason,
+        Some(EntryBlockReason::FundingFilter)
+    );
+}
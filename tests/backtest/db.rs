@@ -4,7 +4,7 @@ use chrono::{TimeZone, Utc};
 use rust_decimal_macros::dec;
 
 use eth_btc_strategy::backtest::{BacktestBar, load_backtest_bars_from_db};
-use eth_btc_strategy::config::PriceField;
+use eth_btc_strategy::config::{BarInterval, PairConfig, PriceField};
 use eth_btc_strategy::storage::{PriceBarRecord, PriceStore};
 
 #[test]
@@ -23,48 +23,80 @@ fn backtest_loads_bars_from_sqlite() {
     store
         .save(&PriceBarRecord {
             timestamp: t1,
-            eth_mid: Some(dec!(2000)),
-            eth_mark: None,
-            eth_close: None,
-            btc_mid: Some(dec!(30000)),
-            btc_mark: None,
-            btc_close: None,
-            funding_eth: Some(dec!(0.0001)),
-            funding_btc: Some(dec!(0.0002)),
+            base_mid: Some(dec!(2000)),
+            base_mark: None,
+            base_close: None,
+            quote_mid: Some(dec!(30000)),
+            quote_mark: None,
+            quote_close: None,
+            funding_base: Some(dec!(0.0001)),
+            funding_quote: Some(dec!(0.0002)),
             funding_interval_hours: Some(8),
+            base_open: None,
+            base_high: None,
+            base_low: None,
+            quote_open: None,
+            quote_high: None,
+            quote_low: None,
         })
         .unwrap();
     store
         .save(&PriceBarRecord {
             timestamp: t2,
-            eth_mid: Some(dec!(2100)),
-            eth_mark: None,
-            eth_close: None,
-            btc_mid: Some(dec!(31000)),
-            btc_mark: None,
-            btc_close: None,
-            funding_eth: None,
-            funding_btc: None,
+            base_mid: Some(dec!(2100)),
+            base_mark: None,
+            base_close: None,
+            quote_mid: Some(dec!(31000)),
+            quote_mark: None,
+            quote_close: None,
+            funding_base: None,
+            funding_quote: None,
             funding_interval_hours: None,
+            base_open: None,
+            base_high: None,
+            base_low: None,
+            quote_open: None,
+            quote_high: None,
+            quote_low: None,
         })
         .unwrap();
 
-    let bars = load_backtest_bars_from_db(Path::new(&path), t1, t2, PriceField::Mid).unwrap();
+    let bars = load_backtest_bars_from_db(
+        Path::new(&path),
+        t1,
+        t2,
+        PriceField::Mid,
+        &PairConfig::default(),
+        BarInterval::M15,
+    )
+    .unwrap();
 
-    let expected = vec![
+    let expected = [
         BacktestBar {
             timestamp: t1,
-            eth_price: dec!(2000),
-            btc_price: dec!(30000),
-            funding_eth: Some(dec!(0.0001)),
-            funding_btc: Some(dec!(0.0002)),
+            base_price: dec!(2000),
+            quote_price: dec!(30000),
+            funding_base: Some(dec!(0.0001)),
+            funding_quote: Some(dec!(0.0002)),
+            base_open: None,
+            base_high: None,
+            base_low: None,
+            quote_open: None,
+            quote_high: None,
+            quote_low: None,
         },
         BacktestBar {
             timestamp: t2,
-            eth_price: dec!(2100),
-            btc_price: dec!(31000),
-            funding_eth: None,
-            funding_btc: None,
+            base_price: dec!(2100),
+            quote_price: dec!(31000),
+            funding_base: None,
+            funding_quote: None,
+            base_open: None,
+            base_high: None,
+            base_low: None,
+            quote_open: None,
+            quote_high: None,
+            quote_low: None,
         },
     ];
 
@@ -73,3 +105,59 @@ fn backtest_loads_bars_from_sqlite() {
     assert_eq!(bars[1], expected[1]);
     let _ = std::fs::remove_file(path);
 }
+
+#[test]
+fn backtest_loads_bars_for_the_configured_interval() {
+    let path = std::env::temp_dir().join(format!("hourly-{}.sqlite", uuid::Uuid::new_v4()));
+    let pair = PairConfig::default();
+    let store = PriceStore::new_for_series(path.to_string_lossy().as_ref(), &pair, BarInterval::H1)
+        .unwrap();
+    for hour in 0..3 {
+        store
+            .save(&PriceBarRecord {
+                timestamp: Utc.timestamp_opt(hour * 3600, 0).unwrap(),
+                base_mid: Some(dec!(2000)),
+                base_mark: None,
+                base_close: None,
+                quote_mid: Some(dec!(30000)),
+                quote_mark: None,
+                quote_close: None,
+                funding_base: None,
+                funding_quote: None,
+                funding_interval_hours: None,
+                base_open: None,
+                base_high: None,
+                base_low: None,
+                quote_open: None,
+                quote_high: None,
+                quote_low: None,
+            })
+            .unwrap();
+    }
+
+    // Mid-bar bounds floor to the hourly grid.
+    let bars = load_backtest_bars_from_db(
+        &path,
+        Utc.timestamp_opt(1200, 0).unwrap(),
+        Utc.timestamp_opt(2 * 3600 + 1800, 0).unwrap(),
+        PriceField::Mid,
+        &pair,
+        BarInterval::H1,
+    )
+    .unwrap();
+    let timestamps: Vec<_> = bars.iter().map(|bar| bar.timestamp.timestamp()).collect();
+    assert_eq!(timestamps, [0, 3600, 7200]);
+
+    assert!(
+        load_backtest_bars_from_db(
+            &path,
+            Utc.timestamp_opt(0, 0).unwrap(),
+            Utc.timestamp_opt(7200, 0).unwrap(),
+            PriceField::Mid,
+            &pair,
+            BarInterval::M15,
+        )
+        .is_err()
+    );
+    let _ = std::fs::remove_file(path);
+}
@@ -0,0 +1,145 @@
+use chrono::{TimeZone, Utc};
+use rust_decimal::{Decimal, MathematicalOps};
+use rust_decimal_macros::dec;
+
+use eth_btc_strategy::backtest::diff::{DiffDriver, TradeChange, diff_backtests};
+use eth_btc_strategy::backtest::parity::ParityDecision;
+use eth_btc_strategy::backtest::{BacktestBar, BacktestEngine, BacktestResult, TradeExitReason};
+use eth_btc_strategy::config::{Config, SigmaFloorMode};
+
+fn bar(timestamp: i64, r: Decimal) -> BacktestBar {
+    BacktestBar {
+        timestamp: Utc.timestamp_opt(timestamp, 0).unwrap(),
+        base_price: dec!(100) * r.exp(),
+        quote_price: dec!(100),
+        funding_base: None,
+        funding_quote: None,
+        base_open: None,
+        base_high: None,
+        base_low: None,
+        quote_open: None,
+        quote_high: None,
+        quote_low: None,
+    }
+}
+
+fn config() -> Config {
+    let mut config = Config::default();
+    config.strategy.n_z = 4;
+    config.position.n_vol = 1;
+    config.strategy.tp_z = dec!(0.6);
+    config.sigma_floor.mode = SigmaFloorMode::Const;
+    config
+}
+
+fn run(config: Config) -> BacktestResult {
+    let bars: Vec<BacktestBar> = [
+        dec!(0.0),
+        dec!(0.01),
+        dec!(0.0),
+        dec!(0.01),
+        dec!(0.0),
+        dec!(0.08),
+        dec!(0.0),
+        dec!(0.0),
+    ]
+    .iter()
+    .enumerate()
+    .map(|(i, r)| bar(i as i64 * 900, *r))
+    .collect();
+    BacktestEngine::new(config).run(&bars).unwrap()
+}
+
+#[test]
+fn identical_runs_have_no_differences() {
+    let result = run(config());
+    let diff = diff_backtests(
+        &result.trades,
+        &result.bar_logs,
+        &result.trades,
+        &result.bar_logs,
+    );
+
+    assert_eq!(diff.summary.unchanged, 1);
+    assert_eq!(diff.summary.compared_bars, result.bar_logs.len());
+    assert!(diff.trades.is_empty());
+    assert!(diff.bars.is_empty());
+}
+
+#[test]
+fn removed_trade_is_attributed_to_block_reason() {
+    let baseline = run(config());
+    let mut candidate_config = config();
+    candidate_config.strategy.entry_z = dec!(2.5);
+    let candidate = run(candidate_config);
+    let diff = diff_backtests(
+        &baseline.trades,
+        &baseline.bar_logs,
+        &candidate.trades,
+        &candidate.bar_logs,
+    );
+
+    assert_eq!(diff.summary.removed, 1);
+    assert_eq!(diff.summary.added, 0);
+    let removed = &diff.trades[0];
+    assert_eq!(removed.change, TradeChange::Removed);
+    assert_eq!(removed.entry_time, baseline.trades[0].entry_time);
+    assert_eq!(removed.pnl_delta, -baseline.trades[0].pnl);
+    assert!(matches!(removed.driver, DiffDriver::EntryBlocked(_)));
+    assert_eq!(diff.summary.trade_drivers.get("NO_CROSS"), Some(&1));
+
+    let decisions: Vec<_> = diff
+        .bars
+        .iter()
+        .map(|bar| (bar.baseline, bar.candidate))
+        .collect();
+    assert_eq!(
+        decisions,
+        vec![
+            (ParityDecision::Enter, ParityDecision::Hold),
+            (ParityDecision::Exit, ParityDecision::Hold),
+        ]
+    );
+    assert_eq!(
+        diff.bars[1].driver,
+        DiffDriver::Exit {
+            baseline: Some(TradeExitReason::TakeProfit),
+            candidate: None,
+        }
+    );
+}
+
+#[test]
+fn earlier_exit_is_a_modified_trade() {
+    let baseline = run(config());
+    let mut candidate_config = config();
+    candidate_config.strategy.tp_z = dec!(0.7);
+    let candidate = run(candidate_config);
+    let diff = diff_backtests(
+        &baseline.trades,
+        &baseline.bar_logs,
+        &candidate.trades,
+        &candidate.bar_logs,
+    );
+
+    assert_eq!(diff.summary.modified, 1);
+    let modified = &diff.trades[0];
+    assert_eq!(modified.change, TradeChange::Modified);
+    assert!(modified.candidate.as_ref().unwrap().exit_time < baseline.trades[0].exit_time);
+    assert_eq!(
+        diff.summary
+            .trade_drivers
+            .get("EXIT:TAKE_PROFIT->TAKE_PROFIT"),
+        Some(&1)
+    );
+}
+
+#[test]
+fn saved_runs_without_bar_logs_report_unknown_drivers() {
+    let baseline = run(config());
+    let diff = diff_backtests(&baseline.trades, &[], &[], &[]);
+
+    assert_eq!(diff.summary.removed, 1);
+    assert_eq!(diff.trades[0].driver, DiffDriver::Unknown);
+    assert_eq!(diff.summary.compared_bars, 0);
+}
@@ -8,9 +8,11 @@ use serde_json::json;
 
 use eth_btc_strategy::backtest::BacktestBar;
 use eth_btc_strategy::backtest::download::{
-    DownloadError, HyperliquidDownloader, write_bars_to_output,
+    DownloadError, HyperliquidDownloader, apply_funding_history, write_bars_to_output,
 };
+use eth_btc_strategy::config::{BarInterval, PairConfig, Symbol};
 use eth_btc_strategy::data::{DataError, HttpClient, HttpResponse};
+use eth_btc_strategy::funding::{FundingRate, MockFundingSource};
 use eth_btc_strategy::storage::PriceStore;
 
 #[derive(Clone)]
@@ -54,22 +56,22 @@ fn candle_payload(candles: &[(i64, Decimal)]) -> String {
 }
 
 #[tokio::test]
-async fn download_merges_eth_and_btc_candles() {
+async fn download_merges_base_and_quote_candles() {
     let ts1 = Utc.with_ymd_and_hms(2024, 1, 1, 0, 15, 0).unwrap();
     let ts2 = Utc.with_ymd_and_hms(2024, 1, 1, 0, 30, 0).unwrap();
 
-    let eth_payload = candle_payload(&[
+    let base_payload = candle_payload(&[
         (ts1.timestamp_millis(), dec!(2300)),
         (ts2.timestamp_millis(), dec!(2310)),
     ]);
-    let btc_payload = candle_payload(&[
+    let quote_payload = candle_payload(&[
         (ts1.timestamp_millis(), dec!(42000)),
         (ts2.timestamp_millis(), dec!(42100)),
     ]);
 
     let mut responses = HashMap::new();
-    responses.insert("ETH".to_string(), eth_payload);
-    responses.insert("BTC".to_string(), btc_payload);
+    responses.insert("ETH".to_string(), base_payload);
+    responses.insert("BTC".to_string(), quote_payload);
 
     let http = Arc::new(MockHttpClient::new(responses));
     let downloader = HyperliquidDownloader::with_client("http://localhost", http);
@@ -78,13 +80,94 @@ async fn download_merges_eth_and_btc_candles() {
 
     assert_eq!(bars.len(), 2);
     assert_eq!(bars[0].timestamp, ts1);
-    assert_eq!(bars[0].eth_price, dec!(2300));
-    assert_eq!(bars[0].btc_price, dec!(42000));
-    assert!(bars[0].funding_eth.is_none());
-    assert!(bars[0].funding_btc.is_none());
+    assert_eq!(bars[0].base_price, dec!(2300));
+    assert_eq!(bars[0].quote_price, dec!(42000));
+    assert!(bars[0].funding_base.is_none());
+    assert!(bars[0].funding_quote.is_none());
     assert_eq!(bars[1].timestamp, ts2);
-    assert_eq!(bars[1].eth_price, dec!(2310));
-    assert_eq!(bars[1].btc_price, dec!(42100));
+    assert_eq!(bars[1].base_price, dec!(2310));
+    assert_eq!(bars[1].quote_price, dec!(42100));
+}
+
+fn hourly_rate(symbol: Symbol, hour: u32, rate: Decimal) -> FundingRate {
+    FundingRate {
+        symbol,
+        rate,
+        timestamp: Utc.with_ymd_and_hms(2024, 1, 1, hour, 0, 0).unwrap(),
+        interval_hours: 1,
+    }
+}
+
+#[tokio::test]
+async fn download_fills_funding_from_history() {
+    let ts1 = Utc.with_ymd_and_hms(2024, 1, 1, 0, 45, 0).unwrap();
+    let ts2 = Utc.with_ymd_and_hms(2024, 1, 1, 1, 0, 0).unwrap();
+    let mut responses = HashMap::new();
+    responses.insert(
+        "ETH".to_string(),
+        candle_payload(&[
+            (ts1.timestamp_millis(), dec!(2300)),
+            (ts2.timestamp_millis(), dec!(2310)),
+        ]),
+    );
+    responses.insert(
+        "BTC".to_string(),
+        candle_payload(&[
+            (ts1.timestamp_millis(), dec!(42000)),
+            (ts2.timestamp_millis(), dec!(42100)),
+        ]),
+    );
+    let mut funding = MockFundingSource::default();
+    funding.insert_history(
+        Symbol::Base,
+        vec![
+            hourly_rate(Symbol::Base, 0, dec!(0.0001)),
+            hourly_rate(Symbol::Base, 1, dec!(0.0003)),
+        ],
+    );
+    funding.insert_history(
+        Symbol::Quote,
+        vec![hourly_rate(Symbol::Quote, 0, dec!(0.0002))],
+    );
+
+    let downloader = HyperliquidDownloader::with_client(
+        "http://localhost",
+        Arc::new(MockHttpClient::new(responses)),
+    )
+    .with_funding_source(Arc::new(funding));
+    let bars = downloader.fetch_backtest_bars(ts1, ts2).await.unwrap();
+
+    assert_eq!(bars[0].funding_base, Some(dec!(0.0001)));
+    assert_eq!(bars[0].funding_quote, Some(dec!(0.0002)));
+    // The 01:00 bar closes on the next settlement; BTC has no newer rate within the hour.
+    assert_eq!(bars[1].funding_base, Some(dec!(0.0003)));
+    assert_eq!(bars[1].funding_quote, None);
+}
+
+#[test]
+fn funding_history_leaves_bars_before_first_rate_empty() {
+    let bar = |hour, minute| BacktestBar {
+        timestamp: Utc.with_ymd_and_hms(2024, 1, 1, hour, minute, 0).unwrap(),
+        base_price: dec!(2300),
+        quote_price: dec!(42000),
+        funding_base: None,
+        funding_quote: None,
+        base_open: None,
+        base_high: None,
+        base_low: None,
+        quote_open: None,
+        quote_high: None,
+        quote_low: None,
+    };
+    let mut bars = vec![bar(0, 45), bar(1, 15)];
+    let base = vec![hourly_rate(Symbol::Base, 1, dec!(0.0001))];
+    let quote = vec![hourly_rate(Symbol::Quote, 1, dec!(0.0002))];
+
+    apply_funding_history(&mut bars, &base, &quote);
+
+    assert_eq!(bars[0].funding_base, None);
+    assert_eq!(bars[1].funding_base, Some(dec!(0.0001)));
+    assert_eq!(bars[1].funding_quote, Some(dec!(0.0002)));
 }
 
 #[tokio::test]
@@ -92,12 +175,12 @@ async fn download_errors_on_incomplete_coverage() {
     let ts1 = Utc.with_ymd_and_hms(2024, 1, 1, 0, 15, 0).unwrap();
     let ts2 = Utc.with_ymd_and_hms(2024, 1, 1, 0, 30, 0).unwrap();
 
-    let eth_payload = candle_payload(&[(ts2.timestamp_millis(), dec!(2300))]);
-    let btc_payload = candle_payload(&[(ts2.timestamp_millis(), dec!(42000))]);
+    let base_payload = candle_payload(&[(ts2.timestamp_millis(), dec!(2300))]);
+    let quote_payload = candle_payload(&[(ts2.timestamp_millis(), dec!(42000))]);
 
     let mut responses = HashMap::new();
-    responses.insert("ETH".to_string(), eth_payload);
-    responses.insert("BTC".to_string(), btc_payload);
+    responses.insert("ETH".to_string(), base_payload);
+    responses.insert("BTC".to_string(), quote_payload);
 
     let http = Arc::new(MockHttpClient::new(responses));
     let downloader = HyperliquidDownloader::with_client("http://localhost", http);
@@ -111,14 +194,26 @@ async fn download_writes_json_output() {
     let ts1 = Utc.with_ymd_and_hms(2024, 1, 1, 0, 15, 0).unwrap();
     let bars = vec![BacktestBar {
         timestamp: ts1,
-        eth_price: dec!(2300),
-        btc_price: dec!(42000),
-        funding_eth: None,
-        funding_btc: None,
+        base_price: dec!(2300),
+        quote_price: dec!(42000),
+        funding_base: None,
+        funding_quote: None,
+        base_open: None,
+        base_high: None,
+        base_low: None,
+        quote_open: None,
+        quote_high: None,
+        quote_low: None,
     }];
 
     let path = format!("/tmp/bars-{}.json", ts1.timestamp());
-    write_bars_to_output(&bars, std::path::Path::new(&path)).unwrap();
+    write_bars_to_output(
+        &bars,
+        std::path::Path::new(&path),
+        &PairConfig::default(),
+        BarInterval::M15,
+    )
+    .unwrap();
 
     let contents = std::fs::read_to_string(&path).unwrap();
     let decoded: Vec<BacktestBar> = serde_json::from_str(&contents).unwrap();
@@ -133,30 +228,50 @@ async fn download_writes_sqlite_output() {
     let bars = vec![
         BacktestBar {
             timestamp: ts1,
-            eth_price: dec!(2300),
-            btc_price: dec!(42000),
-            funding_eth: Some(dec!(0.0001)),
-            funding_btc: Some(dec!(0.0002)),
+            base_price: dec!(2300),
+            quote_price: dec!(42000),
+            funding_base: Some(dec!(0.0001)),
+            funding_quote: Some(dec!(0.0002)),
+            base_open: None,
+            base_high: None,
+            base_low: None,
+            quote_open: None,
+            quote_high: None,
+            quote_low: None,
         },
         BacktestBar {
             timestamp: ts2,
-            eth_price: dec!(2310),
-            btc_price: dec!(42100),
-            funding_eth: None,
-            funding_btc: None,
+            base_price: dec!(2310),
+            quote_price: dec!(42100),
+            funding_base: None,
+            funding_quote: None,
+            base_open: None,
+            base_high: None,
+            base_low: None,
+            quote_open: None,
+            quote_high: None,
+            quote_low: None,
         },
     ];
 
     let path = format!("/tmp/bars-{}.sqlite", ts1.timestamp());
-    write_bars_to_output(&bars, std::path::Path::new(&path)).unwrap();
+    write_bars_to_output(
+        &bars,
+        std::path::Path::new(&path),
+        &PairConfig::default(),
+        BarInterval::M15,
+    )
+    .unwrap();
 
     let store = PriceStore::new(&path).unwrap();
     let records = store.load_range(ts1, ts2).unwrap();
     assert_eq!(records.len(), 2);
-    assert_eq!(records[0].eth_mid, Some(dec!(2300)));
-    assert_eq!(records[0].btc_mid, Some(dec!(42000)));
-    assert_eq!(records[0].funding_eth, Some(dec!(0.0001)));
-    assert_eq!(records[0].funding_btc, Some(dec!(0.0002)));
+    assert_eq!(records[0].base_mid, Some(dec!(2300)));
+    assert_eq!(records[0].quote_mid, Some(dec!(42000)));
+    assert_eq!(records[0].funding_base, Some(dec!(0.0001)));
+    assert_eq!(records[0].funding_quote, Some(dec!(0.0002)));
+    assert_eq!(records[0].funding_interval_hours, Some(1));
     assert_eq!(records[1].timestamp, ts2);
+    assert_eq!(records[1].funding_interval_hours, None);
     let _ = std::fs::remove_file(path);
 }
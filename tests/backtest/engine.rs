@@ -1,20 +1,29 @@
 use chrono::{TimeZone, Utc};
-use rust_decimal::MathematicalOps;
+use rust_decimal::{Decimal, MathematicalOps};
 use rust_decimal_macros::dec;
 
 use eth_btc_strategy::backtest::{BacktestBar, BacktestEngine};
-use eth_btc_strategy::config::{CapitalMode, Config, SigmaFloorMode};
+use eth_btc_strategy::config::{CapitalMode, Config, SigmaFloorMode, SpreadModel};
+use eth_btc_strategy::core::TradeDirection;
+use eth_btc_strategy::indicators::relative_price;
+use eth_btc_strategy::logging::EntryBlockReason;
 use eth_btc_strategy::position::MinSizePolicy;
 
 fn bar(timestamp: i64, r: rust_decimal::Decimal) -> BacktestBar {
-    let base = dec!(100);
-    let eth = base * r.exp();
+    let quote = dec!(100);
+    let base = quote * r.exp();
     BacktestBar {
         timestamp: Utc.timestamp_opt(timestamp, 0).unwrap(),
-        eth_price: eth,
-        btc_price: base,
-        funding_eth: None,
-        funding_btc: None,
+        base_price: base,
+        quote_price: quote,
+        funding_base: None,
+        funding_quote: None,
+        base_open: None,
+        base_high: None,
+        base_low: None,
+        quote_open: None,
+        quote_high: None,
+        quote_low: None,
     }
 }
 
@@ -43,6 +52,89 @@ fn backtest_engine_runs_and_generates_trades() {
     assert_eq!(result.bar_logs.len(), bars.len());
 }
 
+#[test]
+fn bar_logs_keep_log_ratio_and_report_hedged_residual_separately() {
+    let mut config = Config::default();
+    config.strategy.n_z = 3;
+    config.position.n_vol = 2;
+    config.spread.model = SpreadModel::RollingBeta;
+    config.spread.beta_lookback_bars = 3;
+    let quote_prices = [
+        dec!(100),
+        dec!(104),
+        dec!(98),
+        dec!(101),
+        dec!(103),
+        dec!(99),
+    ];
+    let bars: Vec<BacktestBar> = quote_prices
+        .into_iter()
+        .enumerate()
+        .map(|(index, quote)| BacktestBar {
+            base_price: quote * dec!(0.05) + dec!(0.01) * Decimal::from(index as u64),
+            quote_price: quote,
+            ..bar(index as i64 * 900, dec!(0.0))
+        })
+        .collect();
+
+    let result = BacktestEngine::new(config).run(&bars).unwrap();
+
+    for (log, bar) in result.bar_logs.iter().zip(&bars) {
+        assert_eq!(
+            log.r,
+            Some(relative_price(bar.base_price, bar.quote_price).unwrap())
+        );
+    }
+    let fitted = result.bar_logs.last().unwrap();
+    assert!(fitted.beta.is_some());
+    assert!(fitted.spread.is_some());
+    assert_ne!(fitted.spread, fitted.r);
+}
+
+#[test]
+fn backtest_trades_carry_rich_records() {
+    let mut config = Config::default();
+    config.strategy.n_z = 4;
+    config.strategy.entry_z = dec!(1.5);
+    config.strategy.tp_z = dec!(0.6);
+    config.position.n_vol = 2;
+    config.sigma_floor.mode = SigmaFloorMode::Const;
+
+    let bars = vec![
+        bar(0, dec!(0.0)),
+        bar(900, dec!(0.0)),
+        bar(1800, dec!(0.0)),
+        bar(2700, dec!(0.0)),
+        bar(3600, dec!(0.04)),
+        bar(4500, dec!(0.0)),
+    ];
+    let result = BacktestEngine::new(config.clone()).run(&bars).unwrap();
+    let trade = &result.trades[0];
+
+    assert_eq!(trade.pair.as_deref(), Some("ETH/BTC"));
+    assert_eq!(trade.direction, TradeDirection::ShortBaseLongQuote);
+    assert!(trade.base_qty < Decimal::ZERO && trade.quote_qty > Decimal::ZERO);
+    assert_eq!(trade.entry_base_price, bars[4].base_price);
+    assert_eq!(trade.base_price, bars[5].base_price);
+    assert_eq!(trade.quote_price, bars[5].quote_price);
+    assert!(trade.entry_zscore.unwrap() > config.strategy.entry_z);
+    assert!(trade.exit_zscore.unwrap().abs() <= config.strategy.tp_z);
+    assert_eq!(trade.holding_hours, dec!(0.25));
+    assert!(trade.gross_pnl > Decimal::ZERO);
+    assert_eq!(
+        trade.fee,
+        trade.notional * Decimal::from(config.backtest.fee_bps) / dec!(10000)
+    );
+    // Half of slippage_bps on the entry fill and half on the exit fill's notional.
+    let half_bps = Decimal::from(config.backtest.slippage_bps) / dec!(2);
+    let base_slippage_bps = half_bps + half_bps * trade.base_price / trade.entry_base_price;
+    assert!((trade.base_slippage_bps.unwrap() - base_slippage_bps).abs() < dec!(0.000001));
+    assert_eq!(
+        trade.pnl,
+        trade.gross_pnl - trade.fee - trade.slippage - trade.funding
+    );
+}
+
 #[test]
 fn backtest_engine_skips_entry_below_minimum_size() {
     let mut config = Config::default();
@@ -114,3 +206,115 @@ fn backtest_engine_requires_equity_value_for_equity_ratio() {
     let result = engine.run(&bars);
     assert!(result.is_err());
 }
+
+fn entry_bars() -> Vec<BacktestBar> {
+    vec![
+        bar(0, dec!(0.0)),
+        bar(900, dec!(0.0)),
+        bar(1800, dec!(0.0)),
+        bar(2700, dec!(0.0)),
+        bar(3600, dec!(0.04)),
+        bar(4500, dec!(0.0)),
+    ]
+}
+
+fn entry_config() -> Config {
+    let mut config = Config::default();
+    config.strategy.n_z = 4;
+    config.strategy.entry_z = dec!(1.5);
+    config.strategy.tp_z = dec!(0.6);
+    config.position.n_vol = 2;
+    config.sigma_floor.mode = SigmaFloorMode::Const;
+    config
+}
+
+#[test]
+fn backtest_engine_blocks_entry_when_cost_gate_enforced() {
+    let mut config = entry_config();
+    config.cost_gate.enabled = true;
+    config.cost_gate.enforce = true;
+    config.cost_gate.min_net_edge_bps = dec!(100000);
+
+    let engine = BacktestEngine::new(config);
+    let result = engine.run(&entry_bars()).unwrap();
+
+    assert!(result.trades.is_empty());
+    let entry_log = &result.bar_logs[4];
+    assert_eq!(entry_log.cost_gate_pass, Some(false));
+    assert!(entry_log.expected_edge_bps.is_some());
+    assert_eq!(
+        entry_log.entry_block_reason,
+        Some(EntryBlockReason::CostGate)
+    );
+}
+
+#[test]
+fn backtest_engine_records_cost_gate_without_blocking_when_not_enforced() {
+    let mut config = entry_config();
+    config.cost_gate.enabled = true;
+    config.cost_gate.enforce = false;
+    config.cost_gate.min_net_edge_bps = dec!(100000);
+
+    let engine = BacktestEngine::new(config);
+    let result = engine.run(&entry_bars()).unwrap();
+
+    assert_eq!(result.trades.len(), 1);
+    let entry_log = &result.bar_logs[4];
+    assert_eq!(entry_log.cost_gate_pass, Some(false));
+    assert_eq!(entry_log.entry_block_reason, None);
+}
+
+#[test]
+fn backtest_engine_blocks_entry_when_regime_gate_not_ready() {
+    let mut config = entry_config();
+    config.regime_gate.enabled = true;
+    config.regime_gate.lookback_bars = 28;
+
+    let engine = BacktestEngine::new(config);
+    let result = engine.run(&entry_bars()).unwrap();
+
+    assert!(result.trades.is_empty());
+    let entry_log = &result.bar_logs[4];
+    assert_eq!(entry_log.regime_gate_pass, Some(false));
+    assert_eq!(
+        entry_log.entry_block_reason,
+        Some(EntryBlockReason::RegimeGate)
+    );
+}
+
+#[test]
+fn backtest_engine_applies_directional_size_multiplier() {
+    let baseline = BacktestEngine::new(entry_config())
+        .run(&entry_bars())
+        .unwrap();
+
+    let mut config = entry_config();
+    config.directional_sizing.short_base_long_quote_multiplier = dec!(0.5);
+    let scaled = BacktestEngine::new(config).run(&entry_bars()).unwrap();
+
+    let base_log = &baseline.bar_logs[4];
+    let scaled_log = &scaled.bar_logs[4];
+    assert_eq!(
+        scaled_log.notional_base.unwrap(),
+        base_log.notional_base.unwrap() * dec!(0.5)
+    );
+    assert_eq!(
+        scaled_log.notional_quote.unwrap(),
+        base_log.notional_quote.unwrap() * dec!(0.5)
+    );
+}
+
+#[test]
+fn backtest_engine_records_no_cross_when_flat() {
+    let engine = BacktestEngine::new(entry_config());
+    let result = engine.run(&entry_bars()).unwrap();
+
+    assert_eq!(
+        result.bar_logs[0].entry_block_reason,
+        Some(EntryBlockReason::ZscoreUnavailable)
+    );
+    assert_eq!(
+        result.bar_logs[3].entry_block_reason,
+        Some(EntryBlockReason::NoCross)
+    );
+}
@@ -1,13 +1,21 @@
 use std::fs;
 
 use chrono::{TimeZone, Utc};
+use rust_decimal::{Decimal, MathematicalOps};
 use rust_decimal_macros::dec;
 use uuid::Uuid;
 
+use eth_btc_strategy::analysis::{default_replay_strategy_configs, replay_stats_log};
+
+use eth_btc_strategy::backtest::margin::MarginPoint;
 use eth_btc_strategy::backtest::{
-    BacktestResult, EquityPoint, Metrics, Trade, TradeExitReason, export_equity_csv,
-    export_metrics_json, export_trades_csv,
+    BacktestBar, BacktestEngine, BacktestResult, EquityPoint, Metrics, Trade, TradeExitReason,
+    export_bar_logs_jsonl, export_equity_csv, export_margin_csv, export_metrics_json,
+    export_trades_csv, load_trades_csv,
 };
+use eth_btc_strategy::config::{BarInterval, Config, LogFormat, Symbol};
+use eth_btc_strategy::core::TradeDirection;
+use eth_btc_strategy::logging::{BarLogFileWriter, BarLogWriter};
 
 #[test]
 fn exports_results_to_files() {
@@ -16,28 +24,131 @@ fn exports_results_to_files() {
 
     let result = BacktestResult {
         trades: vec![Trade {
+            pair: None,
+            direction: TradeDirection::LongBaseShortQuote,
             entry_time: Utc.timestamp_opt(0, 0).unwrap(),
             exit_time: Utc.timestamp_opt(3600, 0).unwrap(),
+            base_qty: dec!(0),
+            quote_qty: dec!(0),
+            entry_base_price: dec!(0),
+            entry_quote_price: dec!(0),
+            base_price: dec!(0),
+            quote_price: dec!(0),
+            entry_zscore: None,
+            exit_zscore: None,
             pnl: dec!(10),
+            gross_pnl: dec!(0),
             exit_reason: TradeExitReason::TakeProfit,
+            notional: dec!(0),
+            fee: dec!(0),
+            slippage: dec!(0),
+            base_slippage_bps: None,
+            quote_slippage_bps: None,
+            funding: dec!(0),
+            holding_hours: dec!(0),
         }],
         equity_curve: vec![EquityPoint {
             timestamp: Utc.timestamp_opt(0, 0).unwrap(),
             equity: dec!(1000),
         }],
         bar_logs: vec![],
+        margin: vec![MarginPoint {
+            timestamp: Utc.timestamp_opt(0, 0).unwrap(),
+            account_equity: dec!(1000),
+            used_margin: dec!(400),
+            available_margin: dec!(600),
+            liquidated: vec![Symbol::Base, Symbol::Quote],
+        }],
         metrics: Metrics::default(),
     };
 
     let metrics_path = dir.join("metrics.json");
     let trades_path = dir.join("trades.csv");
     let equity_path = dir.join("equity.csv");
+    let margin_path = dir.join("margin.csv");
 
     export_metrics_json(&metrics_path, &result.metrics).unwrap();
     export_trades_csv(&trades_path, &result.trades).unwrap();
     export_equity_csv(&equity_path, &result.equity_curve).unwrap();
+    export_margin_csv(&margin_path, &result.margin).unwrap();
 
+    let exported: Metrics =
+        serde_json::from_str(&fs::read_to_string(&metrics_path).unwrap()).unwrap();
+    assert_eq!(exported, result.metrics);
+    assert!(
+        fs::read_to_string(&metrics_path)
+            .unwrap()
+            .contains("\"max_drawdown_duration_hours\"")
+    );
+    let trades_csv = fs::read_to_string(&trades_path).unwrap();
+    let mut lines = trades_csv.lines();
+    assert!(
+        lines
+            .next()
+            .unwrap()
+            .starts_with("pair,direction,entry_time,exit_time,holding_hours,exit_reason,")
+    );
+    let row: Vec<&str> = lines.next().unwrap().split(',').collect();
+    assert_eq!(row.len(), 22);
+    assert_eq!(row[1], "LongBaseShortQuote");
+    assert_eq!(row[21], "10");
+    assert_eq!(load_trades_csv(&trades_path).unwrap(), result.trades);
+    assert_eq!(
+        fs::read_to_string(&margin_path).unwrap(),
+        "timestamp,account_equity,used_margin,available_margin,liquidated\n\
+         1970-01-01T00:00:00+00:00,1000,400,600,BASE|QUOTE\n"
+    );
     assert!(metrics_path.exists());
     assert!(trades_path.exists());
     assert!(equity_path.exists());
 }
+
+#[test]
+fn bar_logs_export_matches_live_stats_log() {
+    let dir = std::env::temp_dir().join(format!("eth_btc_bar_logs_{}", Uuid::new_v4()));
+    fs::create_dir_all(&dir).unwrap();
+
+    let bars: Vec<BacktestBar> = (0..40)
+        .map(|index| {
+            let r = Decimal::from(index % 7) / dec!(100) - dec!(0.03);
+            BacktestBar {
+                timestamp: Utc.timestamp_opt(index * 900, 0).unwrap(),
+                base_price: dec!(100) * r.exp(),
+                quote_price: dec!(100),
+                funding_base: None,
+                funding_quote: None,
+                base_open: None,
+                base_high: None,
+                base_low: None,
+                quote_open: None,
+                quote_high: None,
+                quote_low: None,
+            }
+        })
+        .collect();
+    let mut config = Config::default();
+    config.strategy.n_z = 4;
+    config.position.n_vol = 2;
+    let result = BacktestEngine::new(config).run(&bars).unwrap();
+
+    let exported = dir.join("backtest_stats.jsonl");
+    export_bar_logs_jsonl(&exported, &result.bar_logs).unwrap();
+    let live = dir.join("live_stats.jsonl");
+    let writer = BarLogFileWriter::new(live.clone(), LogFormat::Json).unwrap();
+    for log in &result.bar_logs {
+        writer.write(log).unwrap();
+    }
+    let contents = fs::read_to_string(&exported).unwrap();
+    assert_eq!(contents, fs::read_to_string(&live).unwrap());
+    assert_eq!(contents.lines().count(), bars.len());
+
+    let report = replay_stats_log(
+        &contents,
+        None,
+        &default_replay_strategy_configs(),
+        BarInterval::M15,
+    )
+    .unwrap();
+    assert!(report.rows > 0);
+    fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,155 @@
+use chrono::{TimeZone, Utc};
+use rust_decimal::MathematicalOps;
+use rust_decimal_macros::dec;
+
+use eth_btc_strategy::backtest::grid::{
+    GridSearchConfig, ParameterGrid, SearchObjective, export_grid_results_csv, run_grid_search,
+};
+use eth_btc_strategy::backtest::{BacktestBar, BacktestEngine, Metrics, precompute_indicators};
+use eth_btc_strategy::config::{Config, SigmaFloorMode};
+
+fn bar(timestamp: i64, r: rust_decimal::Decimal) -> BacktestBar {
+    let quote = dec!(100);
+    let base = quote * r.exp();
+    BacktestBar {
+        timestamp: Utc.timestamp_opt(timestamp, 0).unwrap(),
+        base_price: base,
+        quote_price: quote,
+        funding_base: None,
+        funding_quote: None,
+        base_open: None,
+        base_high: None,
+        base_low: None,
+        quote_open: None,
+        quote_high: None,
+        quote_low: None,
+    }
+}
+
+fn spiking_bars(count: usize) -> Vec<BacktestBar> {
+    (0..count)
+        .map(|idx| {
+            let r = match idx % 8 {
+                4 => dec!(0.04),
+                5 => dec!(0.01),
+                _ => dec!(0.0),
+            };
+            bar(idx as i64 * 900, r)
+        })
+        .collect()
+}
+
+fn base_config() -> Config {
+    let mut config = Config::default();
+    config.strategy.n_z = 4;
+    config.strategy.entry_z = dec!(1.5);
+    config.strategy.tp_z = dec!(0.6);
+    config.position.n_vol = 2;
+    config.sigma_floor.mode = SigmaFloorMode::Const;
+    config
+}
+
+fn search_config(threads: usize) -> GridSearchConfig {
+    GridSearchConfig {
+        grid: ParameterGrid {
+            entry_z: vec![dec!(1.2), dec!(1.5)],
+            n_z: vec![4, 6],
+            cooldown_hours: vec![0, 24],
+            ..ParameterGrid::default()
+        },
+        objective: SearchObjective::NetPnl,
+        min_trades: 1,
+        threads: Some(threads),
+    }
+}
+
+#[test]
+fn precomputed_indicators_match_sequential_run() {
+    let bars = spiking_bars(48);
+    let config = base_config();
+    let engine = BacktestEngine::new(config.clone());
+
+    let sequential = engine.run(&bars).unwrap();
+    let shared = engine
+        .run_with_indicators(&bars, &precompute_indicators(&config, &bars).unwrap())
+        .unwrap();
+
+    assert_eq!(shared.trades, sequential.trades);
+    assert_eq!(shared.equity_curve, sequential.equity_curve);
+    assert!(engine.run_with_indicators(&bars[1..], &[]).is_err());
+}
+
+#[test]
+fn grid_search_ranks_every_combination_like_individual_runs() {
+    let bars = spiking_bars(64);
+    let base = base_config();
+
+    let results = run_grid_search(&base, &bars, &search_config(4)).unwrap();
+
+    assert_eq!(results.len(), 8);
+    for (index, result) in results.iter().enumerate() {
+        assert_eq!(result.rank, index + 1);
+        let expected = BacktestEngine::new(result.parameters.apply(&base))
+            .run(&bars)
+            .unwrap();
+        assert_eq!(result.metrics, expected.metrics);
+        let net_pnl: rust_decimal::Decimal = expected.trades.iter().map(|trade| trade.pnl).sum();
+        assert_eq!(result.net_pnl, net_pnl);
+        assert_eq!(result.score, net_pnl);
+    }
+    assert!(results.windows(2).all(|pair| {
+        (pair[0].meets_min_trades, pair[0].score) >= (pair[1].meets_min_trades, pair[1].score)
+    }));
+    assert_eq!(
+        run_grid_search(&base, &bars, &search_config(1)).unwrap(),
+        results
+    );
+}
+
+#[test]
+fn grid_search_rejects_zero_threads_and_empty_grid() {
+    let bars = spiking_bars(16);
+    assert!(run_grid_search(&base_config(), &bars, &search_config(0)).is_err());
+
+    let mut config = search_config(1);
+    config.grid.sl_z = vec![dec!(0.1)];
+    assert!(run_grid_search(&base_config(), &bars, &config).is_err());
+}
+
+#[test]
+fn grid_results_export_includes_metrics_columns() {
+    let bars = spiking_bars(32);
+    let results = run_grid_search(&base_config(), &bars, &search_config(2)).unwrap();
+    let dir = std::env::temp_dir().join(format!("grid_export_{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("grid.csv");
+
+    export_grid_results_csv(&path, &results).unwrap();
+
+    let contents = std::fs::read_to_string(&path).unwrap();
+    let header = contents.lines().next().unwrap();
+    let columns = header.split(',').collect::<Vec<_>>();
+    for column in ["rank", "cooldown_hours", "score", "net_pnl"] {
+        assert!(columns.contains(&column), "{column}");
+    }
+    let metrics = serde_json::to_value(Metrics::default()).unwrap();
+    for field in metrics.as_object().unwrap().keys() {
+        assert!(columns.contains(&field.as_str()), "{field}");
+    }
+    assert_eq!(contents.lines().count(), results.len() + 1);
+    assert!(
+        contents
+            .lines()
+            .skip(1)
+            .all(|line| line.split(',').count() == columns.len())
+    );
+}
+
+#[test]
+fn search_objective_parses_names() {
+    assert_eq!(
+        "net-pnl".parse::<SearchObjective>().unwrap(),
+        SearchObjective::NetPnl
+    );
+    assert!("calmar".parse::<SearchObjective>().is_err());
+}
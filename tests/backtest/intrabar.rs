@@ -0,0 +1,134 @@
+use chrono::{TimeZone, Utc};
+use rust_decimal::{Decimal, MathematicalOps};
+use rust_decimal_macros::dec;
+
+use eth_btc_strategy::backtest::intrabar::worst_case_intrabar_exit;
+use eth_btc_strategy::backtest::{BacktestBar, BacktestEngine, TradeExitReason};
+use eth_btc_strategy::config::{Config, SigmaFloorMode};
+use eth_btc_strategy::core::{ExitReason, TradeDirection};
+use eth_btc_strategy::indicators::ZScoreSnapshot;
+
+fn ohlc_bar(timestamp: i64, r: Decimal, low_r: Decimal, high_r: Decimal) -> BacktestBar {
+    let base = dec!(100);
+    BacktestBar {
+        timestamp: Utc.timestamp_opt(timestamp, 0).unwrap(),
+        base_price: base * r.exp(),
+        quote_price: base,
+        funding_base: None,
+        funding_quote: None,
+        base_open: Some(base * r.exp()),
+        base_high: Some(base * high_r.exp()),
+        base_low: Some(base * low_r.exp()),
+        quote_open: Some(base),
+        quote_high: Some(base),
+        quote_low: Some(base),
+    }
+}
+
+fn snapshot(r: Decimal) -> ZScoreSnapshot {
+    ZScoreSnapshot {
+        r,
+        mean: Some(Decimal::ZERO),
+        sigma: Some(dec!(0.01)),
+        sigma_floor: None,
+        sigma_eff: Some(dec!(0.01)),
+        zscore: Some(r / dec!(0.01)),
+    }
+}
+
+#[test]
+fn intrabar_stop_loss_fills_at_the_bound() {
+    let bar = ohlc_bar(0, dec!(-0.02), dec!(-0.05), dec!(-0.02));
+    let exit = worst_case_intrabar_exit(
+        &bar,
+        TradeDirection::LongBaseShortQuote,
+        Decimal::ONE,
+        &snapshot(dec!(-0.02)),
+        dec!(0.5),
+        dec!(3.5),
+        true,
+    )
+    .expect("stop-loss crossed intrabar");
+
+    assert_eq!(exit.signal.reason, ExitReason::StopLoss);
+    assert_eq!(exit.signal.zscore, dec!(-3.5));
+    let fill_r = (exit.base_price / exit.quote_price).ln();
+    assert!((fill_r - dec!(-0.035)).abs() < dec!(0.000001));
+    assert!(exit.base_price > bar.base_low.unwrap() && exit.base_price < bar.base_price);
+}
+
+#[test]
+fn intrabar_stop_loss_wins_when_both_bounds_are_touched() {
+    let bar = ohlc_bar(0, dec!(-0.02), dec!(-0.05), dec!(0.0));
+    let exit = worst_case_intrabar_exit(
+        &bar,
+        TradeDirection::LongBaseShortQuote,
+        Decimal::ONE,
+        &snapshot(dec!(-0.02)),
+        dec!(0.5),
+        dec!(3.5),
+        true,
+    )
+    .unwrap();
+    assert_eq!(exit.signal.reason, ExitReason::StopLoss);
+
+    let short = worst_case_intrabar_exit(
+        &ohlc_bar(0, dec!(0.02), dec!(0.0), dec!(0.03)),
+        TradeDirection::ShortBaseLongQuote,
+        Decimal::ONE,
+        &snapshot(dec!(0.02)),
+        dec!(0.5),
+        dec!(3.5),
+        true,
+    )
+    .unwrap();
+    assert_eq!(short.signal.reason, ExitReason::TakeProfit);
+    assert_eq!(short.signal.zscore, dec!(0.5));
+}
+
+#[test]
+fn intrabar_exit_requires_ohlc() {
+    let mut bar = ohlc_bar(0, dec!(-0.02), dec!(-0.05), dec!(-0.02));
+    bar.quote_low = None;
+    let exit = worst_case_intrabar_exit(
+        &bar,
+        TradeDirection::LongBaseShortQuote,
+        Decimal::ONE,
+        &snapshot(dec!(-0.02)),
+        dec!(0.5),
+        dec!(3.5),
+        true,
+    );
+    assert!(exit.is_none());
+}
+
+#[test]
+fn backtest_intrabar_exits_catch_stops_missed_at_the_close() {
+    let mut config = Config::default();
+    config.strategy.n_z = 4;
+    config.strategy.entry_z = dec!(1.5);
+    config.strategy.tp_z = dec!(0.6);
+    config.position.n_vol = 2;
+    config.sigma_floor.mode = SigmaFloorMode::Const;
+
+    let flat = |ts| ohlc_bar(ts, dec!(0.0), dec!(0.0), dec!(0.0));
+    let bars = vec![
+        flat(0),
+        flat(900),
+        flat(1800),
+        flat(2700),
+        ohlc_bar(3600, dec!(-0.04), dec!(-0.04), dec!(-0.04)),
+        ohlc_bar(4500, dec!(-0.04), dec!(-0.5), dec!(-0.04)),
+    ];
+
+    let close_only = BacktestEngine::new(config.clone()).run(&bars).unwrap();
+    assert!(close_only.trades.is_empty());
+
+    config.backtest.intrabar_exits = true;
+    let intrabar = BacktestEngine::new(config).run(&bars).unwrap();
+    assert_eq!(intrabar.trades.len(), 1);
+    let trade = &intrabar.trades[0];
+    assert_eq!(trade.exit_reason, TradeExitReason::StopLoss);
+    assert_eq!(trade.exit_time, bars[5].timestamp);
+    assert!(trade.pnl < Decimal::ZERO);
+}
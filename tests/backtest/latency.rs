@@ -0,0 +1,106 @@
+use chrono::{TimeZone, Utc};
+use rust_decimal::{Decimal, MathematicalOps};
+use rust_decimal_macros::dec;
+
+use eth_btc_strategy::backtest::latency::delayed_fill_prices;
+use eth_btc_strategy::backtest::{BacktestBar, BacktestEngine};
+use eth_btc_strategy::config::{Config, ExecutionDelay, SigmaFloorMode};
+
+fn bar(timestamp: i64, base: Decimal, quote: Decimal) -> BacktestBar {
+    BacktestBar {
+        timestamp: Utc.timestamp_opt(timestamp, 0).unwrap(),
+        base_price: base,
+        quote_price: quote,
+        funding_base: None,
+        funding_quote: None,
+        base_open: None,
+        base_high: None,
+        base_low: None,
+        quote_open: None,
+        quote_high: None,
+        quote_low: None,
+    }
+}
+
+#[test]
+fn delayed_fills_follow_the_configured_model() {
+    let bars = vec![
+        bar(0, dec!(100), dec!(1000)),
+        bar(900, dec!(130), dec!(1090)),
+    ];
+    let minutes = vec![bar(60, dec!(101), dec!(1001))];
+
+    let fill = |delay, offset, minutes: &[BacktestBar]| {
+        delayed_fill_prices(delay, offset, &bars, 0, minutes)
+    };
+    assert_eq!(fill(ExecutionDelay::None, 0, &[]), (dec!(100), dec!(1000)));
+    assert_eq!(
+        fill(ExecutionDelay::NextBar, 0, &[]),
+        (dec!(130), dec!(1090))
+    );
+    assert_eq!(
+        fill(ExecutionDelay::NextMinute, 0, &minutes),
+        (dec!(101), dec!(1001))
+    );
+    assert_eq!(
+        fill(ExecutionDelay::NextMinute, 0, &[]),
+        (dec!(102), dec!(1006))
+    );
+    assert_eq!(
+        fill(ExecutionDelay::FixedOffset, 300, &[]),
+        (dec!(110), dec!(1030))
+    );
+    // Delays past the last bar fill at the last close.
+    assert_eq!(
+        delayed_fill_prices(ExecutionDelay::NextBar, 0, &bars, 1, &[]),
+        (dec!(130), dec!(1090))
+    );
+
+    // With OHLC bars the next bar fills at its open, leg by leg.
+    let mut with_open = bars.clone();
+    with_open[1].base_open = Some(dec!(104));
+    assert_eq!(
+        delayed_fill_prices(ExecutionDelay::NextBar, 0, &with_open, 0, &[]),
+        (dec!(104), dec!(1090))
+    );
+    with_open[1].quote_open = Some(dec!(1005));
+    assert_eq!(
+        delayed_fill_prices(ExecutionDelay::NextBar, 0, &with_open, 0, &[]),
+        (dec!(104), dec!(1005))
+    );
+}
+
+#[test]
+fn next_bar_execution_changes_entry_and_exit_prices() {
+    let mut config = Config::default();
+    config.strategy.n_z = 4;
+    config.strategy.entry_z = dec!(1.5);
+    config.strategy.tp_z = dec!(0.6);
+    config.position.n_vol = 2;
+    config.sigma_floor.mode = SigmaFloorMode::Const;
+    config.backtest.include_fees = false;
+    config.backtest.include_slippage = false;
+
+    let base = dec!(100);
+    let at = |ts, r: Decimal| bar(ts, base * r.exp(), base);
+    let bars = vec![
+        at(0, dec!(0.0)),
+        at(900, dec!(0.0)),
+        at(1800, dec!(0.0)),
+        at(2700, dec!(0.0)),
+        at(3600, dec!(0.04)),
+        at(4500, dec!(0.0)),
+        at(5400, dec!(0.0)),
+        at(6300, dec!(0.0)),
+    ];
+
+    let same_bar = BacktestEngine::new(config.clone()).run(&bars).unwrap();
+    config.backtest.execution_delay = ExecutionDelay::NextBar;
+    let next_bar = BacktestEngine::new(config).run(&bars).unwrap();
+
+    assert_eq!(same_bar.trades.len(), 1);
+    assert_eq!(next_bar.trades.len(), 1);
+    assert_eq!(same_bar.trades[0].exit_time, next_bar.trades[0].exit_time);
+    // Entering one bar later gives up part of the reversion.
+    assert!(next_bar.trades[0].pnl < same_bar.trades[0].pnl);
+}
@@ -0,0 +1,144 @@
+use std::fs;
+use std::path::PathBuf;
+
+use chrono::{TimeZone, Utc};
+use rust_decimal::{Decimal, MathematicalOps};
+use rust_decimal_macros::dec;
+use uuid::Uuid;
+
+use eth_btc_strategy::backtest::manifest::{
+    BarSource, CONFIG_FILE, RunInputs, RunManifest, export_manifest, hash_json, load_manifest,
+};
+use eth_btc_strategy::backtest::{BacktestBar, BacktestEngine};
+use eth_btc_strategy::config::{Config, SigmaFloorMode};
+
+fn bar(timestamp: i64, r: Decimal) -> BacktestBar {
+    BacktestBar {
+        timestamp: Utc.timestamp_opt(timestamp, 0).unwrap(),
+        base_price: dec!(100) * r.exp(),
+        quote_price: dec!(100),
+        funding_base: None,
+        funding_quote: None,
+        base_open: None,
+        base_high: None,
+        base_low: None,
+        quote_open: None,
+        quote_high: None,
+        quote_low: None,
+    }
+}
+
+fn bars() -> Vec<BacktestBar> {
+    [
+        dec!(0.0),
+        dec!(0.01),
+        dec!(0.0),
+        dec!(0.01),
+        dec!(0.0),
+        dec!(0.08),
+        dec!(0.0),
+        dec!(0.0),
+    ]
+    .iter()
+    .enumerate()
+    .map(|(i, r)| bar(i as i64 * 900, *r))
+    .collect()
+}
+
+fn config() -> Config {
+    let mut config = Config::default();
+    config.strategy.n_z = 4;
+    config.position.n_vol = 1;
+    config.strategy.tp_z = dec!(0.6);
+    config.sigma_floor.mode = SigmaFloorMode::Const;
+    config
+}
+
+fn inputs() -> RunInputs {
+    RunInputs {
+        bars: BarSource::File {
+            path: PathBuf::from("bars.json"),
+        },
+        minute_bars: None,
+        book_logs: None,
+        simulated_exchange: false,
+    }
+}
+
+fn manifest(config: &Config, bars: &[BacktestBar]) -> RunManifest {
+    let result = BacktestEngine::new(config.clone()).run(bars).unwrap();
+    RunManifest::new(config, bars, inputs(), &result).unwrap()
+}
+
+#[test]
+fn rerun_matches_manifest() {
+    let config = config();
+    let bars = bars();
+    let first = manifest(&config, &bars);
+    let second = manifest(&config, &bars);
+
+    assert_eq!(first, second);
+    assert_eq!(first.trade_count, 1);
+    assert_eq!(first.bars.count, bars.len());
+    assert_eq!(first.bars.start, Some(bars[0].timestamp));
+    assert_eq!(first.crate_version, env!("CARGO_PKG_VERSION"));
+    let verification = first.compare(&second);
+    assert!(verification.verified);
+    assert!(verification.checks.iter().all(|check| check.matches));
+}
+
+#[test]
+fn changed_bars_and_outputs_fail_verification() {
+    let config = config();
+    let bars = bars();
+    let recorded = manifest(&config, &bars);
+
+    let mut shifted = bars.clone();
+    shifted[5].base_price += dec!(0.5);
+    let verification = recorded.compare(&manifest(&config, &shifted));
+    assert!(!verification.verified);
+    let failed: Vec<_> = verification
+        .checks
+        .iter()
+        .filter(|check| !check.matches)
+        .map(|check| check.field.as_str())
+        .collect();
+    assert!(failed.contains(&"bars.checksum"));
+    assert!(failed.contains(&"trades_hash"));
+
+    let mut rerun = recorded.clone();
+    rerun.git_revision = Some("other".to_string());
+    assert!(recorded.compare(&rerun).verified);
+}
+
+#[test]
+fn config_hash_tracks_values() {
+    let mut config = config();
+    let before = hash_json(&config).unwrap();
+    assert_eq!(hash_json(&config.clone()).unwrap(), before);
+    config.strategy.entry_z += dec!(0.1);
+    assert_ne!(hash_json(&config).unwrap(), before);
+}
+
+#[test]
+fn manifest_round_trips_without_credentials() {
+    let dir = std::env::temp_dir().join(format!("eth_btc_manifest_{}", Uuid::new_v4()));
+    fs::create_dir_all(&dir).unwrap();
+    let mut config = config();
+    config.auth.private_key = Some("0xsecret".to_string());
+    config.alerts.webhook_url = "https://hooks.example/token".to_string();
+    let recorded = manifest(&config, &bars());
+
+    export_manifest(&dir, &recorded, &config).unwrap();
+    let archived = fs::read_to_string(dir.join(CONFIG_FILE)).unwrap();
+    assert!(!archived.contains("0xsecret"));
+    assert!(!archived.contains("hooks.example"));
+
+    let (loaded, loaded_config) = load_manifest(&dir).unwrap();
+    assert_eq!(loaded, recorded);
+    assert!(
+        recorded
+            .compare(&manifest(&loaded_config, &bars()))
+            .verified
+    );
+}
@@ -0,0 +1,196 @@
+use chrono::{TimeZone, Utc};
+use rust_decimal::{Decimal, MathematicalOps};
+use rust_decimal_macros::dec;
+
+use eth_btc_strategy::backtest::margin::{EntryMargin, entry_margin, mark_margin};
+use eth_btc_strategy::backtest::{BacktestBar, BacktestEngine};
+use eth_btc_strategy::config::{
+    CapitalMode, Config, MarginMode, MarginPolicy, SigmaFloorMode, Symbol,
+};
+use eth_btc_strategy::core::TradeDirection;
+use eth_btc_strategy::logging::EntryBlockReason;
+use eth_btc_strategy::state::{PositionLeg, PositionSnapshot};
+
+fn bar(timestamp: i64, r: Decimal) -> BacktestBar {
+    BacktestBar {
+        timestamp: Utc.timestamp_opt(timestamp, 0).unwrap(),
+        base_price: dec!(100) * r.exp(),
+        quote_price: dec!(100),
+        funding_base: None,
+        funding_quote: None,
+        base_open: None,
+        base_high: None,
+        base_low: None,
+        quote_open: None,
+        quote_high: None,
+        quote_low: None,
+    }
+}
+
+/// Two rounds of the spike that opens and takes profit on one trade.
+fn bars() -> Vec<BacktestBar> {
+    let spike = [
+        dec!(0.0),
+        dec!(0.01),
+        dec!(0.0),
+        dec!(0.01),
+        dec!(0.0),
+        dec!(0.08),
+        dec!(0.0),
+        dec!(0.0),
+    ];
+    spike
+        .iter()
+        .chain(spike.iter())
+        .enumerate()
+        .map(|(index, r)| bar(index as i64 * 900, *r))
+        .collect()
+}
+
+fn config() -> Config {
+    let mut config = Config::default();
+    config.strategy.n_z = 4;
+    config.position.n_vol = 1;
+    config.strategy.tp_z = dec!(0.6);
+    config.sigma_floor.mode = SigmaFloorMode::Const;
+    config.position.c_mode = CapitalMode::EquityRatio;
+    config.position.equity_value = Some(dec!(10000));
+    config.position.equity_ratio_k = Some(dec!(3));
+    config
+}
+
+fn position(base_qty: Decimal, quote_qty: Decimal) -> PositionSnapshot {
+    PositionSnapshot {
+        direction: TradeDirection::ShortBaseLongQuote,
+        entry_time: Utc.timestamp_opt(0, 0).unwrap(),
+        base: PositionLeg {
+            qty: base_qty,
+            avg_price: dec!(100),
+            notional: base_qty.abs() * dec!(100),
+        },
+        quote: PositionLeg {
+            qty: quote_qty,
+            avg_price: dec!(100),
+            notional: quote_qty.abs() * dec!(100),
+        },
+    }
+}
+
+#[test]
+fn equity_ratio_sizing_compounds_realized_pnl() {
+    let result = BacktestEngine::new(config()).run(&bars()).unwrap();
+
+    assert_eq!(result.trades.len(), 3);
+    let mut equity = dec!(10000);
+    for trade in &result.trades {
+        assert_eq!(trade.notional.round_dp(12), (equity * dec!(3)).round_dp(12));
+        equity += trade.pnl;
+    }
+    assert_eq!(result.margin.len(), result.equity_curve.len());
+    assert_eq!(result.metrics.max_margin_usage, Decimal::ZERO);
+}
+
+#[test]
+fn entries_beyond_available_margin_are_rejected_or_shrunk() {
+    let mut config = config();
+    config.execution.leverage = Some(2);
+    let rejected = BacktestEngine::new(config.clone()).run(&bars()).unwrap();
+    assert!(rejected.trades.is_empty());
+    assert!(
+        rejected
+            .bar_logs
+            .iter()
+            .any(|log| log.entry_block_reason == Some(EntryBlockReason::InsufficientMargin))
+    );
+
+    config.backtest.margin_policy = MarginPolicy::Shrink;
+    config.execution.margin_mode = MarginMode::Isolated;
+    let shrunk = BacktestEngine::new(config).run(&bars()).unwrap();
+    assert_eq!(shrunk.trades.len(), 3);
+    let mut equity = dec!(10000);
+    for trade in &shrunk.trades {
+        assert_eq!(trade.notional.round_dp(12), (equity * dec!(2)).round_dp(12));
+        equity += trade.pnl;
+    }
+    let open = shrunk
+        .margin
+        .iter()
+        .find(|point| point.used_margin > Decimal::ZERO)
+        .unwrap();
+    assert_eq!(open.used_margin.round_dp(12), dec!(10000));
+    assert_eq!(shrunk.metrics.liquidation_bars, 0);
+}
+
+#[test]
+fn entry_margin_follows_leverage_and_policy() {
+    let mut config = Config::default();
+    assert_eq!(
+        entry_margin(&config, dec!(1000), dec!(50000)),
+        EntryMargin::Fits
+    );
+    config.execution.leverage = Some(5);
+    assert_eq!(
+        entry_margin(&config, dec!(1000), dec!(5000)),
+        EntryMargin::Fits
+    );
+    assert_eq!(
+        entry_margin(&config, dec!(1000), dec!(8000)),
+        EntryMargin::Reject
+    );
+    config.backtest.margin_policy = MarginPolicy::Shrink;
+    assert_eq!(
+        entry_margin(&config, dec!(1000), dec!(8000)),
+        EntryMargin::Shrink(dec!(0.625))
+    );
+    assert_eq!(
+        entry_margin(&config, dec!(0), dec!(8000)),
+        EntryMargin::Reject
+    );
+}
+
+#[test]
+fn isolated_leg_is_flagged_at_its_adverse_extreme() {
+    let mut config = Config::default();
+    config.execution.leverage = Some(10);
+    config.execution.margin_mode = MarginMode::Isolated;
+    // Short 10 ETH and long 10 BTC at 100, 100 margin per leg.
+    let position = position(dec!(-10), dec!(10));
+    let mut bar = bar(900, dec!(0));
+    bar.base_price = dec!(105);
+    bar.quote_price = dec!(99);
+
+    let point = mark_margin(&config, dec!(1000), Some(&position), &bar);
+    assert_eq!(point.account_equity, dec!(940));
+    assert_eq!(point.used_margin, dec!(200));
+    assert_eq!(point.available_margin, dec!(800));
+    assert!(point.liquidated.is_empty());
+
+    // 100 margin - 85 loss < 2% of 1085 notional.
+    bar.base_high = Some(dec!(108.5));
+    let point = mark_margin(&config, dec!(1000), Some(&position), &bar);
+    assert_eq!(point.liquidated, vec![Symbol::Base]);
+}
+
+#[test]
+fn cross_margin_pools_legs_against_account_equity() {
+    let mut config = Config::default();
+    config.execution.leverage = Some(10);
+    config.execution.margin_mode = MarginMode::Cross;
+    let position = position(dec!(-10), dec!(10));
+    let mut bar = bar(900, dec!(0));
+    bar.base_high = Some(dec!(108.5));
+    bar.base_price = dec!(100);
+
+    // The ETH leg's isolated margin would be gone, but the account still covers it.
+    let point = mark_margin(&config, dec!(1000), Some(&position), &bar);
+    assert_eq!(point.used_margin, dec!(200));
+    assert_eq!(point.available_margin, dec!(800));
+    assert!(point.liquidated.is_empty());
+
+    let point = mark_margin(&config, dec!(100), Some(&position), &bar);
+    assert_eq!(point.liquidated, vec![Symbol::Base, Symbol::Quote]);
+    assert_eq!(
+        mark_margin(&config, dec!(100), None, &bar).liquidated,
+        Vec::<Symbol>::new()
+    );
+}
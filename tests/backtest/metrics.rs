@@ -4,21 +4,58 @@ use rust_decimal::prelude::FromPrimitive;
 use rust_decimal_macros::dec;
 
 use eth_btc_strategy::backtest::{EquityPoint, Trade, TradeExitReason, compute_metrics};
+use eth_btc_strategy::core::TradeDirection;
 
 #[test]
 fn metrics_compute_win_rate_and_profit_factor() {
     let trades = vec![
         Trade {
+            pair: None,
+            direction: TradeDirection::LongBaseShortQuote,
             entry_time: Utc.timestamp_opt(0, 0).unwrap(),
             exit_time: Utc.timestamp_opt(3600, 0).unwrap(),
+            base_qty: dec!(0),
+            quote_qty: dec!(0),
+            entry_base_price: dec!(0),
+            entry_quote_price: dec!(0),
+            base_price: dec!(0),
+            quote_price: dec!(0),
+            entry_zscore: None,
+            exit_zscore: None,
             pnl: dec!(100),
+            gross_pnl: dec!(0),
             exit_reason: TradeExitReason::TakeProfit,
+            notional: dec!(0),
+            fee: dec!(0),
+            slippage: dec!(0),
+            base_slippage_bps: None,
+            quote_slippage_bps: None,
+            funding: dec!(0),
+            holding_hours: dec!(0),
         },
         Trade {
+            pair: None,
+            direction: TradeDirection::LongBaseShortQuote,
             entry_time: Utc.timestamp_opt(7200, 0).unwrap(),
             exit_time: Utc.timestamp_opt(10800, 0).unwrap(),
+            base_qty: dec!(0),
+            quote_qty: dec!(0),
+            entry_base_price: dec!(0),
+            entry_quote_price: dec!(0),
+            base_price: dec!(0),
+            quote_price: dec!(0),
+            entry_zscore: None,
+            exit_zscore: None,
             pnl: dec!(-50),
+            gross_pnl: dec!(0),
             exit_reason: TradeExitReason::StopLoss,
+            notional: dec!(0),
+            fee: dec!(0),
+            slippage: dec!(0),
+            base_slippage_bps: None,
+            quote_slippage_bps: None,
+            funding: dec!(0),
+            holding_hours: dec!(0),
         },
     ];
     let equity = vec![
@@ -41,10 +78,28 @@ fn metrics_compute_win_rate_and_profit_factor() {
 #[test]
 fn metrics_compute_annualized_and_drawdown() {
     let trades = vec![Trade {
+        pair: None,
+        direction: TradeDirection::LongBaseShortQuote,
         entry_time: Utc.timestamp_opt(0, 0).unwrap(),
         exit_time: Utc.timestamp_opt(3600, 0).unwrap(),
+        base_qty: dec!(0),
+        quote_qty: dec!(0),
+        entry_base_price: dec!(0),
+        entry_quote_price: dec!(0),
+        base_price: dec!(0),
+        quote_price: dec!(0),
+        entry_zscore: None,
+        exit_zscore: None,
         pnl: dec!(10),
+        gross_pnl: dec!(0),
         exit_reason: TradeExitReason::TakeProfit,
+        notional: dec!(0),
+        fee: dec!(0),
+        slippage: dec!(0),
+        base_slippage_bps: None,
+        quote_slippage_bps: None,
+        funding: dec!(0),
+        holding_hours: dec!(0),
     }];
     let equity = vec![
         EquityPoint {
@@ -72,10 +127,28 @@ fn metrics_compute_annualized_and_drawdown() {
 #[test]
 fn metrics_compute_sharpe_ratio() {
     let trades = vec![Trade {
+        pair: None,
+        direction: TradeDirection::LongBaseShortQuote,
         entry_time: Utc.timestamp_opt(0, 0).unwrap(),
         exit_time: Utc.timestamp_opt(3600, 0).unwrap(),
+        base_qty: dec!(0),
+        quote_qty: dec!(0),
+        entry_base_price: dec!(0),
+        entry_quote_price: dec!(0),
+        base_price: dec!(0),
+        quote_price: dec!(0),
+        entry_zscore: None,
+        exit_zscore: None,
         pnl: dec!(10),
+        gross_pnl: dec!(0),
         exit_reason: TradeExitReason::TakeProfit,
+        notional: dec!(0),
+        fee: dec!(0),
+        slippage: dec!(0),
+        base_slippage_bps: None,
+        quote_slippage_bps: None,
+        funding: dec!(0),
+        holding_hours: dec!(0),
     }];
     let equity = vec![
         EquityPoint {
@@ -109,3 +182,85 @@ fn metrics_compute_sharpe_ratio() {
     let diff = (metrics.sharpe_ratio - expected).abs();
     assert!(diff <= dec!(0.0001));
 }
+
+#[test]
+fn metrics_compute_sortino_exposure_and_cost_breakdown() {
+    let hour = |value: i64| Utc.timestamp_opt(value * 3600, 0).unwrap();
+    let trade = |entry: i64, exit: i64, pnl, exit_reason, fees| Trade {
+        pair: None,
+        direction: TradeDirection::LongBaseShortQuote,
+        entry_time: hour(entry),
+        exit_time: hour(exit),
+        base_qty: dec!(0),
+        quote_qty: dec!(0),
+        entry_base_price: dec!(0),
+        entry_quote_price: dec!(0),
+        base_price: dec!(0),
+        quote_price: dec!(0),
+        entry_zscore: None,
+        exit_zscore: None,
+        pnl,
+        gross_pnl: dec!(0),
+        exit_reason,
+        notional: dec!(1000),
+        fee: fees,
+        slippage: dec!(0),
+        base_slippage_bps: None,
+        quote_slippage_bps: None,
+        funding: dec!(0.5),
+        holding_hours: dec!(0),
+    };
+    let trades = vec![
+        trade(0, 1, dec!(100), TradeExitReason::TakeProfit, dec!(1)),
+        trade(2, 4, dec!(-110), TradeExitReason::StopLoss, dec!(2)),
+        trade(3, 5, dec!(55), TradeExitReason::TakeProfit, dec!(3)),
+    ];
+    let equity: Vec<EquityPoint> = [
+        dec!(1000),
+        dec!(1100),
+        dec!(990),
+        dec!(1045),
+        dec!(1100),
+        dec!(1210),
+    ]
+    .into_iter()
+    .enumerate()
+    .map(|(index, equity)| EquityPoint {
+        timestamp: hour(index as i64),
+        equity,
+    })
+    .collect();
+
+    let metrics = compute_metrics(&trades, &equity, dec!(0)).unwrap();
+
+    assert_eq!(metrics.max_drawdown_duration_hours, dec!(2));
+    assert_eq!(metrics.time_in_market, dec!(0.8));
+    assert_eq!(metrics.median_holding_hours, dec!(2));
+    assert!((metrics.avg_holding_hours - dec!(5) / dec!(3)).abs() <= dec!(0.000001));
+    assert_eq!(metrics.traded_notional, dec!(6000));
+    assert_eq!(metrics.turnover, dec!(6));
+    assert_eq!(metrics.total_fees, dec!(6));
+    assert_eq!(metrics.total_funding, dec!(1.5));
+    assert_eq!(metrics.pnl_by_exit_reason["TakeProfit"], dec!(155));
+    assert_eq!(metrics.pnl_by_exit_reason["StopLoss"], dec!(-110));
+    assert_eq!(
+        metrics.calmar_ratio,
+        metrics.annualized_return / metrics.max_drawdown
+    );
+
+    let returns = [
+        0.1f64,
+        -0.1,
+        1045.0 / 990.0 - 1.0,
+        1100.0 / 1045.0 - 1.0,
+        0.1,
+    ];
+    let mean = returns.iter().sum::<f64>() / returns.len() as f64;
+    let downside = (0.01f64 / returns.len() as f64).sqrt();
+    let expected = Decimal::from_f64(mean / downside * 8760f64.sqrt()).unwrap();
+    assert!((metrics.sortino_ratio - expected).abs() <= dec!(0.0001));
+
+    let with_rf = compute_metrics(&trades, &equity, dec!(0.05)).unwrap();
+    assert!(with_rf.sharpe_ratio < metrics.sharpe_ratio);
+    assert!(with_rf.sortino_ratio < metrics.sortino_ratio);
+}
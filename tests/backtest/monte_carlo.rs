@@ -0,0 +1,186 @@
+use chrono::{Duration, TimeZone, Utc};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+use eth_btc_strategy::backtest::monte_carlo::{
+    MonteCarloConfig, ResampleMethod, export_monte_carlo_csv, run_monte_carlo,
+};
+use eth_btc_strategy::backtest::{
+    BacktestResult, EquityPoint, Trade, TradeExitReason, compute_metrics,
+};
+use eth_btc_strategy::core::TradeDirection;
+
+fn result() -> BacktestResult {
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let pnls = [
+        dec!(120),
+        dec!(-80),
+        dec!(45),
+        dec!(-150),
+        dec!(60),
+        dec!(90),
+        dec!(-30),
+        dec!(75),
+    ];
+    let trades: Vec<Trade> = pnls
+        .iter()
+        .enumerate()
+        .map(|(index, pnl)| Trade {
+            pair: None,
+            direction: TradeDirection::LongBaseShortQuote,
+            entry_time: start + Duration::hours(index as i64 * 24 + 2),
+            exit_time: start + Duration::hours(index as i64 * 24 + 12),
+            base_qty: dec!(0),
+            quote_qty: dec!(0),
+            entry_base_price: dec!(0),
+            entry_quote_price: dec!(0),
+            base_price: dec!(0),
+            quote_price: dec!(0),
+            entry_zscore: None,
+            exit_zscore: None,
+            pnl: *pnl,
+            gross_pnl: dec!(0),
+            exit_reason: if *pnl < Decimal::ZERO {
+                TradeExitReason::StopLoss
+            } else {
+                TradeExitReason::TakeProfit
+            },
+            notional: dec!(5000),
+            fee: dec!(2),
+            slippage: dec!(0),
+            base_slippage_bps: None,
+            quote_slippage_bps: None,
+            funding: Decimal::ZERO,
+            holding_hours: dec!(0),
+        })
+        .collect();
+    let mut equity = dec!(10000);
+    let equity_curve: Vec<EquityPoint> = (0..pnls.len() as i64 * 4)
+        .map(|step| {
+            let timestamp = start + Duration::hours(step * 6);
+            equity += trades
+                .iter()
+                .filter(|trade| {
+                    trade.exit_time <= timestamp && trade.exit_time > timestamp - Duration::hours(6)
+                })
+                .map(|trade| trade.pnl)
+                .sum::<Decimal>();
+            EquityPoint { timestamp, equity }
+        })
+        .collect();
+    let metrics = compute_metrics(&trades, &equity_curve, Decimal::ZERO).unwrap();
+    BacktestResult {
+        trades,
+        equity_curve,
+        bar_logs: Vec::new(),
+        margin: Vec::new(),
+        metrics,
+    }
+}
+
+fn config(seed: u64) -> MonteCarloConfig {
+    MonteCarloConfig {
+        iterations: 200,
+        block_size: 3,
+        seed,
+    }
+}
+
+#[test]
+fn observed_path_matches_backtest_metrics() {
+    let result = result();
+    let monte_carlo = run_monte_carlo(&result, &config(1)).unwrap();
+    let observed = monte_carlo.summary.observed;
+    assert_eq!(observed.max_drawdown, result.metrics.max_drawdown);
+    assert_eq!(observed.sharpe_ratio, result.metrics.sharpe_ratio);
+    assert_eq!(observed.annualized_return, result.metrics.annualized_return);
+    assert_eq!(
+        observed.time_to_recovery_hours,
+        result.metrics.max_drawdown_duration_hours
+    );
+    assert_eq!(monte_carlo.summary.trade_count, 8);
+}
+
+#[test]
+fn resampled_paths_are_seeded_and_summarized() {
+    let result = result();
+    let first = run_monte_carlo(&result, &config(7)).unwrap();
+    let second = run_monte_carlo(&result, &config(7)).unwrap();
+    assert_eq!(first, second);
+    assert_ne!(
+        first.samples,
+        run_monte_carlo(&result, &config(8)).unwrap().samples
+    );
+
+    assert_eq!(first.samples.len(), 400);
+    let methods: Vec<ResampleMethod> = first
+        .summary
+        .methods
+        .iter()
+        .map(|summary| summary.method)
+        .collect();
+    assert_eq!(
+        methods,
+        vec![ResampleMethod::BlockBootstrap, ResampleMethod::Shuffle]
+    );
+
+    let final_equity = result.equity_curve.last().unwrap().equity;
+    for sample in first
+        .samples
+        .iter()
+        .filter(|sample| sample.method == ResampleMethod::Shuffle)
+    {
+        assert_eq!(sample.final_equity, final_equity);
+    }
+    let shuffle = &first.summary.methods[1];
+    assert_eq!(shuffle.loss_probability, Decimal::ZERO);
+    assert!(shuffle.max_drawdown.min <= first.summary.observed.max_drawdown);
+    assert!(shuffle.max_drawdown.max >= first.summary.observed.max_drawdown);
+
+    for summary in &first.summary.methods {
+        let drawdown = &summary.max_drawdown;
+        assert!(drawdown.min <= drawdown.p5);
+        assert!(drawdown.p5 <= drawdown.p25);
+        assert!(drawdown.p25 <= drawdown.p50);
+        assert!(drawdown.p50 <= drawdown.p75);
+        assert!(drawdown.p75 <= drawdown.p95);
+        assert!(drawdown.p95 <= drawdown.max);
+    }
+}
+
+#[test]
+fn monte_carlo_rejects_empty_runs() {
+    let mut result = result();
+    assert!(
+        run_monte_carlo(
+            &result,
+            &MonteCarloConfig {
+                iterations: 0,
+                block_size: 3,
+                seed: 0,
+            },
+        )
+        .is_err()
+    );
+    result.trades.clear();
+    assert!(run_monte_carlo(&result, &config(0)).is_err());
+}
+
+#[test]
+fn monte_carlo_csv_has_one_row_per_path() {
+    let monte_carlo = run_monte_carlo(&result(), &config(3)).unwrap();
+    let dir = std::env::temp_dir().join(format!("monte_carlo_{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("monte_carlo.csv");
+    export_monte_carlo_csv(&path, &monte_carlo.samples).unwrap();
+
+    let contents = std::fs::read_to_string(&path).unwrap();
+    let mut lines = contents.lines();
+    assert_eq!(
+        lines.next().unwrap(),
+        "method,iteration,final_equity,max_drawdown,annualized_return,sharpe_ratio,time_to_recovery_hours"
+    );
+    assert_eq!(lines.clone().count(), 400);
+    assert!(lines.next().unwrap().starts_with("BLOCK_BOOTSTRAP,0,"));
+    std::fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,92 @@
+use chrono::{TimeZone, Utc};
+use rust_decimal::{Decimal, MathematicalOps};
+use rust_decimal_macros::dec;
+use uuid::Uuid;
+
+use eth_btc_strategy::backtest::report::{export_html_report, render_html_report};
+use eth_btc_strategy::backtest::{BacktestBar, BacktestEngine};
+use eth_btc_strategy::config::{Config, SigmaFloorMode};
+
+fn bar(timestamp: i64, r: Decimal) -> BacktestBar {
+    BacktestBar {
+        timestamp: Utc.timestamp_opt(timestamp, 0).unwrap(),
+        base_price: dec!(100) * r.exp(),
+        quote_price: dec!(100),
+        funding_base: None,
+        funding_quote: None,
+        base_open: None,
+        base_high: None,
+        base_low: None,
+        quote_open: None,
+        quote_high: None,
+        quote_low: None,
+    }
+}
+
+fn bars() -> Vec<BacktestBar> {
+    let mut bars: Vec<BacktestBar> = (0..4).map(|i| bar(i * 900, Decimal::ZERO)).collect();
+    bars.extend((4..9).map(|i| bar(i * 900, dec!(-0.04))));
+    bars.extend((9..12).map(|i| bar(i * 900, Decimal::ZERO)));
+    bars
+}
+
+#[test]
+fn html_report_is_self_contained() {
+    let mut config = Config::default();
+    config.strategy.n_z = 4;
+    config.strategy.entry_z = dec!(1.5);
+    config.strategy.tp_z = dec!(0.6);
+    config.position.n_vol = 2;
+    config.sigma_floor.mode = SigmaFloorMode::Const;
+    let result = BacktestEngine::new(config).run(&bars()).unwrap();
+    assert!(!result.trades.is_empty());
+
+    let html = render_html_report(&result);
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.trim_end().ends_with("</html>"));
+    for section in [
+        "<h2>Equity</h2>",
+        "<h2>Drawdown (%)</h2>",
+        "<h2>Monthly PnL</h2>",
+        "<h2>Exit reasons</h2>",
+        "<h2>Trades</h2>",
+    ] {
+        assert!(html.contains(section), "missing {section}");
+    }
+    assert_eq!(html.matches("<svg").count(), 4);
+    assert!(html.contains("<title>entry 1970-01-01"));
+    assert!(html.contains("<title>exit 1970-01-01"));
+    assert!(html.contains("<td class=\"l\">TakeProfit</td>"));
+    assert!(!html.contains("http"));
+    assert!(!html.contains("<script"));
+
+    let trade_rows = html
+        .split("<h2>Trades</h2>")
+        .nth(1)
+        .unwrap()
+        .matches("<tr><td>")
+        .count();
+    assert_eq!(trade_rows, result.trades.len());
+
+    let path = std::env::temp_dir().join(format!("eth_btc_report_{}.html", Uuid::new_v4()));
+    export_html_report(&path, &result).unwrap();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), html);
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn html_report_handles_runs_without_trades() {
+    let result = BacktestEngine::new(Config::default())
+        .run(&bars()[..3])
+        .unwrap();
+    let html = render_html_report(&result);
+    assert!(html.contains("No closed trades."));
+    assert_eq!(
+        html.split("<h2>Trades</h2>")
+            .nth(1)
+            .unwrap()
+            .matches("<tr><td>")
+            .count(),
+        0
+    );
+}
@@ -6,14 +6,20 @@ use eth_btc_strategy::backtest::{BacktestBar, verify_reproducibility};
 use eth_btc_strategy::config::Config;
 
 fn bar(timestamp: i64, r: rust_decimal::Decimal) -> BacktestBar {
-    let base = dec!(100);
-    let eth = base * r.exp();
+    let quote = dec!(100);
+    let base = quote * r.exp();
     BacktestBar {
         timestamp: Utc.timestamp_opt(timestamp, 0).unwrap(),
-        eth_price: eth,
-        btc_price: base,
-        funding_eth: None,
-        funding_btc: None,
+        base_price: base,
+        quote_price: quote,
+        funding_base: None,
+        funding_quote: None,
+        base_open: None,
+        base_high: None,
+        base_low: None,
+        quote_open: None,
+        quote_high: None,
+        quote_low: None,
     }
 }
 
@@ -0,0 +1,122 @@
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal_macros::dec;
+
+use eth_btc_strategy::backtest::scenario::{
+    BarGap, FundingSpike, PriceShock, ScenarioConfig, SpreadRegime, generate_bars,
+};
+
+fn ratio(bar: &eth_btc_strategy::backtest::BacktestBar) -> f64 {
+    (bar.base_price / bar.quote_price).to_f64().unwrap()
+}
+
+#[test]
+fn same_seed_gives_same_bars() {
+    let scenario = ScenarioConfig {
+        bars: 200,
+        seed: 7,
+        ..ScenarioConfig::default()
+    };
+    let first = generate_bars(&scenario).unwrap();
+    let second = generate_bars(&scenario).unwrap();
+    let other = generate_bars(&ScenarioConfig {
+        seed: 8,
+        ..scenario.clone()
+    })
+    .unwrap();
+
+    assert_eq!(first, second);
+    assert_ne!(first, other);
+    assert_eq!(first[0].base_price, dec!(3000));
+    assert_eq!(first[0].quote_price, dec!(60000));
+    assert_eq!((first[1].timestamp - first[0].timestamp).num_minutes(), 15);
+}
+
+#[test]
+fn shock_moves_only_the_shocked_leg() {
+    let scenario = ScenarioConfig {
+        bars: 20,
+        quote_vol: 0.0,
+        regimes: vec![SpreadRegime {
+            bars: 20,
+            half_life_bars: None,
+            vol: 0.0,
+            drift: 0.0,
+        }],
+        shocks: vec![PriceShock {
+            start_bar: 10,
+            bars: 4,
+            base_return: -0.3,
+            quote_return: 0.0,
+        }],
+        ..ScenarioConfig::default()
+    };
+    let bars = generate_bars(&scenario).unwrap();
+
+    assert_eq!(bars[9].base_price, dec!(3000));
+    assert_eq!(bars[13].base_price, dec!(2100));
+    assert_eq!(bars[19].base_price, dec!(2100));
+    assert!(bars.iter().all(|bar| bar.quote_price == dec!(60000)));
+}
+
+#[test]
+fn random_walk_regime_drifts_while_mean_reverting_one_does_not() {
+    let regime = |half_life_bars| SpreadRegime {
+        bars: 2000,
+        half_life_bars,
+        vol: 0.0,
+        drift: 0.0005,
+    };
+    let drift = |half_life_bars| {
+        let bars = generate_bars(&ScenarioConfig {
+            regimes: vec![regime(half_life_bars)],
+            ..ScenarioConfig::default()
+        })
+        .unwrap();
+        (ratio(&bars[bars.len() - 1]) / ratio(&bars[0])).ln()
+    };
+
+    // A random walk accumulates the drift; an OU spread settles at drift / (1 - phi).
+    assert!((drift(None) - 0.9995).abs() < 1e-3);
+    assert!(drift(Some(4.0)) < 0.01);
+}
+
+#[test]
+fn funding_spikes_and_gaps_apply_to_their_bars() {
+    let scenario = ScenarioConfig {
+        bars: 20,
+        funding_spikes: vec![FundingSpike {
+            start_bar: 5,
+            bars: 3,
+            base_rate: dec!(0.001),
+            quote_rate: dec!(-0.001),
+        }],
+        gaps: vec![BarGap {
+            start_bar: 10,
+            bars: 2,
+        }],
+        ..ScenarioConfig::default()
+    };
+    let bars = generate_bars(&scenario).unwrap();
+
+    assert_eq!(bars.len(), 18);
+    assert_eq!(bars[4].funding_base, Some(dec!(0.0000125)));
+    assert_eq!(bars[5].funding_base, Some(dec!(0.001)));
+    assert_eq!(bars[7].funding_quote, Some(dec!(-0.001)));
+    assert_eq!(bars[8].funding_quote, Some(dec!(0.0000125)));
+    assert_eq!((bars[10].timestamp - bars[9].timestamp).num_minutes(), 45);
+}
+
+#[test]
+fn rejects_scenarios_without_regimes_or_prices() {
+    let scenario = ScenarioConfig {
+        regimes: Vec::new(),
+        ..ScenarioConfig::default()
+    };
+    assert!(generate_bars(&scenario).is_err());
+    let scenario = ScenarioConfig {
+        base_price: Decimal::ZERO,
+        ..ScenarioConfig::default()
+    };
+    assert!(generate_bars(&scenario).is_err());
+}
@@ -0,0 +1,302 @@
+use chrono::{TimeZone, Utc};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+use eth_btc_strategy::backtest::{BacktestBar, BacktestEngine, TradeExitReason};
+use eth_btc_strategy::config::{Config, ExecutionDelay, OrderType, SigmaFloorMode};
+use eth_btc_strategy::logging::LogEvent;
+
+fn bar(timestamp: i64, base: Decimal, quote: Decimal) -> BacktestBar {
+    BacktestBar {
+        timestamp: Utc.timestamp_opt(timestamp, 0).unwrap(),
+        base_price: base,
+        quote_price: quote,
+        funding_base: None,
+        funding_quote: None,
+        base_open: None,
+        base_high: None,
+        base_low: None,
+        quote_open: None,
+        quote_high: None,
+        quote_low: None,
+    }
+}
+
+fn config() -> Config {
+    let mut config = Config::default();
+    config.strategy.n_z = 4;
+    config.strategy.entry_z = dec!(1.5);
+    config.strategy.tp_z = dec!(0.6);
+    config.position.n_vol = 2;
+    config.sigma_floor.mode = SigmaFloorMode::Const;
+    config
+}
+
+#[tokio::test]
+async fn simulated_backtest_books_fill_based_trades() {
+    let bars = vec![
+        bar(0, dec!(100), dec!(100)),
+        bar(900, dec!(100), dec!(100)),
+        bar(1800, dec!(100), dec!(100)),
+        bar(2700, dec!(100), dec!(100)),
+        bar(3600, dec!(104.08), dec!(100)),
+        bar(4500, dec!(100), dec!(100)),
+    ];
+
+    let engine = BacktestEngine::new(config());
+    let result = engine.run_simulated(&bars).await.unwrap();
+
+    assert_eq!(result.trades.len(), 1);
+    assert_eq!(result.trades[0].exit_reason, TradeExitReason::TakeProfit);
+    assert!(result.trades[0].pnl > Decimal::ZERO);
+    assert_eq!(result.equity_curve.len(), bars.len());
+    assert_eq!(result.bar_logs.len(), bars.len());
+    let start = result.equity_curve.first().unwrap().equity;
+    let end = result.equity_curve.last().unwrap().equity;
+    assert_eq!(end - start, result.trades[0].pnl);
+    assert_eq!(result.metrics.trade_count, 1);
+}
+
+#[tokio::test]
+async fn simulated_backtest_confirms_resting_post_only_entry() {
+    let mut config = config();
+    config.execution.order_type = OrderType::PostOnly;
+    let bars = vec![
+        bar(0, dec!(100), dec!(100)),
+        bar(900, dec!(100), dec!(100)),
+        bar(1800, dec!(100), dec!(100)),
+        bar(2700, dec!(100), dec!(100)),
+        bar(3600, dec!(104.08), dec!(100)),
+        bar(4500, dec!(104.2), dec!(99.9)),
+    ];
+
+    let engine = BacktestEngine::new(config);
+    let result = engine.run_simulated(&bars).await.unwrap();
+
+    assert!(
+        result.bar_logs[4]
+            .events
+            .contains(&LogEvent::EntrySubmitted)
+    );
+    assert!(result.bar_logs[5].events.contains(&LogEvent::Entry));
+    assert!(result.bar_logs[5].position.is_some());
+}
+
+#[tokio::test]
+async fn simulated_backtest_cancels_unfilled_post_only_entry() {
+    let mut config = config();
+    config.execution.order_type = OrderType::PostOnly;
+    let bars = vec![
+        bar(0, dec!(100), dec!(100)),
+        bar(900, dec!(100), dec!(100)),
+        bar(1800, dec!(100), dec!(100)),
+        bar(2700, dec!(100), dec!(100)),
+        bar(3600, dec!(104.08), dec!(100)),
+        bar(4500, dec!(104.0), dec!(100.1)),
+        bar(5400, dec!(104.0), dec!(100.1)),
+    ];
+
+    let engine = BacktestEngine::new(config);
+    let result = engine.run_simulated(&bars).await.unwrap();
+
+    assert!(result.trades.is_empty());
+    assert!(
+        result.bar_logs[4]
+            .events
+            .contains(&LogEvent::EntrySubmitted)
+    );
+    assert!(
+        result
+            .bar_logs
+            .iter()
+            .any(|log| log.events.contains(&LogEvent::EntryCancelled))
+    );
+    assert!(result.bar_logs.last().unwrap().position.is_none());
+}
+
+#[tokio::test]
+async fn simulated_backtest_books_residual_repair_as_trade() {
+    let mut config = config();
+    config.execution.order_type = OrderType::PostOnly;
+    let bars = vec![
+        bar(0, dec!(100), dec!(100)),
+        bar(900, dec!(100), dec!(100)),
+        bar(1800, dec!(100), dec!(100)),
+        bar(2700, dec!(100), dec!(100)),
+        bar(3600, dec!(104.08), dec!(100)),
+        bar(4500, dec!(104.2), dec!(100.1)),
+        bar(5400, dec!(104.0), dec!(100.1)),
+    ];
+
+    let engine = BacktestEngine::new(config);
+    let result = engine.run_simulated(&bars).await.unwrap();
+
+    assert!(
+        result
+            .bar_logs
+            .iter()
+            .any(|log| log.events.contains(&LogEvent::ResidualRepair))
+    );
+    let repair = result
+        .trades
+        .iter()
+        .find(|trade| trade.exit_reason == TradeExitReason::ResidualRepair)
+        .unwrap();
+    assert!(repair.notional > Decimal::ZERO);
+    let start = result.equity_curve.first().unwrap().equity;
+    let end = result.equity_curve.last().unwrap().equity;
+    let booked: Decimal = result.trades.iter().map(|trade| trade.pnl).sum();
+    assert_ne!(end, start);
+    assert_eq!(end - start, booked);
+}
+
+#[tokio::test]
+async fn simulated_backtest_applies_execution_delay() {
+    let mut config = config();
+    config.backtest.include_slippage = false;
+    let bars = vec![
+        bar(0, dec!(100), dec!(100)),
+        bar(900, dec!(100), dec!(100)),
+        bar(1800, dec!(100), dec!(100)),
+        bar(2700, dec!(100), dec!(100)),
+        bar(3600, dec!(104.08), dec!(100)),
+        bar(4500, dec!(101), dec!(100)),
+        bar(5400, dec!(100), dec!(100)),
+    ];
+
+    let immediate = BacktestEngine::new(config.clone())
+        .run_simulated(&bars)
+        .await
+        .unwrap();
+    config.backtest.execution_delay = ExecutionDelay::NextBar;
+    let delayed = BacktestEngine::new(config)
+        .run_simulated(&bars)
+        .await
+        .unwrap();
+    assert_eq!(immediate.trades[0].entry_base_price, bars[4].base_price);
+    assert_eq!(delayed.trades[0].entry_base_price, bars[5].base_price);
+    assert!(delayed.trades[0].pnl < immediate.trades[0].pnl);
+}
+
+#[tokio::test]
+async fn simulated_backtest_books_scaled_in_groups_as_one_trade() {
+    let mut config = Config::default();
+    config.strategy.n_z = 20;
+    config.position.n_vol = 1;
+    config.position.c_value = Some(dec!(100));
+    config.position.max_position_groups = 2;
+    config.position.scale_in_step_z = dec!(0.5);
+    let mut prices: Vec<Decimal> = (0..20)
+        .map(|i| if i % 2 == 0 { dec!(100) } else { dec!(101) })
+        .collect();
+    prices.extend([
+        dec!(101.5),
+        dec!(102),
+        dec!(102.5),
+        dec!(101.9),
+        dec!(101.4),
+        dec!(100.9),
+    ]);
+    let bars: Vec<BacktestBar> = prices
+        .into_iter()
+        .enumerate()
+        .map(|(index, base)| bar(index as i64 * 900, base, dec!(100)))
+        .collect();
+
+    let engine = BacktestEngine::new(config);
+    let result = engine.run_simulated(&bars).await.unwrap();
+
+    assert!(result.bar_logs[21].events.contains(&LogEvent::ScaleIn));
+    // The scaled-in group takes profit first while the first group stays open.
+    assert!(result.bar_logs[24].position.is_some());
+    assert!(result.bar_logs[25].position.is_none());
+    assert_eq!(result.trades.len(), 1);
+    let trade = &result.trades[0];
+    assert_eq!(trade.exit_reason, TradeExitReason::TakeProfit);
+    assert_eq!(trade.entry_time, bars[20].timestamp);
+    assert_eq!(trade.exit_time, bars[25].timestamp);
+    assert!(trade.entry_zscore.is_some());
+    let first = result.bar_logs[20].position.as_ref().unwrap();
+    let scaled = result.bar_logs[21].position.as_ref().unwrap();
+    assert_eq!(trade.base_qty, scaled.base.qty);
+    assert!(trade.base_qty.abs() > first.base.qty.abs());
+    assert_eq!(trade.notional, scaled.base.notional + scaled.quote.notional);
+    let start = result.equity_curve.first().unwrap().equity;
+    let end = result.equity_curve.last().unwrap().equity;
+    assert!((end - start - trade.pnl).abs() < dec!(0.000000001));
+    assert_eq!(result.metrics.trade_count, 1);
+}
+
+#[tokio::test]
+async fn simulated_backtest_charges_funding_at_each_settlement() {
+    let mut config = config();
+    config.backtest.include_funding = true;
+    config.backtest.funding_interval_hours = 1;
+    let funded = |timestamp, base| BacktestBar {
+        funding_base: Some(dec!(0.001)),
+        funding_quote: Some(dec!(-0.0005)),
+        ..bar(timestamp, base, dec!(100))
+    };
+    let bars = vec![
+        funded(0, dec!(100)),
+        funded(3600, dec!(100)),
+        funded(7200, dec!(100)),
+        funded(10800, dec!(100)),
+        funded(14400, dec!(104.08)),
+        funded(18000, dec!(103)),
+        funded(21600, dec!(101.5)),
+    ];
+
+    let engine = BacktestEngine::new(config);
+    let result = engine.run_simulated(&bars).await.unwrap();
+
+    assert_eq!(result.trades.len(), 1);
+    let trade = &result.trades[0];
+    assert_eq!(trade.exit_time, bars[6].timestamp);
+    // Settlements at the two closes after entry, each on the legs marked at that close.
+    let position = result.bar_logs[4].position.as_ref().unwrap();
+    let expected: Decimal = [&bars[5], &bars[6]]
+        .iter()
+        .map(|bar| {
+            position.base.qty * bar.base_price * dec!(0.001)
+                + position.quote.qty * bar.quote_price * dec!(-0.0005)
+        })
+        .sum();
+    assert_ne!(expected, Decimal::ZERO);
+    assert_eq!(trade.funding, expected);
+    assert_eq!(
+        trade.pnl,
+        trade.gross_pnl - trade.fee - trade.slippage - trade.funding
+    );
+    assert_eq!(result.metrics.total_funding, trade.funding);
+    let start = result.equity_curve.first().unwrap().equity;
+    let end = result.equity_curve.last().unwrap().equity;
+    assert_eq!(end - start, trade.pnl);
+}
+
+#[tokio::test]
+async fn simulated_and_native_backtests_charge_the_same_flat_slippage() {
+    let mut config = config();
+    config.backtest.include_fees = false;
+    config.backtest.slippage_bps = 20;
+    let bars = vec![
+        bar(0, dec!(100), dec!(100)),
+        bar(900, dec!(100), dec!(100)),
+        bar(1800, dec!(100), dec!(100)),
+        bar(2700, dec!(100), dec!(100)),
+        bar(3600, dec!(104.08), dec!(100)),
+        bar(4500, dec!(100), dec!(100)),
+    ];
+
+    let engine = BacktestEngine::new(config);
+    let native = engine.run(&bars).unwrap();
+    let simulated = engine.run_simulated(&bars).await.unwrap();
+
+    assert_eq!(native.trades.len(), 1);
+    assert_eq!(simulated.trades.len(), 1);
+    let native = &native.trades[0];
+    let simulated = &simulated.trades[0];
+    // Both pay half the round-trip rate per fill; only lot rounding of the notional differs.
+    assert!(simulated.slippage > Decimal::ZERO);
+    assert!((simulated.slippage - native.slippage).abs() < dec!(0.01));
+}
@@ -0,0 +1,171 @@
+use chrono::{TimeZone, Utc};
+use rust_decimal::{Decimal, MathematicalOps};
+use rust_decimal_macros::dec;
+use serde_json::json;
+
+use eth_btc_strategy::backtest::slippage::{BookSlippageModel, LegBook, load_bar_logs_jsonl};
+use eth_btc_strategy::backtest::{BacktestBar, BacktestEngine};
+use eth_btc_strategy::config::{Config, SigmaFloorMode, SlippageModel, Symbol};
+
+fn book_line(timestamp: i64, with_quote: bool) -> String {
+    let mut value = json!({
+        "timestamp": Utc.timestamp_opt(timestamp, 0).unwrap(),
+        "eth_price": "100",
+        "btc_price": "100",
+        "eth_best_bid": "99.95",
+        "eth_best_ask": "100.05",
+        "eth_bid_size": "40",
+        "eth_ask_size": "40",
+        "unrealized_pnl": "0",
+        "state": "Flat",
+        "events": [],
+    });
+    if with_quote {
+        value["btc_best_bid"] = json!("99.9");
+        value["btc_best_ask"] = json!("100.1");
+        value["btc_bid_size"] = json!("10");
+        value["btc_ask_size"] = json!("10");
+    }
+    value.to_string()
+}
+
+fn load_model(lines: &[String]) -> BookSlippageModel {
+    let path = std::env::temp_dir().join(format!("book-{}.jsonl", uuid::Uuid::new_v4()));
+    std::fs::write(&path, lines.join("\n") + "\n").unwrap();
+    let logs = load_bar_logs_jsonl(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    BookSlippageModel::from_bar_logs(&logs)
+}
+
+#[test]
+fn book_slippage_charges_half_spread_plus_impact() {
+    let model = load_model(&[book_line(0, true), book_line(900, false)]);
+    assert_eq!(model.len(), 2);
+    let t0 = Utc.timestamp_opt(0, 0).unwrap();
+    let t1 = Utc.timestamp_opt(900, 0).unwrap();
+
+    // 5 bps half spread plus 10 bps * sqrt(1000 / 4002) of impact on the ask.
+    let base = model.fill_cost(t0, Symbol::Base, true, dec!(1000), dec!(10), dec!(5));
+    let impact = (dec!(1000) / (dec!(40) * dec!(100.05))).sqrt().unwrap();
+    let expected = dec!(1000) * (dec!(5) + dec!(10) * impact) / dec!(10000);
+    assert!((base - expected).abs() < dec!(0.0000001));
+
+    // No BTC book on the second bar and no data at all on a third fall back to flat bps.
+    let quote = model.fill_cost(t1, Symbol::Quote, false, dec!(1000), dec!(10), dec!(5));
+    assert_eq!(quote, dec!(0.5));
+    let missing = model.fill_cost(
+        Utc.timestamp_opt(1800, 0).unwrap(),
+        Symbol::Base,
+        true,
+        dec!(1000),
+        dec!(10),
+        dec!(5),
+    );
+    assert_eq!(missing, dec!(0.5));
+}
+
+#[test]
+fn book_slippage_accepts_stored_snapshots() {
+    let mut model = BookSlippageModel::default();
+    let timestamp = Utc.timestamp_opt(0, 0).unwrap();
+    let book = LegBook {
+        best_bid: dec!(99),
+        best_ask: dec!(101),
+        bid_size: dec!(1),
+        ask_size: dec!(1),
+    };
+    model.insert(timestamp, Symbol::Quote, book);
+    assert_eq!(model.book(timestamp, Symbol::Quote), Some(book));
+    assert_eq!(model.book(timestamp, Symbol::Base), None);
+    assert_eq!(book.half_spread_bps(), dec!(100));
+}
+
+fn bar(timestamp: i64, r: Decimal) -> BacktestBar {
+    let base = dec!(100);
+    BacktestBar {
+        timestamp: Utc.timestamp_opt(timestamp, 0).unwrap(),
+        base_price: base * r.exp(),
+        quote_price: base,
+        funding_base: None,
+        funding_quote: None,
+        base_open: None,
+        base_high: None,
+        base_low: None,
+        quote_open: None,
+        quote_high: None,
+        quote_low: None,
+    }
+}
+
+#[test]
+fn book_depth_model_replaces_flat_trade_slippage() {
+    let mut config = Config::default();
+    config.strategy.n_z = 4;
+    config.strategy.entry_z = dec!(1.5);
+    config.strategy.tp_z = dec!(0.6);
+    config.position.n_vol = 2;
+    config.sigma_floor.mode = SigmaFloorMode::Const;
+    config.backtest.include_fees = false;
+    let bars = vec![
+        bar(0, dec!(0.0)),
+        bar(900, dec!(0.0)),
+        bar(1800, dec!(0.0)),
+        bar(2700, dec!(0.0)),
+        bar(3600, dec!(0.04)),
+        bar(4500, dec!(0.0)),
+    ];
+
+    config.backtest.include_slippage = false;
+    let frictionless = BacktestEngine::new(config.clone()).run(&bars).unwrap();
+    config.backtest.include_slippage = true;
+    let flat = BacktestEngine::new(config.clone()).run(&bars).unwrap();
+    config.backtest.slippage_model = SlippageModel::BookDepth;
+    let with_books = BacktestEngine::new(config)
+        .with_book_slippage(load_model(&[book_line(3600, true), book_line(4500, true)]))
+        .run(&bars)
+        .unwrap();
+
+    let cost = |pnl: Decimal| frictionless.trades[0].pnl - pnl;
+    let trade = &frictionless.trades[0];
+    let exit_notional =
+        trade.base_qty.abs() * trade.base_price + trade.quote_qty.abs() * trade.quote_price;
+    // Flat: half of slippage_bps on the entry fill and half on the exit fill.
+    let flat_cost = cost(flat.trades[0].pnl);
+    let expected = (trade.notional + exit_notional) * dec!(0.00025);
+    assert!((flat_cost - expected).abs() < dec!(0.0001));
+    let book_cost = cost(with_books.trades[0].pnl);
+    assert!(book_cost > Decimal::ZERO);
+    assert_ne!(book_cost, flat_cost);
+}
+
+#[test]
+fn book_depth_without_books_matches_flat() {
+    let mut config = Config::default();
+    config.strategy.n_z = 4;
+    config.strategy.entry_z = dec!(1.5);
+    config.strategy.tp_z = dec!(0.6);
+    config.position.n_vol = 2;
+    config.sigma_floor.mode = SigmaFloorMode::Const;
+    config.backtest.include_fees = false;
+    config.backtest.include_slippage = true;
+    let bars = vec![
+        bar(0, dec!(0.0)),
+        bar(900, dec!(0.0)),
+        bar(1800, dec!(0.0)),
+        bar(2700, dec!(0.0)),
+        bar(3600, dec!(0.04)),
+        bar(4500, dec!(0.0)),
+    ];
+
+    let flat = BacktestEngine::new(config.clone()).run(&bars).unwrap();
+    config.backtest.slippage_model = SlippageModel::BookDepth;
+    let book = BacktestEngine::new(config)
+        .with_book_slippage(BookSlippageModel::default())
+        .run(&bars)
+        .unwrap();
+
+    assert_eq!(flat.trades.len(), 1);
+    assert_eq!(book.trades.len(), 1);
+    assert_eq!(book.trades[0].slippage, flat.trades[0].slippage);
+    assert_eq!(book.trades[0].pnl, flat.trades[0].pnl);
+}
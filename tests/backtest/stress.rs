@@ -0,0 +1,82 @@
+use eth_btc_strategy::backtest::TradeExitReason;
+use eth_btc_strategy::backtest::stress::{
+    StressEventKind, default_stress_scenarios, format_stress_text, run_stress,
+};
+use eth_btc_strategy::config::{BarInterval, Config};
+use rust_decimal_macros::dec;
+
+#[test]
+fn crash_stops_out_and_cools_down_for_the_configured_hours() {
+    let config = Config::default();
+    let scenarios: Vec<_> = default_stress_scenarios(0, BarInterval::M15)
+        .into_iter()
+        .filter(|scenario| scenario.name == "ETH_CRASH_30")
+        .collect();
+    let reports = run_stress(&config, &scenarios).unwrap();
+    let report = &reports[0];
+
+    assert_eq!(report.stop_losses, 1);
+    assert_eq!(report.cooldown_periods, 1);
+    assert_eq!(report.cooldown_hours, dec!(24));
+    let stop = report
+        .events
+        .iter()
+        .position(|event| event.kind == StressEventKind::Exit(TradeExitReason::StopLoss))
+        .unwrap();
+    let (exit, start, end) = (
+        &report.events[stop],
+        &report.events[stop + 1],
+        &report.events[stop + 2],
+    );
+    assert!(exit.timestamp >= report.event_time);
+    assert_eq!(start.kind, StressEventKind::CooldownStart);
+    assert_eq!(start.timestamp, exit.timestamp);
+    assert_eq!(end.kind, StressEventKind::CooldownEnd);
+    assert_eq!((end.timestamp - start.timestamp).num_hours(), 24);
+}
+
+#[test]
+fn default_scenarios_run_and_format() {
+    let reports = run_stress(
+        &Config::default(),
+        &default_stress_scenarios(3, BarInterval::M15),
+    )
+    .unwrap();
+    let names: Vec<_> = reports
+        .iter()
+        .map(|report| report.scenario.as_str())
+        .collect();
+    assert_eq!(names, ["ETH_CRASH_30", "DECOUPLING", "FUNDING_FLIP"]);
+    for report in &reports {
+        assert_eq!(report.bars, 1800);
+        assert_eq!(
+            report.trades,
+            report.take_profits + report.stop_losses + report.time_stops
+        );
+    }
+    assert!(reports[0].max_abs_zscore.unwrap() > dec!(5));
+
+    let text = format_stress_text(&reports);
+    assert!(text.contains("ETH_CRASH_30 (2025-01-11T10:00:00+00:00)"));
+    assert!(text.contains("FUNDING_FLIP"));
+}
+
+#[test]
+fn scenarios_follow_the_bar_interval() {
+    let mut config = Config::default();
+    config.data.bar_interval = BarInterval::H1;
+    config.runtime.interval_secs = 3600;
+    let scenarios: Vec<_> = default_stress_scenarios(0, BarInterval::H1)
+        .into_iter()
+        .filter(|scenario| scenario.name == "ETH_CRASH_30")
+        .collect();
+    let report = &run_stress(&config, &scenarios).unwrap()[0];
+
+    assert_eq!(report.bars, 1200);
+    assert_eq!(
+        (report.event_time - scenarios[0].config.start).num_hours(),
+        1000
+    );
+    assert_eq!(report.stop_losses, 1);
+    assert_eq!(report.cooldown_hours, dec!(24));
+}
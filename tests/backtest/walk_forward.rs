@@ -0,0 +1,171 @@
+use chrono::{TimeZone, Utc};
+use rust_decimal::MathematicalOps;
+use rust_decimal_macros::dec;
+
+use eth_btc_strategy::backtest::grid::{ParameterGrid, SearchObjective};
+use eth_btc_strategy::backtest::walk_forward::{WalkForwardConfig, run_walk_forward};
+use eth_btc_strategy::backtest::{BacktestBar, BacktestEngine};
+use eth_btc_strategy::config::{CapitalMode, Config, SigmaFloorMode};
+
+fn bar(timestamp: i64, r: rust_decimal::Decimal) -> BacktestBar {
+    let quote = dec!(100);
+    let base = quote * r.exp();
+    BacktestBar {
+        timestamp: Utc.timestamp_opt(timestamp, 0).unwrap(),
+        base_price: base,
+        quote_price: quote,
+        funding_base: None,
+        funding_quote: None,
+        base_open: None,
+        base_high: None,
+        base_low: None,
+        quote_open: None,
+        quote_high: None,
+        quote_low: None,
+    }
+}
+
+fn spiking_bars(count: usize) -> Vec<BacktestBar> {
+    (0..count)
+        .map(|idx| {
+            let r = if idx % 6 == 4 { dec!(0.04) } else { dec!(0.0) };
+            bar(idx as i64 * 900, r)
+        })
+        .collect()
+}
+
+fn base_config() -> Config {
+    let mut config = Config::default();
+    config.strategy.n_z = 4;
+    config.strategy.entry_z = dec!(1.5);
+    config.strategy.tp_z = dec!(0.6);
+    config.position.n_vol = 2;
+    config.sigma_floor.mode = SigmaFloorMode::Const;
+    config
+}
+
+#[test]
+fn walk_forward_selects_parameters_per_window_and_stitches_oos_equity() {
+    let bars = spiking_bars(60);
+    let config = WalkForwardConfig {
+        train_bars: 24,
+        test_bars: 12,
+        step_bars: None,
+        objective: SearchObjective::NetPnl,
+        min_trades: 1,
+        grid: ParameterGrid {
+            entry_z: vec![dec!(1.5), dec!(1.2)],
+            sigma_floor_mode: vec![SigmaFloorMode::Const, SigmaFloorMode::Quantile],
+            ..ParameterGrid::default()
+        },
+        threads: Some(2),
+    };
+
+    let report = run_walk_forward(&base_config(), &bars, &config).unwrap();
+
+    assert_eq!(report.windows.len(), 3);
+    assert_eq!(report.windows[0].candidates, 4);
+    assert_eq!(report.windows[1].test_start, bars[36].timestamp);
+    assert_eq!(report.oos_equity_curve.len(), 36);
+    assert_eq!(report.oos_equity_curve[0].timestamp, bars[24].timestamp);
+    assert!(
+        report
+            .oos_trades
+            .iter()
+            .all(|trade| trade.entry_time >= bars[24].timestamp)
+    );
+    let oos_pnl: rust_decimal::Decimal = report.windows.iter().map(|window| window.test_pnl).sum();
+    let start = BacktestEngine::new(base_config())
+        .starting_equity()
+        .unwrap();
+    assert_eq!(
+        report.oos_equity_curve.last().unwrap().equity,
+        start + oos_pnl
+    );
+    assert_eq!(report.stability.len(), 6);
+    assert!(
+        report
+            .stability
+            .iter()
+            .all(|row| row.values.len() == report.windows.len())
+    );
+}
+
+#[test]
+fn walk_forward_test_windows_start_flat_and_size_from_stitched_equity() {
+    let bars = spiking_bars(60);
+    let mut base = base_config();
+    base.position.c_mode = CapitalMode::EquityRatio;
+    base.position.equity_value = Some(dec!(1000));
+    base.position.equity_ratio_k = Some(dec!(1));
+
+    // Bars before the trading start only warm the indicators.
+    let full = BacktestEngine::new(base.clone()).run(&bars).unwrap();
+    let warmed = BacktestEngine::new(base.clone())
+        .with_trading_start(bars[5].timestamp)
+        .run(&bars)
+        .unwrap();
+    assert_eq!(full.trades[0].entry_time, bars[4].timestamp);
+    assert_eq!(warmed.trades.len(), full.trades.len() - 1);
+    assert_eq!(warmed.trades[0].entry_time, full.trades[1].entry_time);
+    let richer = BacktestEngine::new(base.clone())
+        .with_starting_equity(dec!(2000))
+        .run(&bars)
+        .unwrap();
+    assert!(richer.trades[0].base_qty.abs() > full.trades[0].base_qty.abs());
+
+    let config = WalkForwardConfig {
+        train_bars: 24,
+        test_bars: 12,
+        step_bars: None,
+        objective: SearchObjective::NetPnl,
+        min_trades: 1,
+        grid: ParameterGrid::default(),
+        threads: Some(1),
+    };
+    let report = run_walk_forward(&base, &bars, &config).unwrap();
+
+    let first = &report.windows[0];
+    assert_ne!(first.test_pnl, dec!(0));
+    let second = &report.windows[1];
+    let expected = BacktestEngine::new(second.parameters.apply(&base))
+        .with_trading_start(second.test_start)
+        .with_starting_equity(dec!(1000) + first.test_pnl)
+        .run(&bars[12..48])
+        .unwrap()
+        .trades;
+    let actual: Vec<_> = report
+        .oos_trades
+        .iter()
+        .filter(|trade| trade.entry_time >= second.test_start)
+        .filter(|trade| trade.entry_time <= second.test_end)
+        .cloned()
+        .collect();
+    assert!(!actual.is_empty());
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn walk_forward_rejects_overlapping_or_short_windows() {
+    let bars = spiking_bars(30);
+    let mut config = WalkForwardConfig {
+        train_bars: 24,
+        test_bars: 12,
+        step_bars: None,
+        objective: SearchObjective::Sharpe,
+        min_trades: 0,
+        grid: ParameterGrid::default(),
+        threads: None,
+    };
+    assert!(run_walk_forward(&base_config(), &bars, &config).is_err());
+
+    config.train_bars = 12;
+    config.test_bars = 6;
+    config.step_bars = Some(3);
+    assert!(run_walk_forward(&base_config(), &bars, &config).is_err());
+
+    config.step_bars = Some(6);
+    let report = run_walk_forward(&base_config(), &bars, &config).unwrap();
+    assert_eq!(report.windows.len(), 3);
+    assert!(report.stability.iter().all(|row| row.changes == 0));
+}
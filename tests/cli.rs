@@ -1,7 +1,8 @@
 use clap::Parser;
 use rust_decimal_macros::dec;
 
-use eth_btc_strategy::cli::{AnalyzeOutputFormat, Cli, Command};
+use eth_btc_strategy::backtest::grid::SearchObjective;
+use eth_btc_strategy::cli::{AnalyzeOutputFormat, BacktestAction, Cli, Command};
 use eth_btc_strategy::config::Symbol;
 use eth_btc_strategy::execution::OrderSide;
 
@@ -51,6 +52,260 @@ fn cli_parses_backtest_subcommand() {
     match cli.command {
         Some(Command::Backtest(args)) => {
             assert_eq!(args.bars.unwrap().to_str().unwrap(), "bars.json");
+            assert!(!args.simulated_exchange);
+            assert_eq!(args.output_dir.unwrap().to_str().unwrap(), "out");
+        }
+        other => panic!("unexpected command {other:?}"),
+    }
+}
+
+#[test]
+fn cli_parses_backtest_simulated_exchange_flag() {
+    let cli = Cli::try_parse_from([
+        "bin",
+        "backtest",
+        "--bars",
+        "bars.json",
+        "--simulated-exchange",
+    ])
+    .unwrap();
+
+    match cli.command {
+        Some(Command::Backtest(args)) => assert!(args.simulated_exchange),
+        other => panic!("unexpected command {other:?}"),
+    }
+}
+
+#[test]
+fn cli_parses_backtest_minute_bars() {
+    let cli = Cli::try_parse_from([
+        "bin",
+        "backtest",
+        "--bars",
+        "bars.json",
+        "--minute-bars",
+        "bars_1m.json",
+    ])
+    .unwrap();
+
+    match cli.command {
+        Some(Command::Backtest(args)) => {
+            assert_eq!(args.minute_bars.unwrap().to_str().unwrap(), "bars_1m.json");
+        }
+        other => panic!("unexpected command {other:?}"),
+    }
+}
+
+#[test]
+fn cli_parses_backtest_monte_carlo() {
+    let cli = Cli::try_parse_from([
+        "bin",
+        "backtest",
+        "--bars",
+        "bars.json",
+        "--monte-carlo",
+        "500",
+        "--monte-carlo-seed",
+        "7",
+    ])
+    .unwrap();
+
+    match cli.command {
+        Some(Command::Backtest(args)) => {
+            assert_eq!(args.monte_carlo, Some(500));
+            assert_eq!(args.monte_carlo_block_size, 5);
+            assert_eq!(args.monte_carlo_seed, 7);
+        }
+        other => panic!("unexpected command {other:?}"),
+    }
+}
+
+#[test]
+fn cli_parses_backtest_report_outputs() {
+    let cli = Cli::try_parse_from([
+        "bin",
+        "backtest",
+        "--bars",
+        "bars.json",
+        "--report",
+        "out.html",
+        "--bar-log",
+        "stats.jsonl",
+    ])
+    .unwrap();
+
+    match cli.command {
+        Some(Command::Backtest(args)) => {
+            assert_eq!(args.report.unwrap().to_str().unwrap(), "out.html");
+            assert_eq!(args.bar_log.unwrap().to_str().unwrap(), "stats.jsonl");
+        }
+        other => panic!("unexpected command {other:?}"),
+    }
+}
+
+#[test]
+fn cli_parses_parity_subcommand() {
+    let cli = Cli::try_parse_from([
+        "bin",
+        "parity",
+        "--stats-log",
+        "stats.jsonl",
+        "--trade-log",
+        "trades.jsonl",
+        "--db",
+        "prices.sqlite",
+        "--z-tolerance",
+        "0.05",
+        "--format",
+        "json",
+    ])
+    .unwrap();
+
+    match cli.command {
+        Some(Command::Parity(args)) => {
+            assert_eq!(args.stats_log.to_str().unwrap(), "stats.jsonl");
+            assert_eq!(args.trade_log.unwrap().to_str().unwrap(), "trades.jsonl");
+            assert_eq!(args.db.to_str().unwrap(), "prices.sqlite");
+            assert_eq!(args.warmup_bars, 2880);
+            assert_eq!(args.z_tolerance, dec!(0.05));
+            assert_eq!(args.sizing_tolerance, dec!(0.01));
+            assert_eq!(args.format, AnalyzeOutputFormat::Json);
+        }
+        other => panic!("unexpected command {other:?}"),
+    }
+}
+
+#[test]
+fn cli_parses_backtest_verify_subcommand() {
+    let cli = Cli::try_parse_from([
+        "bin",
+        "backtest",
+        "verify",
+        "./out/run",
+        "--bars",
+        "moved.json",
+    ])
+    .unwrap();
+
+    match cli.command {
+        Some(Command::Backtest(args)) => match args.action {
+            Some(BacktestAction::Verify(verify)) => {
+                assert_eq!(verify.dir.to_str().unwrap(), "./out/run");
+                assert_eq!(verify.bars.unwrap().to_str().unwrap(), "moved.json");
+                assert!(verify.db.is_none());
+            }
+            other => panic!("unexpected action {other:?}"),
+        },
+        other => panic!("unexpected command {other:?}"),
+    }
+}
+
+#[test]
+fn cli_parses_backtest_diff_subcommand() {
+    let cli = Cli::try_parse_from([
+        "bin",
+        "backtest-diff",
+        "--bars",
+        "bars.json",
+        "--candidate-config",
+        "candidate.toml",
+        "--baseline-dir",
+        "./out/base",
+    ])
+    .unwrap();
+
+    match cli.command {
+        Some(Command::BacktestDiff(args)) => {
+            assert_eq!(args.bars.unwrap().to_str().unwrap(), "bars.json");
+            assert_eq!(
+                args.candidate_config.unwrap().to_str().unwrap(),
+                "candidate.toml"
+            );
+            assert_eq!(args.baseline_dir.unwrap().to_str().unwrap(), "./out/base");
+            assert!(args.baseline_config.is_none());
+            assert_eq!(args.format, AnalyzeOutputFormat::Text);
+        }
+        other => panic!("unexpected command {other:?}"),
+    }
+
+    assert!(
+        Cli::try_parse_from([
+            "bin",
+            "backtest-diff",
+            "--candidate-config",
+            "a.toml",
+            "--candidate-dir",
+            "./out/b",
+        ])
+        .is_err()
+    );
+}
+
+#[test]
+fn cli_parses_walk_forward_subcommand() {
+    let cli = Cli::try_parse_from([
+        "bin",
+        "walk-forward",
+        "--bars",
+        "bars.json",
+        "--train-bars",
+        "96",
+        "--test-bars",
+        "48",
+        "--objective",
+        "PROFIT_FACTOR",
+        "--entry-zs",
+        "1.5,2.0",
+        "--sigma-floor-modes",
+        "CONST,EWMA_MIX",
+        "--format",
+        "json",
+    ])
+    .unwrap();
+
+    match cli.command {
+        Some(Command::WalkForward(args)) => {
+            assert_eq!(args.bars.unwrap().to_str().unwrap(), "bars.json");
+            assert_eq!(args.train_bars, 96);
+            assert_eq!(args.test_bars, 48);
+            assert!(args.step_bars.is_none());
+            assert_eq!(args.objective, SearchObjective::ProfitFactor);
+            assert_eq!(args.grid.entry_zs.as_deref(), Some("1.5,2.0"));
+            assert_eq!(
+                args.grid.sigma_floor_modes.as_deref(),
+                Some("CONST,EWMA_MIX")
+            );
+            assert_eq!(args.format, AnalyzeOutputFormat::Json);
+        }
+        other => panic!("unexpected command {other:?}"),
+    }
+}
+
+#[test]
+fn cli_parses_grid_search_subcommand() {
+    let cli = Cli::try_parse_from([
+        "bin",
+        "grid-search",
+        "--bars",
+        "bars.json",
+        "--n-zs",
+        "96,192",
+        "--cooldown-hours",
+        "0,24",
+        "--threads",
+        "4",
+        "--output-dir",
+        "out",
+    ])
+    .unwrap();
+
+    match cli.command {
+        Some(Command::GridSearch(args)) => {
+            assert_eq!(args.objective, SearchObjective::Sharpe);
+            assert_eq!(args.grid.n_zs.as_deref(), Some("96,192"));
+            assert_eq!(args.grid.cooldown_hours.as_deref(), Some("0,24"));
+            assert_eq!(args.threads, Some(4));
+            assert_eq!(args.top, 20);
             assert_eq!(args.output_dir.unwrap().to_str().unwrap(), "out");
         }
         other => panic!("unexpected command {other:?}"),
@@ -76,6 +331,7 @@ fn cli_parses_download_subcommand() {
             assert_eq!(args.start, "2024-01-01T00:00:00Z");
             assert_eq!(args.end, "2024-01-01T01:00:00Z");
             assert_eq!(args.output.to_str().unwrap(), "bars.json");
+            assert!(!args.skip_funding);
         }
         other => panic!("unexpected command {other:?}"),
     }
@@ -128,7 +384,7 @@ fn cli_parses_order_test_subcommand() {
 
     match cli.command {
         Some(Command::OrderTest(args)) => {
-            assert_eq!(args.symbol, Symbol::EthPerp);
+            assert_eq!(args.symbol, Symbol::Base);
             assert_eq!(args.side, OrderSide::Buy);
             assert_eq!(args.qty, dec!(0.01));
             assert_eq!(args.limit_price, dec!(1000));
@@ -154,7 +410,7 @@ fn cli_parses_cancel_order_subcommand() {
 
     match cli.command {
         Some(Command::CancelOrder(args)) => {
-            assert_eq!(args.symbol, Symbol::EthPerp);
+            assert_eq!(args.symbol, Symbol::Base);
             assert_eq!(args.oid, 42);
             assert!(args.dry_run);
         }
@@ -230,3 +486,28 @@ fn cli_parses_analyze_trades_subcommand() {
         other => panic!("unexpected command {other:?}"),
     }
 }
+
+#[test]
+fn cli_parses_stress_subcommand() {
+    let cli = Cli::try_parse_from([
+        "bin",
+        "stress",
+        "--scenario",
+        "ETH_CRASH_30,DECOUPLING",
+        "--seed",
+        "7",
+        "--bars-dir",
+        "./stress",
+    ])
+    .unwrap();
+
+    match cli.command {
+        Some(Command::Stress(args)) => {
+            assert_eq!(args.scenario, vec!["ETH_CRASH_30", "DECOUPLING"]);
+            assert_eq!(args.seed, 7);
+            assert_eq!(args.bars_dir.unwrap().to_str().unwrap(), "./stress");
+            assert_eq!(args.format, AnalyzeOutputFormat::Text);
+        }
+        other => panic!("unexpected command {other:?}"),
+    }
+}
@@ -3,7 +3,10 @@ use std::fs;
 use std::path::PathBuf;
 use std::sync::Mutex;
 
-use eth_btc_strategy::config::{LogFormat, PriceField, load_config};
+use eth_btc_strategy::config::{
+    BarInterval, ConfigOverrides, LogFormat, PriceField, RuntimeOverrides, SpreadModel, Symbol,
+    load_config, load_config_with_cli,
+};
 use once_cell::sync::Lazy;
 use rust_decimal_macros::dec;
 use uuid::Uuid;
@@ -94,6 +97,66 @@ vault_address = "0xtoml"
     fs::remove_file(&path).unwrap();
 }
 
+#[test]
+fn runtime_interval_follows_the_bar_interval_unless_set() {
+    let path = temp_toml_path();
+    fs::write(&path, "[data]\nbar_interval = \"1h\"\n").unwrap();
+    let config = load_config(Some(&path)).unwrap();
+    assert_eq!(config.data.bar_interval, BarInterval::H1);
+    assert_eq!(config.runtime.interval_secs, 3600);
+
+    let toml = r#"
+[data]
+bar_interval = "4h"
+
+[runtime]
+interval_secs = 600
+"#;
+    fs::write(&path, toml).unwrap();
+    let config = load_config(Some(&path)).unwrap();
+    assert_eq!(config.data.bar_interval, BarInterval::H4);
+    assert_eq!(config.runtime.interval_secs, 600);
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn kalman_warmup_is_one_day_of_bars_unless_set() {
+    assert_eq!(load_config(None).unwrap().spread.kalman_warmup_bars, 96);
+
+    let path = temp_toml_path();
+    fs::write(&path, "[data]\nbar_interval = \"1h\"\n").unwrap();
+    let config = load_config(Some(&path)).unwrap();
+    assert_eq!(config.spread.kalman_warmup_bars, 24);
+
+    let toml = r#"
+[data]
+bar_interval = "4h"
+
+[spread]
+kalman_warmup_bars = 30
+"#;
+    fs::write(&path, toml).unwrap();
+    let config = load_config(Some(&path)).unwrap();
+    assert_eq!(config.spread.kalman_warmup_bars, 30);
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn cli_interval_override_is_validated_against_the_bar_interval() {
+    let overrides = |interval_secs| ConfigOverrides {
+        runtime: RuntimeOverrides {
+            interval_secs: Some(interval_secs),
+            ..RuntimeOverrides::default()
+        },
+        ..ConfigOverrides::default()
+    };
+    let config = load_config_with_cli(None, Some(overrides(300))).unwrap();
+    assert_eq!(config.runtime.interval_secs, 300);
+    assert!(load_config_with_cli(None, Some(overrides(3600))).is_err());
+}
+
 #[test]
 fn env_overrides_are_ignored() {
     let _guard = ENV_LOCK.lock().unwrap();
@@ -183,6 +246,124 @@ trade_format = "TEXT"
     fs::remove_file(&path).unwrap();
 }
 
+#[test]
+fn load_reads_pair_and_leg_constraints_from_toml() {
+    let _guard = ENV_LOCK.lock().unwrap();
+
+    let path = temp_toml_path();
+    let toml = r#"
+[pair]
+base_coin = "SOL"
+quote_coin = "ETH"
+
+[instrument_constraints.BASE]
+min_qty = 0.1
+step_size = 0.01
+qty_precision = 2
+
+[instrument_constraints.QUOTE]
+min_qty = 0.001
+"#;
+    fs::write(&path, toml).unwrap();
+
+    let config = load_config(Some(&path)).unwrap();
+
+    assert_eq!(config.pair.base_coin, "SOL");
+    assert_eq!(config.pair.quote_coin, "ETH");
+    assert_eq!(config.pair.label(), "SOL/ETH");
+    let base = &config.instrument_constraints[&Symbol::Base];
+    assert_eq!(base.min_qty, dec!(0.1));
+    assert_eq!(base.step_size, dec!(0.01));
+    assert_eq!(base.qty_precision, 2);
+    let quote = &config.instrument_constraints[&Symbol::Quote];
+    assert_eq!(quote.min_qty, dec!(0.001));
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn load_accepts_legacy_eth_btc_names() {
+    let _guard = ENV_LOCK.lock().unwrap();
+
+    let path = temp_toml_path();
+    let toml = r#"
+[persistent_extreme]
+allow_long_eth_short_btc = true
+
+[directional_sizing]
+short_eth_long_btc_multiplier = 1.25
+
+[cost_gate]
+long_eth_short_btc_extra_bps = 2.0
+
+[instrument_constraints.ETH_PERP]
+min_qty = 0.1
+
+[instrument_constraints.BTC_PERP]
+min_qty = 0.001
+"#;
+    fs::write(&path, toml).unwrap();
+
+    let config = load_config(Some(&path)).unwrap();
+
+    assert!(config.persistent_extreme.allow_long_base_short_quote);
+    assert_eq!(
+        config.directional_sizing.short_base_long_quote_multiplier,
+        dec!(1.25)
+    );
+    assert_eq!(config.cost_gate.long_base_short_quote_extra_bps, dec!(2.0));
+    assert_eq!(
+        config.instrument_constraints[&Symbol::Base].min_qty,
+        dec!(0.1)
+    );
+    assert_eq!(
+        config.instrument_constraints[&Symbol::Quote].min_qty,
+        dec!(0.001)
+    );
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn load_reads_portfolio_from_toml() {
+    let _guard = ENV_LOCK.lock().unwrap();
+
+    let path = temp_toml_path();
+    let toml = r#"
+[portfolio]
+equity_budget = 5000
+max_gross_notional = 8000
+
+[[portfolio.strategies]]
+name = "fast"
+config_path = "fast.toml"
+allocation = 0.6
+state_path = "fast.sqlite"
+
+[[portfolio.strategies]]
+name = "slow"
+config_path = "slow.toml"
+allocation = 0.4
+"#;
+    fs::write(&path, toml).unwrap();
+
+    let config = load_config(Some(&path)).unwrap();
+
+    assert!(config.portfolio.is_enabled());
+    assert_eq!(config.portfolio.equity_budget, Some(dec!(5000)));
+    assert_eq!(config.portfolio.max_gross_notional, Some(dec!(8000)));
+    assert_eq!(config.portfolio.strategies.len(), 2);
+    assert_eq!(config.portfolio.strategies[0].name, "fast");
+    assert_eq!(
+        config.portfolio.strategies[0].state_path.as_deref(),
+        Some("fast.sqlite")
+    );
+    assert_eq!(config.portfolio.strategies[1].allocation, dec!(0.4));
+    assert_eq!(config.portfolio.strategies[1].state_path, None);
+
+    fs::remove_file(&path).unwrap();
+}
+
 #[test]
 fn load_fails_on_invalid_config() {
     let _guard = ENV_LOCK.lock().unwrap();
@@ -202,3 +383,23 @@ sl_z = 3.5
 
     fs::remove_file(&path).unwrap();
 }
+
+#[test]
+fn load_reads_spread_model_from_toml() {
+    let _guard = ENV_LOCK.lock().unwrap();
+
+    let path = temp_toml_path();
+    let toml = r#"
+[spread]
+model = "ROLLING_BETA"
+beta_lookback_bars = 96
+"#;
+    fs::write(&path, toml).unwrap();
+
+    let config = load_config(Some(&path)).unwrap();
+
+    assert_eq!(config.spread.model, SpreadModel::RollingBeta);
+    assert_eq!(config.spread.beta_lookback_bars, 96);
+
+    fs::remove_file(&path).unwrap();
+}
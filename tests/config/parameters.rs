@@ -1,6 +1,7 @@
 use eth_btc_strategy::config::{
-    CapitalMode, Config, FundingMode, LogFormat, PriceField, SigmaFloorMode, Symbol,
-    V1_BASELINE_CONFIG, get_default_config,
+    BarInterval, CapitalMode, Config, ExecutionDelay, FundingMode, LogFormat, PairConfig,
+    PortfolioStrategyConfig, PriceField, SigmaFloorMode, SpreadModel, Symbol, V1_BASELINE_CONFIG,
+    get_default_config,
 };
 use eth_btc_strategy::position::MinSizePolicy;
 use rust_decimal_macros::dec;
@@ -37,6 +38,7 @@ fn default_position_parameters() {
     assert!(config.position.c_value.is_some() || config.position.equity_ratio_k.is_some());
     assert_eq!(config.position.n_vol, 672);
     assert_eq!(config.position.max_position_groups, 1);
+    assert_eq!(config.position.scale_in_step_z, dec!(0.5));
 }
 
 #[test]
@@ -61,8 +63,8 @@ fn default_cost_gate_is_shadow_safe_and_disabled() {
     assert!(config.cost_gate.exit_fee_bps >= dec!(0));
     assert!(config.cost_gate.slippage_bps >= dec!(0));
     assert!(config.cost_gate.spread_bps >= dec!(0));
-    assert_eq!(config.cost_gate.long_eth_short_btc_extra_bps, dec!(0));
-    assert_eq!(config.cost_gate.short_eth_long_btc_extra_bps, dec!(0));
+    assert_eq!(config.cost_gate.long_base_short_quote_extra_bps, dec!(0));
+    assert_eq!(config.cost_gate.short_base_long_quote_extra_bps, dec!(0));
 }
 
 #[test]
@@ -71,8 +73,8 @@ fn default_persistent_extreme_is_disabled_and_direction_safe() {
 
     assert!(!config.persistent_extreme.enabled);
     assert_eq!(config.persistent_extreme.min_abs_z, dec!(1.5));
-    assert!(!config.persistent_extreme.allow_long_eth_short_btc);
-    assert!(!config.persistent_extreme.allow_short_eth_long_btc);
+    assert!(!config.persistent_extreme.allow_long_base_short_quote);
+    assert!(!config.persistent_extreme.allow_short_base_long_quote);
 }
 
 #[test]
@@ -80,7 +82,7 @@ fn persistent_extreme_requires_positive_threshold_when_enabled() {
     let mut config = get_default_config();
     config.persistent_extreme.enabled = true;
     config.persistent_extreme.min_abs_z = dec!(0);
-    config.persistent_extreme.allow_short_eth_long_btc = true;
+    config.persistent_extreme.allow_short_base_long_quote = true;
 
     let err = config
         .validate()
@@ -112,11 +114,11 @@ fn default_directional_sizing_is_neutral() {
     let config = get_default_config();
 
     assert_eq!(
-        config.directional_sizing.long_eth_short_btc_multiplier,
+        config.directional_sizing.long_base_short_quote_multiplier,
         dec!(1)
     );
     assert_eq!(
-        config.directional_sizing.short_eth_long_btc_multiplier,
+        config.directional_sizing.short_base_long_quote_multiplier,
         dec!(1)
     );
 }
@@ -124,7 +126,7 @@ fn default_directional_sizing_is_neutral() {
 #[test]
 fn directional_sizing_multipliers_must_be_positive() {
     let mut config = get_default_config();
-    config.directional_sizing.short_eth_long_btc_multiplier = dec!(0);
+    config.directional_sizing.short_base_long_quote_multiplier = dec!(0);
 
     let err = config
         .validate()
@@ -132,7 +134,7 @@ fn directional_sizing_multipliers_must_be_positive() {
     assert!(matches!(
         err,
         eth_btc_strategy::config::ConfigError::InvalidValue { field, .. }
-        if field == "directional_sizing.short_eth_long_btc_multiplier"
+        if field == "directional_sizing.short_base_long_quote_multiplier"
     ));
 }
 
@@ -179,7 +181,7 @@ fn cost_gate_bps_values_must_be_non_negative() {
 #[test]
 fn direction_specific_cost_buffers_must_be_non_negative() {
     let mut config = get_default_config();
-    config.cost_gate.short_eth_long_btc_extra_bps = dec!(-1);
+    config.cost_gate.short_base_long_quote_extra_bps = dec!(-1);
 
     let err = config
         .validate()
@@ -187,7 +189,7 @@ fn direction_specific_cost_buffers_must_be_non_negative() {
     assert!(matches!(
         err,
         eth_btc_strategy::config::ConfigError::InvalidValue { field, .. }
-        if field == "cost_gate.short_eth_long_btc_extra_bps"
+        if field == "cost_gate.short_base_long_quote_extra_bps"
     ));
 }
 
@@ -238,8 +240,8 @@ fn default_price_field_and_constraints() {
         config.data.price_field,
         PriceField::Mid | PriceField::Mark | PriceField::Close
     ));
-    assert!(config.instrument_constraints.contains_key(&Symbol::EthPerp));
-    assert!(config.instrument_constraints.contains_key(&Symbol::BtcPerp));
+    assert!(config.instrument_constraints.contains_key(&Symbol::Base));
+    assert!(config.instrument_constraints.contains_key(&Symbol::Quote));
     assert!(matches!(
         config.position.min_size_policy,
         MinSizePolicy::Skip | MinSizePolicy::Adjust
@@ -292,6 +294,184 @@ fn v1_baseline_config_matches_defaults() {
     assert_eq!(V1_BASELINE_CONFIG.position.n_vol, 672);
 }
 
+#[test]
+fn default_pair_is_eth_btc() {
+    let config = get_default_config();
+
+    assert_eq!(config.pair.coin(Symbol::Base), "ETH");
+    assert_eq!(config.pair.coin(Symbol::Quote), "BTC");
+    assert_eq!(config.pair.symbol_for_coin("BTC"), Some(Symbol::Quote));
+    assert_eq!(config.pair.symbol_for_coin("SOL"), None);
+    assert!(config.pair.is_default());
+}
+
+#[test]
+fn pair_coins_must_be_distinct_and_non_empty() {
+    let mut config = get_default_config();
+    config.pair = PairConfig {
+        base_coin: "ETH".to_string(),
+        quote_coin: "ETH".to_string(),
+    };
+    assert!(config.validate().is_err());
+
+    config.pair.quote_coin = String::new();
+    assert!(config.validate().is_err());
+}
+
+fn portfolio_strategy(name: &str, allocation: rust_decimal::Decimal) -> PortfolioStrategyConfig {
+    PortfolioStrategyConfig {
+        name: name.to_string(),
+        config_path: format!("{name}.toml"),
+        allocation,
+        state_path: None,
+    }
+}
+
+#[test]
+fn default_portfolio_is_disabled() {
+    let config = get_default_config();
+
+    assert!(!config.portfolio.is_enabled());
+    assert_eq!(config.portfolio.equity_budget, None);
+    assert_eq!(config.portfolio.max_gross_notional, None);
+}
+
+#[test]
+fn portfolio_allocations_must_be_positive_and_sum_to_at_most_one() {
+    let mut config = get_default_config();
+    config.portfolio.strategies = vec![
+        portfolio_strategy("a", dec!(0.6)),
+        portfolio_strategy("b", dec!(0.4)),
+    ];
+    assert!(config.validate().is_ok());
+
+    config.portfolio.strategies[1].allocation = dec!(0.5);
+    assert!(config.validate().is_err());
+
+    config.portfolio.strategies[1].allocation = dec!(0);
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn portfolio_strategy_names_must_be_unique() {
+    let mut config = get_default_config();
+    config.portfolio.strategies = vec![
+        portfolio_strategy("a", dec!(0.3)),
+        portfolio_strategy("a", dec!(0.3)),
+    ];
+
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn scale_in_levels_must_stay_below_stop_loss() {
+    let mut config = get_default_config();
+    config.position.max_position_groups = 4;
+    config.position.scale_in_step_z = dec!(0.5);
+    assert!(config.validate().is_ok());
+
+    config.position.max_position_groups = 5;
+    let err = config.validate().unwrap_err();
+    assert!(matches!(
+        err,
+        eth_btc_strategy::config::ConfigError::InvalidValue { field, .. }
+            if field == "position.scale_in_step_z"
+    ));
+
+    config.position.max_position_groups = 2;
+    config.position.scale_in_step_z = dec!(0);
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn rolling_beta_spread_requires_lookback() {
+    let mut config = get_default_config();
+    assert_eq!(config.spread.model, SpreadModel::Fixed);
+
+    config.spread.model = SpreadModel::RollingBeta;
+    config.spread.beta_lookback_bars = 2;
+    let err = config.validate().unwrap_err();
+    assert!(matches!(
+        err,
+        eth_btc_strategy::config::ConfigError::InvalidValue { field, .. }
+            if field == "spread.beta_lookback_bars"
+    ));
+
+    config.spread.beta_lookback_bars = 96;
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn kalman_spread_requires_valid_noise() {
+    let mut config = get_default_config();
+    config.spread.model = SpreadModel::Kalman;
+    assert!(config.validate().is_ok());
+
+    config.spread.kalman_delta = dec!(1);
+    assert!(config.validate().is_err());
+    config.spread.kalman_delta = dec!(0.0001);
+    config.spread.kalman_observation_var = dec!(0);
+    let err = config.validate().unwrap_err();
+    assert!(matches!(
+        err,
+        eth_btc_strategy::config::ConfigError::InvalidValue { field, .. }
+            if field == "spread.kalman_observation_var"
+    ));
+}
+
+#[test]
+fn fixed_offset_execution_delay_requires_offset() {
+    let mut config = get_default_config();
+    config.backtest.execution_delay = "fixed_offset".parse::<ExecutionDelay>().unwrap();
+    let err = config.validate().unwrap_err();
+    assert!(matches!(
+        err,
+        eth_btc_strategy::config::ConfigError::InvalidValue { field, .. }
+            if field == "backtest.execution_delay_secs"
+    ));
+    config.backtest.execution_delay_secs = 5;
+    assert!(config.validate().is_ok());
+    assert!("LATER".parse::<ExecutionDelay>().is_err());
+}
+
+#[test]
+fn funding_interval_must_be_positive() {
+    let mut config = get_default_config();
+    assert_eq!(config.backtest.funding_interval_hours, 1);
+    config.backtest.funding_interval_hours = 0;
+    let err = config.validate().unwrap_err();
+    assert!(matches!(
+        err,
+        eth_btc_strategy::config::ConfigError::InvalidValue { field, .. }
+            if field == "backtest.funding_interval_hours"
+    ));
+}
+
+#[test]
+fn bar_interval_parses_and_converts_day_windows() {
+    assert_eq!(get_default_config().data.bar_interval, BarInterval::M15);
+    assert_eq!("1h".parse::<BarInterval>().unwrap(), BarInterval::H1);
+    assert_eq!(" 4H ".parse::<BarInterval>().unwrap(), BarInterval::H4);
+    assert!("2h".parse::<BarInterval>().is_err());
+    assert_eq!(BarInterval::M5.bars_per_day(), 288);
+    assert_eq!(BarInterval::M15.bars_per_day(), 96);
+    assert_eq!(BarInterval::H4.bars_per_day(), 6);
+}
+
+#[test]
+fn runtime_interval_must_not_exceed_bar_interval() {
+    let mut config = get_default_config();
+    config.runtime.interval_secs = 1800;
+    let err = config.validate().unwrap_err();
+    assert!(matches!(
+        err,
+        eth_btc_strategy::config::ConfigError::InvalidValue { field, .. }
+            if field == "runtime.interval_secs"
+    ));
+    config.data.bar_interval = BarInterval::H1;
+    assert!(config.validate().is_ok());
+}
+
 #[test]
 fn symbol_all_returns_static_slice() {
     let symbols: &'static [Symbol] = Symbol::all();
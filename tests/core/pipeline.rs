@@ -1,7 +1,7 @@
 use chrono::{TimeZone, Utc};
 use rust_decimal_macros::dec;
 
-use eth_btc_strategy::config::Config;
+use eth_btc_strategy::config::{BarInterval, Config, SigmaFloorMode, SpreadModel};
 use eth_btc_strategy::core::pipeline::SignalPipeline;
 use eth_btc_strategy::state::StrategyStatus;
 
@@ -23,6 +23,7 @@ fn pipeline_emits_entry_on_crossing() {
                 dec!(100),
                 StrategyStatus::Flat,
                 None,
+                &[],
             )
             .unwrap();
         assert!(output.entry_signal.is_none());
@@ -35,7 +36,116 @@ fn pipeline_emits_entry_on_crossing() {
             dec!(100),
             StrategyStatus::Flat,
             None,
+            &[],
         )
         .unwrap();
     assert!(output.entry_signal.is_some());
 }
+
+#[test]
+fn pipeline_reports_beta_for_rolling_beta_spread() {
+    let mut config = Config::default();
+    config.strategy.n_z = 3;
+    config.position.n_vol = 1;
+    config.spread.model = SpreadModel::RollingBeta;
+    config.spread.beta_lookback_bars = 3;
+
+    let mut pipeline = SignalPipeline::new(&config).expect("pipeline");
+    let quote_prices = [
+        dec!(100),
+        dec!(104),
+        dec!(98),
+        dec!(101),
+        dec!(103),
+        dec!(99),
+    ];
+    let mut outputs = Vec::new();
+    for (index, quote) in quote_prices.into_iter().enumerate() {
+        outputs.push(
+            pipeline
+                .update(
+                    Utc.timestamp_opt(index as i64 * 900, 0).unwrap(),
+                    quote * dec!(0.05) + dec!(0.01) * rust_decimal::Decimal::from(index as u64),
+                    quote,
+                    StrategyStatus::Flat,
+                    None,
+                    &[],
+                )
+                .unwrap(),
+        );
+    }
+
+    assert!(outputs[..3].iter().all(|output| output.beta.is_none()));
+    assert!(
+        outputs[..3]
+            .iter()
+            .all(|output| output.z_snapshot.zscore.is_none())
+    );
+    assert!(outputs[3..].iter().all(|output| output.beta.is_some()));
+    assert!(outputs[5].z_snapshot.zscore.is_some());
+}
+
+#[test]
+fn pipeline_uses_kalman_innovation_zscore() {
+    let mut config = Config::default();
+    config.position.n_vol = 1;
+    config.spread.model = SpreadModel::Kalman;
+    config.spread.kalman_warmup_bars = 2;
+
+    let mut pipeline = SignalPipeline::new(&config).expect("pipeline");
+    let mut outputs = Vec::new();
+    for (index, quote) in [dec!(100), dec!(104), dec!(98), dec!(101)]
+        .into_iter()
+        .enumerate()
+    {
+        outputs.push(
+            pipeline
+                .update(
+                    Utc.timestamp_opt(index as i64 * 900, 0).unwrap(),
+                    quote * dec!(0.05),
+                    quote,
+                    StrategyStatus::Flat,
+                    None,
+                    &[],
+                )
+                .unwrap(),
+        );
+    }
+
+    assert!(outputs.iter().all(|output| output.beta.is_some()));
+    assert!(outputs[1].z_snapshot.zscore.is_none());
+    let last = &outputs[3].z_snapshot;
+    assert_eq!(last.mean, Some(rust_decimal::Decimal::ZERO));
+    assert_eq!(last.zscore, Some(last.r / last.sigma_eff.unwrap()));
+    assert_eq!(pipeline.kalman_state().unwrap().updates, 4);
+}
+
+#[test]
+fn sigma_floor_quantile_window_spans_days_of_the_bar_interval() {
+    let mut config = Config::default();
+    config.strategy.n_z = 2;
+    config.position.n_vol = 1;
+    config.sigma_floor.mode = SigmaFloorMode::Quantile;
+    config.sigma_floor.sigma_floor_quantile_window = 1;
+    config.data.bar_interval = BarInterval::H4;
+
+    let mut pipeline = SignalPipeline::new(&config).expect("pipeline");
+    // The first bar only fills the z-score window; one day of 4h bars follows.
+    let floors: Vec<_> = (0..7)
+        .map(|index| {
+            let base = if index % 2 == 0 { dec!(100) } else { dec!(101) };
+            pipeline
+                .update_indicators(
+                    Utc.timestamp_opt(index * 14_400, 0).unwrap(),
+                    base,
+                    dec!(100),
+                )
+                .unwrap()
+                .z_snapshot
+                .sigma_floor
+        })
+        .collect();
+
+    assert!(floors[..6].iter().all(Option::is_none));
+    assert!(floors[6].is_some());
+}
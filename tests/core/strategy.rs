@@ -3,8 +3,8 @@ use rust_decimal_macros::dec;
 
 use eth_btc_strategy::account::{AccountFillSource, ExchangeFill, ExchangePosition, PairExposure};
 use eth_btc_strategy::config::{CapitalMode, Config, FundingMode, OrderType, Symbol};
-use eth_btc_strategy::core::TradeDirection;
 use eth_btc_strategy::core::strategy::StrategyEngine;
+use eth_btc_strategy::core::{ExitReason, TradeDirection};
 use eth_btc_strategy::execution::{
     ExecutionEngine, ExecutionError, OrderExecutor, OrderFill, OrderRequest, OrderSide,
     OrderSubmitResult, PaperOrderExecutor, RetryConfig,
@@ -80,11 +80,7 @@ impl OrderExecutor for RestingEntryExecutor {
             .lock()
             .expect("submit lock")
             .push(order.clone());
-        let oid = if order.symbol == Symbol::EthPerp {
-            11
-        } else {
-            22
-        };
+        let oid = if order.symbol == Symbol::Base { 11 } else { 22 };
         Ok(OrderSubmitResult::Resting { oid })
     }
 }
@@ -170,8 +166,8 @@ impl OrderExecutor for DetailedFillExecutor {
         order: &OrderRequest,
     ) -> Result<OrderSubmitResult, ExecutionError> {
         let (avg_price, oid) = match order.symbol {
-            Symbol::EthPerp => (dec!(101), 1001),
-            Symbol::BtcPerp => (dec!(99), 1002),
+            Symbol::Base => (dec!(101), 1001),
+            Symbol::Quote => (dec!(99), 1002),
         };
         Ok(OrderSubmitResult::Filled(OrderFill {
             qty: order.qty,
@@ -185,8 +181,8 @@ impl OrderExecutor for DetailedFillExecutor {
         order: &OrderRequest,
     ) -> Result<OrderSubmitResult, ExecutionError> {
         let (avg_price, oid) = match order.symbol {
-            Symbol::EthPerp => (dec!(110), 2001),
-            Symbol::BtcPerp => (dec!(90), 2002),
+            Symbol::Base => (dec!(110), 2001),
+            Symbol::Quote => (dec!(90), 2002),
         };
         Ok(OrderSubmitResult::Filled(OrderFill {
             qty: order.qty,
@@ -215,14 +211,14 @@ fn strategy_engine_applies_state() {
     let mut engine = StrategyEngine::new(config, execution).unwrap();
 
     let position = PositionSnapshot {
-        direction: TradeDirection::LongEthShortBtc,
+        direction: TradeDirection::LongBaseShortQuote,
         entry_time: Utc.timestamp_opt(0, 0).unwrap(),
-        eth: PositionLeg {
+        base: PositionLeg {
             qty: dec!(1),
             avg_price: dec!(100),
             notional: dec!(100),
         },
-        btc: PositionLeg {
+        quote: PositionLeg {
             qty: dec!(-1),
             avg_price: dec!(200),
             notional: dec!(200),
@@ -235,6 +231,7 @@ fn strategy_engine_applies_state() {
         pending_entry: None,
         cooldown_until: None,
         cumulative_realized_pnl: dec!(0),
+        groups: Vec::new(),
     };
 
     engine.apply_state(state).unwrap();
@@ -268,11 +265,11 @@ async fn strategy_engine_uses_bar_funding_interval() {
     for offset in [0, 900, 1800] {
         let bar = eth_btc_strategy::core::strategy::StrategyBar {
             timestamp: Utc.timestamp_opt(offset, 0).unwrap(),
-            eth_price: dec!(100),
-            btc_price: dec!(100),
+            base_price: dec!(100),
+            quote_price: dec!(100),
             equity: None,
-            funding_eth: None,
-            funding_btc: None,
+            funding_base: None,
+            funding_quote: None,
             funding_interval_hours: Some(1),
         };
         engine.process_bar(bar).await.unwrap();
@@ -280,27 +277,27 @@ async fn strategy_engine_uses_bar_funding_interval() {
 
     let bar = eth_btc_strategy::core::strategy::StrategyBar {
         timestamp: Utc.timestamp_opt(2700, 0).unwrap(),
-        eth_price: dec!(271.8281828),
-        btc_price: dec!(100),
+        base_price: dec!(271.8281828),
+        quote_price: dec!(100),
         equity: None,
-        funding_eth: Some(dec!(0.001)),
-        funding_btc: Some(dec!(0.01)),
+        funding_base: Some(dec!(0.001)),
+        funding_quote: Some(dec!(0.01)),
         funding_interval_hours: Some(1),
     };
 
     let outcome = engine.process_bar(bar).await.unwrap();
     let expected = estimate_funding_cost(
-        TradeDirection::ShortEthLongBtc,
+        TradeDirection::ShortBaseLongQuote,
         dec!(50),
         dec!(50),
         &eth_btc_strategy::funding::FundingRate {
-            symbol: eth_btc_strategy::config::Symbol::EthPerp,
+            symbol: eth_btc_strategy::config::Symbol::Base,
             rate: dec!(0.001),
             timestamp: outcome.bar_log.timestamp,
             interval_hours: 1,
         },
         &eth_btc_strategy::funding::FundingRate {
-            symbol: eth_btc_strategy::config::Symbol::BtcPerp,
+            symbol: eth_btc_strategy::config::Symbol::Quote,
             rate: dec!(0.01),
             timestamp: outcome.bar_log.timestamp,
             interval_hours: 1,
@@ -331,31 +328,31 @@ async fn strategy_engine_uses_bar_equity_for_equity_ratio() {
     for offset in [0, 900, 1800] {
         let bar = eth_btc_strategy::core::strategy::StrategyBar {
             timestamp: Utc.timestamp_opt(offset, 0).unwrap(),
-            eth_price: dec!(100),
-            btc_price: dec!(100),
+            base_price: dec!(100),
+            quote_price: dec!(100),
             equity: None,
-            funding_eth: None,
-            funding_btc: None,
+            funding_base: None,
+            funding_quote: None,
             funding_interval_hours: None,
         };
         engine.process_bar(bar).await.unwrap();
     }
 
-    let eth_price = dec!(271.8281828);
-    let btc_price = dec!(100);
+    let base_price = dec!(271.8281828);
+    let quote_price = dec!(100);
     let bar = eth_btc_strategy::core::strategy::StrategyBar {
         timestamp: Utc.timestamp_opt(2700, 0).unwrap(),
-        eth_price,
-        btc_price,
+        base_price,
+        quote_price,
         equity: Some(dec!(1000)),
-        funding_eth: None,
-        funding_btc: None,
+        funding_base: None,
+        funding_quote: None,
         funding_interval_hours: None,
     };
 
     let outcome = engine.process_bar(bar).await.unwrap();
-    assert_eq!(outcome.bar_log.notional_eth, Some(dec!(50)));
-    assert_eq!(outcome.bar_log.notional_btc, Some(dec!(50)));
+    assert_eq!(outcome.bar_log.notional_base, Some(dec!(50)));
+    assert_eq!(outcome.bar_log.notional_quote, Some(dec!(50)));
 }
 
 #[tokio::test]
@@ -369,14 +366,14 @@ async fn strategy_engine_repairs_residual_leg_and_flats_state() {
     let mut engine = StrategyEngine::new(config, execution).unwrap();
 
     let position = PositionSnapshot {
-        direction: TradeDirection::LongEthShortBtc,
+        direction: TradeDirection::LongBaseShortQuote,
         entry_time: Utc.timestamp_opt(0, 0).unwrap(),
-        eth: PositionLeg {
+        base: PositionLeg {
             qty: dec!(0),
             avg_price: dec!(2000),
             notional: dec!(0),
         },
-        btc: PositionLeg {
+        quote: PositionLeg {
             qty: dec!(1),
             avg_price: dec!(30000),
             notional: dec!(30000),
@@ -389,16 +386,17 @@ async fn strategy_engine_repairs_residual_leg_and_flats_state() {
         pending_entry: None,
         cooldown_until: None,
         cumulative_realized_pnl: dec!(0),
+        groups: Vec::new(),
     };
     engine.apply_state(state).unwrap();
 
     let bar = eth_btc_strategy::core::strategy::StrategyBar {
         timestamp: Utc.timestamp_opt(900, 0).unwrap(),
-        eth_price: dec!(2000),
-        btc_price: dec!(30000),
+        base_price: dec!(2000),
+        quote_price: dec!(30000),
         equity: None,
-        funding_eth: None,
-        funding_btc: None,
+        funding_base: None,
+        funding_quote: None,
         funding_interval_hours: None,
     };
 
@@ -413,7 +411,7 @@ async fn strategy_engine_repairs_residual_leg_and_flats_state() {
 
     let submitted = executor.submitted.lock().unwrap();
     assert_eq!(submitted.len(), 1);
-    assert_eq!(submitted[0].symbol, Symbol::BtcPerp);
+    assert_eq!(submitted[0].symbol, Symbol::Quote);
 }
 
 #[tokio::test]
@@ -431,22 +429,24 @@ async fn strategy_engine_cancels_pending_orders_when_reconcile_repairs_residual(
             status: StrategyStatus::PendingEntry,
             position: None,
             pending_entry: Some(eth_btc_strategy::state::PendingEntrySnapshot {
-                direction: TradeDirection::LongEthShortBtc,
-                eth_qty: dec!(0.079),
-                btc_qty: dec!(-0.0027),
-                eth_order_id: 11,
-                btc_order_id: 22,
+                direction: TradeDirection::LongBaseShortQuote,
+                base_qty: dec!(0.079),
+                quote_qty: dec!(-0.0027),
+                base_order_id: 11,
+                quote_order_id: 22,
                 submitted_at: Utc.timestamp_opt(100, 0).unwrap(),
                 expires_at: Utc.timestamp_opt(200, 0).unwrap(),
+                entry_z: None,
             }),
             cooldown_until: None,
             cumulative_realized_pnl: dec!(0),
+            groups: Vec::new(),
         })
         .unwrap();
 
     let residual_exposure = PairExposure {
-        eth: None,
-        btc: Some(ExchangePosition {
+        base: None,
+        quote: Some(ExchangePosition {
             qty: dec!(-0.0027),
             entry_price: dec!(76546),
             notional: dec!(206.6742),
@@ -464,10 +464,7 @@ async fn strategy_engine_cancels_pending_orders_when_reconcile_repairs_residual(
         .unwrap();
 
     let cancelled = executor.cancelled.lock().expect("cancel lock").clone();
-    assert_eq!(
-        cancelled,
-        vec![(Symbol::EthPerp, 11), (Symbol::BtcPerp, 22)]
-    );
+    assert_eq!(cancelled, vec![(Symbol::Base, 11), (Symbol::Quote, 22)]);
     assert_eq!(engine.state().state().status, StrategyStatus::Flat);
     assert!(engine.state().state().pending_entry.is_none());
 }
@@ -491,11 +488,11 @@ async fn strategy_engine_treats_post_only_would_take_as_blocked_entry() {
     for offset in [0, 900, 1800] {
         let bar = eth_btc_strategy::core::strategy::StrategyBar {
             timestamp: Utc.timestamp_opt(offset, 0).unwrap(),
-            eth_price: dec!(100),
-            btc_price: dec!(100),
+            base_price: dec!(100),
+            quote_price: dec!(100),
             equity: None,
-            funding_eth: None,
-            funding_btc: None,
+            funding_base: None,
+            funding_quote: None,
             funding_interval_hours: None,
         };
         engine.process_bar(bar).await.unwrap();
@@ -504,11 +501,11 @@ async fn strategy_engine_treats_post_only_would_take_as_blocked_entry() {
     let outcome = engine
         .process_bar(eth_btc_strategy::core::strategy::StrategyBar {
             timestamp: Utc.timestamp_opt(2700, 0).unwrap(),
-            eth_price: dec!(271.8281828),
-            btc_price: dec!(100),
+            base_price: dec!(271.8281828),
+            quote_price: dec!(100),
             equity: None,
-            funding_eth: None,
-            funding_btc: None,
+            funding_base: None,
+            funding_quote: None,
             funding_interval_hours: None,
         })
         .await
@@ -547,11 +544,11 @@ async fn strategy_engine_blocks_entry_when_cost_gate_fails() {
     for offset in [0, 900, 1800] {
         let bar = eth_btc_strategy::core::strategy::StrategyBar {
             timestamp: Utc.timestamp_opt(offset, 0).unwrap(),
-            eth_price: dec!(100),
-            btc_price: dec!(100),
+            base_price: dec!(100),
+            quote_price: dec!(100),
             equity: None,
-            funding_eth: None,
-            funding_btc: None,
+            funding_base: None,
+            funding_quote: None,
             funding_interval_hours: None,
         };
         engine.process_bar(bar).await.unwrap();
@@ -560,11 +557,11 @@ async fn strategy_engine_blocks_entry_when_cost_gate_fails() {
     let outcome = engine
         .process_bar(eth_btc_strategy::core::strategy::StrategyBar {
             timestamp: Utc.timestamp_opt(2700, 0).unwrap(),
-            eth_price: dec!(271.8281828),
-            btc_price: dec!(100),
+            base_price: dec!(271.8281828),
+            quote_price: dec!(100),
             equity: None,
-            funding_eth: None,
-            funding_btc: None,
+            funding_base: None,
+            funding_quote: None,
             funding_interval_hours: None,
         })
         .await
@@ -600,7 +597,7 @@ async fn strategy_engine_blocks_entry_when_half_life_gate_fails() {
     let execution = ExecutionEngine::new(recorder.clone(), RetryConfig::fast());
     let mut engine = StrategyEngine::new(config, execution).unwrap();
 
-    for (offset, eth_price) in [
+    for (offset, base_price) in [
         (0, dec!(100)),
         (900, dec!(100)),
         (1800, dec!(100)),
@@ -609,11 +606,11 @@ async fn strategy_engine_blocks_entry_when_half_life_gate_fails() {
         let outcome = engine
             .process_bar(eth_btc_strategy::core::strategy::StrategyBar {
                 timestamp: Utc.timestamp_opt(offset, 0).unwrap(),
-                eth_price,
-                btc_price: dec!(100),
+                base_price,
+                quote_price: dec!(100),
                 equity: None,
-                funding_eth: None,
-                funding_btc: None,
+                funding_base: None,
+                funding_quote: None,
                 funding_interval_hours: None,
             })
             .await
@@ -652,11 +649,11 @@ async fn strategy_engine_records_shadow_cost_gate_without_blocking() {
     for offset in [0, 900, 1800] {
         let bar = eth_btc_strategy::core::strategy::StrategyBar {
             timestamp: Utc.timestamp_opt(offset, 0).unwrap(),
-            eth_price: dec!(100),
-            btc_price: dec!(100),
+            base_price: dec!(100),
+            quote_price: dec!(100),
             equity: None,
-            funding_eth: None,
-            funding_btc: None,
+            funding_base: None,
+            funding_quote: None,
             funding_interval_hours: None,
         };
         engine.process_bar(bar).await.unwrap();
@@ -665,11 +662,11 @@ async fn strategy_engine_records_shadow_cost_gate_without_blocking() {
     let outcome = engine
         .process_bar(eth_btc_strategy::core::strategy::StrategyBar {
             timestamp: Utc.timestamp_opt(2700, 0).unwrap(),
-            eth_price: dec!(271.8281828),
-            btc_price: dec!(100),
+            base_price: dec!(271.8281828),
+            quote_price: dec!(100),
             equity: None,
-            funding_eth: None,
-            funding_btc: None,
+            funding_base: None,
+            funding_quote: None,
             funding_interval_hours: None,
         })
         .await
@@ -693,7 +690,7 @@ async fn strategy_engine_applies_direction_specific_cost_buffer() {
     config.cost_gate.enabled = true;
     config.cost_gate.enforce = true;
     config.cost_gate.min_net_edge_bps = dec!(0);
-    config.cost_gate.short_eth_long_btc_extra_bps = dec!(10000);
+    config.cost_gate.short_base_long_quote_extra_bps = dec!(10000);
 
     let recorder = std::sync::Arc::new(RecordingExecutor::default());
     let execution = ExecutionEngine::new(recorder.clone(), RetryConfig::fast());
@@ -702,11 +699,11 @@ async fn strategy_engine_applies_direction_specific_cost_buffer() {
     for offset in [0, 900, 1800] {
         let bar = eth_btc_strategy::core::strategy::StrategyBar {
             timestamp: Utc.timestamp_opt(offset, 0).unwrap(),
-            eth_price: dec!(100),
-            btc_price: dec!(100),
+            base_price: dec!(100),
+            quote_price: dec!(100),
             equity: None,
-            funding_eth: None,
-            funding_btc: None,
+            funding_base: None,
+            funding_quote: None,
             funding_interval_hours: None,
         };
         engine.process_bar(bar).await.unwrap();
@@ -715,11 +712,11 @@ async fn strategy_engine_applies_direction_specific_cost_buffer() {
     let outcome = engine
         .process_bar(eth_btc_strategy::core::strategy::StrategyBar {
             timestamp: Utc.timestamp_opt(2700, 0).unwrap(),
-            eth_price: dec!(271.8281828),
-            btc_price: dec!(100),
+            base_price: dec!(271.8281828),
+            quote_price: dec!(100),
             equity: None,
-            funding_eth: None,
-            funding_btc: None,
+            funding_base: None,
+            funding_quote: None,
             funding_interval_hours: None,
         })
         .await
@@ -748,8 +745,8 @@ async fn strategy_engine_enters_short_on_persistent_positive_extreme_without_cro
     config.position.c_value = Some(dec!(100));
     config.persistent_extreme.enabled = true;
     config.persistent_extreme.min_abs_z = dec!(0.5);
-    config.persistent_extreme.allow_short_eth_long_btc = true;
-    config.persistent_extreme.allow_long_eth_short_btc = false;
+    config.persistent_extreme.allow_short_base_long_quote = true;
+    config.persistent_extreme.allow_long_base_short_quote = false;
 
     let recorder = std::sync::Arc::new(RecordingExecutor::default());
     let execution = ExecutionEngine::new(recorder.clone(), RetryConfig::fast());
@@ -759,11 +756,11 @@ async fn strategy_engine_enters_short_on_persistent_positive_extreme_without_cro
         engine
             .process_bar(eth_btc_strategy::core::strategy::StrategyBar {
                 timestamp: Utc.timestamp_opt(offset, 0).unwrap(),
-                eth_price: dec!(100),
-                btc_price: dec!(100),
+                base_price: dec!(100),
+                quote_price: dec!(100),
                 equity: None,
-                funding_eth: None,
-                funding_btc: None,
+                funding_base: None,
+                funding_quote: None,
                 funding_interval_hours: None,
             })
             .await
@@ -774,14 +771,14 @@ async fn strategy_engine_enters_short_on_persistent_positive_extreme_without_cro
         .apply_state(StrategyState {
             status: StrategyStatus::InPosition,
             position: Some(PositionSnapshot {
-                direction: TradeDirection::LongEthShortBtc,
+                direction: TradeDirection::LongBaseShortQuote,
                 entry_time: Utc.timestamp_opt(0, 0).unwrap(),
-                eth: PositionLeg {
+                base: PositionLeg {
                     qty: dec!(1),
                     avg_price: dec!(100),
                     notional: dec!(100),
                 },
-                btc: PositionLeg {
+                quote: PositionLeg {
                     qty: dec!(-1),
                     avg_price: dec!(100),
                     notional: dec!(100),
@@ -790,16 +787,17 @@ async fn strategy_engine_enters_short_on_persistent_positive_extreme_without_cro
             pending_entry: None,
             cooldown_until: None,
             cumulative_realized_pnl: dec!(0),
+            groups: Vec::new(),
         })
         .unwrap();
     engine
         .process_bar(eth_btc_strategy::core::strategy::StrategyBar {
             timestamp: Utc.timestamp_opt(2700, 0).unwrap(),
-            eth_price: dec!(271.8281828),
-            btc_price: dec!(100),
+            base_price: dec!(271.8281828),
+            quote_price: dec!(100),
             equity: None,
-            funding_eth: None,
-            funding_btc: None,
+            funding_base: None,
+            funding_quote: None,
             funding_interval_hours: None,
         })
         .await
@@ -812,16 +810,17 @@ async fn strategy_engine_enters_short_on_persistent_positive_extreme_without_cro
             pending_entry: None,
             cooldown_until: None,
             cumulative_realized_pnl: dec!(0),
+            groups: Vec::new(),
         })
         .unwrap();
     let outcome = engine
         .process_bar(eth_btc_strategy::core::strategy::StrategyBar {
             timestamp: Utc.timestamp_opt(3600, 0).unwrap(),
-            eth_price: dec!(271.8281828),
-            btc_price: dec!(100),
+            base_price: dec!(271.8281828),
+            quote_price: dec!(100),
             equity: None,
-            funding_eth: None,
-            funding_btc: None,
+            funding_base: None,
+            funding_quote: None,
             funding_interval_hours: None,
         })
         .await
@@ -834,12 +833,12 @@ async fn strategy_engine_enters_short_on_persistent_positive_extreme_without_cro
     assert!(
         submitted
             .iter()
-            .any(|order| { order.symbol == Symbol::EthPerp && order.side == OrderSide::Sell })
+            .any(|order| { order.symbol == Symbol::Base && order.side == OrderSide::Sell })
     );
     assert!(
         submitted
             .iter()
-            .any(|order| { order.symbol == Symbol::BtcPerp && order.side == OrderSide::Buy })
+            .any(|order| { order.symbol == Symbol::Quote && order.side == OrderSide::Buy })
     );
 }
 
@@ -853,8 +852,8 @@ async fn strategy_engine_does_not_enter_long_on_disabled_persistent_negative_ext
     config.position.c_value = Some(dec!(100));
     config.persistent_extreme.enabled = true;
     config.persistent_extreme.min_abs_z = dec!(0.5);
-    config.persistent_extreme.allow_short_eth_long_btc = true;
-    config.persistent_extreme.allow_long_eth_short_btc = false;
+    config.persistent_extreme.allow_short_base_long_quote = true;
+    config.persistent_extreme.allow_long_base_short_quote = false;
 
     let recorder = std::sync::Arc::new(RecordingExecutor::default());
     let execution = ExecutionEngine::new(recorder.clone(), RetryConfig::fast());
@@ -864,11 +863,11 @@ async fn strategy_engine_does_not_enter_long_on_disabled_persistent_negative_ext
         engine
             .process_bar(eth_btc_strategy::core::strategy::StrategyBar {
                 timestamp: Utc.timestamp_opt(offset, 0).unwrap(),
-                eth_price: dec!(100),
-                btc_price: dec!(100),
+                base_price: dec!(100),
+                quote_price: dec!(100),
                 equity: None,
-                funding_eth: None,
-                funding_btc: None,
+                funding_base: None,
+                funding_quote: None,
                 funding_interval_hours: None,
             })
             .await
@@ -879,14 +878,14 @@ async fn strategy_engine_does_not_enter_long_on_disabled_persistent_negative_ext
         .apply_state(StrategyState {
             status: StrategyStatus::InPosition,
             position: Some(PositionSnapshot {
-                direction: TradeDirection::LongEthShortBtc,
+                direction: TradeDirection::LongBaseShortQuote,
                 entry_time: Utc.timestamp_opt(0, 0).unwrap(),
-                eth: PositionLeg {
+                base: PositionLeg {
                     qty: dec!(1),
                     avg_price: dec!(100),
                     notional: dec!(100),
                 },
-                btc: PositionLeg {
+                quote: PositionLeg {
                     qty: dec!(-1),
                     avg_price: dec!(100),
                     notional: dec!(100),
@@ -895,16 +894,17 @@ async fn strategy_engine_does_not_enter_long_on_disabled_persistent_negative_ext
             pending_entry: None,
             cooldown_until: None,
             cumulative_realized_pnl: dec!(0),
+            groups: Vec::new(),
         })
         .unwrap();
     engine
         .process_bar(eth_btc_strategy::core::strategy::StrategyBar {
             timestamp: Utc.timestamp_opt(2700, 0).unwrap(),
-            eth_price: dec!(36.78794412),
-            btc_price: dec!(100),
+            base_price: dec!(36.78794412),
+            quote_price: dec!(100),
             equity: None,
-            funding_eth: None,
-            funding_btc: None,
+            funding_base: None,
+            funding_quote: None,
             funding_interval_hours: None,
         })
         .await
@@ -917,16 +917,17 @@ async fn strategy_engine_does_not_enter_long_on_disabled_persistent_negative_ext
             pending_entry: None,
             cooldown_until: None,
             cumulative_realized_pnl: dec!(0),
+            groups: Vec::new(),
         })
         .unwrap();
     let outcome = engine
         .process_bar(eth_btc_strategy::core::strategy::StrategyBar {
             timestamp: Utc.timestamp_opt(3600, 0).unwrap(),
-            eth_price: dec!(36.78794412),
-            btc_price: dec!(100),
+            base_price: dec!(36.78794412),
+            quote_price: dec!(100),
             equity: None,
-            funding_eth: None,
-            funding_btc: None,
+            funding_base: None,
+            funding_quote: None,
             funding_interval_hours: None,
         })
         .await
@@ -948,7 +949,7 @@ async fn strategy_engine_applies_short_directional_size_multiplier_before_orderi
     config.strategy.entry_z = dec!(0.5);
     config.strategy.sl_z = dec!(10.0);
     config.position.c_value = Some(dec!(100));
-    config.directional_sizing.short_eth_long_btc_multiplier = dec!(1.25);
+    config.directional_sizing.short_base_long_quote_multiplier = dec!(1.25);
 
     let recorder = std::sync::Arc::new(RecordingExecutor::default());
     let execution = ExecutionEngine::new(recorder.clone(), RetryConfig::fast());
@@ -958,11 +959,11 @@ async fn strategy_engine_applies_short_directional_size_multiplier_before_orderi
         engine
             .process_bar(eth_btc_strategy::core::strategy::StrategyBar {
                 timestamp: Utc.timestamp_opt(offset, 0).unwrap(),
-                eth_price: dec!(100),
-                btc_price: dec!(100),
+                base_price: dec!(100),
+                quote_price: dec!(100),
                 equity: None,
-                funding_eth: None,
-                funding_btc: None,
+                funding_base: None,
+                funding_quote: None,
                 funding_interval_hours: None,
             })
             .await
@@ -972,19 +973,19 @@ async fn strategy_engine_applies_short_directional_size_multiplier_before_orderi
     let outcome = engine
         .process_bar(eth_btc_strategy::core::strategy::StrategyBar {
             timestamp: Utc.timestamp_opt(2700, 0).unwrap(),
-            eth_price: dec!(271.8281828),
-            btc_price: dec!(100),
+            base_price: dec!(271.8281828),
+            quote_price: dec!(100),
             equity: None,
-            funding_eth: None,
-            funding_btc: None,
+            funding_base: None,
+            funding_quote: None,
             funding_interval_hours: None,
         })
         .await
         .unwrap();
 
     assert_eq!(outcome.state, StrategyStatus::InPosition);
-    assert_eq!(outcome.bar_log.notional_eth, Some(dec!(62.5)));
-    assert_eq!(outcome.bar_log.notional_btc, Some(dec!(62.5)));
+    assert_eq!(outcome.bar_log.notional_base, Some(dec!(62.5)));
+    assert_eq!(outcome.bar_log.notional_quote, Some(dec!(62.5)));
     assert_eq!(recorder.submitted.lock().expect("submit lock").len(), 2);
 }
 
@@ -1006,25 +1007,25 @@ async fn strategy_engine_enforces_max_notional_limit() {
     for offset in [0, 900, 1800] {
         let bar = eth_btc_strategy::core::strategy::StrategyBar {
             timestamp: Utc.timestamp_opt(offset, 0).unwrap(),
-            eth_price: dec!(100),
-            btc_price: dec!(100),
+            base_price: dec!(100),
+            quote_price: dec!(100),
             equity: None,
-            funding_eth: None,
-            funding_btc: None,
+            funding_base: None,
+            funding_quote: None,
             funding_interval_hours: None,
         };
         engine.process_bar(bar).await.unwrap();
     }
 
-    let eth_price = dec!(271.8281828);
-    let btc_price = dec!(100);
+    let base_price = dec!(271.8281828);
+    let quote_price = dec!(100);
     let bar = eth_btc_strategy::core::strategy::StrategyBar {
         timestamp: Utc.timestamp_opt(2700, 0).unwrap(),
-        eth_price,
-        btc_price,
+        base_price,
+        quote_price,
         equity: None,
-        funding_eth: None,
-        funding_btc: None,
+        funding_base: None,
+        funding_quote: None,
         funding_interval_hours: None,
     };
 
@@ -1052,25 +1053,25 @@ async fn strategy_engine_sets_limit_price_with_slippage() {
     for offset in [0, 900, 1800] {
         let bar = eth_btc_strategy::core::strategy::StrategyBar {
             timestamp: Utc.timestamp_opt(offset, 0).unwrap(),
-            eth_price: dec!(100),
-            btc_price: dec!(100),
+            base_price: dec!(100),
+            quote_price: dec!(100),
             equity: None,
-            funding_eth: None,
-            funding_btc: None,
+            funding_base: None,
+            funding_quote: None,
             funding_interval_hours: None,
         };
         engine.process_bar(bar).await.unwrap();
     }
 
-    let eth_price = dec!(271.8281828);
-    let btc_price = dec!(100);
+    let base_price = dec!(271.8281828);
+    let quote_price = dec!(100);
     let bar = eth_btc_strategy::core::strategy::StrategyBar {
         timestamp: Utc.timestamp_opt(2700, 0).unwrap(),
-        eth_price,
-        btc_price,
+        base_price,
+        quote_price,
         equity: None,
-        funding_eth: None,
-        funding_btc: None,
+        funding_base: None,
+        funding_quote: None,
         funding_interval_hours: None,
     };
 
@@ -1082,10 +1083,10 @@ async fn strategy_engine_sets_limit_price_with_slippage() {
         / rust_decimal::Decimal::new(10000, 0);
     for order in submitted.iter() {
         let limit = order.limit_price.expect("limit price");
-        if order.symbol == eth_btc_strategy::config::Symbol::EthPerp {
-            assert_eq!(limit, eth_price * (rust_decimal::Decimal::ONE - slippage));
+        if order.symbol == eth_btc_strategy::config::Symbol::Base {
+            assert_eq!(limit, base_price * (rust_decimal::Decimal::ONE - slippage));
         } else {
-            assert_eq!(limit, btc_price * (rust_decimal::Decimal::ONE + slippage));
+            assert_eq!(limit, quote_price * (rust_decimal::Decimal::ONE + slippage));
         }
     }
 }
@@ -1109,11 +1110,11 @@ async fn strategy_engine_post_only_entry_waits_for_exchange_fill() {
     for offset in [0, 900, 1800] {
         let bar = eth_btc_strategy::core::strategy::StrategyBar {
             timestamp: Utc.timestamp_opt(offset, 0).unwrap(),
-            eth_price: dec!(100),
-            btc_price: dec!(100),
+            base_price: dec!(100),
+            quote_price: dec!(100),
             equity: None,
-            funding_eth: None,
-            funding_btc: None,
+            funding_base: None,
+            funding_quote: None,
             funding_interval_hours: None,
         };
         engine.process_bar(bar).await.unwrap();
@@ -1121,11 +1122,11 @@ async fn strategy_engine_post_only_entry_waits_for_exchange_fill() {
 
     let entry_bar = eth_btc_strategy::core::strategy::StrategyBar {
         timestamp: Utc.timestamp_opt(2700, 0).unwrap(),
-        eth_price: dec!(271.8281828),
-        btc_price: dec!(100),
+        base_price: dec!(271.8281828),
+        quote_price: dec!(100),
         equity: None,
-        funding_eth: None,
-        funding_btc: None,
+        funding_base: None,
+        funding_quote: None,
         funding_interval_hours: None,
     };
     let pending = engine.process_bar(entry_bar).await.unwrap();
@@ -1135,12 +1136,12 @@ async fn strategy_engine_post_only_entry_waits_for_exchange_fill() {
     assert_eq!(pending.events, vec![LogEvent::EntrySubmitted]);
 
     let exposure = PairExposure {
-        eth: Some(ExchangePosition {
+        base: Some(ExchangePosition {
             qty: dec!(0.184),
             entry_price: dec!(271.7),
             notional: dec!(50.0),
         }),
-        btc: Some(ExchangePosition {
+        quote: Some(ExchangePosition {
             qty: dec!(-0.0008),
             entry_price: dec!(100.1),
             notional: dec!(50.0),
@@ -1158,11 +1159,11 @@ async fn strategy_engine_post_only_entry_waits_for_exchange_fill() {
 
     let fill_bar = eth_btc_strategy::core::strategy::StrategyBar {
         timestamp: Utc.timestamp_opt(3600, 0).unwrap(),
-        eth_price: dec!(271.7),
-        btc_price: dec!(100.1),
+        base_price: dec!(271.7),
+        quote_price: dec!(100.1),
         equity: None,
-        funding_eth: None,
-        funding_btc: None,
+        funding_base: None,
+        funding_quote: None,
         funding_interval_hours: None,
     };
     let filled = engine.process_bar(fill_bar).await.unwrap();
@@ -1170,8 +1171,8 @@ async fn strategy_engine_post_only_entry_waits_for_exchange_fill() {
     assert_eq!(filled.state, StrategyStatus::InPosition);
     assert!(filled.events.contains(&LogEvent::Entry));
     assert_eq!(filled.trade_logs.len(), 1);
-    assert_eq!(filled.trade_logs[0].eth_price, dec!(271.7));
-    assert_eq!(filled.trade_logs[0].btc_price, dec!(100.1));
+    assert_eq!(filled.trade_logs[0].base_price, dec!(271.7));
+    assert_eq!(filled.trade_logs[0].quote_price, dec!(100.1));
 }
 
 #[tokio::test]
@@ -1193,14 +1194,14 @@ async fn strategy_engine_forces_market_exit_when_post_only_is_configured() {
     let state = StrategyState {
         status: StrategyStatus::InPosition,
         position: Some(PositionSnapshot {
-            direction: TradeDirection::LongEthShortBtc,
+            direction: TradeDirection::LongBaseShortQuote,
             entry_time: Utc.timestamp_opt(0, 0).unwrap(),
-            eth: PositionLeg {
+            base: PositionLeg {
                 qty: dec!(1),
                 avg_price: dec!(100),
                 notional: dec!(100),
             },
-            btc: PositionLeg {
+            quote: PositionLeg {
                 qty: dec!(-1),
                 avg_price: dec!(100),
                 notional: dec!(100),
@@ -1209,17 +1210,18 @@ async fn strategy_engine_forces_market_exit_when_post_only_is_configured() {
         pending_entry: None,
         cooldown_until: None,
         cumulative_realized_pnl: dec!(0),
+        groups: Vec::new(),
     };
     engine.apply_state(state).unwrap();
 
     for offset in [0, 900, 1800] {
         let bar = eth_btc_strategy::core::strategy::StrategyBar {
             timestamp: Utc.timestamp_opt(offset, 0).unwrap(),
-            eth_price: dec!(100),
-            btc_price: dec!(100),
+            base_price: dec!(100),
+            quote_price: dec!(100),
             equity: None,
-            funding_eth: None,
-            funding_btc: None,
+            funding_base: None,
+            funding_quote: None,
             funding_interval_hours: None,
         };
         engine.process_bar(bar).await.unwrap();
@@ -1227,11 +1229,11 @@ async fn strategy_engine_forces_market_exit_when_post_only_is_configured() {
 
     let exit_bar = eth_btc_strategy::core::strategy::StrategyBar {
         timestamp: Utc.timestamp_opt(2700, 0).unwrap(),
-        eth_price: dec!(100),
-        btc_price: dec!(100),
+        base_price: dec!(100),
+        quote_price: dec!(100),
         equity: None,
-        funding_eth: None,
-        funding_btc: None,
+        funding_base: None,
+        funding_quote: None,
         funding_interval_hours: None,
     };
     let _ = engine.process_bar(exit_bar).await.unwrap();
@@ -1258,37 +1260,36 @@ async fn strategy_engine_cancels_expired_pending_entry_before_flattening() {
             status: StrategyStatus::PendingEntry,
             position: None,
             pending_entry: Some(eth_btc_strategy::state::PendingEntrySnapshot {
-                direction: TradeDirection::LongEthShortBtc,
-                eth_qty: dec!(0.01),
-                btc_qty: dec!(-0.001),
-                eth_order_id: 11,
-                btc_order_id: 22,
+                direction: TradeDirection::LongBaseShortQuote,
+                base_qty: dec!(0.01),
+                quote_qty: dec!(-0.001),
+                base_order_id: 11,
+                quote_order_id: 22,
                 submitted_at: Utc.timestamp_opt(100, 0).unwrap(),
                 expires_at: Utc.timestamp_opt(200, 0).unwrap(),
+                entry_z: None,
             }),
             cooldown_until: None,
             cumulative_realized_pnl: dec!(0),
+            groups: Vec::new(),
         })
         .unwrap();
 
     let outcome = engine
         .process_bar(eth_btc_strategy::core::strategy::StrategyBar {
             timestamp: Utc.timestamp_opt(200, 0).unwrap(),
-            eth_price: dec!(2000),
-            btc_price: dec!(60000),
+            base_price: dec!(2000),
+            quote_price: dec!(60000),
             equity: None,
-            funding_eth: None,
-            funding_btc: None,
+            funding_base: None,
+            funding_quote: None,
             funding_interval_hours: None,
         })
         .await
         .unwrap();
 
     let cancelled = executor.cancelled.lock().expect("cancel lock").clone();
-    assert_eq!(
-        cancelled,
-        vec![(Symbol::EthPerp, 11), (Symbol::BtcPerp, 22)]
-    );
+    assert_eq!(cancelled, vec![(Symbol::Base, 11), (Symbol::Quote, 22)]);
     assert_eq!(outcome.state, StrategyStatus::Flat);
     assert!(outcome.events.contains(&LogEvent::EntryCancelled));
 }
@@ -1310,11 +1311,11 @@ async fn strategy_engine_emits_trade_logs_on_entry_and_exit() {
     for offset in [0, 900, 1800] {
         let bar = eth_btc_strategy::core::strategy::StrategyBar {
             timestamp: Utc.timestamp_opt(offset, 0).unwrap(),
-            eth_price: dec!(100),
-            btc_price: dec!(100),
+            base_price: dec!(100),
+            quote_price: dec!(100),
             equity: None,
-            funding_eth: None,
-            funding_btc: None,
+            funding_base: None,
+            funding_quote: None,
             funding_interval_hours: None,
         };
         engine.process_bar(bar).await.unwrap();
@@ -1322,11 +1323,11 @@ async fn strategy_engine_emits_trade_logs_on_entry_and_exit() {
 
     let entry_bar = eth_btc_strategy::core::strategy::StrategyBar {
         timestamp: Utc.timestamp_opt(2700, 0).unwrap(),
-        eth_price: dec!(271.8281828),
-        btc_price: dec!(100),
+        base_price: dec!(271.8281828),
+        quote_price: dec!(100),
         equity: None,
-        funding_eth: None,
-        funding_btc: None,
+        funding_base: None,
+        funding_quote: None,
         funding_interval_hours: None,
     };
 
@@ -1348,11 +1349,11 @@ async fn strategy_engine_emits_trade_logs_on_entry_and_exit() {
 
     let exit_bar = eth_btc_strategy::core::strategy::StrategyBar {
         timestamp: Utc.timestamp_opt(3600, 0).unwrap(),
-        eth_price: dec!(164.872127),
-        btc_price: dec!(100),
+        base_price: dec!(164.872127),
+        quote_price: dec!(100),
         equity: None,
-        funding_eth: None,
-        funding_btc: None,
+        funding_base: None,
+        funding_quote: None,
         funding_interval_hours: None,
     };
 
@@ -1372,24 +1373,13 @@ async fn strategy_engine_emits_trade_logs_on_entry_and_exit() {
     }
 }
 
-#[tokio::test]
-async fn strategy_engine_uses_exchange_fills_for_trade_log_pnl() {
-    let mut config = Config::default();
-    config.strategy.n_z = 3;
-    config.position.n_vol = 1;
-    config.strategy.entry_z = dec!(0.5);
-    config.strategy.tp_z = dec!(0.45);
-    config.strategy.sl_z = dec!(2.0);
-    config.position.c_value = Some(dec!(100));
-
-    let execution = ExecutionEngine::new(
-        std::sync::Arc::new(DetailedFillExecutor),
-        RetryConfig::fast(),
-    );
-    let fill_source = StaticFillSource {
+/// Exchange fills for a `DetailedFillExecutor` entry and exit; each close fill reports 9 of
+/// closed PnL.
+fn round_trip_fill_source() -> StaticFillSource {
+    StaticFillSource {
         fills: std::sync::Arc::new(vec![
             ExchangeFill {
-                coin: Symbol::EthPerp,
+                coin: Symbol::Base,
                 price: dec!(101),
                 size: dec!(1),
                 fee: dec!(0.01),
@@ -1399,7 +1389,7 @@ async fn strategy_engine_uses_exchange_fills_for_trade_log_pnl() {
                 tid: Some(1),
             },
             ExchangeFill {
-                coin: Symbol::BtcPerp,
+                coin: Symbol::Quote,
                 price: dec!(99),
                 size: dec!(1),
                 fee: dec!(0.02),
@@ -1409,7 +1399,7 @@ async fn strategy_engine_uses_exchange_fills_for_trade_log_pnl() {
                 tid: Some(2),
             },
             ExchangeFill {
-                coin: Symbol::EthPerp,
+                coin: Symbol::Base,
                 price: dec!(110),
                 size: dec!(1),
                 fee: dec!(0.03),
@@ -1419,7 +1409,7 @@ async fn strategy_engine_uses_exchange_fills_for_trade_log_pnl() {
                 tid: Some(3),
             },
             ExchangeFill {
-                coin: Symbol::BtcPerp,
+                coin: Symbol::Quote,
                 price: dec!(90),
                 size: dec!(1),
                 fee: dec!(0.04),
@@ -1429,19 +1419,35 @@ async fn strategy_engine_uses_exchange_fills_for_trade_log_pnl() {
                 tid: Some(4),
             },
         ]),
-    };
+    }
+}
+
+#[tokio::test]
+async fn strategy_engine_uses_exchange_fills_for_trade_log_pnl() {
+    let mut config = Config::default();
+    config.strategy.n_z = 3;
+    config.position.n_vol = 1;
+    config.strategy.entry_z = dec!(0.5);
+    config.strategy.tp_z = dec!(0.45);
+    config.strategy.sl_z = dec!(2.0);
+    config.position.c_value = Some(dec!(100));
+
+    let execution = ExecutionEngine::new(
+        std::sync::Arc::new(DetailedFillExecutor),
+        RetryConfig::fast(),
+    );
     let mut engine = StrategyEngine::new(config.clone(), execution)
         .unwrap()
-        .with_fill_source(std::sync::Arc::new(fill_source));
+        .with_fill_source(std::sync::Arc::new(round_trip_fill_source()));
 
     for offset in [0, 900, 1800] {
         let bar = eth_btc_strategy::core::strategy::StrategyBar {
             timestamp: Utc.timestamp_opt(offset, 0).unwrap(),
-            eth_price: dec!(100),
-            btc_price: dec!(100),
+            base_price: dec!(100),
+            quote_price: dec!(100),
             equity: None,
-            funding_eth: None,
-            funding_btc: None,
+            funding_base: None,
+            funding_quote: None,
             funding_interval_hours: None,
         };
         engine.process_bar(bar).await.unwrap();
@@ -1450,11 +1456,11 @@ async fn strategy_engine_uses_exchange_fills_for_trade_log_pnl() {
     let entry_outcome = engine
         .process_bar(eth_btc_strategy::core::strategy::StrategyBar {
             timestamp: Utc.timestamp_opt(2700, 0).unwrap(),
-            eth_price: dec!(271.8281828),
-            btc_price: dec!(100),
+            base_price: dec!(271.8281828),
+            quote_price: dec!(100),
             equity: None,
-            funding_eth: None,
-            funding_btc: None,
+            funding_base: None,
+            funding_quote: None,
             funding_interval_hours: None,
         })
         .await
@@ -1464,28 +1470,28 @@ async fn strategy_engine_uses_exchange_fills_for_trade_log_pnl() {
     let entry_log = &entry_outcome.trade_logs[0];
     assert_eq!(entry_log.event, TradeEvent::Entry);
     assert_eq!(entry_log.pnl_source, PnlSource::ExchangeFills);
-    assert_eq!(entry_log.eth_price, dec!(101));
-    assert_eq!(entry_log.btc_price, dec!(99));
+    assert_eq!(entry_log.base_price, dec!(101));
+    assert_eq!(entry_log.quote_price, dec!(99));
     assert_eq!(entry_log.fee, dec!(0.03));
     assert_eq!(entry_log.exchange_closed_pnl, Some(dec!(0)));
     assert_eq!(entry_log.realized_pnl, dec!(-0.03));
     assert_eq!(entry_log.cumulative_realized_pnl, dec!(-0.03));
-    assert_eq!(entry_log.eth_ref_price, Some(dec!(271.8281828)));
-    assert_eq!(entry_log.btc_ref_price, Some(dec!(100)));
-    assert!(entry_log.eth_slippage_bps.is_some());
-    assert!(entry_log.btc_slippage_bps.is_some());
+    assert_eq!(entry_log.base_ref_price, Some(dec!(271.8281828)));
+    assert_eq!(entry_log.quote_ref_price, Some(dec!(100)));
+    assert!(entry_log.base_slippage_bps.is_some());
+    assert!(entry_log.quote_slippage_bps.is_some());
     let position = engine.state().state().position.as_ref().unwrap();
-    assert_eq!(position.eth.avg_price, dec!(101));
-    assert_eq!(position.btc.avg_price, dec!(99));
+    assert_eq!(position.base.avg_price, dec!(101));
+    assert_eq!(position.quote.avg_price, dec!(99));
 
     let exit_outcome = engine
         .process_bar(eth_btc_strategy::core::strategy::StrategyBar {
             timestamp: Utc.timestamp_opt(3600, 0).unwrap(),
-            eth_price: dec!(164.872127),
-            btc_price: dec!(100),
+            base_price: dec!(164.872127),
+            quote_price: dec!(100),
             equity: None,
-            funding_eth: None,
-            funding_btc: None,
+            funding_base: None,
+            funding_quote: None,
             funding_interval_hours: None,
         })
         .await
@@ -1495,19 +1501,75 @@ async fn strategy_engine_uses_exchange_fills_for_trade_log_pnl() {
     let exit_log = &exit_outcome.trade_logs[0];
     assert!(matches!(exit_log.event, TradeEvent::Exit(_)));
     assert_eq!(exit_log.pnl_source, PnlSource::ExchangeFills);
-    assert_eq!(exit_log.eth_price, dec!(110));
-    assert_eq!(exit_log.btc_price, dec!(90));
+    assert_eq!(exit_log.base_price, dec!(110));
+    assert_eq!(exit_log.quote_price, dec!(90));
     assert_eq!(exit_log.fee, dec!(0.07));
     assert_eq!(exit_log.exchange_closed_pnl, Some(dec!(18)));
     assert_eq!(exit_log.realized_pnl, dec!(17.93));
     assert_eq!(exit_log.cumulative_realized_pnl, dec!(17.90));
-    assert_eq!(exit_log.eth_ref_price, Some(dec!(164.872127)));
-    assert_eq!(exit_log.btc_ref_price, Some(dec!(100)));
-    assert!(exit_log.eth_slippage_bps.is_some());
-    assert!(exit_log.btc_slippage_bps.is_some());
+    assert_eq!(exit_log.base_ref_price, Some(dec!(164.872127)));
+    assert_eq!(exit_log.quote_ref_price, Some(dec!(100)));
+    assert!(exit_log.base_slippage_bps.is_some());
+    assert!(exit_log.quote_slippage_bps.is_some());
     assert_eq!(engine.state().state().cumulative_realized_pnl, dec!(17.90));
 }
 
+#[tokio::test]
+async fn strategy_engine_on_shared_account_ignores_exchange_closed_pnl() {
+    let mut config = Config::default();
+    config.strategy.n_z = 3;
+    config.position.n_vol = 1;
+    config.strategy.entry_z = dec!(0.5);
+    config.strategy.tp_z = dec!(0.45);
+    config.strategy.sl_z = dec!(2.0);
+    config.position.c_value = Some(dec!(100));
+
+    let execution = ExecutionEngine::new(
+        std::sync::Arc::new(DetailedFillExecutor),
+        RetryConfig::fast(),
+    );
+    let mut engine = StrategyEngine::new(config, execution)
+        .unwrap()
+        .with_fill_source(std::sync::Arc::new(round_trip_fill_source()))
+        .with_shared_account();
+
+    let bars = [
+        (0, dec!(100)),
+        (900, dec!(100)),
+        (1800, dec!(100)),
+        (2700, dec!(271.8281828)),
+        (3600, dec!(164.872127)),
+    ];
+    let mut logs = Vec::new();
+    for (offset, base_price) in bars {
+        let outcome = engine
+            .process_bar(eth_btc_strategy::core::strategy::StrategyBar {
+                timestamp: Utc.timestamp_opt(offset, 0).unwrap(),
+                base_price,
+                quote_price: dec!(100),
+                equity: None,
+                funding_base: None,
+                funding_quote: None,
+                funding_interval_hours: None,
+            })
+            .await
+            .unwrap();
+        logs.extend(outcome.trade_logs);
+    }
+
+    let exit_log = logs
+        .iter()
+        .find(|log| matches!(log.event, TradeEvent::Exit(_)))
+        .unwrap();
+    assert_eq!(exit_log.pnl_source, PnlSource::ExchangeFills);
+    assert_eq!(exit_log.exchange_closed_pnl, Some(dec!(18)));
+    assert_eq!(exit_log.fee, dec!(0.07));
+    // PnL comes from this engine's own 101/99 entry and 110/90 exit fills.
+    let own_pnl =
+        exit_log.base_qty * (dec!(110) - dec!(101)) + exit_log.quote_qty * (dec!(90) - dec!(99));
+    assert_eq!(exit_log.realized_pnl, own_pnl - dec!(0.07));
+}
+
 #[tokio::test]
 async fn strategy_engine_populates_unrealized_pnl_while_holding_position() {
     let mut config = Config::default();
@@ -1525,11 +1587,11 @@ async fn strategy_engine_populates_unrealized_pnl_while_holding_position() {
     for offset in [0, 900, 1800] {
         let bar = eth_btc_strategy::core::strategy::StrategyBar {
             timestamp: Utc.timestamp_opt(offset, 0).unwrap(),
-            eth_price: dec!(100),
-            btc_price: dec!(100),
+            base_price: dec!(100),
+            quote_price: dec!(100),
             equity: None,
-            funding_eth: None,
-            funding_btc: None,
+            funding_base: None,
+            funding_quote: None,
             funding_interval_hours: None,
         };
         engine.process_bar(bar).await.unwrap();
@@ -1537,11 +1599,11 @@ async fn strategy_engine_populates_unrealized_pnl_while_holding_position() {
 
     let entry_bar = eth_btc_strategy::core::strategy::StrategyBar {
         timestamp: Utc.timestamp_opt(2700, 0).unwrap(),
-        eth_price: dec!(271.8281828),
-        btc_price: dec!(100),
+        base_price: dec!(271.8281828),
+        quote_price: dec!(100),
         equity: None,
-        funding_eth: None,
-        funding_btc: None,
+        funding_base: None,
+        funding_quote: None,
         funding_interval_hours: None,
     };
     let entry_outcome = engine.process_bar(entry_bar).await.unwrap();
@@ -1550,11 +1612,11 @@ async fn strategy_engine_populates_unrealized_pnl_while_holding_position() {
 
     let holding_bar = eth_btc_strategy::core::strategy::StrategyBar {
         timestamp: Utc.timestamp_opt(3600, 0).unwrap(),
-        eth_price: dec!(250),
-        btc_price: dec!(105),
+        base_price: dec!(250),
+        quote_price: dec!(105),
         equity: None,
-        funding_eth: None,
-        funding_btc: None,
+        funding_base: None,
+        funding_quote: None,
         funding_interval_hours: None,
     };
     let holding_outcome = engine.process_bar(holding_bar).await.unwrap();
@@ -1579,11 +1641,11 @@ async fn strategy_engine_exit_realized_pnl_deducts_funding_cost() {
     for offset in [0, 900, 1800] {
         let bar = eth_btc_strategy::core::strategy::StrategyBar {
             timestamp: Utc.timestamp_opt(offset, 0).unwrap(),
-            eth_price: dec!(100),
-            btc_price: dec!(100),
+            base_price: dec!(100),
+            quote_price: dec!(100),
             equity: None,
-            funding_eth: None,
-            funding_btc: None,
+            funding_base: None,
+            funding_quote: None,
             funding_interval_hours: Some(1),
         };
         engine.process_bar(bar).await.unwrap();
@@ -1591,42 +1653,42 @@ async fn strategy_engine_exit_realized_pnl_deducts_funding_cost() {
 
     let entry_bar = eth_btc_strategy::core::strategy::StrategyBar {
         timestamp: Utc.timestamp_opt(2700, 0).unwrap(),
-        eth_price: dec!(271.8281828),
-        btc_price: dec!(100),
+        base_price: dec!(271.8281828),
+        quote_price: dec!(100),
         equity: None,
-        funding_eth: None,
-        funding_btc: None,
+        funding_base: None,
+        funding_quote: None,
         funding_interval_hours: Some(1),
     };
     let entry_outcome = engine.process_bar(entry_bar).await.unwrap();
-    let notional_eth = entry_outcome.bar_log.notional_eth.unwrap();
-    let notional_btc = entry_outcome.bar_log.notional_btc.unwrap();
+    let notional_base = entry_outcome.bar_log.notional_base.unwrap();
+    let notional_quote = entry_outcome.bar_log.notional_quote.unwrap();
 
     let exit_bar = eth_btc_strategy::core::strategy::StrategyBar {
         timestamp: Utc.timestamp_opt(6300, 0).unwrap(),
-        eth_price: dec!(164.872127),
-        btc_price: dec!(100),
+        base_price: dec!(164.872127),
+        quote_price: dec!(100),
         equity: None,
-        funding_eth: Some(dec!(0)),
-        funding_btc: Some(dec!(0.01)),
+        funding_base: Some(dec!(0)),
+        funding_quote: Some(dec!(0.01)),
         funding_interval_hours: Some(1),
     };
     let exit_outcome = engine.process_bar(exit_bar.clone()).await.unwrap();
     let log = &exit_outcome.trade_logs[0];
-    let gross = log.eth_qty * (log.eth_price - log.entry_eth_price)
-        + log.btc_qty * (log.btc_price - log.entry_btc_price);
+    let gross = log.base_qty * (log.base_price - log.entry_base_price)
+        + log.quote_qty * (log.quote_price - log.entry_quote_price);
     let funding = estimate_funding_cost(
         log.direction,
-        notional_eth,
-        notional_btc,
+        notional_base,
+        notional_quote,
         &FundingRate {
-            symbol: Symbol::EthPerp,
+            symbol: Symbol::Base,
             rate: dec!(0),
             timestamp: exit_bar.timestamp,
             interval_hours: 1,
         },
         &FundingRate {
-            symbol: Symbol::BtcPerp,
+            symbol: Symbol::Quote,
             rate: dec!(0.01),
             timestamp: exit_bar.timestamp,
             interval_hours: 1,
@@ -1658,11 +1720,11 @@ async fn strategy_engine_threshold_mode_requires_adjusted_threshold_cross() {
     for offset in [0, 900, 1800] {
         let bar = eth_btc_strategy::core::strategy::StrategyBar {
             timestamp: Utc.timestamp_opt(offset, 0).unwrap(),
-            eth_price: dec!(100),
-            btc_price: dec!(100),
+            base_price: dec!(100),
+            quote_price: dec!(100),
             equity: None,
-            funding_eth: None,
-            funding_btc: None,
+            funding_base: None,
+            funding_quote: None,
             funding_interval_hours: Some(1),
         };
         engine.process_bar(bar).await.unwrap();
@@ -1670,11 +1732,11 @@ async fn strategy_engine_threshold_mode_requires_adjusted_threshold_cross() {
 
     let entry_candidate = eth_btc_strategy::core::strategy::StrategyBar {
         timestamp: Utc.timestamp_opt(2700, 0).unwrap(),
-        eth_price: dec!(271.8281828),
-        btc_price: dec!(100),
+        base_price: dec!(271.8281828),
+        quote_price: dec!(100),
         equity: None,
-        funding_eth: Some(dec!(0)),
-        funding_btc: Some(dec!(0.01)),
+        funding_base: Some(dec!(0)),
+        funding_quote: Some(dec!(0.01)),
         funding_interval_hours: Some(1),
     };
 
@@ -1706,11 +1768,11 @@ async fn strategy_engine_skips_entry_below_minimum_size() {
     for offset in [0, 900, 1800] {
         let bar = eth_btc_strategy::core::strategy::StrategyBar {
             timestamp: Utc.timestamp_opt(offset, 0).unwrap(),
-            eth_price: dec!(100),
-            btc_price: dec!(100),
+            base_price: dec!(100),
+            quote_price: dec!(100),
             equity: None,
-            funding_eth: None,
-            funding_btc: None,
+            funding_base: None,
+            funding_quote: None,
             funding_interval_hours: None,
         };
         engine.process_bar(bar).await.unwrap();
@@ -1718,11 +1780,11 @@ async fn strategy_engine_skips_entry_below_minimum_size() {
 
     let entry_bar = eth_btc_strategy::core::strategy::StrategyBar {
         timestamp: Utc.timestamp_opt(2700, 0).unwrap(),
-        eth_price: dec!(271.8281828),
-        btc_price: dec!(100),
+        base_price: dec!(271.8281828),
+        quote_price: dec!(100),
         equity: None,
-        funding_eth: None,
-        funding_btc: None,
+        funding_base: None,
+        funding_quote: None,
         funding_interval_hours: None,
     };
 
@@ -1731,7 +1793,7 @@ async fn strategy_engine_skips_entry_below_minimum_size() {
     assert!(outcome.trade_logs.is_empty());
     assert_eq!(
         outcome.bar_log.entry_block_reason,
-        Some(EntryBlockReason::BelowMinSizeEth)
+        Some(EntryBlockReason::BelowMinSizeBase)
     );
     let submitted = recorder.submitted.lock().expect("submit lock");
     assert!(submitted.is_empty());
@@ -1751,11 +1813,11 @@ async fn strategy_engine_marks_unavailable_zscore_as_not_ready() {
 
     let first_bar = eth_btc_strategy::core::strategy::StrategyBar {
         timestamp: Utc.timestamp_opt(0, 0).unwrap(),
-        eth_price: dec!(2000),
-        btc_price: dec!(30000),
+        base_price: dec!(2000),
+        quote_price: dec!(30000),
         equity: None,
-        funding_eth: None,
-        funding_btc: None,
+        funding_base: None,
+        funding_quote: None,
         funding_interval_hours: None,
     };
     let outcome = engine.process_bar(first_bar).await.unwrap();
@@ -1765,3 +1827,69 @@ async fn strategy_engine_marks_unavailable_zscore_as_not_ready() {
         Some(EntryBlockReason::ZscoreUnavailable)
     );
 }
+
+#[tokio::test]
+async fn strategy_engine_scales_in_and_exits_groups_separately() {
+    let mut config = Config::default();
+    config.strategy.n_z = 20;
+    config.position.n_vol = 1;
+    config.position.c_value = Some(dec!(100));
+    config.position.max_position_groups = 2;
+    config.position.scale_in_step_z = dec!(0.5);
+
+    let execution =
+        ExecutionEngine::new(std::sync::Arc::new(PaperOrderExecutor), RetryConfig::fast());
+    let mut engine = StrategyEngine::new(config, execution).unwrap();
+
+    let mut prices: Vec<rust_decimal::Decimal> = (0..20)
+        .map(|i| if i % 2 == 0 { dec!(100) } else { dec!(101) })
+        .collect();
+    prices.extend([
+        dec!(101.5),
+        dec!(102),
+        dec!(102.5),
+        dec!(101.9),
+        dec!(101.4),
+        dec!(100.9),
+    ]);
+    let mut outcomes = Vec::new();
+    for (index, base_price) in prices.into_iter().enumerate() {
+        let bar = eth_btc_strategy::core::strategy::StrategyBar {
+            timestamp: Utc.timestamp_opt(index as i64 * 900, 0).unwrap(),
+            base_price,
+            quote_price: dec!(100),
+            equity: None,
+            funding_base: None,
+            funding_quote: None,
+            funding_interval_hours: None,
+        };
+        outcomes.push(engine.process_bar(bar).await.unwrap());
+    }
+
+    assert!(outcomes[20].events.contains(&LogEvent::Entry));
+    assert!(outcomes[21].events.contains(&LogEvent::ScaleIn));
+    assert_eq!(outcomes[21].trade_logs[0].event, TradeEvent::ScaleIn);
+    let scaled = outcomes[21].bar_log.position.clone().unwrap();
+    assert_eq!(scaled.direction, TradeDirection::ShortBaseLongQuote);
+    assert!(outcomes[22].events.is_empty());
+    assert!(outcomes[23].events.is_empty());
+
+    assert_eq!(
+        outcomes[24].events,
+        vec![LogEvent::Exit(ExitReason::TakeProfit)]
+    );
+    assert_eq!(outcomes[24].state, StrategyStatus::InPosition);
+    let remaining = outcomes[24].bar_log.position.clone().unwrap();
+    assert_eq!(
+        remaining.entry_time,
+        Utc.timestamp_opt(20 * 900, 0).unwrap()
+    );
+    assert!(remaining.base.qty.abs() < scaled.base.qty.abs());
+
+    assert_eq!(
+        outcomes[25].events,
+        vec![LogEvent::Exit(ExitReason::TakeProfit)]
+    );
+    assert_eq!(outcomes[25].state, StrategyStatus::Flat);
+    assert!(engine.state().state().groups.is_empty());
+}
@@ -5,7 +5,7 @@ use chrono::{TimeZone, Utc};
 use rust_decimal_macros::dec;
 use serde_json::json;
 
-use eth_btc_strategy::config::{PriceField, Symbol};
+use eth_btc_strategy::config::{BarInterval, PairConfig, PriceField, Symbol};
 use eth_btc_strategy::data::{
     BookSource, DataError, HttpClient, HttpResponse, HyperliquidPriceSource, MockPriceSource,
     PriceBar, PriceFetcher, PriceSource, align_to_bar_close,
@@ -49,8 +49,8 @@ impl HttpClient for SequencedHttpClient {
 
 #[derive(Debug, Clone)]
 struct MismatchSource {
-    eth: PriceBar,
-    btc: PriceBar,
+    base: PriceBar,
+    quote: PriceBar,
 }
 
 #[derive(Default)]
@@ -73,8 +73,8 @@ impl PriceSource for MismatchSource {
         _timestamp: chrono::DateTime<chrono::Utc>,
     ) -> Result<PriceBar, DataError> {
         match symbol {
-            Symbol::EthPerp => Ok(self.eth.clone()),
-            Symbol::BtcPerp => Ok(self.btc.clone()),
+            Symbol::Base => Ok(self.base.clone()),
+            Symbol::Quote => Ok(self.quote.clone()),
         }
     }
 
@@ -91,14 +91,32 @@ impl PriceSource for MismatchSource {
 #[test]
 fn aligns_to_bar_close_on_15m_boundary() {
     let timestamp = Utc.with_ymd_and_hms(2024, 1, 1, 12, 7, 30).unwrap();
-    let aligned = align_to_bar_close(timestamp).expect("aligned timestamp");
+    let aligned = align_to_bar_close(timestamp, BarInterval::M15).expect("aligned timestamp");
     assert_eq!(aligned, Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap());
 
     let exact = Utc.with_ymd_and_hms(2024, 1, 1, 12, 15, 0).unwrap();
-    let aligned_exact = align_to_bar_close(exact).expect("aligned timestamp");
+    let aligned_exact = align_to_bar_close(exact, BarInterval::M15).expect("aligned timestamp");
     assert_eq!(aligned_exact, exact);
 }
 
+#[test]
+fn aligns_to_bar_close_on_the_configured_interval() {
+    let timestamp = Utc.with_ymd_and_hms(2024, 1, 1, 14, 37, 30).unwrap();
+    let align = |interval| align_to_bar_close(timestamp, interval).expect("aligned timestamp");
+    assert_eq!(
+        align(BarInterval::M5),
+        Utc.with_ymd_and_hms(2024, 1, 1, 14, 35, 0).unwrap()
+    );
+    assert_eq!(
+        align(BarInterval::H1),
+        Utc.with_ymd_and_hms(2024, 1, 1, 14, 0, 0).unwrap()
+    );
+    assert_eq!(
+        align(BarInterval::H4),
+        Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap()
+    );
+}
+
 #[tokio::test]
 async fn hyperliquid_price_source_fetch_bar_parses_response() {
     let timestamp = Utc.with_ymd_and_hms(2024, 1, 1, 0, 15, 0).unwrap();
@@ -133,13 +151,104 @@ async fn hyperliquid_price_source_fetch_bar_parses_response() {
 
     let source =
         HyperliquidPriceSource::with_client("http://localhost".to_string(), Arc::new(client));
-    let bar = source.fetch_bar(Symbol::EthPerp, timestamp).await.unwrap();
+    let bar = source.fetch_bar(Symbol::Base, timestamp).await.unwrap();
 
-    assert_eq!(bar.symbol, Symbol::EthPerp);
+    assert_eq!(bar.symbol, Symbol::Base);
     assert_eq!(bar.timestamp, timestamp);
     assert_eq!(bar.mid, Some(dec!(99.9)));
     assert_eq!(bar.mark, Some(dec!(99.9)));
     assert_eq!(bar.close, Some(dec!(99.9)));
+    assert_eq!(bar.open, Some(dec!(100.0)));
+    assert_eq!(bar.high, Some(dec!(101.0)));
+    assert_eq!(bar.low, Some(dec!(99.0)));
+}
+
+#[tokio::test]
+async fn hyperliquid_price_source_requests_configured_pair_coin() {
+    let timestamp = Utc.with_ymd_and_hms(2024, 1, 1, 0, 15, 0).unwrap();
+    let start_ms = timestamp.timestamp_millis();
+    let end_ms = start_ms + 900_000;
+    let body = json!([
+        {
+            "t": start_ms,
+            "T": end_ms,
+            "o": "150.0",
+            "h": "151.0",
+            "l": "149.0",
+            "c": "150.5",
+            "v": "10.0"
+        }
+    ])
+    .to_string();
+
+    let client = TestHttpClient {
+        expected_url: "http://localhost/info".to_string(),
+        expected_body: json!({
+            "type": "candleSnapshot",
+            "req": {
+                "coin": "SOL",
+                "interval": "15m",
+                "startTime": start_ms,
+                "endTime": end_ms,
+            }
+        }),
+        response: HttpResponse { status: 200, body },
+    };
+
+    let source =
+        HyperliquidPriceSource::with_client("http://localhost".to_string(), Arc::new(client))
+            .with_pair(PairConfig {
+                base_coin: "SOL".to_string(),
+                quote_coin: "ETH".to_string(),
+            });
+    let bar = source.fetch_bar(Symbol::Base, timestamp).await.unwrap();
+
+    assert_eq!(bar.symbol, Symbol::Base);
+    assert_eq!(bar.mid, Some(dec!(150.5)));
+}
+
+#[tokio::test]
+async fn hyperliquid_price_source_requests_configured_interval() {
+    let timestamp = Utc.with_ymd_and_hms(2024, 1, 1, 5, 20, 0).unwrap();
+    let start_ms = Utc
+        .with_ymd_and_hms(2024, 1, 1, 4, 0, 0)
+        .unwrap()
+        .timestamp_millis();
+    let end_ms = start_ms + 14_400_000;
+    let body = json!([
+        {
+            "t": start_ms,
+            "T": end_ms,
+            "o": "100.0",
+            "h": "101.0",
+            "l": "99.0",
+            "c": "100.5",
+            "v": "10.0"
+        }
+    ])
+    .to_string();
+
+    let client = TestHttpClient {
+        expected_url: "http://localhost/info".to_string(),
+        expected_body: json!({
+            "type": "candleSnapshot",
+            "req": {
+                "coin": "ETH",
+                "interval": "4h",
+                "startTime": start_ms,
+                "endTime": end_ms,
+            }
+        }),
+        response: HttpResponse { status: 200, body },
+    };
+
+    let source =
+        HyperliquidPriceSource::with_client("http://localhost".to_string(), Arc::new(client))
+            .with_interval(BarInterval::H4);
+    let bar = source.fetch_bar(Symbol::Base, timestamp).await.unwrap();
+
+    assert_eq!(bar.timestamp.timestamp_millis(), start_ms);
+    assert_eq!(bar.mid, Some(dec!(100.5)));
 }
 
 #[tokio::test]
@@ -166,10 +275,7 @@ async fn hyperliquid_price_source_handles_rate_limits() {
 
     let source =
         HyperliquidPriceSource::with_client("http://localhost".to_string(), Arc::new(client));
-    let err = source
-        .fetch_bar(Symbol::EthPerp, timestamp)
-        .await
-        .unwrap_err();
+    let err = source.fetch_bar(Symbol::Base, timestamp).await.unwrap_err();
     assert!(matches!(err, DataError::RateLimited));
 }
 
@@ -193,9 +299,9 @@ async fn hyperliquid_price_source_fetch_book_parses_best_levels() {
 
     let source =
         HyperliquidPriceSource::with_client("http://localhost".to_string(), Arc::new(client));
-    let book = source.fetch_book(Symbol::EthPerp).await.unwrap();
+    let book = source.fetch_book(Symbol::Base).await.unwrap();
 
-    assert_eq!(book.symbol, Symbol::EthPerp);
+    assert_eq!(book.symbol, Symbol::Base);
     assert_eq!(book.best_bid, dec!(99));
     assert_eq!(book.best_ask, dec!(101));
     assert_eq!(book.bid_size, dec!(2));
@@ -241,7 +347,7 @@ async fn hyperliquid_price_source_applies_rate_limiter() {
         Arc::new(client),
         limiter.clone(),
     );
-    source.fetch_bar(Symbol::EthPerp, timestamp).await.unwrap();
+    source.fetch_bar(Symbol::Base, timestamp).await.unwrap();
 
     assert_eq!(limiter.calls.load(Ordering::SeqCst), 1);
 }
@@ -268,10 +374,7 @@ async fn hyperliquid_price_source_handles_missing_data() {
 
     let source =
         HyperliquidPriceSource::with_client("http://localhost".to_string(), Arc::new(client));
-    let err = source
-        .fetch_bar(Symbol::EthPerp, timestamp)
-        .await
-        .unwrap_err();
+    let err = source.fetch_bar(Symbol::Base, timestamp).await.unwrap_err();
     assert!(matches!(err, DataError::MissingData(_)));
 }
 
@@ -355,7 +458,7 @@ async fn hyperliquid_price_source_fetch_history_paginates() {
     );
 
     let bars = source
-        .fetch_history(Symbol::EthPerp, start, end)
+        .fetch_history(Symbol::Base, start, end)
         .await
         .unwrap();
 
@@ -372,14 +475,14 @@ async fn price_fetcher_resolves_prices_with_fallback() {
     let timestamp = Utc.with_ymd_and_hms(2024, 1, 1, 1, 0, 0).unwrap();
 
     source.insert_bar(PriceBar::new(
-        Symbol::EthPerp,
+        Symbol::Base,
         timestamp,
         Some(dec!(100.0)),
         None,
         None,
     ));
     source.insert_bar(PriceBar::new(
-        Symbol::BtcPerp,
+        Symbol::Quote,
         timestamp,
         Some(dec!(200.0)),
         Some(dec!(201.0)),
@@ -389,8 +492,8 @@ async fn price_fetcher_resolves_prices_with_fallback() {
     let fetcher = PriceFetcher::new(Arc::new(source), PriceField::Mark);
     let snapshot = fetcher.fetch_pair_prices(timestamp).await.unwrap();
 
-    assert_eq!(snapshot.eth, dec!(100.0));
-    assert_eq!(snapshot.btc, dec!(201.0));
+    assert_eq!(snapshot.base, dec!(100.0));
+    assert_eq!(snapshot.quote, dec!(201.0));
     assert_eq!(snapshot.field, PriceField::Mark);
 }
 
@@ -399,8 +502,8 @@ async fn price_fetcher_returns_missing_when_fields_unavailable() {
     let mut source = MockPriceSource::default();
     let timestamp = Utc.with_ymd_and_hms(2024, 1, 1, 2, 0, 0).unwrap();
 
-    source.insert_bar(PriceBar::new(Symbol::EthPerp, timestamp, None, None, None));
-    source.insert_bar(PriceBar::new(Symbol::BtcPerp, timestamp, None, None, None));
+    source.insert_bar(PriceBar::new(Symbol::Base, timestamp, None, None, None));
+    source.insert_bar(PriceBar::new(Symbol::Quote, timestamp, None, None, None));
 
     let fetcher = PriceFetcher::new(Arc::new(source), PriceField::Mid);
     let err = fetcher.fetch_pair_prices(timestamp).await.unwrap_err();
@@ -412,9 +515,9 @@ async fn price_fetcher_returns_missing_when_fields_unavailable() {
 async fn price_fetcher_rejects_mismatched_timestamps() {
     let timestamp = Utc.with_ymd_and_hms(2024, 1, 1, 3, 0, 0).unwrap();
     let source = MismatchSource {
-        eth: PriceBar::new(Symbol::EthPerp, timestamp, Some(dec!(100.0)), None, None),
-        btc: PriceBar::new(
-            Symbol::BtcPerp,
+        base: PriceBar::new(Symbol::Base, timestamp, Some(dec!(100.0)), None, None),
+        quote: PriceBar::new(
+            Symbol::Quote,
             Utc.with_ymd_and_hms(2024, 1, 1, 3, 15, 0).unwrap(),
             Some(dec!(200.0)),
             None,
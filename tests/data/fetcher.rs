@@ -11,28 +11,22 @@ async fn price_fetcher_falls_back_to_last_snapshot_when_all_prices_missing() {
     let t0 = Utc.timestamp_opt(0, 0).unwrap();
     let t1 = Utc.timestamp_opt(900, 0).unwrap();
     let mut source = MockPriceSource::default();
+    source.insert_bar(PriceBar::new(Symbol::Base, t0, Some(dec!(100)), None, None));
     source.insert_bar(PriceBar::new(
-        Symbol::EthPerp,
-        t0,
-        Some(dec!(100)),
-        None,
-        None,
-    ));
-    source.insert_bar(PriceBar::new(
-        Symbol::BtcPerp,
+        Symbol::Quote,
         t0,
         Some(dec!(200)),
         None,
         None,
     ));
-    source.insert_bar(PriceBar::new(Symbol::EthPerp, t1, None, None, None));
-    source.insert_bar(PriceBar::new(Symbol::BtcPerp, t1, None, None, None));
+    source.insert_bar(PriceBar::new(Symbol::Base, t1, None, None, None));
+    source.insert_bar(PriceBar::new(Symbol::Quote, t1, None, None, None));
 
     let fetcher = PriceFetcher::new(Arc::new(source), PriceField::Mid);
 
     let first = fetcher.fetch_pair_prices(t0).await.unwrap();
     let fallback = fetcher.fetch_pair_prices(t1).await.unwrap();
 
-    assert_eq!(fallback.eth, first.eth);
-    assert_eq!(fallback.btc, first.btc);
+    assert_eq!(fallback.base, first.base);
+    assert_eq!(fallback.quote, first.quote);
 }
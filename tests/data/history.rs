@@ -22,8 +22,8 @@ fn price_history_set_tracks_multiple_windows() {
         let ts = 100 + i;
         history
             .push_pair(
-                bar(Symbol::EthPerp, ts, 100 + i),
-                bar(Symbol::BtcPerp, ts, 200 + i),
+                bar(Symbol::Base, ts, 100 + i),
+                bar(Symbol::Quote, ts, 200 + i),
             )
             .unwrap();
     }
@@ -32,21 +32,21 @@ fn price_history_set_tracks_multiple_windows() {
     assert!(history.is_warmed_up(PriceWindow::Volatility));
     assert!(history.is_warmed_up(PriceWindow::SigmaQuantile));
 
-    let eth_z: Vec<_> = history
-        .window(Symbol::EthPerp, PriceWindow::ZScore)
+    let base_z: Vec<_> = history
+        .window(Symbol::Base, PriceWindow::ZScore)
         .cloned()
         .collect();
-    assert_eq!(eth_z.len(), 3);
-    assert_eq!(eth_z[0].timestamp, Utc.timestamp_opt(103, 0).unwrap());
-    assert_eq!(eth_z[2].timestamp, Utc.timestamp_opt(105, 0).unwrap());
+    assert_eq!(base_z.len(), 3);
+    assert_eq!(base_z[0].timestamp, Utc.timestamp_opt(103, 0).unwrap());
+    assert_eq!(base_z[2].timestamp, Utc.timestamp_opt(105, 0).unwrap());
 
-    let btc_vol: Vec<_> = history
-        .window(Symbol::BtcPerp, PriceWindow::Volatility)
+    let quote_vol: Vec<_> = history
+        .window(Symbol::Quote, PriceWindow::Volatility)
         .cloned()
         .collect();
-    assert_eq!(btc_vol.len(), 5);
-    assert_eq!(btc_vol[0].timestamp, Utc.timestamp_opt(101, 0).unwrap());
-    assert_eq!(btc_vol[4].timestamp, Utc.timestamp_opt(105, 0).unwrap());
+    assert_eq!(quote_vol.len(), 5);
+    assert_eq!(quote_vol[0].timestamp, Utc.timestamp_opt(101, 0).unwrap());
+    assert_eq!(quote_vol[4].timestamp, Utc.timestamp_opt(105, 0).unwrap());
 }
 
 #[test]
@@ -55,10 +55,10 @@ fn price_history_set_rejects_out_of_order_timestamps() {
     let ts = 100;
 
     history
-        .push_pair(bar(Symbol::EthPerp, ts, 100), bar(Symbol::BtcPerp, ts, 200))
+        .push_pair(bar(Symbol::Base, ts, 100), bar(Symbol::Quote, ts, 200))
         .unwrap();
 
-    let err = history.push_pair(bar(Symbol::EthPerp, ts, 101), bar(Symbol::BtcPerp, ts, 201));
+    let err = history.push_pair(bar(Symbol::Base, ts, 101), bar(Symbol::Quote, ts, 201));
     assert!(matches!(err, Err(DataError::InvalidTimestamp(_))));
 }
 
@@ -66,9 +66,6 @@ fn price_history_set_rejects_out_of_order_timestamps() {
 fn price_history_set_rejects_mismatched_symbols_or_times() {
     let mut history = PriceHistorySet::new(2, 3, 4).unwrap();
 
-    let err = history.push_pair(
-        bar(Symbol::EthPerp, 100, 100),
-        bar(Symbol::BtcPerp, 101, 200),
-    );
+    let err = history.push_pair(bar(Symbol::Base, 100, 100), bar(Symbol::Quote, 101, 200));
     assert!(matches!(err, Err(DataError::InconsistentData(_))));
 }
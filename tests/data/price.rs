@@ -7,7 +7,7 @@ use eth_btc_strategy::data::{DataError, MockPriceSource, PriceBar, PriceHistory,
 #[test]
 fn price_field_fallback_chain() {
     let bar = PriceBar::new(
-        Symbol::EthPerp,
+        Symbol::Base,
         Utc.timestamp_opt(0, 0).unwrap(),
         Some(dec!(100.0)),
         Some(dec!(101.0)),
@@ -16,7 +16,7 @@ fn price_field_fallback_chain() {
     assert_eq!(bar.effective_price(PriceField::Mid), Some(dec!(100.0)));
 
     let bar = PriceBar::new(
-        Symbol::EthPerp,
+        Symbol::Base,
         Utc.timestamp_opt(0, 0).unwrap(),
         None,
         Some(dec!(101.0)),
@@ -25,7 +25,7 @@ fn price_field_fallback_chain() {
     assert_eq!(bar.effective_price(PriceField::Mid), Some(dec!(101.0)));
 
     let bar = PriceBar::new(
-        Symbol::EthPerp,
+        Symbol::Base,
         Utc.timestamp_opt(0, 0).unwrap(),
         None,
         None,
@@ -34,7 +34,7 @@ fn price_field_fallback_chain() {
     assert_eq!(bar.effective_price(PriceField::Mid), Some(dec!(102.0)));
 
     let bar = PriceBar::new(
-        Symbol::EthPerp,
+        Symbol::Base,
         Utc.timestamp_opt(0, 0).unwrap(),
         None,
         None,
@@ -46,7 +46,7 @@ fn price_field_fallback_chain() {
 #[test]
 fn price_bar_validation_rejects_non_positive_prices() {
     let bar = PriceBar::new(
-        Symbol::EthPerp,
+        Symbol::Base,
         Utc.timestamp_opt(0, 0).unwrap(),
         Some(dec!(0.0)),
         None,
@@ -55,7 +55,7 @@ fn price_bar_validation_rejects_non_positive_prices() {
     assert!(matches!(bar.validate(), Err(DataError::InvalidPrice(_))));
 
     let bar = PriceBar::new(
-        Symbol::EthPerp,
+        Symbol::Base,
         Utc.timestamp_opt(0, 0).unwrap(),
         Some(dec!(-1.0)),
         None,
@@ -68,21 +68,21 @@ fn price_bar_validation_rejects_non_positive_prices() {
 fn price_history_ring_buffer_and_warmup() {
     let mut history = PriceHistory::new(2);
     let bar1 = PriceBar::new(
-        Symbol::EthPerp,
+        Symbol::Base,
         Utc.timestamp_opt(1, 0).unwrap(),
         Some(dec!(100.0)),
         None,
         None,
     );
     let bar2 = PriceBar::new(
-        Symbol::EthPerp,
+        Symbol::Base,
         Utc.timestamp_opt(2, 0).unwrap(),
         Some(dec!(101.0)),
         None,
         None,
     );
     let bar3 = PriceBar::new(
-        Symbol::EthPerp,
+        Symbol::Base,
         Utc.timestamp_opt(3, 0).unwrap(),
         Some(dec!(102.0)),
         None,
@@ -105,18 +105,18 @@ fn price_history_ring_buffer_and_warmup() {
 async fn mock_price_source_fetch_behaviors() {
     let mut source = MockPriceSource::default();
     let timestamp = Utc.timestamp_opt(10, 0).unwrap();
-    let bar = PriceBar::new(Symbol::BtcPerp, timestamp, Some(dec!(200.0)), None, None);
+    let bar = PriceBar::new(Symbol::Quote, timestamp, Some(dec!(200.0)), None, None);
     source.insert_bar(bar.clone());
 
-    let fetched = source.fetch_bar(Symbol::BtcPerp, timestamp).await.unwrap();
+    let fetched = source.fetch_bar(Symbol::Quote, timestamp).await.unwrap();
     assert_eq!(fetched, bar);
 
     let missing = source
-        .fetch_bar(Symbol::BtcPerp, Utc.timestamp_opt(11, 0).unwrap())
+        .fetch_bar(Symbol::Quote, Utc.timestamp_opt(11, 0).unwrap())
         .await;
     assert!(matches!(missing, Err(DataError::MissingData(_))));
 
-    source.insert_error(Symbol::BtcPerp, timestamp, DataError::RateLimited);
-    let rate_limited = source.fetch_bar(Symbol::BtcPerp, timestamp).await;
+    source.insert_error(Symbol::Quote, timestamp, DataError::RateLimited);
+    let rate_limited = source.fetch_bar(Symbol::Quote, timestamp).await;
     assert!(matches!(rate_limited, Err(DataError::RateLimited)));
 }
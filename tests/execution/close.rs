@@ -52,14 +52,14 @@ impl eth_btc_strategy::execution::OrderExecutor for RecordingExecutor {
 #[tokio::test]
 async fn close_pair_closes_both_legs() {
     let mut executor = MockOrderExecutor::default();
-    executor.push_close_response(Symbol::EthPerp, Ok(dec!(1)));
-    executor.push_close_response(Symbol::BtcPerp, Ok(dec!(1)));
+    executor.push_close_response(Symbol::Base, Ok(dec!(1)));
+    executor.push_close_response(Symbol::Quote, Ok(dec!(1)));
 
     let engine = ExecutionEngine::new(std::sync::Arc::new(executor), RetryConfig::fast());
     let result = engine
         .close_pair(
-            order(Symbol::EthPerp, OrderSide::Buy),
-            order(Symbol::BtcPerp, OrderSide::Sell),
+            order(Symbol::Base, OrderSide::Buy),
+            order(Symbol::Quote, OrderSide::Sell),
         )
         .await;
 
@@ -69,20 +69,20 @@ async fn close_pair_closes_both_legs() {
 #[tokio::test]
 async fn close_pair_rolls_back_on_second_leg_failure() {
     let mut executor = MockOrderExecutor::default();
-    executor.push_close_response(Symbol::EthPerp, Ok(dec!(1)));
+    executor.push_close_response(Symbol::Base, Ok(dec!(1)));
     executor.push_close_response(
-        Symbol::BtcPerp,
+        Symbol::Quote,
         Err(eth_btc_strategy::execution::ExecutionError::Fatal(
             "btc close failed".to_string(),
         )),
     );
-    executor.push_submit_response(Symbol::EthPerp, Ok(dec!(1)));
+    executor.push_submit_response(Symbol::Base, Ok(dec!(1)));
 
     let engine = ExecutionEngine::new(std::sync::Arc::new(executor), RetryConfig::fast());
     let result = engine
         .close_pair(
-            order(Symbol::EthPerp, OrderSide::Buy),
-            order(Symbol::BtcPerp, OrderSide::Sell),
+            order(Symbol::Base, OrderSide::Buy),
+            order(Symbol::Quote, OrderSide::Sell),
         )
         .await;
 
@@ -114,8 +114,8 @@ async fn close_pair_rolls_back_with_filled_qty() {
     let engine = ExecutionEngine::new(executor.clone(), RetryConfig::fast());
     let result = engine
         .close_pair(
-            order(Symbol::EthPerp, OrderSide::Buy),
-            order(Symbol::BtcPerp, OrderSide::Sell),
+            order(Symbol::Base, OrderSide::Buy),
+            order(Symbol::Quote, OrderSide::Sell),
         )
         .await;
 
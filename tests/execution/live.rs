@@ -95,7 +95,7 @@ impl OrderHttpClient for MockOrderHttpClient {
 
 fn order() -> OrderRequest {
     OrderRequest {
-        symbol: Symbol::EthPerp,
+        symbol: Symbol::Base,
         side: OrderSide::Buy,
         qty: dec!(1.25),
         order_type: OrderType::Market,
@@ -196,7 +196,7 @@ async fn live_executor_posts_post_only_order_with_expiry_and_resting_oid() {
     let executor = signed_executor(client.clone());
     let resting = executor
         .submit_result(&OrderRequest {
-            symbol: Symbol::EthPerp,
+            symbol: Symbol::Base,
             side: OrderSide::Buy,
             qty: dec!(1.25),
             order_type: OrderType::PostOnly,
@@ -239,7 +239,7 @@ async fn live_executor_signs_expires_after_into_post_only_order() {
     let executor = signed_executor(client.clone());
     executor
         .submit_result(&OrderRequest {
-            symbol: Symbol::EthPerp,
+            symbol: Symbol::Base,
             side: OrderSide::Buy,
             qty: dec!(1.25),
             order_type: OrderType::PostOnly,
@@ -250,7 +250,7 @@ async fn live_executor_signs_expires_after_into_post_only_order() {
         .unwrap();
     executor
         .submit_result(&OrderRequest {
-            symbol: Symbol::EthPerp,
+            symbol: Symbol::Base,
             side: OrderSide::Buy,
             qty: dec!(1.25),
             order_type: OrderType::PostOnly,
@@ -281,7 +281,7 @@ async fn live_executor_posts_cancel_payload() {
     });
 
     let executor = signed_executor(client.clone());
-    executor.cancel(Symbol::EthPerp, 42).await.unwrap();
+    executor.cancel(Symbol::Base, 42).await.unwrap();
 
     let requests = client.requests.lock().expect("requests lock");
     let recorded = requests.last().expect("recorded request");
@@ -326,7 +326,7 @@ async fn live_executor_updates_leverage_before_order() {
 }
 
 #[tokio::test]
-async fn live_executor_updates_leverage_before_btc_order() {
+async fn live_executor_updates_leverage_before_quote_order() {
     let client = std::sync::Arc::new(MockOrderHttpClient::default());
     client.push_response(OrderHttpResponse {
         status: 200,
@@ -347,7 +347,7 @@ async fn live_executor_updates_leverage_before_btc_order() {
     let executor = signed_executor(client.clone()).with_leverage_config(2, false);
     let filled = executor
         .submit(&OrderRequest {
-            symbol: Symbol::BtcPerp,
+            symbol: Symbol::Quote,
             side: OrderSide::Sell,
             qty: dec!(0.01),
             order_type: OrderType::Market,
@@ -17,7 +17,7 @@ fn order(symbol: Symbol, side: OrderSide, qty: rust_decimal::Decimal) -> OrderRe
 #[tokio::test]
 async fn paper_executor_echoes_qty() {
     let executor = PaperOrderExecutor;
-    let order = order(Symbol::EthPerp, OrderSide::Buy, dec!(1.25));
+    let order = order(Symbol::Base, OrderSide::Buy, dec!(1.25));
 
     let submitted = executor.submit(&order).await.unwrap();
     let closed = executor.close(&order).await.unwrap();
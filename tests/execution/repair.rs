@@ -9,14 +9,14 @@ use eth_btc_strategy::state::{PositionLeg, PositionSnapshot};
 #[tokio::test]
 async fn residual_repair_closes_remaining_leg() {
     let position = PositionSnapshot {
-        direction: TradeDirection::LongEthShortBtc,
+        direction: TradeDirection::LongBaseShortQuote,
         entry_time: Utc.timestamp_opt(0, 0).unwrap(),
-        eth: PositionLeg {
+        base: PositionLeg {
             qty: dec!(1),
             avg_price: dec!(100),
             notional: dec!(100),
         },
-        btc: PositionLeg {
+        quote: PositionLeg {
             qty: dec!(0),
             avg_price: dec!(200),
             notional: dec!(0),
@@ -24,7 +24,7 @@ async fn residual_repair_closes_remaining_leg() {
     };
 
     let mut executor = MockOrderExecutor::default();
-    executor.push_close_response(Symbol::EthPerp, Ok(dec!(1)));
+    executor.push_close_response(Symbol::Base, Ok(dec!(1)));
 
     let engine = ExecutionEngine::new(std::sync::Arc::new(executor), RetryConfig::fast());
     let result = engine.repair_residual(&position).await;
@@ -37,8 +37,8 @@ impl OrderExecutor for RestingThenFailingExecutor {
         order: &OrderRequest,
     ) -> Result<OrderSubmitResult, ExecutionError> {
         match order.symbol {
-            Symbol::EthPerp => Ok(OrderSubmitResult::Resting { oid: 42 }),
-            Symbol::BtcPerp => Err(ExecutionError::Fatal(
+            Symbol::Base => Ok(OrderSubmitResult::Resting { oid: 42 }),
+            Symbol::Quote => Err(ExecutionError::Fatal(
                 "Post only order would have immediately matched".into(),
             )),
         }
@@ -62,18 +62,15 @@ fn retry_config_has_default() {
 #[tokio::test]
 async fn open_pair_retries_transient_failures() {
     let mut executor = MockOrderExecutor::default();
-    executor.push_submit_response(
-        Symbol::EthPerp,
-        Err(ExecutionError::Transient("temp".into())),
-    );
-    executor.push_submit_response(Symbol::EthPerp, Ok(dec!(1)));
-    executor.push_submit_response(Symbol::BtcPerp, Ok(dec!(1)));
+    executor.push_submit_response(Symbol::Base, Err(ExecutionError::Transient("temp".into())));
+    executor.push_submit_response(Symbol::Base, Ok(dec!(1)));
+    executor.push_submit_response(Symbol::Quote, Ok(dec!(1)));
 
     let engine = ExecutionEngine::new(std::sync::Arc::new(executor), RetryConfig::fast());
     let result = engine
         .open_pair(
-            order(Symbol::EthPerp, OrderSide::Sell),
-            order(Symbol::BtcPerp, OrderSide::Buy),
+            order(Symbol::Base, OrderSide::Sell),
+            order(Symbol::Quote, OrderSide::Buy),
         )
         .await;
 
@@ -83,15 +80,15 @@ async fn open_pair_retries_transient_failures() {
 #[tokio::test]
 async fn open_pair_repairs_on_partial_fill() {
     let mut executor = MockOrderExecutor::default();
-    executor.push_submit_response(Symbol::EthPerp, Ok(dec!(1)));
-    executor.push_submit_response(Symbol::BtcPerp, Err(ExecutionError::Fatal("fail".into())));
-    executor.push_close_response(Symbol::EthPerp, Ok(dec!(1)));
+    executor.push_submit_response(Symbol::Base, Ok(dec!(1)));
+    executor.push_submit_response(Symbol::Quote, Err(ExecutionError::Fatal("fail".into())));
+    executor.push_close_response(Symbol::Base, Ok(dec!(1)));
 
     let engine = ExecutionEngine::new(std::sync::Arc::new(executor), RetryConfig::fast());
     let result = engine
         .open_pair(
-            order(Symbol::EthPerp, OrderSide::Sell),
-            order(Symbol::BtcPerp, OrderSide::Buy),
+            order(Symbol::Base, OrderSide::Sell),
+            order(Symbol::Quote, OrderSide::Buy),
         )
         .await;
 
@@ -101,18 +98,18 @@ async fn open_pair_repairs_on_partial_fill() {
 #[tokio::test]
 async fn open_pair_reports_rollback_failure() {
     let mut executor = MockOrderExecutor::default();
-    executor.push_submit_response(Symbol::EthPerp, Ok(dec!(1)));
-    executor.push_submit_response(Symbol::BtcPerp, Err(ExecutionError::Fatal("fail".into())));
+    executor.push_submit_response(Symbol::Base, Ok(dec!(1)));
+    executor.push_submit_response(Symbol::Quote, Err(ExecutionError::Fatal("fail".into())));
     executor.push_close_response(
-        Symbol::EthPerp,
+        Symbol::Base,
         Err(ExecutionError::Fatal("rollback close failed".into())),
     );
 
     let engine = ExecutionEngine::new(std::sync::Arc::new(executor), RetryConfig::fast());
     let result = engine
         .open_pair(
-            order(Symbol::EthPerp, OrderSide::Sell),
-            order(Symbol::BtcPerp, OrderSide::Buy),
+            order(Symbol::Base, OrderSide::Sell),
+            order(Symbol::Quote, OrderSide::Buy),
         )
         .await;
 
@@ -132,11 +129,11 @@ async fn open_pair_cancels_first_resting_leg_when_second_leg_fails() {
         .open_pair(
             OrderRequest {
                 order_type: OrderType::PostOnly,
-                ..order(Symbol::EthPerp, OrderSide::Sell)
+                ..order(Symbol::Base, OrderSide::Sell)
             },
             OrderRequest {
                 order_type: OrderType::PostOnly,
-                ..order(Symbol::BtcPerp, OrderSide::Buy)
+                ..order(Symbol::Quote, OrderSide::Buy)
             },
         )
         .await;
@@ -146,15 +143,15 @@ async fn open_pair_cancels_first_resting_leg_when_second_leg_fails() {
     );
     assert_eq!(
         executor.cancelled.lock().expect("cancel lock").as_slice(),
-        &[(Symbol::EthPerp, 42)]
+        &[(Symbol::Base, 42)]
     );
 }
 
 #[tokio::test]
 async fn retry_with_zero_max_attempts_still_attempts_once() {
     let mut executor = MockOrderExecutor::default();
-    executor.push_submit_response(Symbol::EthPerp, Ok(dec!(1)));
-    executor.push_submit_response(Symbol::BtcPerp, Ok(dec!(1)));
+    executor.push_submit_response(Symbol::Base, Ok(dec!(1)));
+    executor.push_submit_response(Symbol::Quote, Ok(dec!(1)));
 
     let engine = ExecutionEngine::new(
         std::sync::Arc::new(executor),
@@ -165,8 +162,8 @@ async fn retry_with_zero_max_attempts_still_attempts_once() {
     );
     let result = engine
         .open_pair(
-            order(Symbol::EthPerp, OrderSide::Sell),
-            order(Symbol::BtcPerp, OrderSide::Buy),
+            order(Symbol::Base, OrderSide::Sell),
+            order(Symbol::Quote, OrderSide::Buy),
         )
         .await;
 
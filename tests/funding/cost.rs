@@ -9,25 +9,25 @@ use eth_btc_strategy::funding::{
 
 #[test]
 fn funding_cost_estimate_accounts_for_direction_and_hold() {
-    let eth_rate = FundingRate {
-        symbol: Symbol::EthPerp,
+    let base_rate = FundingRate {
+        symbol: Symbol::Base,
         rate: dec!(0.01),
         timestamp: Utc.timestamp_opt(0, 0).unwrap(),
         interval_hours: 8,
     };
-    let btc_rate = FundingRate {
-        symbol: Symbol::BtcPerp,
+    let quote_rate = FundingRate {
+        symbol: Symbol::Quote,
         rate: dec!(0.005),
         timestamp: Utc.timestamp_opt(0, 0).unwrap(),
         interval_hours: 8,
     };
 
     let estimate = estimate_funding_cost(
-        TradeDirection::LongEthShortBtc,
+        TradeDirection::LongBaseShortQuote,
         dec!(100),
         dec!(100),
-        &eth_rate,
-        &btc_rate,
+        &base_rate,
+        &quote_rate,
         16,
     )
     .unwrap();
@@ -44,16 +44,17 @@ fn funding_controls_filter_threshold_and_size() {
         interval_hours: 8,
     };
 
-    let mut config = FundingConfig::default();
-    config.modes = vec![
-        FundingMode::Filter,
-        FundingMode::Threshold,
-        FundingMode::Size,
-    ];
-    config.funding_cost_threshold = Some(dec!(1.0));
-    config.funding_threshold_k = Some(dec!(5.0));
-    config.funding_size_alpha = Some(dec!(5.0));
-    config.c_min_ratio = Some(dec!(0.5));
+    let config = FundingConfig {
+        modes: vec![
+            FundingMode::Filter,
+            FundingMode::Threshold,
+            FundingMode::Size,
+        ],
+        funding_cost_threshold: Some(dec!(1.0)),
+        funding_threshold_k: Some(dec!(5.0)),
+        funding_size_alpha: Some(dec!(5.0)),
+        c_min_ratio: Some(dec!(0.5)),
+    };
 
     let decision = apply_funding_controls(&config, dec!(1.5), dec!(100), &estimate).unwrap();
 
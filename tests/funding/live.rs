@@ -4,7 +4,7 @@ use chrono::{TimeZone, Utc};
 use rust_decimal_macros::dec;
 use serde_json::json;
 
-use eth_btc_strategy::config::Symbol;
+use eth_btc_strategy::config::{PairConfig, Symbol};
 use eth_btc_strategy::funding::{
     FundingError, FundingHttpClient, FundingHttpResponse, FundingSource, HyperliquidFundingSource,
 };
@@ -57,8 +57,109 @@ async fn hyperliquid_funding_source_fetch_rate_parses_response() {
     };
 
     let source = HyperliquidFundingSource::with_client("http://localhost", Arc::new(client));
-    let rate = source.fetch_rate(Symbol::EthPerp, timestamp).await.unwrap();
+    let rate = source.fetch_rate(Symbol::Base, timestamp).await.unwrap();
 
     assert_eq!(rate.rate, dec!(0.01));
     assert_eq!(rate.interval_hours, 1);
 }
+
+#[tokio::test]
+async fn hyperliquid_funding_source_reads_configured_pair_coins() {
+    let timestamp = Utc.timestamp_opt(0, 0).unwrap();
+    let body = json!({
+        "data": [
+            {
+                "universe": [
+                    {"name": "BTC"},
+                    {"name": "ETH"},
+                    {"name": "SOL"}
+                ]
+            },
+            [
+                {"funding": "0.03"},
+                {"funding": "0.02"},
+                {"funding": "0.01"}
+            ]
+        ]
+    })
+    .to_string();
+
+    let client = TestHttpClient {
+        expected_url: "http://localhost/info".to_string(),
+        expected_body: json!({
+            "type": "metaAndAssetCtxs"
+        }),
+        response: FundingHttpResponse { status: 200, body },
+    };
+
+    let source = HyperliquidFundingSource::with_client("http://localhost", Arc::new(client))
+        .with_pair(PairConfig {
+            base_coin: "SOL".to_string(),
+            quote_coin: "ETH".to_string(),
+        });
+
+    let base = source.fetch_rate(Symbol::Base, timestamp).await.unwrap();
+    let quote = source.fetch_rate(Symbol::Quote, timestamp).await.unwrap();
+
+    assert_eq!(base.rate, dec!(0.01));
+    assert_eq!(quote.rate, dec!(0.02));
+}
+
+/// Serves hourly `fundingHistory` entries 76ms past each hour, 500 per page.
+#[derive(Debug, Default)]
+struct PagedHistoryClient {
+    requests: std::sync::Mutex<Vec<serde_json::Value>>,
+}
+
+#[async_trait::async_trait]
+impl FundingHttpClient for PagedHistoryClient {
+    async fn post(
+        &self,
+        _url: &str,
+        body: serde_json::Value,
+    ) -> Result<FundingHttpResponse, FundingError> {
+        self.requests.lock().unwrap().push(body.clone());
+        assert_eq!(body["type"], "fundingHistory");
+        assert_eq!(body["coin"], "BTC");
+        let start = body["startTime"].as_i64().unwrap();
+        let end = body["endTime"].as_i64().unwrap();
+        let hour = 3_600_000;
+        let first = (start - 76 + hour - 1).div_euclid(hour);
+        let entries: Vec<_> = (first..)
+            .map(|index| index * hour + 76)
+            .take_while(|time| *time <= end)
+            .take(500)
+            .map(|time| json!({"coin": "BTC", "fundingRate": "0.0000125", "premium": "0", "time": time}))
+            .collect();
+        Ok(FundingHttpResponse {
+            status: 200,
+            body: serde_json::Value::Array(entries).to_string(),
+        })
+    }
+}
+
+#[tokio::test]
+async fn hyperliquid_funding_history_paginates_and_aligns_to_the_hour() {
+    let client = Arc::new(PagedHistoryClient::default());
+    let source = HyperliquidFundingSource::with_client("http://localhost", client.clone());
+    let start = Utc.timestamp_opt(0, 0).unwrap();
+    let end = start + chrono::Duration::hours(600) + chrono::Duration::seconds(1);
+
+    let history = source
+        .fetch_history(Symbol::Quote, start, end)
+        .await
+        .unwrap();
+
+    assert_eq!(history.len(), 601);
+    assert_eq!(client.requests.lock().unwrap().len(), 2);
+    assert_eq!(history[0].timestamp, start);
+    assert_eq!(history[600].timestamp, start + chrono::Duration::hours(600));
+    assert!(
+        history
+            .windows(2)
+            .all(|pair| pair[1].timestamp - pair[0].timestamp == chrono::Duration::hours(1))
+    );
+    assert!(history.iter().all(|rate| rate.rate == dec!(0.0000125)
+        && rate.symbol == Symbol::Quote
+        && rate.interval_hours == 1));
+}
@@ -10,13 +10,13 @@ async fn funding_fetcher_returns_pair_rates() {
     let timestamp = Utc.timestamp_opt(0, 0).unwrap();
 
     source.insert_rate(FundingRate {
-        symbol: Symbol::EthPerp,
+        symbol: Symbol::Base,
         rate: dec!(0.01),
         timestamp,
         interval_hours: 8,
     });
     source.insert_rate(FundingRate {
-        symbol: Symbol::BtcPerp,
+        symbol: Symbol::Quote,
         rate: dec!(0.005),
         timestamp,
         interval_hours: 8,
@@ -25,7 +25,7 @@ async fn funding_fetcher_returns_pair_rates() {
     let fetcher = FundingFetcher::new(std::sync::Arc::new(source));
     let snapshot = fetcher.fetch_pair_rates(timestamp).await.unwrap();
 
-    assert_eq!(snapshot.eth.rate, dec!(0.01));
-    assert_eq!(snapshot.btc.rate, dec!(0.005));
+    assert_eq!(snapshot.base.rate, dec!(0.01));
+    assert_eq!(snapshot.quote.rate, dec!(0.005));
     assert_eq!(snapshot.interval_hours, 8);
 }
@@ -1,7 +1,11 @@
+#[path = "indicators/kalman.rs"]
+mod kalman;
 #[path = "indicators/relative.rs"]
 mod relative;
 #[path = "indicators/sigma_floor.rs"]
 mod sigma_floor;
+#[path = "indicators/spread.rs"]
+mod spread;
 #[path = "indicators/volatility.rs"]
 mod volatility;
 #[path = "indicators/zscore.rs"]
@@ -0,0 +1,110 @@
+use chrono::{Duration, TimeZone, Utc};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+use eth_btc_strategy::config::{SpreadConfig, SpreadModel};
+use eth_btc_strategy::indicators::{KalmanHedgeFilter, KalmanState};
+
+fn kalman_config(warmup_bars: usize) -> SpreadConfig {
+    SpreadConfig {
+        model: SpreadModel::Kalman,
+        kalman_delta: dec!(0.0001),
+        kalman_observation_var: dec!(0.0001),
+        kalman_warmup_bars: warmup_bars,
+        ..SpreadConfig::default()
+    }
+}
+
+fn quote_path(len: usize) -> Vec<Decimal> {
+    (0..len)
+        .map(|idx| dec!(100) + Decimal::from((idx * 7) % 23) - dec!(11))
+        .collect()
+}
+
+#[test]
+fn kalman_filter_converges_to_hedge_ratio() {
+    let mut filter = KalmanHedgeFilter::new(&kalman_config(5)).unwrap();
+    let start = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+    let mut last = None;
+    // ETH = BTC^2 / 50, so ln(ETH) = 2 * ln(BTC) - ln(50).
+    for (idx, quote) in quote_path(400).into_iter().enumerate() {
+        let snapshot = filter
+            .update(
+                start + Duration::minutes(15 * idx as i64),
+                quote * quote / dec!(50),
+                quote,
+            )
+            .unwrap()
+            .unwrap();
+        if idx < 5 {
+            assert!(snapshot.zscore.is_none());
+        } else {
+            assert!(snapshot.zscore.is_some());
+        }
+        last = Some(snapshot);
+    }
+
+    let snapshot = last.unwrap();
+    assert!(
+        (snapshot.beta - dec!(2)).abs() < dec!(0.05),
+        "beta {}",
+        snapshot.beta
+    );
+    assert!(snapshot.innovation_std > Decimal::ZERO);
+    assert!(snapshot.covariance[0][0] > Decimal::ZERO);
+    assert!(snapshot.covariance[1][1] > Decimal::ZERO);
+    assert_eq!(filter.state().updates, 400);
+}
+
+#[test]
+fn kalman_filter_restores_checkpoint_and_skips_applied_bars() {
+    let config = kalman_config(2);
+    let start = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+    let bars: Vec<_> = quote_path(20)
+        .into_iter()
+        .enumerate()
+        .map(|(idx, quote)| {
+            (
+                start + Duration::minutes(15 * idx as i64),
+                quote * dec!(0.05),
+                quote,
+            )
+        })
+        .collect();
+
+    let mut original = KalmanHedgeFilter::new(&config).unwrap();
+    for (timestamp, base, quote) in &bars[..10] {
+        original.update(*timestamp, *base, *quote).unwrap();
+    }
+    let checkpoint: KalmanState =
+        serde_json::from_str(&serde_json::to_string(original.state()).unwrap()).unwrap();
+    assert_eq!(&checkpoint, original.state());
+
+    let mut restored = KalmanHedgeFilter::new(&config).unwrap();
+    restored.restore(checkpoint);
+    // Warm-up history overlapping the checkpoint is not applied twice.
+    for (timestamp, base, quote) in &bars[5..10] {
+        assert!(
+            restored
+                .update(*timestamp, *base, *quote)
+                .unwrap()
+                .is_none()
+        );
+    }
+    for (timestamp, base, quote) in &bars[10..] {
+        let expected = original.update(*timestamp, *base, *quote).unwrap().unwrap();
+        let actual = restored.update(*timestamp, *base, *quote).unwrap().unwrap();
+        assert_eq!(actual.residual, expected.residual);
+        assert_eq!(actual.zscore, expected.zscore);
+    }
+    assert_eq!(restored.state(), original.state());
+}
+
+#[test]
+fn kalman_filter_rejects_invalid_delta() {
+    let config = SpreadConfig {
+        kalman_delta: Decimal::ONE,
+        ..kalman_config(2)
+    };
+    assert!(KalmanHedgeFilter::new(&config).is_err());
+}
@@ -14,10 +14,10 @@ fn assert_close(
 
 #[test]
 fn relative_price_uses_log_ratio() {
-    let eth = dec!(200);
-    let btc = dec!(100);
-    let r = relative_price(eth, btc).unwrap();
-    let expected = (eth / btc).ln();
+    let base = dec!(200);
+    let quote = dec!(100);
+    let r = relative_price(base, quote).unwrap();
+    let expected = (base / quote).ln();
     assert_close(r, expected, dec!(0.0000001));
 }
 
@@ -16,9 +16,11 @@ fn assert_close(
 
 #[test]
 fn sigma_floor_const_returns_configured_value() {
-    let mut config = SigmaFloorConfig::default();
-    config.mode = SigmaFloorMode::Const;
-    config.sigma_floor_const = dec!(0.5);
+    let config = SigmaFloorConfig {
+        mode: SigmaFloorMode::Const,
+        sigma_floor_const: dec!(0.5),
+        ..SigmaFloorConfig::default()
+    };
 
     let mut calc = SigmaFloorCalculator::new(config, 1).unwrap();
     let floor = calc.update(dec!(0.1), &[dec!(1.0)]).unwrap();
@@ -28,10 +30,12 @@ fn sigma_floor_const_returns_configured_value() {
 
 #[test]
 fn sigma_floor_quantile_uses_history_window() {
-    let mut config = SigmaFloorConfig::default();
-    config.mode = SigmaFloorMode::Quantile;
-    config.sigma_floor_quantile_window = 3;
-    config.sigma_floor_quantile_p = dec!(0.1);
+    let config = SigmaFloorConfig {
+        mode: SigmaFloorMode::Quantile,
+        sigma_floor_quantile_window: 3,
+        sigma_floor_quantile_p: dec!(0.1),
+        ..SigmaFloorConfig::default()
+    };
 
     let mut calc = SigmaFloorCalculator::new(config, 1).unwrap();
     assert!(calc.update(dec!(0.1), &[]).is_none());
@@ -71,11 +75,13 @@ fn ewma_std_uses_decimal_decay() {
 
 #[test]
 fn sigma_floor_ewma_mix_uses_max_floor() {
-    let mut config = SigmaFloorConfig::default();
-    config.mode = SigmaFloorMode::EwmaMix;
-    config.sigma_floor_quantile_window = 2;
-    config.sigma_floor_quantile_p = dec!(0.5);
-    config.ewma_half_life = 1;
+    let config = SigmaFloorConfig {
+        mode: SigmaFloorMode::EwmaMix,
+        sigma_floor_quantile_window: 2,
+        sigma_floor_quantile_p: dec!(0.5),
+        ewma_half_life: 1,
+        ..SigmaFloorConfig::default()
+    };
 
     let mut calc = SigmaFloorCalculator::new(config, 1).unwrap();
     assert!(calc.update(dec!(0.1), &[dec!(1.0), dec!(2.0)]).is_none());
@@ -0,0 +1,72 @@
+use rust_decimal::MathematicalOps;
+use rust_decimal_macros::dec;
+
+use eth_btc_strategy::config::{SpreadConfig, SpreadModel};
+use eth_btc_strategy::indicators::{SpreadCalculator, ols_alpha_beta, relative_price};
+
+fn assert_close(
+    actual: rust_decimal::Decimal,
+    expected: rust_decimal::Decimal,
+    tol: rust_decimal::Decimal,
+) {
+    let diff = (actual - expected).abs();
+    assert!(diff <= tol, "diff {diff} > tol {tol}");
+}
+
+#[test]
+fn ols_recovers_linear_relationship() {
+    let x = [dec!(1), dec!(2), dec!(3), dec!(4)];
+    let y = [dec!(1.5), dec!(3.5), dec!(5.5), dec!(7.5)];
+
+    let (alpha, beta) = ols_alpha_beta(&x, &y).unwrap();
+    assert_eq!(alpha, dec!(-0.5));
+    assert_eq!(beta, dec!(2));
+    assert!(ols_alpha_beta(&[dec!(1), dec!(1)], &[dec!(1), dec!(2)]).is_none());
+}
+
+#[test]
+fn fixed_spread_matches_relative_price() {
+    let mut calc = SpreadCalculator::new(&SpreadConfig::default()).unwrap();
+
+    let snapshot = calc.update(dec!(200), dec!(100)).unwrap();
+    assert_eq!(
+        snapshot.spread,
+        Some(relative_price(dec!(200), dec!(100)).unwrap())
+    );
+    assert!(snapshot.beta.is_none());
+}
+
+#[test]
+fn rolling_beta_spread_waits_for_lookback_then_returns_residual() {
+    let config = SpreadConfig {
+        model: SpreadModel::RollingBeta,
+        beta_lookback_bars: 4,
+        ..SpreadConfig::default()
+    };
+    let mut calc = SpreadCalculator::new(&config).unwrap();
+    // ETH = BTC^2 / 50, so ln(ETH) = 2 * ln(BTC) - ln(50).
+    let quote_prices = [dec!(100), dec!(110), dec!(95), dec!(120), dec!(105)];
+
+    for quote in &quote_prices[..4] {
+        let snapshot = calc.update(quote * quote / dec!(50), *quote).unwrap();
+        assert!(snapshot.spread.is_none());
+        assert!(snapshot.beta.is_none());
+    }
+
+    let quote = quote_prices[4];
+    let snapshot = calc.update(quote * quote / dec!(50), quote).unwrap();
+    assert_close(snapshot.beta.unwrap(), dec!(2), dec!(0.000001));
+    assert_close(snapshot.alpha.unwrap(), -dec!(50).ln(), dec!(0.000001));
+    assert_close(snapshot.spread.unwrap(), dec!(0), dec!(0.000001));
+}
+
+#[test]
+fn rolling_beta_spread_rejects_short_lookback() {
+    let config = SpreadConfig {
+        model: SpreadModel::RollingBeta,
+        beta_lookback_bars: 2,
+        ..SpreadConfig::default()
+    };
+
+    assert!(SpreadCalculator::new(&config).is_err());
+}
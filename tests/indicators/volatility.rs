@@ -14,23 +14,33 @@ fn assert_close(
 #[test]
 fn volatility_returns_none_until_warm() {
     let mut calc = VolatilityCalculator::new(3).unwrap();
-    assert!(calc.update(dec!(100), dec!(200)).unwrap().vol_eth.is_none());
-    assert!(calc.update(dec!(110), dec!(210)).unwrap().vol_eth.is_none());
+    assert!(
+        calc.update(dec!(100), dec!(200))
+            .unwrap()
+            .vol_base
+            .is_none()
+    );
+    assert!(
+        calc.update(dec!(110), dec!(210))
+            .unwrap()
+            .vol_base
+            .is_none()
+    );
 }
 
 #[test]
 fn volatility_of_constant_returns_is_zero() {
     let mut calc = VolatilityCalculator::new(3).unwrap();
     let prices = [dec!(100), dec!(110), dec!(121), dec!(133.1)];
-    let btc_prices = [dec!(200), dec!(220), dec!(242), dec!(266.2)];
+    let quote_prices = [dec!(200), dec!(220), dec!(242), dec!(266.2)];
 
     for i in 0..prices.len() {
-        let snapshot = calc.update(prices[i], btc_prices[i]).unwrap();
+        let snapshot = calc.update(prices[i], quote_prices[i]).unwrap();
         if i < 3 {
-            assert!(snapshot.vol_eth.is_none());
+            assert!(snapshot.vol_base.is_none());
         } else {
-            assert_close(snapshot.vol_eth.unwrap(), dec!(0), dec!(0.000001));
-            assert_close(snapshot.vol_btc.unwrap(), dec!(0), dec!(0.000001));
+            assert_close(snapshot.vol_base.unwrap(), dec!(0), dec!(0.000001));
+            assert_close(snapshot.vol_quote.unwrap(), dec!(0), dec!(0.000001));
         }
     }
 }
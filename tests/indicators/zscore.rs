@@ -15,9 +15,11 @@ fn assert_close(
 
 #[test]
 fn zscore_returns_none_until_warm() {
-    let mut config = SigmaFloorConfig::default();
-    config.mode = SigmaFloorMode::Const;
-    config.sigma_floor_const = dec!(0.5);
+    let config = SigmaFloorConfig {
+        mode: SigmaFloorMode::Const,
+        sigma_floor_const: dec!(0.5),
+        ..SigmaFloorConfig::default()
+    };
 
     let mut calc = ZScoreCalculator::new(3, config, 1).unwrap();
     let snapshot = calc.update(dec!(1.0)).unwrap();
@@ -28,9 +30,11 @@ fn zscore_returns_none_until_warm() {
 
 #[test]
 fn zscore_uses_sigma_floor_when_sigma_is_small() {
-    let mut config = SigmaFloorConfig::default();
-    config.mode = SigmaFloorMode::Const;
-    config.sigma_floor_const = dec!(0.5);
+    let config = SigmaFloorConfig {
+        mode: SigmaFloorMode::Const,
+        sigma_floor_const: dec!(0.5),
+        ..SigmaFloorConfig::default()
+    };
 
     let mut calc = ZScoreCalculator::new(3, config, 1).unwrap();
     calc.update(dec!(1.0)).unwrap();
@@ -43,9 +47,11 @@ fn zscore_uses_sigma_floor_when_sigma_is_small() {
 
 #[test]
 fn zscore_matches_expected_value() {
-    let mut config = SigmaFloorConfig::default();
-    config.mode = SigmaFloorMode::Const;
-    config.sigma_floor_const = dec!(0.1);
+    let config = SigmaFloorConfig {
+        mode: SigmaFloorMode::Const,
+        sigma_floor_const: dec!(0.1),
+        ..SigmaFloorConfig::default()
+    };
 
     let mut calc = ZScoreCalculator::new(3, config, 1).unwrap();
     calc.update(dec!(1.0)).unwrap();
@@ -62,9 +68,11 @@ fn zscore_matches_expected_value() {
 
 #[test]
 fn zscore_rejects_nan_sigma_floor() {
-    let mut config = SigmaFloorConfig::default();
-    config.mode = SigmaFloorMode::Const;
-    config.sigma_floor_const = dec!(0);
+    let config = SigmaFloorConfig {
+        mode: SigmaFloorMode::Const,
+        sigma_floor_const: dec!(0),
+        ..SigmaFloorConfig::default()
+    };
 
     let err = ZScoreCalculator::new(3, config, 1).unwrap_err();
     assert!(matches!(err, IndicatorError::InvalidConfig(_)));
@@ -19,10 +19,10 @@ fn order(symbol: Symbol, side: OrderSide) -> OrderRequest {
 #[tokio::test]
 async fn concurrency_safety_for_execution() {
     let mut executor = MockOrderExecutor::default();
-    executor.push_submit_response(Symbol::EthPerp, Ok(dec!(1)));
-    executor.push_submit_response(Symbol::EthPerp, Ok(dec!(1)));
-    executor.push_submit_response(Symbol::BtcPerp, Ok(dec!(1)));
-    executor.push_submit_response(Symbol::BtcPerp, Ok(dec!(1)));
+    executor.push_submit_response(Symbol::Base, Ok(dec!(1)));
+    executor.push_submit_response(Symbol::Base, Ok(dec!(1)));
+    executor.push_submit_response(Symbol::Quote, Ok(dec!(1)));
+    executor.push_submit_response(Symbol::Quote, Ok(dec!(1)));
 
     let engine = ExecutionEngine::new(std::sync::Arc::new(executor), RetryConfig::fast());
     let engine2 = engine.clone();
@@ -30,8 +30,8 @@ async fn concurrency_safety_for_execution() {
     let task1 = tokio::spawn(async move {
         engine
             .open_pair(
-                order(Symbol::EthPerp, OrderSide::Sell),
-                order(Symbol::BtcPerp, OrderSide::Buy),
+                order(Symbol::Base, OrderSide::Sell),
+                order(Symbol::Quote, OrderSide::Buy),
             )
             .await
             .unwrap();
@@ -39,8 +39,8 @@ async fn concurrency_safety_for_execution() {
     let task2 = tokio::spawn(async move {
         engine2
             .open_pair(
-                order(Symbol::EthPerp, OrderSide::Sell),
-                order(Symbol::BtcPerp, OrderSide::Buy),
+                order(Symbol::Base, OrderSide::Sell),
+                order(Symbol::Quote, OrderSide::Buy),
             )
             .await
             .unwrap();
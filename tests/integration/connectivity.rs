@@ -12,14 +12,14 @@ async fn api_connectivity_ok_returns_true_when_prices_available() {
     let timestamp = Utc.timestamp_opt(0, 0).unwrap();
     let mut source = MockPriceSource::default();
     source.insert_bar(PriceBar::new(
-        Symbol::EthPerp,
+        Symbol::Base,
         timestamp,
         Some(dec!(100)),
         None,
         None,
     ));
     source.insert_bar(PriceBar::new(
-        Symbol::BtcPerp,
+        Symbol::Quote,
         timestamp,
         Some(dec!(200)),
         None,
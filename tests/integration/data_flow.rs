@@ -8,15 +8,15 @@ use eth_btc_strategy::execution::{ExecutionEngine, MockOrderExecutor, RetryConfi
 use eth_btc_strategy::state::StrategyStatus;
 
 fn bar(timestamp: i64, r: rust_decimal::Decimal) -> StrategyBar {
-    let base = dec!(100);
-    let eth = base * r.exp();
+    let quote = dec!(100);
+    let base = quote * r.exp();
     StrategyBar {
         timestamp: Utc.timestamp_opt(timestamp, 0).unwrap(),
-        eth_price: eth,
-        btc_price: base,
+        base_price: base,
+        quote_price: quote,
         equity: None,
-        funding_eth: None,
-        funding_btc: None,
+        funding_base: None,
+        funding_quote: None,
         funding_interval_hours: None,
     }
 }
@@ -32,10 +32,10 @@ async fn data_flow_triggers_state_and_logging() {
     config.sigma_floor.sigma_floor_const = dec!(0.02);
 
     let mut executor = MockOrderExecutor::default();
-    executor.push_submit_response(eth_btc_strategy::config::Symbol::EthPerp, Ok(dec!(1)));
-    executor.push_submit_response(eth_btc_strategy::config::Symbol::BtcPerp, Ok(dec!(1)));
-    executor.push_close_response(eth_btc_strategy::config::Symbol::EthPerp, Ok(dec!(1)));
-    executor.push_close_response(eth_btc_strategy::config::Symbol::BtcPerp, Ok(dec!(1)));
+    executor.push_submit_response(eth_btc_strategy::config::Symbol::Base, Ok(dec!(1)));
+    executor.push_submit_response(eth_btc_strategy::config::Symbol::Quote, Ok(dec!(1)));
+    executor.push_close_response(eth_btc_strategy::config::Symbol::Base, Ok(dec!(1)));
+    executor.push_close_response(eth_btc_strategy::config::Symbol::Quote, Ok(dec!(1)));
 
     let execution = ExecutionEngine::new(std::sync::Arc::new(executor), RetryConfig::fast());
     let mut engine = StrategyEngine::new(config, execution).unwrap();
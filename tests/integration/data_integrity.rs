@@ -17,6 +17,6 @@ fn bar(symbol: Symbol, ts: i64, price: i64) -> PriceBar {
 #[test]
 fn data_integrity_rejects_mismatched_timestamps() {
     let mut history = PriceHistorySet::new(2, 2, 2).unwrap();
-    let result = history.push_pair(bar(Symbol::EthPerp, 0, 100), bar(Symbol::BtcPerp, 1, 200));
+    let result = history.push_pair(bar(Symbol::Base, 0, 100), bar(Symbol::Quote, 1, 200));
     assert!(result.is_err());
 }
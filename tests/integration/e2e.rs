@@ -9,15 +9,15 @@ use eth_btc_strategy::logging::LogEvent;
 use eth_btc_strategy::state::StrategyStatus;
 
 fn bar(timestamp: i64, r: rust_decimal::Decimal) -> StrategyBar {
-    let base = dec!(100);
-    let eth = base * r.exp();
+    let quote = dec!(100);
+    let base = quote * r.exp();
     StrategyBar {
         timestamp: Utc.timestamp_opt(timestamp, 0).unwrap(),
-        eth_price: eth,
-        btc_price: base,
+        base_price: base,
+        quote_price: quote,
         equity: None,
-        funding_eth: None,
-        funding_btc: None,
+        funding_base: None,
+        funding_quote: None,
         funding_interval_hours: None,
     }
 }
@@ -38,10 +38,10 @@ async fn e2e_tp_flow() {
     config.strategy.tp_z = dec!(1.0);
 
     let mut executor = MockOrderExecutor::default();
-    executor.push_submit_response(eth_btc_strategy::config::Symbol::EthPerp, Ok(dec!(1)));
-    executor.push_submit_response(eth_btc_strategy::config::Symbol::BtcPerp, Ok(dec!(1)));
-    executor.push_close_response(eth_btc_strategy::config::Symbol::EthPerp, Ok(dec!(1)));
-    executor.push_close_response(eth_btc_strategy::config::Symbol::BtcPerp, Ok(dec!(1)));
+    executor.push_submit_response(eth_btc_strategy::config::Symbol::Base, Ok(dec!(1)));
+    executor.push_submit_response(eth_btc_strategy::config::Symbol::Quote, Ok(dec!(1)));
+    executor.push_close_response(eth_btc_strategy::config::Symbol::Base, Ok(dec!(1)));
+    executor.push_close_response(eth_btc_strategy::config::Symbol::Quote, Ok(dec!(1)));
 
     let mut engine = engine_with_mock(config, executor);
     let bars = vec![
@@ -74,10 +74,10 @@ async fn e2e_sl_flow_with_cooldown() {
     config.strategy.sl_z = dec!(1.2);
 
     let mut executor = MockOrderExecutor::default();
-    executor.push_submit_response(eth_btc_strategy::config::Symbol::EthPerp, Ok(dec!(1)));
-    executor.push_submit_response(eth_btc_strategy::config::Symbol::BtcPerp, Ok(dec!(1)));
-    executor.push_close_response(eth_btc_strategy::config::Symbol::EthPerp, Ok(dec!(1)));
-    executor.push_close_response(eth_btc_strategy::config::Symbol::BtcPerp, Ok(dec!(1)));
+    executor.push_submit_response(eth_btc_strategy::config::Symbol::Base, Ok(dec!(1)));
+    executor.push_submit_response(eth_btc_strategy::config::Symbol::Quote, Ok(dec!(1)));
+    executor.push_close_response(eth_btc_strategy::config::Symbol::Base, Ok(dec!(1)));
+    executor.push_close_response(eth_btc_strategy::config::Symbol::Quote, Ok(dec!(1)));
 
     let mut engine = engine_with_mock(config, executor);
     let bars = vec![
@@ -102,10 +102,10 @@ async fn e2e_time_stop_flow() {
     config.risk.max_hold_hours = 1;
 
     let mut executor = MockOrderExecutor::default();
-    executor.push_submit_response(eth_btc_strategy::config::Symbol::EthPerp, Ok(dec!(1)));
-    executor.push_submit_response(eth_btc_strategy::config::Symbol::BtcPerp, Ok(dec!(1)));
-    executor.push_close_response(eth_btc_strategy::config::Symbol::EthPerp, Ok(dec!(1)));
-    executor.push_close_response(eth_btc_strategy::config::Symbol::BtcPerp, Ok(dec!(1)));
+    executor.push_submit_response(eth_btc_strategy::config::Symbol::Base, Ok(dec!(1)));
+    executor.push_submit_response(eth_btc_strategy::config::Symbol::Quote, Ok(dec!(1)));
+    executor.push_close_response(eth_btc_strategy::config::Symbol::Base, Ok(dec!(1)));
+    executor.push_close_response(eth_btc_strategy::config::Symbol::Quote, Ok(dec!(1)));
 
     let mut engine = engine_with_mock(config, executor);
     let bars = vec![
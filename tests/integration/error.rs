@@ -9,15 +9,15 @@ use eth_btc_strategy::execution::{
 };
 
 fn bar(timestamp: i64, r: rust_decimal::Decimal) -> StrategyBar {
-    let base = dec!(100);
-    let eth = base * r.exp();
+    let quote = dec!(100);
+    let base = quote * r.exp();
     StrategyBar {
         timestamp: Utc.timestamp_opt(timestamp, 0).unwrap(),
-        eth_price: eth,
-        btc_price: base,
+        base_price: base,
+        quote_price: quote,
         equity: None,
-        funding_eth: None,
-        funding_btc: None,
+        funding_base: None,
+        funding_quote: None,
         funding_interval_hours: None,
     }
 }
@@ -33,7 +33,7 @@ async fn execution_errors_propagate() {
 
     let mut executor = MockOrderExecutor::default();
     executor.push_submit_response(
-        eth_btc_strategy::config::Symbol::EthPerp,
+        eth_btc_strategy::config::Symbol::Base,
         Err(ExecutionError::Fatal("boom".into())),
     );
 
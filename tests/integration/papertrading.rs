@@ -5,12 +5,14 @@ use eth_btc_strategy::integration::papertrading_gate;
 
 #[test]
 fn papertrading_gate_checks_drawdown() {
-    let mut metrics = Metrics::default();
-    metrics.max_drawdown = dec!(0.10);
-    metrics.sharpe_ratio = dec!(1.0);
-    metrics.win_rate = dec!(0.55);
-    metrics.profit_factor = dec!(1.2);
-    metrics.trade_count = 40;
+    let mut metrics = Metrics {
+        max_drawdown: dec!(0.10),
+        sharpe_ratio: dec!(1.0),
+        win_rate: dec!(0.55),
+        profit_factor: dec!(1.2),
+        trade_count: 40,
+        ..Metrics::default()
+    };
     assert!(papertrading_gate(&metrics));
 
     metrics.max_drawdown = dec!(0.20);
@@ -19,11 +21,13 @@ fn papertrading_gate_checks_drawdown() {
 
 #[test]
 fn papertrading_gate_requires_minimum_trades() {
-    let mut metrics = Metrics::default();
-    metrics.max_drawdown = dec!(0.10);
-    metrics.sharpe_ratio = dec!(1.0);
-    metrics.win_rate = dec!(0.55);
-    metrics.profit_factor = dec!(1.2);
-    metrics.trade_count = 5;
+    let metrics = Metrics {
+        max_drawdown: dec!(0.10),
+        sharpe_ratio: dec!(1.0),
+        win_rate: dec!(0.55),
+        profit_factor: dec!(1.2),
+        trade_count: 5,
+        ..Metrics::default()
+    };
     assert!(!papertrading_gate(&metrics));
 }
@@ -2,34 +2,42 @@ use chrono::{TimeZone, Utc};
 use rust_decimal::MathematicalOps;
 use rust_decimal_macros::dec;
 
+use eth_btc_strategy::backtest::parity::{ParityConfig, ParityField, check_parity};
 use eth_btc_strategy::backtest::{BacktestBar, BacktestEngine};
 use eth_btc_strategy::config::{Config, SigmaFloorMode};
 use eth_btc_strategy::core::strategy::{StrategyBar, StrategyEngine};
 use eth_btc_strategy::execution::{ExecutionEngine, MockOrderExecutor, RetryConfig};
-use eth_btc_strategy::logging::LogEvent;
+use eth_btc_strategy::logging::{EntryBlockReason, LogEvent, TradeEvent};
+use eth_btc_strategy::state::StrategyStatus;
 
 fn backtest_bar(timestamp: i64, r: rust_decimal::Decimal) -> BacktestBar {
-    let base = dec!(100);
-    let eth = base * r.exp();
+    let quote = dec!(100);
+    let base = quote * r.exp();
     BacktestBar {
         timestamp: Utc.timestamp_opt(timestamp, 0).unwrap(),
-        eth_price: eth,
-        btc_price: base,
-        funding_eth: None,
-        funding_btc: None,
+        base_price: base,
+        quote_price: quote,
+        funding_base: None,
+        funding_quote: None,
+        base_open: None,
+        base_high: None,
+        base_low: None,
+        quote_open: None,
+        quote_high: None,
+        quote_low: None,
     }
 }
 
 fn strategy_bar(timestamp: i64, r: rust_decimal::Decimal) -> StrategyBar {
-    let base = dec!(100);
-    let eth = base * r.exp();
+    let quote = dec!(100);
+    let base = quote * r.exp();
     StrategyBar {
         timestamp: Utc.timestamp_opt(timestamp, 0).unwrap(),
-        eth_price: eth,
-        btc_price: base,
+        base_price: base,
+        quote_price: quote,
         equity: None,
-        funding_eth: None,
-        funding_btc: None,
+        funding_base: None,
+        funding_quote: None,
         funding_interval_hours: None,
     }
 }
@@ -42,7 +50,7 @@ async fn backtest_and_strategy_parity_on_trade_count() {
     config.strategy.tp_z = dec!(0.6);
     config.sigma_floor.mode = SigmaFloorMode::Const;
 
-    let bars = vec![dec!(0.0), dec!(0.0), dec!(0.0), dec!(0.04), dec!(0.0)];
+    let bars = [dec!(0.0), dec!(0.0), dec!(0.0), dec!(0.04), dec!(0.0)];
 
     let backtest_bars = bars
         .iter()
@@ -53,10 +61,10 @@ async fn backtest_and_strategy_parity_on_trade_count() {
     let backtest = engine.run(&backtest_bars).unwrap();
 
     let mut executor = MockOrderExecutor::default();
-    executor.push_submit_response(eth_btc_strategy::config::Symbol::EthPerp, Ok(dec!(1)));
-    executor.push_submit_response(eth_btc_strategy::config::Symbol::BtcPerp, Ok(dec!(1)));
-    executor.push_close_response(eth_btc_strategy::config::Symbol::EthPerp, Ok(dec!(1)));
-    executor.push_close_response(eth_btc_strategy::config::Symbol::BtcPerp, Ok(dec!(1)));
+    executor.push_submit_response(eth_btc_strategy::config::Symbol::Base, Ok(dec!(1)));
+    executor.push_submit_response(eth_btc_strategy::config::Symbol::Quote, Ok(dec!(1)));
+    executor.push_close_response(eth_btc_strategy::config::Symbol::Base, Ok(dec!(1)));
+    executor.push_close_response(eth_btc_strategy::config::Symbol::Quote, Ok(dec!(1)));
     let execution = ExecutionEngine::new(std::sync::Arc::new(executor), RetryConfig::fast());
     let mut strategy = StrategyEngine::new(config, execution).unwrap();
 
@@ -77,3 +85,131 @@ async fn backtest_and_strategy_parity_on_trade_count() {
 
     assert_eq!(backtest.trades.len(), exit_count);
 }
+
+fn parity_config() -> Config {
+    let mut config = Config::default();
+    config.strategy.n_z = 4;
+    config.position.n_vol = 1;
+    config.strategy.tp_z = dec!(0.6);
+    config.sigma_floor.mode = SigmaFloorMode::Const;
+    config
+}
+
+fn parity_bars() -> Vec<BacktestBar> {
+    [
+        dec!(0.0),
+        dec!(0.01),
+        dec!(0.0),
+        dec!(0.01),
+        dec!(0.0),
+        dec!(0.08),
+        dec!(0.0),
+        dec!(0.0),
+    ]
+    .iter()
+    .enumerate()
+    .map(|(i, r)| backtest_bar((i as i64) * 900, *r))
+    .collect()
+}
+
+#[test]
+fn parity_report_is_clean_against_own_bar_logs() {
+    let config = parity_config();
+    let bars = parity_bars();
+    let live = BacktestEngine::new(config.clone())
+        .run(&bars)
+        .unwrap()
+        .bar_logs;
+
+    let report = check_parity(&config, &bars, &live, &[], &ParityConfig::default()).unwrap();
+
+    assert_eq!(report.live_bars, live.len());
+    assert_eq!(report.compared_bars, live.len());
+    assert!(report.missing_bars.is_empty());
+    assert!(report.divergences.is_empty(), "{report:?}");
+}
+
+#[test]
+fn parity_report_flags_diverging_bars() {
+    let config = parity_config();
+    let bars = parity_bars();
+    let mut live = BacktestEngine::new(config.clone())
+        .run(&bars)
+        .unwrap()
+        .bar_logs;
+    let entry = live
+        .iter()
+        .position(|log| log.state == StrategyStatus::InPosition)
+        .unwrap();
+    live[entry].zscore = live[entry].zscore.map(|z| z + dec!(0.5));
+    live[entry].state = StrategyStatus::Flat;
+    live[0].entry_block_reason = Some(EntryBlockReason::CostGate);
+    let mut gap = live[1].clone();
+    gap.timestamp = Utc.timestamp_opt(10 * 900, 0).unwrap();
+    live.push(gap);
+
+    let report = check_parity(&config, &bars, &live, &[], &ParityConfig::default()).unwrap();
+
+    assert_eq!(
+        report.missing_bars,
+        vec![Utc.timestamp_opt(10 * 900, 0).unwrap()]
+    );
+    let fields: Vec<_> = report
+        .divergences
+        .iter()
+        .map(|divergence| (divergence.timestamp, divergence.field))
+        .collect();
+    let entry_time = live[entry].timestamp;
+    assert!(fields.contains(&(live[0].timestamp, ParityField::BlockReason)));
+    assert!(fields.contains(&(entry_time, ParityField::Zscore)));
+    assert!(fields.contains(&(entry_time, ParityField::Decision)));
+    assert_eq!(report.counts.get("ZSCORE"), Some(&1));
+}
+
+#[tokio::test]
+async fn parity_report_matches_live_trade_log_fills() {
+    let config = parity_config();
+    let bars = parity_bars();
+    let live = BacktestEngine::new(config.clone())
+        .run(&bars)
+        .unwrap()
+        .bar_logs;
+
+    let mut executor = MockOrderExecutor::default();
+    executor.push_submit_response(eth_btc_strategy::config::Symbol::Base, Ok(dec!(1)));
+    executor.push_submit_response(eth_btc_strategy::config::Symbol::Quote, Ok(dec!(1)));
+    executor.push_close_response(eth_btc_strategy::config::Symbol::Base, Ok(dec!(1)));
+    executor.push_close_response(eth_btc_strategy::config::Symbol::Quote, Ok(dec!(1)));
+    let execution = ExecutionEngine::new(std::sync::Arc::new(executor), RetryConfig::fast());
+    let mut strategy = StrategyEngine::new(config.clone(), execution).unwrap();
+    let mut trades = Vec::new();
+    for bar in &bars {
+        let outcome = strategy
+            .process_bar(StrategyBar {
+                timestamp: bar.timestamp,
+                base_price: bar.base_price,
+                quote_price: bar.quote_price,
+                equity: None,
+                funding_base: None,
+                funding_quote: None,
+                funding_interval_hours: None,
+            })
+            .await
+            .unwrap();
+        trades.extend(outcome.trade_logs);
+    }
+    assert_eq!(trades.len(), 2);
+
+    let report = check_parity(&config, &bars, &live, &trades, &ParityConfig::default()).unwrap();
+    assert!(report.divergences.is_empty(), "{report:?}");
+
+    let exits: Vec<_> = trades
+        .iter()
+        .filter(|log| matches!(log.event, TradeEvent::Exit(_)))
+        .cloned()
+        .collect();
+    let report = check_parity(&config, &bars, &live, &exits, &ParityConfig::default()).unwrap();
+    assert_eq!(report.counts.get("TRADE"), Some(&1));
+    assert_eq!(report.divergences[0].live, "NONE");
+    assert_eq!(report.divergences[0].backtest, "Enter");
+}
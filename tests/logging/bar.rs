@@ -1,29 +1,32 @@
 use chrono::{TimeZone, Utc};
 use rust_decimal_macros::dec;
 
-use eth_btc_strategy::core::ExitReason;
-use eth_btc_strategy::logging::{BarLog, EntryBlockReason, LogEvent};
+use eth_btc_strategy::core::{ExitReason, TradeDirection};
+use eth_btc_strategy::logging::{BarLog, EntryBlockReason, LogEvent, TradeLog};
 use eth_btc_strategy::state::StrategyStatus;
 
 #[test]
 fn bar_log_contains_required_fields() {
     let bar = BarLog {
         timestamp: Utc.timestamp_opt(0, 0).unwrap(),
-        eth_price: Some(dec!(100)),
-        btc_price: Some(dec!(200)),
+        pair: None,
+        base_price: Some(dec!(100)),
+        quote_price: Some(dec!(200)),
         r: Some(dec!(0.1)),
+        spread: None,
+        beta: None,
         mu: Some(dec!(0.05)),
         sigma: Some(dec!(0.2)),
         sigma_eff: Some(dec!(0.2)),
         zscore: Some(dec!(1.5)),
-        vol_eth: Some(dec!(0.3)),
-        vol_btc: Some(dec!(0.4)),
-        w_eth: Some(dec!(0.6)),
-        w_btc: Some(dec!(0.4)),
-        notional_eth: Some(dec!(6000)),
-        notional_btc: Some(dec!(4000)),
-        funding_eth: Some(dec!(0.01)),
-        funding_btc: Some(dec!(0.02)),
+        vol_base: Some(dec!(0.3)),
+        vol_quote: Some(dec!(0.4)),
+        w_base: Some(dec!(0.6)),
+        w_quote: Some(dec!(0.4)),
+        notional_base: Some(dec!(6000)),
+        notional_quote: Some(dec!(4000)),
+        funding_base: Some(dec!(0.01)),
+        funding_quote: Some(dec!(0.02)),
         funding_cost_est: Some(dec!(5)),
         funding_skip: Some(false),
         regime_half_life_bars: Some(12.5),
@@ -33,16 +36,16 @@ fn bar_log_contains_required_fields() {
         estimated_net_edge_bps: Some(dec!(4)),
         cost_gate_required_net_edge_bps: Some(dec!(1)),
         cost_gate_pass: Some(true),
-        eth_best_bid: Some(dec!(99)),
-        eth_best_ask: Some(dec!(101)),
-        eth_bid_size: Some(dec!(2)),
-        eth_ask_size: Some(dec!(3)),
-        eth_spread_bps: Some(dec!(200)),
-        btc_best_bid: Some(dec!(199)),
-        btc_best_ask: Some(dec!(201)),
-        btc_bid_size: Some(dec!(4)),
-        btc_ask_size: Some(dec!(5)),
-        btc_spread_bps: Some(dec!(100)),
+        base_best_bid: Some(dec!(99)),
+        base_best_ask: Some(dec!(101)),
+        base_bid_size: Some(dec!(2)),
+        base_ask_size: Some(dec!(3)),
+        base_spread_bps: Some(dec!(200)),
+        quote_best_bid: Some(dec!(199)),
+        quote_best_ask: Some(dec!(201)),
+        quote_bid_size: Some(dec!(4)),
+        quote_ask_size: Some(dec!(5)),
+        quote_spread_bps: Some(dec!(100)),
         entry_block_reason: Some(EntryBlockReason::NoCross),
         run_error: None,
         unrealized_pnl: dec!(1.23),
@@ -53,13 +56,47 @@ fn bar_log_contains_required_fields() {
 
     let json = bar.to_json_value();
     assert!(json.get("timestamp").is_some());
-    assert!(json.get("eth_price").is_some());
-    assert!(json.get("btc_price").is_some());
+    assert!(json.get("base_price").is_some());
+    assert!(json.get("quote_price").is_some());
     assert!(json.get("zscore").is_some());
     assert!(json.get("regime_half_life_bars").is_some());
-    assert!(json.get("eth_best_bid").is_some());
-    assert!(json.get("btc_spread_bps").is_some());
+    assert!(json.get("base_best_bid").is_some());
+    assert!(json.get("quote_spread_bps").is_some());
     assert!(json.get("unrealized_pnl").is_some());
     assert!(json.get("state").is_some());
     assert!(json.get("events").is_some());
 }
+
+#[test]
+fn logs_written_with_eth_btc_field_names_still_load() {
+    let bar: BarLog = serde_json::from_str(
+        r#"{"timestamp":"2024-01-01T00:00:00Z","eth_price":"100","btc_price":"200","r":null,"mu":null,"sigma":null,"sigma_eff":null,"zscore":null,"w_eth":"0.6","funding_btc":"0.0001","eth_best_bid":"99.9","btc_spread_bps":"1.5","entry_block_reason":"BELOW_MIN_SIZE_ETH","unrealized_pnl":"0","state":"Flat","position":{"direction":"LongEthShortBtc","entry_time":"2024-01-01T00:00:00Z","eth":{"qty":"1","avg_price":"100","notional":"100"},"btc":{"qty":"-0.5","avg_price":"200","notional":"100"}},"events":[]}"#,
+    )
+    .unwrap();
+
+    assert_eq!(bar.base_price, Some(dec!(100)));
+    assert_eq!(bar.quote_price, Some(dec!(200)));
+    assert_eq!(bar.w_base, Some(dec!(0.6)));
+    assert_eq!(bar.funding_quote, Some(dec!(0.0001)));
+    assert_eq!(bar.base_best_bid, Some(dec!(99.9)));
+    assert_eq!(bar.quote_spread_bps, Some(dec!(1.5)));
+    assert_eq!(
+        bar.entry_block_reason,
+        Some(EntryBlockReason::BelowMinSizeBase)
+    );
+    let position = bar.position.unwrap();
+    assert_eq!(position.direction, TradeDirection::LongBaseShortQuote);
+    assert_eq!(position.base.qty, dec!(1));
+    assert_eq!(position.quote.qty, dec!(-0.5));
+
+    let trade: TradeLog = serde_json::from_str(
+        r#"{"timestamp":"2024-01-01T01:00:00Z","event":"Entry","direction":"ShortEthLongBtc","eth_qty":"-1","btc_qty":"0.5","eth_price":"100","btc_price":"200","entry_time":"2024-01-01T01:00:00Z","entry_eth_price":"100","entry_btc_price":"200","realized_pnl":"0","cumulative_realized_pnl":"0","fee":"0.1","exchange_closed_pnl":null,"pnl_source":"MODEL_ESTIMATE","eth_ref_price":null,"btc_ref_price":null,"eth_slippage_bps":"2","btc_slippage_bps":null}"#,
+    )
+    .unwrap();
+
+    assert_eq!(trade.direction, TradeDirection::ShortBaseLongQuote);
+    assert_eq!(trade.base_qty, dec!(-1));
+    assert_eq!(trade.quote_qty, dec!(0.5));
+    assert_eq!(trade.entry_quote_price, dec!(200));
+    assert_eq!(trade.base_slippage_bps, Some(dec!(2)));
+}
@@ -36,6 +36,6 @@ async fn email_channel_throttles_repeats() {
 
 #[tokio::test]
 async fn noop_email_transport_succeeds() {
-    let transport = NoopEmailTransport::default();
+    let transport = NoopEmailTransport;
     transport.send("subject", "body").await.unwrap();
 }
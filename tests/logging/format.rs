@@ -8,21 +8,24 @@ use eth_btc_strategy::state::StrategyStatus;
 fn log_format_outputs_json_and_text() {
     let bar = BarLog {
         timestamp: Utc.timestamp_opt(0, 0).unwrap(),
-        eth_price: Some(dec!(100)),
-        btc_price: Some(dec!(200)),
+        pair: None,
+        base_price: Some(dec!(100)),
+        quote_price: Some(dec!(200)),
         r: Some(dec!(0.1)),
+        spread: None,
+        beta: None,
         mu: Some(dec!(0.05)),
         sigma: Some(dec!(0.2)),
         sigma_eff: Some(dec!(0.2)),
         zscore: Some(dec!(1.5)),
-        vol_eth: None,
-        vol_btc: None,
-        w_eth: None,
-        w_btc: None,
-        notional_eth: None,
-        notional_btc: None,
-        funding_eth: None,
-        funding_btc: None,
+        vol_base: None,
+        vol_quote: None,
+        w_base: None,
+        w_quote: None,
+        notional_base: None,
+        notional_quote: None,
+        funding_base: None,
+        funding_quote: None,
         funding_cost_est: None,
         funding_skip: None,
         regime_half_life_bars: None,
@@ -32,16 +35,16 @@ fn log_format_outputs_json_and_text() {
         estimated_net_edge_bps: None,
         cost_gate_required_net_edge_bps: None,
         cost_gate_pass: None,
-        eth_best_bid: None,
-        eth_best_ask: None,
-        eth_bid_size: None,
-        eth_ask_size: None,
-        eth_spread_bps: None,
-        btc_best_bid: None,
-        btc_best_ask: None,
-        btc_bid_size: None,
-        btc_ask_size: None,
-        btc_spread_bps: None,
+        base_best_bid: None,
+        base_best_ask: None,
+        base_bid_size: None,
+        base_ask_size: None,
+        base_spread_bps: None,
+        quote_best_bid: None,
+        quote_best_ask: None,
+        quote_bid_size: None,
+        quote_ask_size: None,
+        quote_spread_bps: None,
         entry_block_reason: None,
         run_error: None,
         unrealized_pnl: dec!(0.12),
@@ -50,12 +53,12 @@ fn log_format_outputs_json_and_text() {
         events: vec![],
     };
 
-    let formatter = LogFormatter::default();
+    let formatter = LogFormatter;
     let json = formatter.format_json(&bar).unwrap();
     let text = formatter.format_text(&bar);
 
-    assert!(json.contains("\"eth_price\""));
-    assert!(text.contains("ETH"));
+    assert!(json.contains("\"base_price\""));
+    assert!(text.contains("BASE="));
     assert!(text.contains("Z"));
     assert!(text.contains("UPNL"));
 }
@@ -1,7 +1,10 @@
 use rust_decimal_macros::dec;
 
-use eth_btc_strategy::config::{CapitalMode, PositionConfig};
-use eth_btc_strategy::position::{CapitalError, compute_capital, risk_parity_weights};
+use eth_btc_strategy::config::{CapitalMode, PositionConfig, SpreadConfig, SpreadModel};
+use eth_btc_strategy::position::{
+    CapitalError, RiskParityError, beta_hedge_weights, compute_capital, risk_parity_weights,
+    spread_weights,
+};
 
 fn assert_close(
     actual: rust_decimal::Decimal,
@@ -15,25 +18,27 @@ fn assert_close(
 #[test]
 fn risk_parity_weights_match_inverse_vol() {
     let result = risk_parity_weights(dec!(0.2), dec!(0.4)).unwrap();
-    assert_close(result.w_eth, dec!(0.6666667), dec!(0.0001));
-    assert_close(result.w_btc, dec!(0.3333333), dec!(0.0001));
+    assert_close(result.w_base, dec!(0.6666667), dec!(0.0001));
+    assert_close(result.w_quote, dec!(0.3333333), dec!(0.0001));
 }
 
 #[test]
 fn risk_parity_handles_zero_volatility() {
     let result = risk_parity_weights(dec!(0), dec!(0.4)).unwrap();
-    assert_close(result.w_eth, dec!(0.5), dec!(0.0001));
-    assert_close(result.w_btc, dec!(0.5), dec!(0.0001));
+    assert_close(result.w_base, dec!(0.5), dec!(0.0001));
+    assert_close(result.w_quote, dec!(0.5), dec!(0.0001));
 
     let result = risk_parity_weights(dec!(0.0), dec!(0.0)).unwrap();
-    assert_close(result.w_eth, dec!(0.5), dec!(0.0001));
+    assert_close(result.w_base, dec!(0.5), dec!(0.0001));
 }
 
 #[test]
 fn capital_allocation_fixed_and_equity_ratio() {
-    let mut config = PositionConfig::default();
-    config.c_mode = CapitalMode::FixedNotional;
-    config.c_value = Some(dec!(50000));
+    let mut config = PositionConfig {
+        c_mode: CapitalMode::FixedNotional,
+        c_value: Some(dec!(50000)),
+        ..PositionConfig::default()
+    };
 
     let capital = compute_capital(&config, dec!(100000)).unwrap();
     assert_eq!(capital, dec!(50000));
@@ -49,3 +54,38 @@ fn capital_allocation_fixed_and_equity_ratio() {
     let err = compute_capital(&config, dec!(100000)).unwrap_err();
     assert!(matches!(err, CapitalError::InvalidConfig(_)));
 }
+
+#[test]
+fn beta_hedge_weights_scale_quote_leg_by_beta() {
+    let result = beta_hedge_weights(dec!(1.5)).unwrap();
+    assert_close(result.w_base, dec!(0.4), dec!(0.0001));
+    assert_close(result.w_quote, dec!(0.6), dec!(0.0001));
+
+    assert!(matches!(
+        beta_hedge_weights(dec!(0)),
+        Err(RiskParityError::InvalidBeta)
+    ));
+}
+
+#[test]
+fn spread_weights_use_beta_only_for_rolling_beta_model() {
+    let rolling = SpreadConfig {
+        model: SpreadModel::RollingBeta,
+        ..SpreadConfig::default()
+    };
+
+    let hedged = spread_weights(&rolling, dec!(0.2), dec!(0.4), Some(dec!(1))).unwrap();
+    assert_close(hedged.w_base, dec!(0.5), dec!(0.0001));
+
+    let warming_up = spread_weights(&rolling, dec!(0.2), dec!(0.4), None).unwrap();
+    let fixed = spread_weights(
+        &SpreadConfig::default(),
+        dec!(0.2),
+        dec!(0.4),
+        Some(dec!(1)),
+    )
+    .unwrap();
+    let parity = risk_parity_weights(dec!(0.2), dec!(0.4)).unwrap();
+    assert_eq!(warming_up, parity);
+    assert_eq!(fixed, parity);
+}
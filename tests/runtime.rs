@@ -7,7 +7,9 @@ use rust_decimal_macros::dec;
 use tokio::sync::watch;
 
 use eth_btc_strategy::account::{AccountPositionSource, MockAccountSource, PairExposure};
-use eth_btc_strategy::config::{CapitalMode, Config, SigmaFloorMode, Symbol};
+use eth_btc_strategy::config::{
+    CapitalMode, Config, PortfolioConfig, PositionConfig, SigmaFloorMode, Symbol,
+};
 use eth_btc_strategy::core::TradeDirection;
 use eth_btc_strategy::core::strategy::StrategyEngine;
 use eth_btc_strategy::data::{
@@ -16,7 +18,10 @@ use eth_btc_strategy::data::{
 };
 use eth_btc_strategy::execution::{ExecutionEngine, PaperOrderExecutor, RetryConfig};
 use eth_btc_strategy::funding::{FundingFetcher, FundingRate, MockFundingSource};
-use eth_btc_strategy::logging::{BarLogWriter, LogEvent, TradeEvent, TradeLog, TradeLogWriter};
+use eth_btc_strategy::logging::{
+    BarLogWriter, EntryBlockReason, LogEvent, TradeEvent, TradeLog, TradeLogWriter,
+};
+use eth_btc_strategy::runtime::portfolio::PortfolioRunner;
 use eth_btc_strategy::runtime::{LiveRunner, RunnerError, StateWriter};
 use eth_btc_strategy::state::{PositionLeg, PositionSnapshot, StrategyState, StrategyStatus};
 use eth_btc_strategy::storage::{PriceBarRecord, PriceBarWriter};
@@ -135,14 +140,14 @@ fn runner_with_mocks(timestamp: chrono::DateTime<Utc>) -> LiveRunner {
 
     let mut price_source = MockPriceSource::default();
     price_source.insert_bar(PriceBar::new(
-        Symbol::EthPerp,
+        Symbol::Base,
         timestamp,
         Some(dec!(2000)),
         None,
         None,
     ));
     price_source.insert_bar(PriceBar::new(
-        Symbol::BtcPerp,
+        Symbol::Quote,
         timestamp,
         Some(dec!(30000)),
         None,
@@ -151,13 +156,13 @@ fn runner_with_mocks(timestamp: chrono::DateTime<Utc>) -> LiveRunner {
 
     let mut funding_source = MockFundingSource::default();
     funding_source.insert_rate(FundingRate {
-        symbol: Symbol::EthPerp,
+        symbol: Symbol::Base,
         rate: dec!(0.0001),
         timestamp,
         interval_hours: 8,
     });
     funding_source.insert_rate(FundingRate {
-        symbol: Symbol::BtcPerp,
+        symbol: Symbol::Quote,
         rate: dec!(0.0002),
         timestamp,
         interval_hours: 8,
@@ -184,13 +189,13 @@ fn runner_with_trade_sequence(timestamps: &[chrono::DateTime<Utc>]) -> LiveRunne
 
     let mut price_source = MockPriceSource::default();
     for (idx, ts) in timestamps.iter().enumerate() {
-        let (eth, btc) = match idx {
-            0 | 1 | 2 => (dec!(100), dec!(100)),
+        let (base, quote) = match idx {
+            0..=2 => (dec!(100), dec!(100)),
             3 => (dec!(271.8281828), dec!(100)),
             _ => (dec!(164.872127), dec!(100)),
         };
-        price_source.insert_bar(PriceBar::new(Symbol::EthPerp, *ts, Some(eth), None, None));
-        price_source.insert_bar(PriceBar::new(Symbol::BtcPerp, *ts, Some(btc), None, None));
+        price_source.insert_bar(PriceBar::new(Symbol::Base, *ts, Some(base), None, None));
+        price_source.insert_bar(PriceBar::new(Symbol::Quote, *ts, Some(quote), None, None));
     }
 
     let execution = ExecutionEngine::new(Arc::new(PaperOrderExecutor), RetryConfig::fast());
@@ -208,10 +213,10 @@ async fn runner_builds_bar_with_funding() {
 
     let outcome = runner.run_once_at(timestamp).await.unwrap();
 
-    assert_eq!(outcome.bar_log.eth_price, Some(dec!(2000)));
-    assert_eq!(outcome.bar_log.btc_price, Some(dec!(30000)));
-    assert_eq!(outcome.bar_log.funding_eth, Some(dec!(0.0001)));
-    assert_eq!(outcome.bar_log.funding_btc, Some(dec!(0.0002)));
+    assert_eq!(outcome.bar_log.base_price, Some(dec!(2000)));
+    assert_eq!(outcome.bar_log.quote_price, Some(dec!(30000)));
+    assert_eq!(outcome.bar_log.funding_base, Some(dec!(0.0001)));
+    assert_eq!(outcome.bar_log.funding_quote, Some(dec!(0.0002)));
 }
 
 #[tokio::test]
@@ -278,12 +283,12 @@ async fn runner_repairs_exchange_residual_before_processing_bar() {
     let mut runner = runner_with_mocks(timestamp);
     let position_source = Arc::new(MockPositionSource::default());
     *position_source.exposure.lock().expect("exposure lock") = PairExposure {
-        eth: Some(eth_btc_strategy::account::ExchangePosition {
+        base: Some(eth_btc_strategy::account::ExchangePosition {
             qty: dec!(-0.088),
             entry_price: dec!(2077.03),
             notional: dec!(182.77864),
         }),
-        btc: None,
+        quote: None,
     };
     let writer = Arc::new(MockBarLogWriter::default());
     let trade_writer = Arc::new(MockTradeLogWriter::default());
@@ -312,14 +317,14 @@ async fn runner_flattens_local_state_when_exchange_is_flat() {
         .apply_state(StrategyState {
             status: StrategyStatus::InPosition,
             position: Some(PositionSnapshot {
-                direction: TradeDirection::LongEthShortBtc,
+                direction: TradeDirection::LongBaseShortQuote,
                 entry_time: timestamp,
-                eth: PositionLeg {
+                base: PositionLeg {
                     qty: dec!(1),
                     avg_price: dec!(2000),
                     notional: dec!(2000),
                 },
-                btc: PositionLeg {
+                quote: PositionLeg {
                     qty: dec!(-0.1),
                     avg_price: dec!(30000),
                     notional: dec!(3000),
@@ -328,6 +333,7 @@ async fn runner_flattens_local_state_when_exchange_is_flat() {
             pending_entry: None,
             cooldown_until: None,
             cumulative_realized_pnl: dec!(0),
+            groups: Vec::new(),
         })
         .unwrap();
     let position_source = Arc::new(MockPositionSource::default());
@@ -344,12 +350,12 @@ async fn runner_errors_when_exchange_has_untracked_open_pair() {
     let mut runner = runner_with_mocks(timestamp);
     let position_source = Arc::new(MockPositionSource::default());
     *position_source.exposure.lock().expect("exposure lock") = PairExposure {
-        eth: Some(eth_btc_strategy::account::ExchangePosition {
+        base: Some(eth_btc_strategy::account::ExchangePosition {
             qty: dec!(1),
             entry_price: dec!(2000),
             notional: dec!(2000),
         }),
-        btc: Some(eth_btc_strategy::account::ExchangePosition {
+        quote: Some(eth_btc_strategy::account::ExchangePosition {
             qty: dec!(-0.1),
             entry_price: dec!(30000),
             notional: dec!(3000),
@@ -386,8 +392,8 @@ async fn runner_writes_stats_log() {
 
     let logged = writer.last().expect("expected stats log");
     assert_eq!(logged.timestamp, timestamp);
-    assert_eq!(logged.eth_price, Some(dec!(2000)));
-    assert_eq!(logged.btc_price, Some(dec!(30000)));
+    assert_eq!(logged.base_price, Some(dec!(2000)));
+    assert_eq!(logged.quote_price, Some(dec!(30000)));
 }
 
 #[tokio::test]
@@ -396,14 +402,14 @@ async fn runner_writes_market_book_telemetry_to_stats_log() {
     let mut runner = runner_with_mocks(timestamp);
     let mut book_source = MockBookSource::default();
     book_source.insert_book(OrderBookSnapshot {
-        symbol: Symbol::EthPerp,
+        symbol: Symbol::Base,
         best_bid: dec!(99),
         best_ask: dec!(101),
         bid_size: dec!(2),
         ask_size: dec!(3),
     });
     book_source.insert_book(OrderBookSnapshot {
-        symbol: Symbol::BtcPerp,
+        symbol: Symbol::Quote,
         best_bid: dec!(199),
         best_ask: dec!(201),
         bid_size: dec!(4),
@@ -417,16 +423,16 @@ async fn runner_writes_market_book_telemetry_to_stats_log() {
     runner.run_once_at(timestamp).await.unwrap();
 
     let logged = writer.last().expect("expected stats log");
-    assert_eq!(logged.eth_best_bid, Some(dec!(99)));
-    assert_eq!(logged.eth_best_ask, Some(dec!(101)));
-    assert_eq!(logged.eth_bid_size, Some(dec!(2)));
-    assert_eq!(logged.eth_ask_size, Some(dec!(3)));
-    assert_eq!(logged.eth_spread_bps, Some(dec!(200)));
-    assert_eq!(logged.btc_best_bid, Some(dec!(199)));
-    assert_eq!(logged.btc_best_ask, Some(dec!(201)));
-    assert_eq!(logged.btc_bid_size, Some(dec!(4)));
-    assert_eq!(logged.btc_ask_size, Some(dec!(5)));
-    assert_eq!(logged.btc_spread_bps, Some(dec!(100)));
+    assert_eq!(logged.base_best_bid, Some(dec!(99)));
+    assert_eq!(logged.base_best_ask, Some(dec!(101)));
+    assert_eq!(logged.base_bid_size, Some(dec!(2)));
+    assert_eq!(logged.base_ask_size, Some(dec!(3)));
+    assert_eq!(logged.base_spread_bps, Some(dec!(200)));
+    assert_eq!(logged.quote_best_bid, Some(dec!(199)));
+    assert_eq!(logged.quote_best_ask, Some(dec!(201)));
+    assert_eq!(logged.quote_bid_size, Some(dec!(4)));
+    assert_eq!(logged.quote_ask_size, Some(dec!(5)));
+    assert_eq!(logged.quote_spread_bps, Some(dec!(100)));
 }
 
 #[tokio::test]
@@ -468,14 +474,14 @@ async fn runner_writes_price_records() {
 
     let record = writer.last().expect("expected price record");
     assert_eq!(record.timestamp, timestamp);
-    assert_eq!(record.eth_mid, Some(dec!(2000)));
-    assert_eq!(record.eth_mark, None);
-    assert_eq!(record.eth_close, None);
-    assert_eq!(record.btc_mid, Some(dec!(30000)));
-    assert_eq!(record.btc_mark, None);
-    assert_eq!(record.btc_close, None);
-    assert_eq!(record.funding_eth, Some(dec!(0.0001)));
-    assert_eq!(record.funding_btc, Some(dec!(0.0002)));
+    assert_eq!(record.base_mid, Some(dec!(2000)));
+    assert_eq!(record.base_mark, None);
+    assert_eq!(record.base_close, None);
+    assert_eq!(record.quote_mid, Some(dec!(30000)));
+    assert_eq!(record.quote_mark, None);
+    assert_eq!(record.quote_close, None);
+    assert_eq!(record.funding_base, Some(dec!(0.0001)));
+    assert_eq!(record.funding_quote, Some(dec!(0.0002)));
     assert_eq!(record.funding_interval_hours, Some(8));
 }
 
@@ -499,12 +505,12 @@ async fn runner_uses_account_balance_for_equity_ratio() {
 
     let mut price_source = MockPriceSource::default();
     for (idx, ts) in timestamps.iter().enumerate() {
-        let (eth, btc) = match idx {
-            0 | 1 | 2 => (dec!(100), dec!(100)),
+        let (base, quote) = match idx {
+            0..=2 => (dec!(100), dec!(100)),
             _ => (dec!(271.8281828), dec!(100)),
         };
-        price_source.insert_bar(PriceBar::new(Symbol::EthPerp, *ts, Some(eth), None, None));
-        price_source.insert_bar(PriceBar::new(Symbol::BtcPerp, *ts, Some(btc), None, None));
+        price_source.insert_bar(PriceBar::new(Symbol::Base, *ts, Some(base), None, None));
+        price_source.insert_bar(PriceBar::new(Symbol::Quote, *ts, Some(quote), None, None));
     }
 
     let execution = ExecutionEngine::new(Arc::new(PaperOrderExecutor), RetryConfig::fast());
@@ -526,8 +532,8 @@ async fn runner_uses_account_balance_for_equity_ratio() {
     }
 
     let outcome = last.expect("expected outcome");
-    assert_eq!(outcome.bar_log.notional_eth, Some(dec!(50)));
-    assert_eq!(outcome.bar_log.notional_btc, Some(dec!(50)));
+    assert_eq!(outcome.bar_log.notional_base, Some(dec!(50)));
+    assert_eq!(outcome.bar_log.notional_quote, Some(dec!(50)));
 }
 
 #[tokio::test]
@@ -549,3 +555,273 @@ async fn runner_surfaces_data_errors() {
         other => panic!("unexpected error: {other:?}"),
     }
 }
+
+fn entry_timestamps() -> [chrono::DateTime<Utc>; 4] {
+    [
+        Utc.timestamp_opt(0, 0).unwrap(),
+        Utc.timestamp_opt(900, 0).unwrap(),
+        Utc.timestamp_opt(1800, 0).unwrap(),
+        Utc.timestamp_opt(2700, 0).unwrap(),
+    ]
+}
+
+fn portfolio_member_runner(timestamps: &[chrono::DateTime<Utc>]) -> LiveRunner {
+    let mut config = Config::default();
+    config.strategy.n_z = 3;
+    config.position.n_vol = 1;
+    config.strategy.entry_z = dec!(0.5);
+    config.strategy.sl_z = dec!(2.0);
+    config.position.c_value = Some(dec!(10));
+
+    let mut price_source = MockPriceSource::default();
+    for (idx, ts) in timestamps.iter().enumerate() {
+        let (base, quote) = match idx {
+            0..=2 => (dec!(100), dec!(100)),
+            _ => (dec!(271.8281828), dec!(100)),
+        };
+        price_source.insert_bar(PriceBar::new(Symbol::Base, *ts, Some(base), None, None));
+        price_source.insert_bar(PriceBar::new(Symbol::Quote, *ts, Some(quote), None, None));
+    }
+
+    let execution = ExecutionEngine::new(Arc::new(PaperOrderExecutor), RetryConfig::fast());
+    let engine = StrategyEngine::new(config.clone(), execution).expect("engine");
+    let price_fetcher = PriceFetcher::new(Arc::new(price_source), config.data.price_field);
+    LiveRunner::new(engine, price_fetcher, None)
+}
+
+fn fixed_portfolio_position(c_value: rust_decimal::Decimal) -> PositionConfig {
+    PositionConfig {
+        c_mode: CapitalMode::FixedNotional,
+        c_value: Some(c_value),
+        ..PositionConfig::default()
+    }
+}
+
+#[tokio::test]
+async fn portfolio_splits_capital_by_allocation() {
+    let timestamps = entry_timestamps();
+    let mut portfolio = PortfolioRunner::new(
+        fixed_portfolio_position(dec!(1000)),
+        PortfolioConfig::default(),
+    )
+    .with_member("a", dec!(0.5), portfolio_member_runner(&timestamps))
+    .with_member("b", dec!(0.25), portfolio_member_runner(&timestamps));
+
+    let mut last = Vec::new();
+    for ts in timestamps {
+        last = portfolio.run_once_at(ts).await;
+    }
+
+    assert_eq!(last.len(), 2);
+    let a = last[0].result.as_ref().expect("member a outcome");
+    let b = last[1].result.as_ref().expect("member b outcome");
+    assert!(a.events.contains(&LogEvent::Entry));
+    assert!(b.events.contains(&LogEvent::Entry));
+    assert_eq!(
+        a.bar_log.notional_base.unwrap() + a.bar_log.notional_quote.unwrap(),
+        dec!(500)
+    );
+    assert_eq!(
+        b.bar_log.notional_base.unwrap() + b.bar_log.notional_quote.unwrap(),
+        dec!(250)
+    );
+    assert!(portfolio.gross_notional() > dec!(740));
+}
+
+#[tokio::test]
+async fn portfolio_refuses_entries_beyond_gross_cap() {
+    let timestamps = entry_timestamps();
+    let portfolio_config = PortfolioConfig {
+        max_gross_notional: Some(dec!(600)),
+        ..PortfolioConfig::default()
+    };
+    let mut portfolio =
+        PortfolioRunner::new(fixed_portfolio_position(dec!(1000)), portfolio_config)
+            .with_member("a", dec!(0.5), portfolio_member_runner(&timestamps))
+            .with_member("b", dec!(0.5), portfolio_member_runner(&timestamps));
+
+    let mut last = Vec::new();
+    for ts in timestamps {
+        last = portfolio.run_once_at(ts).await;
+    }
+
+    let a = last[0].result.as_ref().expect("member a outcome");
+    let b = last[1].result.as_ref().expect("member b outcome");
+    assert!(a.events.contains(&LogEvent::Entry));
+    assert!(!b.events.contains(&LogEvent::Entry));
+    assert_eq!(
+        b.bar_log.entry_block_reason,
+        Some(EntryBlockReason::PortfolioGrossCap)
+    );
+    assert_eq!(
+        portfolio.members()[1]
+            .runner()
+            .engine()
+            .state()
+            .state()
+            .status,
+        StrategyStatus::Flat
+    );
+    assert!(portfolio.gross_notional() <= dec!(600));
+}
+
+#[tokio::test]
+async fn portfolio_blocks_entries_without_equity_for_equity_ratio() {
+    let timestamps = entry_timestamps();
+    let position = PositionConfig {
+        c_mode: CapitalMode::EquityRatio,
+        equity_ratio_k: Some(dec!(0.5)),
+        equity_value: None,
+        ..PositionConfig::default()
+    };
+    let mut account_source = MockAccountSource::default();
+    account_source.push_response(Ok(dec!(1000)));
+    let mut portfolio = PortfolioRunner::new(position, PortfolioConfig::default())
+        .with_account_source(Arc::new(account_source))
+        .with_member("a", dec!(1), portfolio_member_runner(&timestamps));
+
+    let mut last = Vec::new();
+    for ts in timestamps {
+        last = portfolio.run_once_at(ts).await;
+    }
+
+    let outcome = last[0].result.as_ref().expect("member outcome");
+    assert!(!outcome.events.contains(&LogEvent::Entry));
+    assert_eq!(
+        outcome.bar_log.entry_block_reason,
+        Some(EntryBlockReason::PortfolioBudget)
+    );
+}
+
+#[tokio::test]
+async fn portfolio_caps_account_equity_at_budget() {
+    let timestamps = entry_timestamps();
+    let position = PositionConfig {
+        c_mode: CapitalMode::EquityRatio,
+        equity_ratio_k: Some(dec!(0.5)),
+        ..PositionConfig::default()
+    };
+    let portfolio_config = PortfolioConfig {
+        equity_budget: Some(dec!(400)),
+        ..PortfolioConfig::default()
+    };
+    let mut account_source = MockAccountSource::default();
+    for _ in 0..timestamps.len() {
+        account_source.push_response(Ok(dec!(1000)));
+    }
+    let mut portfolio = PortfolioRunner::new(position, portfolio_config)
+        .with_account_source(Arc::new(account_source))
+        .with_member("a", dec!(1), portfolio_member_runner(&timestamps));
+
+    let mut last = Vec::new();
+    for ts in timestamps {
+        last = portfolio.run_once_at(ts).await;
+    }
+
+    let outcome = last[0].result.as_ref().expect("member outcome");
+    assert_eq!(
+        outcome.bar_log.notional_base.unwrap() + outcome.bar_log.notional_quote.unwrap(),
+        dec!(200)
+    );
+}
+
+#[tokio::test]
+async fn portfolio_reconciles_shared_account_against_summed_members() {
+    let timestamps = [
+        Utc.timestamp_opt(0, 0).unwrap(),
+        Utc.timestamp_opt(900, 0).unwrap(),
+        Utc.timestamp_opt(1800, 0).unwrap(),
+        Utc.timestamp_opt(2700, 0).unwrap(),
+        Utc.timestamp_opt(3600, 0).unwrap(),
+    ];
+    let source = Arc::new(MockPositionSource::default());
+    let mut portfolio = PortfolioRunner::new(
+        fixed_portfolio_position(dec!(1000)),
+        PortfolioConfig::default(),
+    )
+    .with_member("a", dec!(0.5), portfolio_member_runner(&timestamps))
+    .with_member("b", dec!(0.25), portfolio_member_runner(&timestamps))
+    .with_shared_account(source.clone(), ["a", "b"]);
+
+    for ts in &timestamps[..4] {
+        for outcome in portfolio.run_once_at(*ts).await {
+            outcome.result.expect("member outcome");
+        }
+    }
+    let positions: Vec<PositionSnapshot> = portfolio
+        .members()
+        .iter()
+        .map(|member| {
+            member
+                .runner()
+                .engine()
+                .state()
+                .state()
+                .position
+                .clone()
+                .expect("member position")
+        })
+        .collect();
+    let leg = |qty: rust_decimal::Decimal| {
+        Some(eth_btc_strategy::account::ExchangePosition {
+            qty,
+            entry_price: dec!(100),
+            notional: qty.abs() * dec!(100),
+        })
+    };
+    // The account holds both members' legs netted together.
+    *source.exposure.lock().expect("exposure lock") = PairExposure {
+        base: leg(positions[0].base.qty + positions[1].base.qty),
+        quote: leg(positions[0].quote.qty + positions[1].quote.qty),
+    };
+
+    let outcomes = portfolio.run_once_at(timestamps[4]).await;
+
+    for outcome in outcomes {
+        outcome.result.expect("member outcome");
+    }
+    for member in portfolio.members() {
+        assert_eq!(
+            member.runner().engine().state().state().status,
+            StrategyStatus::InPosition
+        );
+    }
+}
+
+#[tokio::test]
+async fn portfolio_blocks_entries_when_shared_account_differs_from_members() {
+    let timestamps = entry_timestamps();
+    let source = Arc::new(MockPositionSource::default());
+    *source.exposure.lock().expect("exposure lock") = PairExposure {
+        base: Some(eth_btc_strategy::account::ExchangePosition {
+            qty: dec!(0.1),
+            entry_price: dec!(100),
+            notional: dec!(10),
+        }),
+        quote: None,
+    };
+    let mut portfolio = PortfolioRunner::new(
+        fixed_portfolio_position(dec!(1000)),
+        PortfolioConfig::default(),
+    )
+    .with_member("a", dec!(0.5), portfolio_member_runner(&timestamps))
+    .with_member("b", dec!(0.5), portfolio_member_runner(&timestamps))
+    .with_member("c", dec!(0.5), portfolio_member_runner(&timestamps))
+    .with_shared_account(source, ["a", "b"]);
+
+    let mut last = Vec::new();
+    for ts in timestamps {
+        last = portfolio.run_once_at(ts).await;
+    }
+
+    for outcome in &last[..2] {
+        let outcome = outcome.result.as_ref().expect("shared member outcome");
+        assert!(!outcome.events.contains(&LogEvent::Entry));
+        assert_eq!(
+            outcome.bar_log.entry_block_reason,
+            Some(EntryBlockReason::PortfolioBudget)
+        );
+    }
+    let separate = last[2].result.as_ref().expect("separate member outcome");
+    assert!(separate.events.contains(&LogEvent::Entry));
+}
@@ -1,9 +1,12 @@
+use chrono::{TimeZone, Utc};
 use rust_decimal_macros::dec;
 
-use eth_btc_strategy::config::{PersistentExtremeConfig, StaleCrossConfig, StrategyConfig};
+use eth_btc_strategy::config::{
+    PersistentExtremeConfig, PositionConfig, StaleCrossConfig, StrategyConfig,
+};
 use eth_btc_strategy::core::TradeDirection;
-use eth_btc_strategy::signals::EntrySignalDetector;
-use eth_btc_strategy::state::StrategyStatus;
+use eth_btc_strategy::signals::{EntrySignalDetector, ScaleInSignalDetector};
+use eth_btc_strategy::state::{PositionLeg, PositionSnapshot, StrategyStatus};
 
 #[test]
 fn entry_signal_requires_crossing_and_flat_state() {
@@ -19,7 +22,7 @@ fn entry_signal_requires_crossing_and_flat_state() {
     let signal = detector
         .update(Some(dec!(1.6)), StrategyStatus::Flat)
         .unwrap();
-    assert_eq!(signal.direction, TradeDirection::ShortEthLongBtc);
+    assert_eq!(signal.direction, TradeDirection::ShortBaseLongQuote);
 
     let no_repeat = detector
         .update(Some(dec!(1.7)), StrategyStatus::Flat)
@@ -31,7 +34,7 @@ fn entry_signal_requires_crossing_and_flat_state() {
 }
 
 #[test]
-fn entry_signal_negative_z_goes_long_eth_short_btc() {
+fn entry_signal_negative_z_goes_long_base_short_quote() {
     let config = StrategyConfig::default();
     let mut detector = EntrySignalDetector::new(config);
 
@@ -40,7 +43,7 @@ fn entry_signal_negative_z_goes_long_eth_short_btc() {
         .update(Some(dec!(-1.6)), StrategyStatus::Flat)
         .unwrap();
 
-    assert_eq!(signal.direction, TradeDirection::LongEthShortBtc);
+    assert_eq!(signal.direction, TradeDirection::LongBaseShortQuote);
 }
 
 #[test]
@@ -80,7 +83,7 @@ fn stale_cross_signal_can_recover_after_cooldown_release_when_reverting() {
     let signal = detector
         .update(Some(dec!(1.6)), StrategyStatus::Flat)
         .expect("expected cooldown-aware stale-cross recovery");
-    assert_eq!(signal.direction, TradeDirection::ShortEthLongBtc);
+    assert_eq!(signal.direction, TradeDirection::ShortBaseLongQuote);
 }
 
 #[test]
@@ -142,8 +145,8 @@ fn persistent_extreme_signal_can_enter_without_new_crossing() {
     let persistent = PersistentExtremeConfig {
         enabled: true,
         min_abs_z: dec!(1.4),
-        allow_long_eth_short_btc: false,
-        allow_short_eth_long_btc: true,
+        allow_long_base_short_quote: false,
+        allow_short_base_long_quote: true,
     };
     let mut detector =
         EntrySignalDetector::with_entry_controls(config, StaleCrossConfig::default(), persistent);
@@ -157,7 +160,7 @@ fn persistent_extreme_signal_can_enter_without_new_crossing() {
     let signal = detector
         .update(Some(dec!(1.7)), StrategyStatus::Flat)
         .expect("expected persistent positive extreme entry");
-    assert_eq!(signal.direction, TradeDirection::ShortEthLongBtc);
+    assert_eq!(signal.direction, TradeDirection::ShortBaseLongQuote);
 }
 
 #[test]
@@ -166,8 +169,8 @@ fn persistent_extreme_signal_respects_direction_flags() {
     let persistent = PersistentExtremeConfig {
         enabled: true,
         min_abs_z: dec!(1.4),
-        allow_long_eth_short_btc: false,
-        allow_short_eth_long_btc: true,
+        allow_long_base_short_quote: false,
+        allow_short_base_long_quote: true,
     };
     let mut detector =
         EntrySignalDetector::with_entry_controls(config, StaleCrossConfig::default(), persistent);
@@ -177,3 +180,56 @@ fn persistent_extreme_signal_respects_direction_flags() {
 
     assert!(signal.is_none());
 }
+
+#[test]
+fn scale_in_signal_fires_on_next_level_in_position_direction() {
+    let position_config = PositionConfig {
+        max_position_groups: 3,
+        scale_in_step_z: dec!(0.5),
+        ..PositionConfig::default()
+    };
+    let mut detector = ScaleInSignalDetector::new(StrategyConfig::default(), position_config);
+    let position = PositionSnapshot {
+        direction: TradeDirection::ShortBaseLongQuote,
+        entry_time: Utc.timestamp_opt(0, 0).unwrap(),
+        base: PositionLeg {
+            qty: dec!(-1),
+            avg_price: dec!(100),
+            notional: dec!(100),
+        },
+        quote: PositionLeg {
+            qty: dec!(1),
+            avg_price: dec!(100),
+            notional: dec!(100),
+        },
+    };
+    let status = StrategyStatus::InPosition;
+
+    assert!(
+        detector
+            .update(Some(dec!(1.6)), status, Some(&position), 1)
+            .is_none()
+    );
+    let signal = detector
+        .update(Some(dec!(2.1)), status, Some(&position), 1)
+        .unwrap();
+    assert_eq!(signal.direction, TradeDirection::ShortBaseLongQuote);
+    assert_eq!(signal.zscore, dec!(2.1));
+
+    // Level 2 is entry_z + 2 * step = 2.5; staying above 2.0 does not re-fire level 1.
+    assert!(
+        detector
+            .update(Some(dec!(2.2)), status, Some(&position), 2)
+            .is_none()
+    );
+    assert!(
+        detector
+            .update(Some(dec!(2.6)), status, Some(&position), 3)
+            .is_none()
+    );
+    assert!(
+        detector
+            .update(Some(dec!(-2.1)), status, Some(&position), 1)
+            .is_none()
+    );
+}
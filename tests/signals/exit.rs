@@ -4,18 +4,18 @@ use rust_decimal_macros::dec;
 use eth_btc_strategy::config::{RiskConfig, StrategyConfig};
 use eth_btc_strategy::core::{ExitReason, TradeDirection};
 use eth_btc_strategy::signals::ExitSignalDetector;
-use eth_btc_strategy::state::{PositionLeg, PositionSnapshot, StrategyStatus};
+use eth_btc_strategy::state::{PositionGroup, PositionLeg, PositionSnapshot, StrategyStatus};
 
 fn sample_position(entry_time: i64) -> PositionSnapshot {
     PositionSnapshot {
-        direction: TradeDirection::LongEthShortBtc,
+        direction: TradeDirection::LongBaseShortQuote,
         entry_time: Utc.timestamp_opt(entry_time, 0).unwrap(),
-        eth: PositionLeg {
+        base: PositionLeg {
             qty: dec!(1),
             avg_price: dec!(100),
             notional: dec!(100),
         },
-        btc: PositionLeg {
+        quote: PositionLeg {
             qty: dec!(-1),
             avg_price: dec!(200),
             notional: dec!(200),
@@ -25,8 +25,10 @@ fn sample_position(entry_time: i64) -> PositionSnapshot {
 
 #[test]
 fn exit_signal_take_profit_with_confirmation() {
-    let mut risk = RiskConfig::default();
-    risk.confirm_bars_tp = 2;
+    let risk = RiskConfig {
+        confirm_bars_tp: 2,
+        ..RiskConfig::default()
+    };
 
     let mut detector = ExitSignalDetector::new(StrategyConfig::default(), risk);
     let position = sample_position(0);
@@ -78,3 +80,28 @@ fn exit_signal_time_stop_when_no_tp_sl() {
     );
     assert!(matches!(signal, Some(signal) if signal.reason == ExitReason::TimeStop));
 }
+
+#[test]
+fn exit_signal_group_uses_scaled_take_profit_and_own_hold_time() {
+    let detector = ExitSignalDetector::new(StrategyConfig::default(), RiskConfig::default())
+        .with_scale_in_step_z(dec!(0.5));
+    let group = PositionGroup::from_snapshot(&sample_position(60 * 60 * 10), Some(dec!(-2.0)));
+    let now = Utc.timestamp_opt(60 * 60 * 49, 0).unwrap();
+
+    assert!(
+        detector
+            .evaluate_group(Some(dec!(0.5)), 0, &group, now)
+            .is_none()
+    );
+    assert!(
+        detector
+            .evaluate_group(Some(dec!(-1.0)), 1, &group, now)
+            .is_none()
+    );
+    let take_profit = detector.evaluate_group(Some(dec!(-0.9)), 1, &group, now);
+    assert!(matches!(take_profit, Some(signal) if signal.reason == ExitReason::TakeProfit));
+
+    let later = Utc.timestamp_opt(60 * 60 * 58, 0).unwrap();
+    let time_stop = detector.evaluate_group(Some(dec!(-1.0)), 1, &group, later);
+    assert!(matches!(time_stop, Some(signal) if signal.reason == ExitReason::TimeStop));
+}
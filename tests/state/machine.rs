@@ -4,20 +4,20 @@ use rust_decimal_macros::dec;
 use eth_btc_strategy::config::RiskConfig;
 use eth_btc_strategy::core::{ExitReason, TradeDirection};
 use eth_btc_strategy::state::{
-    PendingEntrySnapshot, PositionLeg, PositionSnapshot, StateError, StateMachine, StrategyState,
-    StrategyStatus,
+    PendingEntrySnapshot, PositionGroup, PositionLeg, PositionSnapshot, StateError, StateMachine,
+    StrategyState, StrategyStatus,
 };
 
 fn sample_position(timestamp: i64) -> PositionSnapshot {
     PositionSnapshot {
-        direction: TradeDirection::LongEthShortBtc,
+        direction: TradeDirection::LongBaseShortQuote,
         entry_time: Utc.timestamp_opt(timestamp, 0).unwrap(),
-        eth: PositionLeg {
+        base: PositionLeg {
             qty: dec!(1),
             avg_price: dec!(100),
             notional: dec!(100),
         },
-        btc: PositionLeg {
+        quote: PositionLeg {
             qty: dec!(-1),
             avg_price: dec!(200),
             notional: dec!(200),
@@ -87,6 +87,7 @@ fn state_machine_hydrate_restores_state() {
         pending_entry: None,
         cooldown_until: None,
         cumulative_realized_pnl: dec!(0),
+        groups: Vec::new(),
     };
 
     machine.hydrate(state).unwrap();
@@ -102,13 +103,14 @@ fn state_machine_pending_entry_stays_pending_until_explicit_cancel() {
 
     machine
         .enter_pending(PendingEntrySnapshot {
-            direction: TradeDirection::LongEthShortBtc,
-            eth_qty: dec!(1),
-            btc_qty: dec!(-1),
-            eth_order_id: 11,
-            btc_order_id: 22,
+            direction: TradeDirection::LongBaseShortQuote,
+            base_qty: dec!(1),
+            quote_qty: dec!(-1),
+            base_order_id: 11,
+            quote_order_id: 22,
             submitted_at: Utc.timestamp_opt(100, 0).unwrap(),
             expires_at: Utc.timestamp_opt(200, 0).unwrap(),
+            entry_z: None,
         })
         .unwrap();
 
@@ -117,3 +119,123 @@ fn state_machine_pending_entry_stays_pending_until_explicit_cancel() {
     assert_eq!(machine.state().status, StrategyStatus::PendingEntry);
     assert!(machine.state().pending_entry.is_some());
 }
+
+#[test]
+fn state_machine_scales_in_and_exits_groups() {
+    let mut machine = StateMachine::new(RiskConfig::default());
+    machine
+        .enter_with_entry_z(
+            sample_position(100),
+            Some(dec!(-1.5)),
+            Utc.timestamp_opt(100, 0).unwrap(),
+        )
+        .unwrap();
+    assert_eq!(machine.state().groups.len(), 1);
+    assert_eq!(machine.state().groups[0].entry_z, Some(dec!(-1.5)));
+
+    let scale_in = PositionGroup {
+        entry_time: Utc.timestamp_opt(200, 0).unwrap(),
+        entry_z: Some(dec!(-2.0)),
+        base: PositionLeg {
+            qty: dec!(1),
+            avg_price: dec!(80),
+            notional: dec!(80),
+        },
+        quote: PositionLeg {
+            qty: dec!(-1),
+            avg_price: dec!(220),
+            notional: dec!(220),
+        },
+    };
+    machine.scale_in(scale_in.clone()).unwrap();
+
+    let position = machine.state().position.clone().unwrap();
+    assert_eq!(machine.state().groups.len(), 2);
+    assert_eq!(position.entry_time, Utc.timestamp_opt(100, 0).unwrap());
+    assert_eq!(position.base.qty, dec!(2));
+    assert_eq!(position.base.avg_price, dec!(90));
+    assert_eq!(position.quote.qty, dec!(-2));
+    assert_eq!(position.quote.avg_price, dec!(210));
+
+    machine.exit_group(0).unwrap();
+    assert_eq!(machine.state().groups, vec![scale_in.clone()]);
+    assert_eq!(machine.state().position.as_ref().unwrap().base.qty, dec!(1));
+    assert_eq!(
+        machine.state().position.as_ref().unwrap().entry_time,
+        scale_in.entry_time
+    );
+
+    assert!(matches!(
+        machine.exit_group(0),
+        Err(StateError::InvalidTransition(_))
+    ));
+    machine
+        .exit(ExitReason::TakeProfit, Utc.timestamp_opt(300, 0).unwrap())
+        .unwrap();
+    assert!(machine.state().groups.is_empty());
+}
+
+#[test]
+fn state_machine_treats_legacy_position_as_single_group() {
+    let mut machine = StateMachine::new(RiskConfig::default());
+    let position = sample_position(100);
+    machine
+        .hydrate(StrategyState {
+            status: StrategyStatus::InPosition,
+            position: Some(position.clone()),
+            pending_entry: None,
+            cooldown_until: None,
+            cumulative_realized_pnl: dec!(0),
+            groups: Vec::new(),
+        })
+        .unwrap();
+
+    let groups = machine.state().position_groups();
+    assert_eq!(groups, vec![PositionGroup::from_snapshot(&position, None)]);
+
+    machine
+        .scale_in(PositionGroup::from_snapshot(&sample_position(200), None))
+        .unwrap();
+    assert_eq!(machine.state().groups.len(), 2);
+    assert_eq!(machine.state().position.as_ref().unwrap().base.qty, dec!(2));
+}
+
+#[test]
+fn state_machine_hydrate_rejects_groups_that_do_not_match_position() {
+    let mut machine = StateMachine::new(RiskConfig::default());
+    let position = sample_position(100);
+    let err = machine.hydrate(StrategyState {
+        status: StrategyStatus::InPosition,
+        position: Some(position.clone()),
+        pending_entry: None,
+        cooldown_until: None,
+        cumulative_realized_pnl: dec!(0),
+        groups: vec![
+            PositionGroup::from_snapshot(&position, None),
+            PositionGroup::from_snapshot(&position, None),
+        ],
+    });
+
+    assert!(matches!(err, Err(StateError::InvalidTransition(_))));
+}
+
+#[test]
+fn state_machine_confirmed_pending_entry_keeps_entry_z() {
+    let mut machine = StateMachine::new(RiskConfig::default());
+    machine
+        .enter_pending(PendingEntrySnapshot {
+            direction: TradeDirection::LongBaseShortQuote,
+            base_qty: dec!(1),
+            quote_qty: dec!(-1),
+            base_order_id: 11,
+            quote_order_id: 22,
+            submitted_at: Utc.timestamp_opt(100, 0).unwrap(),
+            expires_at: Utc.timestamp_opt(200, 0).unwrap(),
+            entry_z: Some(dec!(-1.6)),
+        })
+        .unwrap();
+    machine.confirm_pending_entry(sample_position(150)).unwrap();
+
+    assert_eq!(machine.state().groups.len(), 1);
+    assert_eq!(machine.state().groups[0].entry_z, Some(dec!(-1.6)));
+}
@@ -2,6 +2,7 @@ use chrono::{TimeZone, Utc};
 use rust_decimal_macros::dec;
 
 use eth_btc_strategy::core::TradeDirection;
+use eth_btc_strategy::indicators::KalmanState;
 use eth_btc_strategy::state::{
     PositionLeg, PositionSnapshot, StateStore, StrategyState, StrategyStatus,
 };
@@ -12,14 +13,14 @@ fn state_store_round_trip() {
     let state = StrategyState {
         status: StrategyStatus::InPosition,
         position: Some(PositionSnapshot {
-            direction: TradeDirection::ShortEthLongBtc,
+            direction: TradeDirection::ShortBaseLongQuote,
             entry_time: Utc.timestamp_opt(100, 0).unwrap(),
-            eth: PositionLeg {
+            base: PositionLeg {
                 qty: dec!(-1),
                 avg_price: dec!(100),
                 notional: dec!(100),
             },
-            btc: PositionLeg {
+            quote: PositionLeg {
                 qty: dec!(1),
                 avg_price: dec!(200),
                 notional: dec!(200),
@@ -28,6 +29,7 @@ fn state_store_round_trip() {
         pending_entry: None,
         cooldown_until: None,
         cumulative_realized_pnl: dec!(0),
+        groups: Vec::new(),
     };
 
     store.save(&state).unwrap();
@@ -36,6 +38,23 @@ fn state_store_round_trip() {
     assert_eq!(loaded, state);
 }
 
+#[test]
+fn state_store_round_trips_kalman_state() {
+    let store = StateStore::new_in_memory().unwrap();
+    assert!(store.load_kalman_state().unwrap().is_none());
+
+    let state = KalmanState {
+        alpha: dec!(-3.9),
+        beta: dec!(1.02),
+        covariance: [[dec!(0.01), dec!(-0.001)], [dec!(-0.001), dec!(0.0002)]],
+        updates: 96,
+        last_timestamp: Some(Utc.timestamp_opt(900, 0).unwrap()),
+    };
+    store.save_kalman_state(&state).unwrap();
+
+    assert_eq!(store.load_kalman_state().unwrap(), Some(state));
+}
+
 #[test]
 fn strategy_state_defaults_missing_cumulative_pnl() {
     let state: StrategyState = serde_json::from_str(
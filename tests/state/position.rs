@@ -7,14 +7,14 @@ use eth_btc_strategy::state::{PositionLeg, PositionSnapshot};
 #[test]
 fn position_residual_detection() {
     let position = PositionSnapshot {
-        direction: TradeDirection::LongEthShortBtc,
+        direction: TradeDirection::LongBaseShortQuote,
         entry_time: Utc.timestamp_opt(0, 0).unwrap(),
-        eth: PositionLeg {
+        base: PositionLeg {
             qty: dec!(1),
             avg_price: dec!(100),
             notional: dec!(100),
         },
-        btc: PositionLeg {
+        quote: PositionLeg {
             qty: dec!(0),
             avg_price: dec!(200),
             notional: dec!(0),
@@ -25,7 +25,7 @@ fn position_residual_detection() {
     assert!(!position.is_flat());
 
     let position = PositionSnapshot {
-        btc: PositionLeg {
+        quote: PositionLeg {
             qty: dec!(1),
             avg_price: dec!(200),
             notional: dec!(200),
@@ -36,12 +36,12 @@ fn position_residual_detection() {
     assert!(!position.has_residual());
 
     let flat = PositionSnapshot {
-        eth: PositionLeg {
+        base: PositionLeg {
             qty: dec!(0),
             avg_price: dec!(0),
             notional: dec!(0),
         },
-        btc: PositionLeg {
+        quote: PositionLeg {
             qty: dec!(0),
             avg_price: dec!(0),
             notional: dec!(0),
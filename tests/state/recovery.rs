@@ -3,7 +3,8 @@ use rust_decimal_macros::dec;
 
 use eth_btc_strategy::core::TradeDirection;
 use eth_btc_strategy::state::{
-    PositionLeg, PositionSnapshot, RecoveryAction, StrategyState, StrategyStatus, recover_state,
+    PositionGroup, PositionLeg, PositionSnapshot, RecoveryAction, StrategyState, StrategyStatus,
+    recover_state,
 };
 
 #[test]
@@ -14,6 +15,7 @@ fn recovery_handles_missing_position() {
         pending_entry: None,
         cooldown_until: None,
         cumulative_realized_pnl: dec!(0),
+        groups: Vec::new(),
     };
 
     let report = recover_state(state, Utc.timestamp_opt(0, 0).unwrap());
@@ -24,14 +26,14 @@ fn recovery_handles_missing_position() {
 #[test]
 fn recovery_flags_residual_position() {
     let position = PositionSnapshot {
-        direction: TradeDirection::LongEthShortBtc,
+        direction: TradeDirection::LongBaseShortQuote,
         entry_time: Utc.timestamp_opt(0, 0).unwrap(),
-        eth: PositionLeg {
+        base: PositionLeg {
             qty: dec!(1),
             avg_price: dec!(100),
             notional: dec!(100),
         },
-        btc: PositionLeg {
+        quote: PositionLeg {
             qty: dec!(0),
             avg_price: dec!(200),
             notional: dec!(0),
@@ -44,6 +46,7 @@ fn recovery_flags_residual_position() {
         pending_entry: None,
         cooldown_until: None,
         cumulative_realized_pnl: dec!(0),
+        groups: Vec::new(),
     };
 
     let report = recover_state(state, Utc.timestamp_opt(0, 0).unwrap());
@@ -55,3 +58,50 @@ fn recovery_flags_residual_position() {
     );
     assert!(!report.alerts.is_empty());
 }
+
+#[test]
+fn recovery_collapses_groups_that_do_not_match_position() {
+    let position = PositionSnapshot {
+        direction: TradeDirection::ShortBaseLongQuote,
+        entry_time: Utc.timestamp_opt(0, 0).unwrap(),
+        base: PositionLeg {
+            qty: dec!(-2),
+            avg_price: dec!(100),
+            notional: dec!(200),
+        },
+        quote: PositionLeg {
+            qty: dec!(1),
+            avg_price: dec!(200),
+            notional: dec!(200),
+        },
+    };
+    let state = StrategyState {
+        status: StrategyStatus::InPosition,
+        position: Some(position.clone()),
+        pending_entry: None,
+        cooldown_until: None,
+        cumulative_realized_pnl: dec!(0),
+        groups: vec![PositionGroup {
+            entry_time: position.entry_time,
+            entry_z: Some(dec!(1.5)),
+            base: PositionLeg {
+                qty: dec!(-1),
+                avg_price: dec!(100),
+                notional: dec!(100),
+            },
+            quote: PositionLeg {
+                qty: dec!(0.5),
+                avg_price: dec!(200),
+                notional: dec!(100),
+            },
+        }],
+    };
+
+    let report = recover_state(state, Utc.timestamp_opt(0, 0).unwrap());
+    assert_eq!(report.state.status, StrategyStatus::InPosition);
+    assert_eq!(
+        report.state.groups,
+        vec![PositionGroup::from_snapshot(&position, None)]
+    );
+    assert!(!report.alerts.is_empty());
+}
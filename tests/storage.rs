@@ -1,7 +1,8 @@
 use chrono::{TimeZone, Utc};
 use rust_decimal_macros::dec;
 
-use eth_btc_strategy::storage::{PriceBarRecord, PriceStore};
+use eth_btc_strategy::config::{BarInterval, PairConfig};
+use eth_btc_strategy::storage::{PriceBarRecord, PriceStore, price_table_name};
 
 #[test]
 fn price_store_saves_and_loads_record() {
@@ -9,15 +10,21 @@ fn price_store_saves_and_loads_record() {
     let timestamp = Utc.timestamp_opt(0, 0).unwrap();
     let record = PriceBarRecord {
         timestamp,
-        eth_mid: Some(dec!(2000)),
-        eth_mark: Some(dec!(2001)),
-        eth_close: Some(dec!(1999)),
-        btc_mid: Some(dec!(30000)),
-        btc_mark: Some(dec!(30010)),
-        btc_close: Some(dec!(29990)),
-        funding_eth: Some(dec!(0.0001)),
-        funding_btc: Some(dec!(0.0002)),
+        base_mid: Some(dec!(2000)),
+        base_mark: Some(dec!(2001)),
+        base_close: Some(dec!(1999)),
+        quote_mid: Some(dec!(30000)),
+        quote_mark: Some(dec!(30010)),
+        quote_close: Some(dec!(29990)),
+        funding_base: Some(dec!(0.0001)),
+        funding_quote: Some(dec!(0.0002)),
         funding_interval_hours: Some(8),
+        base_open: Some(dec!(1995)),
+        base_high: Some(dec!(2010)),
+        base_low: Some(dec!(1990)),
+        quote_open: Some(dec!(29980)),
+        quote_high: Some(dec!(30050)),
+        quote_low: Some(dec!(29950)),
     };
 
     store.save(&record).unwrap();
@@ -36,43 +43,61 @@ fn price_store_loads_range_in_order() {
     store
         .save(&PriceBarRecord {
             timestamp: t2,
-            eth_mid: Some(dec!(2000)),
-            eth_mark: None,
-            eth_close: None,
-            btc_mid: Some(dec!(30000)),
-            btc_mark: None,
-            btc_close: None,
-            funding_eth: None,
-            funding_btc: None,
+            base_mid: Some(dec!(2000)),
+            base_mark: None,
+            base_close: None,
+            quote_mid: Some(dec!(30000)),
+            quote_mark: None,
+            quote_close: None,
+            funding_base: None,
+            funding_quote: None,
             funding_interval_hours: None,
+            base_open: None,
+            base_high: None,
+            base_low: None,
+            quote_open: None,
+            quote_high: None,
+            quote_low: None,
         })
         .unwrap();
     store
         .save(&PriceBarRecord {
             timestamp: t1,
-            eth_mid: Some(dec!(1900)),
-            eth_mark: None,
-            eth_close: None,
-            btc_mid: Some(dec!(29000)),
-            btc_mark: None,
-            btc_close: None,
-            funding_eth: None,
-            funding_btc: None,
+            base_mid: Some(dec!(1900)),
+            base_mark: None,
+            base_close: None,
+            quote_mid: Some(dec!(29000)),
+            quote_mark: None,
+            quote_close: None,
+            funding_base: None,
+            funding_quote: None,
             funding_interval_hours: None,
+            base_open: None,
+            base_high: None,
+            base_low: None,
+            quote_open: None,
+            quote_high: None,
+            quote_low: None,
         })
         .unwrap();
     store
         .save(&PriceBarRecord {
             timestamp: t3,
-            eth_mid: Some(dec!(2100)),
-            eth_mark: None,
-            eth_close: None,
-            btc_mid: Some(dec!(31000)),
-            btc_mark: None,
-            btc_close: None,
-            funding_eth: None,
-            funding_btc: None,
+            base_mid: Some(dec!(2100)),
+            base_mark: None,
+            base_close: None,
+            quote_mid: Some(dec!(31000)),
+            quote_mark: None,
+            quote_close: None,
+            funding_base: None,
+            funding_quote: None,
             funding_interval_hours: None,
+            base_open: None,
+            base_high: None,
+            base_low: None,
+            quote_open: None,
+            quote_high: None,
+            quote_low: None,
         })
         .unwrap();
 
@@ -82,3 +107,140 @@ fn price_store_loads_range_in_order() {
     assert_eq!(records[1].timestamp, t2);
     assert_eq!(records[2].timestamp, t3);
 }
+
+#[test]
+fn price_store_adds_ohlc_columns_to_existing_table() {
+    let path = std::env::temp_dir().join(format!("legacy-{}.sqlite", uuid::Uuid::new_v4()));
+    let conn = rusqlite::Connection::open(&path).unwrap();
+    conn.execute(
+        "CREATE TABLE price_bars (timestamp TEXT PRIMARY KEY, eth_mid TEXT, eth_mark TEXT, eth_close TEXT, btc_mid TEXT, btc_mark TEXT, btc_close TEXT, funding_eth TEXT, funding_btc TEXT, funding_interval_hours INTEGER, created_at TEXT NOT NULL)",
+        [],
+    )
+    .unwrap();
+    conn.execute(
+        "INSERT INTO price_bars (timestamp, eth_mid, btc_mid, created_at) VALUES ('1970-01-01T00:00:00+00:00', '2000', '30000', '1970-01-01T00:00:00+00:00')",
+        [],
+    )
+    .unwrap();
+    drop(conn);
+
+    let store = PriceStore::new(path.to_string_lossy().as_ref()).unwrap();
+    let legacy = store
+        .load(Utc.timestamp_opt(0, 0).unwrap())
+        .unwrap()
+        .expect("legacy record");
+    assert_eq!(legacy.base_mid, Some(dec!(2000)));
+    assert_eq!(legacy.base_high, None);
+
+    let record = PriceBarRecord {
+        base_high: Some(dec!(2010)),
+        quote_low: Some(dec!(29900)),
+        ..legacy
+    };
+    store.save(&record).unwrap();
+    assert_eq!(store.load(record.timestamp).unwrap(), Some(record));
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn price_table_name_is_scoped_by_pair_and_interval() {
+    let default = PairConfig::default();
+    assert_eq!(price_table_name(&default, BarInterval::M15), "price_bars");
+    assert_eq!(price_table_name(&default, BarInterval::H1), "price_bars_1h");
+    let pair = PairConfig {
+        base_coin: "SOL".to_string(),
+        quote_coin: "kPEPE".to_string(),
+    };
+    assert_eq!(
+        price_table_name(&pair, BarInterval::M15),
+        "price_bars_sol_kpepe"
+    );
+    assert_eq!(
+        price_table_name(&pair, BarInterval::M5),
+        "price_bars_sol_kpepe_5m"
+    );
+}
+
+#[test]
+fn price_store_keeps_pairs_in_separate_tables() {
+    let path = std::env::temp_dir().join(format!("pairs-{}.sqlite", uuid::Uuid::new_v4()));
+    let path = path.to_string_lossy().to_string();
+    let pair = PairConfig {
+        base_coin: "SOL".to_string(),
+        quote_coin: "ETH".to_string(),
+    };
+    let timestamp = Utc.timestamp_opt(0, 0).unwrap();
+    let record = PriceBarRecord {
+        timestamp,
+        base_mid: Some(dec!(150)),
+        base_mark: None,
+        base_close: None,
+        quote_mid: Some(dec!(2000)),
+        quote_mark: None,
+        quote_close: None,
+        funding_base: None,
+        funding_quote: None,
+        funding_interval_hours: None,
+        base_open: None,
+        base_high: None,
+        base_low: None,
+        quote_open: None,
+        quote_high: None,
+        quote_low: None,
+    };
+
+    PriceStore::new_for_pair(&path, &pair)
+        .unwrap()
+        .save(&record)
+        .unwrap();
+
+    let default_store = PriceStore::new(&path).unwrap();
+    assert!(default_store.load(timestamp).unwrap().is_none());
+    let pair_store = PriceStore::new_for_pair(&path, &pair).unwrap();
+    assert_eq!(pair_store.load(timestamp).unwrap(), Some(record));
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn price_store_keeps_intervals_in_separate_tables() {
+    let path = std::env::temp_dir().join(format!("series-{}.sqlite", uuid::Uuid::new_v4()));
+    let path = path.to_string_lossy().to_string();
+    let pair = PairConfig::default();
+    let timestamp = Utc.timestamp_opt(3600, 0).unwrap();
+    let record = PriceBarRecord {
+        timestamp,
+        base_mid: Some(dec!(2000)),
+        base_mark: None,
+        base_close: None,
+        quote_mid: Some(dec!(30000)),
+        quote_mark: None,
+        quote_close: None,
+        funding_base: None,
+        funding_quote: None,
+        funding_interval_hours: None,
+        base_open: None,
+        base_high: None,
+        base_low: None,
+        quote_open: None,
+        quote_high: None,
+        quote_low: None,
+    };
+
+    PriceStore::new_for_series(&path, &pair, BarInterval::H1)
+        .unwrap()
+        .save(&record)
+        .unwrap();
+
+    assert!(
+        PriceStore::new_for_pair(&path, &pair)
+            .unwrap()
+            .load(timestamp)
+            .unwrap()
+            .is_none()
+    );
+    let hourly = PriceStore::new_for_series(&path, &pair, BarInterval::H1).unwrap();
+    assert_eq!(hourly.load(timestamp).unwrap(), Some(record));
+
+    std::fs::remove_file(&path).unwrap();
+}